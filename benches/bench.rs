@@ -1,3 +1,5 @@
+// Run with `RUSTFLAGS='--cfg=bench' cargo +nightly bench`.
+#![cfg(bench)]
 #![feature(test)]
 
 extern crate bip39;
@@ -2,7 +2,7 @@
 
 set -ex
 
-FEATURES="serde rand all-languages chinese-simplified chinese-traditional czech french italian japanese korean portuguese spanish"
+FEATURES="serde rand simd wipe non-standard ascii-only cbor compact-wordlists diceware compat-tiny-bip39 all-languages chinese-simplified chinese-traditional czech french italian japanese korean portuguese spanish"
 
 cargo --version
 rustc --version
@@ -10,6 +10,9 @@ rustc --version
 # Pin dependencies as required if we are using MSRV toolchain.
 if cargo --version | grep "1\.41"; then
     cargo update --package "bitcoin_hashes" --precise "0.12.0"
+    cargo update --package "serde_json" --precise "1.0.39"
+    cargo update --package "serde_test" --precise "1.0.104"
+    cargo update --package "serde" --precise "1.0.104"
 fi
 
 echo "********* Testing std *************"
@@ -28,6 +31,9 @@ done
 
 if cargo --version | grep -v "1\.41"; then
     cargo build --verbose --features="zeroize" --no-default-features
+    cargo build --verbose --features="rand_v09" --no-default-features
+    cargo build --verbose --features="rand_core_v09" --no-default-features
+    cargo test --verbose --features="rand_v09"
 fi
 
 if [ "$DO_NO_STD" = true ]
@@ -40,10 +46,28 @@ then
     cargo build --verbose 
     # Test no_std
     cargo test --verbose --no-default-features
+    cargo test --verbose --no-default-features --features="ascii-only"
+
+    # The self dev-dependency enables `std`, so leave it out to make sure the
+    # ascii-only build really goes without the `unicode-normalization` dependency.
+    if cargo --version | grep nightly; then
+        cargo build --verbose -Z avoid-dev-deps --no-default-features --features="ascii-only"
+        if cargo tree -Z avoid-dev-deps --no-default-features --features="ascii-only" --edges=normal | grep unicode-normalization; then
+            echo "the ascii-only build depends on unicode-normalization"
+            exit 1
+        fi
+    fi
+
+    # Build and test no_std with alloc
+    cargo build --verbose --no-default-features --features="alloc"
+    cargo test --verbose --no-default-features --features="alloc"
 
     # Build all features
     cargo build --verbose --features="$FEATURES" --no-default-features
 
+    # The word lists are provided by the final binary, so only build the library
+    cargo build --verbose --features="external-wordlists all-languages" --no-default-features
+
     # Build specific features
     for feature in ${FEATURES}
     do
@@ -0,0 +1,162 @@
+//! Encoding of arbitrary data as words from the BIP39 word lists.
+//!
+//! This is **not** a BIP39 mnemonic: the data has no checksum and can't be
+//! used to derive a seed. It allows encoding things like pre-shared keys or
+//! backup metadata using the same vocabulary as mnemonics.
+//!
+//! The encoding starts with the length of the data in bytes, encoded in one or
+//! more words carrying 10 bits each, least significant first, with the 11th
+//! bit set on all but the last length word. The data follows, 11 bits per word,
+//! most significant bit first and zero-padded at the end.
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::Language;
+#[cfg(feature = "alloc")]
+use crate::{bits, Error};
+
+/// The number of length bits carried by a word of the length prefix.
+const LENGTH_BITS_PER_WORD: usize = 10;
+
+/// The bit marking that another word of the length prefix follows.
+const LENGTH_CONTINUATION: u16 = 1 << LENGTH_BITS_PER_WORD;
+
+/// The mask selecting the length bits of a word of the length prefix.
+const LENGTH_MASK: u16 = LENGTH_CONTINUATION - 1;
+
+/// The number of words needed to encode the length prefix.
+fn prefix_len(len: usize) -> usize {
+	let mut nb_words = 1;
+	while len.checked_shr((LENGTH_BITS_PER_WORD * nb_words) as u32).unwrap_or(0) != 0 {
+		nb_words += 1;
+	}
+	nb_words
+}
+
+/// The word index encoding the `k`-th 11-bit group of the data.
+fn data_index(data: &[u8], k: usize) -> u16 {
+	let mut idx = 0;
+	for j in 0..11 {
+		let bit = k * 11 + j;
+		let b = data.get(bit / 8).map(|b| b >> (7 - bit % 8) & 1).unwrap_or(0);
+		idx = (idx << 1) | b as u16;
+	}
+	idx
+}
+
+/// Encode the data as words in the given language.
+///
+/// Example:
+///
+/// ```
+/// use bip39::{base2048, Language};
+///
+/// let words: Vec<_> = base2048::encode(Language::English, b"key").collect();
+/// assert_eq!(words.join(" "), "about hidden cloth divorce");
+/// ```
+pub fn encode(language: Language, data: &[u8]) -> impl Iterator<Item = &'static str> + Clone + '_ {
+	let list = language.word_list();
+	let len = data.len();
+	let nb_prefix = prefix_len(len);
+	let nb_data = (len * 8 + 10) / 11;
+
+	let prefix = (0..nb_prefix).map(move |k| {
+		let bits = len >> (LENGTH_BITS_PER_WORD * k);
+		let more = if k + 1 < nb_prefix {
+			LENGTH_CONTINUATION
+		} else {
+			0
+		};
+		(bits as u16 & LENGTH_MASK) | more
+	});
+	let data = (0..nb_data).map(move |k| data_index(data, k));
+	prefix.chain(data).map(move |i| list.get(i as usize).copied().unwrap_or(""))
+}
+
+/// Decode data encoded with [encode] in the given language.
+///
+/// Returns [Error::UnknownWord] for words that are not in the word list and
+/// [Error::BadWordCount] if the number of words doesn't match the encoded length.
+#[cfg(feature = "alloc")]
+pub fn decode(language: Language, s: &str) -> Result<Vec<u8>, Error> {
+	let mut words = s
+		.split_whitespace()
+		.enumerate()
+		.map(|(i, w)| language.find_word(w).ok_or(Error::UnknownWord(i)));
+
+	let mut len = 0usize;
+	let mut nb_prefix = 0;
+	loop {
+		let idx = words.next().ok_or(Error::BadWordCount(nb_prefix))??;
+		let shift = (LENGTH_BITS_PER_WORD * nb_prefix) as u32;
+		nb_prefix += 1;
+		let bits = ((idx & LENGTH_MASK) as usize)
+			.checked_shl(shift)
+			.filter(|b| b >> shift == (idx & LENGTH_MASK) as usize)
+			.ok_or(Error::BadWordCount(nb_prefix))?;
+		len |= bits;
+		if idx & LENGTH_CONTINUATION == 0 {
+			break;
+		}
+	}
+
+	let indices = words.collect::<Result<Vec<u16>, Error>>()?;
+	let nb_data = len.checked_mul(8).and_then(|b| b.checked_add(10)).map(|b| b / 11);
+	if nb_data != Some(indices.len()) {
+		return Err(Error::BadWordCount(nb_prefix + indices.len()));
+	}
+
+	let mut data = vec![0; (indices.len() * 11 + 7) / 8];
+	bits::pack(&indices, &mut data);
+	data.truncate(len);
+	Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_roundtrip() {
+		let data: Vec<u8> = (0..=255).collect();
+		for len in (0..40).chain(1020..1030).map(|l| l % 256).chain(Some(256)) {
+			let data = data.iter().cycle().take(len).cloned().collect::<Vec<_>>();
+			let words = encode(Language::English, &data).collect::<Vec<_>>();
+			assert_eq!(words.len(), prefix_len(len) + (len * 8 + 10) / 11);
+			assert_eq!(decode(Language::English, &words.join(" ")), Ok(data));
+		}
+	}
+
+	#[test]
+	fn test_length_prefix() {
+		assert_eq!(prefix_len(0), 1);
+		assert_eq!(prefix_len(1023), 1);
+		assert_eq!(prefix_len(1024), 2);
+		assert_eq!(
+			prefix_len(usize::max_value()),
+			(usize::max_value().count_ones() as usize + 9) / 10
+		);
+
+		let data = vec![0xff; 1024];
+		let mut words = encode(Language::English, &data);
+		assert_eq!(words.next(), Some(Language::English.word_list()[0x400]));
+		assert_eq!(words.next(), Some(Language::English.word_list()[1]));
+	}
+
+	#[test]
+	fn test_decode_errors() {
+		assert_eq!(decode(Language::English, ""), Err(Error::BadWordCount(0)));
+		assert_eq!(decode(Language::English, "abandon zoo"), Err(Error::BadWordCount(2)));
+		assert_eq!(decode(Language::English, "ability zoo zoo"), Err(Error::BadWordCount(3)));
+		assert_eq!(decode(Language::English, "ability zooo"), Err(Error::UnknownWord(1)));
+		assert_eq!(decode(Language::English, "abandon"), Ok(vec![]));
+		assert_eq!(decode(Language::English, "ability zoo"), Ok(vec![0xff]));
+
+		// A length prefix that can't possibly fit in memory.
+		let huge = "zoo ".repeat(10) + "able";
+		assert_eq!(decode(Language::English, &huge), Err(Error::BadWordCount(7)));
+		let huge = "zoo zoo zoo zoo zoo zoo ability";
+		assert_eq!(decode(Language::English, huge), Err(Error::BadWordCount(7)));
+	}
+}
@@ -0,0 +1,398 @@
+//! Seed derivation for batches of mnemonics, for recovery searches.
+//!
+//! Deriving a seed takes 2048 iterations of HMAC-SHA512, which makes it the
+//! expensive part of checking the candidates of a [recovery] search. This
+//! module derives the seeds of [LANES] mnemonics at once, computing their
+//! SHA512 compressions side by side so that the compiler can use SIMD
+//! instructions for them.
+//!
+//! SIMD instructions are used when the target supports them, like NEON on
+//! aarch64. With the `simd` feature, AVX2 is also used on x86_64 CPUs that
+//! support it, detected at runtime.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::{batch, Mnemonic};
+//!
+//! let a = Mnemonic::from_entropy(&[0x00; 16]).unwrap();
+//! let b = Mnemonic::from_entropy(&[0xff; 16]).unwrap();
+//! let seeds = batch::to_seeds_normalized(&[a.clone(), b.clone()], "TREZOR");
+//! assert_eq!(seeds, vec![a.to_seed_normalized("TREZOR"), b.to_seed_normalized("TREZOR")]);
+//! ```
+//!
+//! [recovery]: crate::recovery
+
+// The SHA512 rounds index their fixed-size state arrays with constants.
+#![allow(clippy::indexing_slicing)]
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use bitcoin_hashes::{hmac, sha512, Hash, HashEngine};
+
+use crate::Mnemonic;
+
+/// The number of seeds derived at once.
+pub const LANES: usize = 4;
+
+/// The number of PBKDF2 iterations of BIP39.
+const ITERATIONS: usize = 2048;
+
+/// The block size of SHA512 in bytes.
+const BLOCK_SIZE: usize = 128;
+
+/// A 64-bit word of every lane.
+type Lanes = [u64; LANES];
+
+/// The SHA512 round constants.
+const K: [u64; 80] = [
+	0x428a2f98d728ae22,
+	0x7137449123ef65cd,
+	0xb5c0fbcfec4d3b2f,
+	0xe9b5dba58189dbbc,
+	0x3956c25bf348b538,
+	0x59f111f1b605d019,
+	0x923f82a4af194f9b,
+	0xab1c5ed5da6d8118,
+	0xd807aa98a3030242,
+	0x12835b0145706fbe,
+	0x243185be4ee4b28c,
+	0x550c7dc3d5ffb4e2,
+	0x72be5d74f27b896f,
+	0x80deb1fe3b1696b1,
+	0x9bdc06a725c71235,
+	0xc19bf174cf692694,
+	0xe49b69c19ef14ad2,
+	0xefbe4786384f25e3,
+	0x0fc19dc68b8cd5b5,
+	0x240ca1cc77ac9c65,
+	0x2de92c6f592b0275,
+	0x4a7484aa6ea6e483,
+	0x5cb0a9dcbd41fbd4,
+	0x76f988da831153b5,
+	0x983e5152ee66dfab,
+	0xa831c66d2db43210,
+	0xb00327c898fb213f,
+	0xbf597fc7beef0ee4,
+	0xc6e00bf33da88fc2,
+	0xd5a79147930aa725,
+	0x06ca6351e003826f,
+	0x142929670a0e6e70,
+	0x27b70a8546d22ffc,
+	0x2e1b21385c26c926,
+	0x4d2c6dfc5ac42aed,
+	0x53380d139d95b3df,
+	0x650a73548baf63de,
+	0x766a0abb3c77b2a8,
+	0x81c2c92e47edaee6,
+	0x92722c851482353b,
+	0xa2bfe8a14cf10364,
+	0xa81a664bbc423001,
+	0xc24b8b70d0f89791,
+	0xc76c51a30654be30,
+	0xd192e819d6ef5218,
+	0xd69906245565a910,
+	0xf40e35855771202a,
+	0x106aa07032bbd1b8,
+	0x19a4c116b8d2d0c8,
+	0x1e376c085141ab53,
+	0x2748774cdf8eeb99,
+	0x34b0bcb5e19b48a8,
+	0x391c0cb3c5c95a63,
+	0x4ed8aa4ae3418acb,
+	0x5b9cca4f7763e373,
+	0x682e6ff3d6b2b8a3,
+	0x748f82ee5defb2fc,
+	0x78a5636f43172f60,
+	0x84c87814a1f0ab72,
+	0x8cc702081a6439ec,
+	0x90befffa23631e28,
+	0xa4506cebde82bde9,
+	0xbef9a3f7b2c67915,
+	0xc67178f2e372532b,
+	0xca273eceea26619c,
+	0xd186b8c721c0c207,
+	0xeada7dd6cde0eb1e,
+	0xf57d4f7fee6ed178,
+	0x06f067aa72176fba,
+	0x0a637dc5a2c898a6,
+	0x113f9804bef90dae,
+	0x1b710b35131c471b,
+	0x28db77f523047d84,
+	0x32caab7b40c72493,
+	0x3c9ebe0a15c9bebc,
+	0x431d67c49c100d4c,
+	0x4cc5d4becb3e42b6,
+	0x597f299cfc657e2a,
+	0x5fcb6fab3ad6faec,
+	0x6c44198c4a475817,
+];
+
+/// The initial SHA512 state.
+const IV: [u64; 8] = [
+	0x6a09e667f3bcc908,
+	0xbb67ae8584caa73b,
+	0x3c6ef372fe94f82b,
+	0xa54ff53a5f1d36f1,
+	0x510e527fade682d1,
+	0x9b05688c2b3e6c1f,
+	0x1f83d9abfb41bd6b,
+	0x5be0cd19137e2179,
+];
+
+/// Apply the function to every lane.
+#[inline(always)]
+fn map<F: Fn(usize) -> u64>(f: F) -> Lanes {
+	let mut res = [0; LANES];
+	for (l, r) in res.iter_mut().enumerate() {
+		*r = f(l);
+	}
+	res
+}
+
+/// Run the SHA512 compression function on every lane.
+#[inline(always)]
+fn compress(state: &mut [Lanes; 8], block: &[Lanes; 16]) {
+	let mut w = [[0u64; LANES]; 80];
+	w[..16].copy_from_slice(block);
+	for t in 16..80 {
+		w[t] = map(|l| {
+			let (w2, w15) = (w[t - 2][l], w[t - 15][l]);
+			let s0 = w15.rotate_right(1) ^ w15.rotate_right(8) ^ (w15 >> 7);
+			let s1 = w2.rotate_right(19) ^ w2.rotate_right(61) ^ (w2 >> 6);
+			w[t - 16][l].wrapping_add(s0).wrapping_add(w[t - 7][l]).wrapping_add(s1)
+		});
+	}
+
+	let mut v = *state;
+	for t in 0..80 {
+		let t1 = map(|l| {
+			let (e, f, g, h) = (v[4][l], v[5][l], v[6][l], v[7][l]);
+			let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+			let ch = (e & f) ^ (!e & g);
+			h.wrapping_add(s1).wrapping_add(ch).wrapping_add(K[t]).wrapping_add(w[t][l])
+		});
+		let t2 = map(|l| {
+			let (a, b, c) = (v[0][l], v[1][l], v[2][l]);
+			let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+			let maj = (a & b) ^ (a & c) ^ (b & c);
+			s0.wrapping_add(maj)
+		});
+		v = [
+			map(|l| t1[l].wrapping_add(t2[l])),
+			v[0],
+			v[1],
+			v[2],
+			map(|l| v[3][l].wrapping_add(t1[l])),
+			v[4],
+			v[5],
+			v[6],
+		];
+	}
+	for (s, v) in state.iter_mut().zip(v.iter()) {
+		*s = map(|l| s[l].wrapping_add(v[l]));
+	}
+}
+
+/// Run the PBKDF2 iterations after the first one on every lane.
+///
+/// `inner` and `outer` are the SHA512 states after the HMAC key blocks and
+/// `u` is the result of the first iteration, the seed is XORed into `seed`.
+#[inline(always)]
+fn iterate(inner: &[Lanes; 8], outer: &[Lanes; 8], u: &mut [Lanes; 8], seed: &mut [Lanes; 8]) {
+	// The padding of a 64-byte message after a 128-byte key block.
+	let mut block = [[0u64; LANES]; 16];
+	block[8] = [1 << 63; LANES];
+	block[15] = [((BLOCK_SIZE + 64) * 8) as u64; LANES];
+
+	for _ in 1..ITERATIONS {
+		block[..8].copy_from_slice(u);
+		let mut state = *inner;
+		compress(&mut state, &block);
+		block[..8].copy_from_slice(&state);
+		*u = *outer;
+		compress(u, &block);
+		for (s, u) in seed.iter_mut().zip(u.iter()) {
+			*s = map(|l| s[l] ^ u[l]);
+		}
+	}
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn iterate_avx2(
+	inner: &[Lanes; 8],
+	outer: &[Lanes; 8],
+	u: &mut [Lanes; 8],
+	seed: &mut [Lanes; 8],
+) {
+	iterate(inner, outer, u, seed)
+}
+
+/// Run the PBKDF2 iterations with the best instructions available.
+fn iterate_dispatch(
+	inner: &[Lanes; 8],
+	outer: &[Lanes; 8],
+	u: &mut [Lanes; 8],
+	seed: &mut [Lanes; 8],
+) {
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	{
+		if is_x86_feature_detected!("avx2") {
+			// Safe because the CPU supports AVX2.
+			return unsafe { iterate_avx2(inner, outer, u, seed) };
+		}
+	}
+	iterate(inner, outer, u, seed)
+}
+
+/// Load big-endian 64-bit words from the bytes into a lane.
+fn load(words: &mut [Lanes], lane: usize, bytes: &[u8]) {
+	for (w, chunk) in words.iter_mut().zip(bytes.chunks(8)) {
+		let mut buf = [0; 8];
+		buf.copy_from_slice(chunk);
+		w[lane] = u64::from_be_bytes(buf);
+	}
+}
+
+/// Derive the seeds of up to [LANES] mnemonics.
+fn derive_lanes(mnemonics: &[Mnemonic], normalized_passphrase: &str, seeds: &mut [[u8; 64]]) {
+	let mut inner = [[0u64; LANES]; 8];
+	for (s, iv) in inner.iter_mut().zip(IV.iter()) {
+		*s = [*iv; LANES];
+	}
+	let mut outer = inner;
+	let mut u = [[0u64; LANES]; 8];
+	let mut ipad = [[0u64; LANES]; 16];
+	let mut opad = [[0u64; LANES]; 16];
+
+	for (lane, m) in mnemonics.iter().enumerate().take(LANES) {
+		// The HMAC key is the phrase, hashed if longer than a block.
+		let mut key = [0u8; BLOCK_SIZE];
+		let phrase_len =
+			m.words().enumerate().map(|(i, w)| w.len() + (i > 0) as usize).sum::<usize>();
+		let mut engine = sha512::Hash::engine();
+		let mut pos = 0;
+		for (i, word) in m.words().enumerate() {
+			let sep = if i > 0 {
+				" "
+			} else {
+				""
+			};
+			for part in &[sep, word] {
+				if phrase_len > BLOCK_SIZE {
+					engine.input(part.as_bytes());
+				} else {
+					key[pos..pos + part.len()].copy_from_slice(part.as_bytes());
+					pos += part.len();
+				}
+			}
+		}
+		if phrase_len > BLOCK_SIZE {
+			key[..64].copy_from_slice(sha512::Hash::from_engine(engine).as_byte_array());
+		}
+
+		let mut pad = [0u8; BLOCK_SIZE];
+		for (p, k) in pad.iter_mut().zip(key.iter()) {
+			*p = k ^ 0x36;
+		}
+		load(&mut ipad, lane, &pad);
+		for (p, k) in pad.iter_mut().zip(key.iter()) {
+			*p = k ^ 0x5c;
+		}
+		load(&mut opad, lane, &pad);
+		#[cfg(any(feature = "wipe", feature = "zeroize"))]
+		crate::wipe::wipe(&mut pad);
+
+		// The first iteration has the salt as message, it is computed separately.
+		let mut hmac = hmac::HmacEngine::<sha512::Hash>::new(&key);
+		hmac.input(b"mnemonic");
+		hmac.input(normalized_passphrase.as_bytes());
+		hmac.input(&1u32.to_be_bytes());
+		load(&mut u, lane, &hmac::Hmac::from_engine(hmac).to_byte_array());
+		#[cfg(any(feature = "wipe", feature = "zeroize"))]
+		crate::wipe::wipe(&mut key);
+	}
+	compress(&mut inner, &ipad);
+	compress(&mut outer, &opad);
+	#[cfg(any(feature = "wipe", feature = "zeroize"))]
+	{
+		crate::wipe::wipe(&mut ipad);
+		crate::wipe::wipe(&mut opad);
+	}
+
+	let mut seed = u;
+	iterate_dispatch(&inner, &outer, &mut u, &mut seed);
+	for (lane, out) in seeds.iter_mut().enumerate().take(mnemonics.len().min(LANES)) {
+		for (chunk, w) in out.chunks_mut(8).zip(seed.iter()) {
+			chunk.copy_from_slice(&w[lane].to_be_bytes());
+		}
+	}
+	#[cfg(any(feature = "wipe", feature = "zeroize"))]
+	{
+		crate::wipe::wipe(&mut inner);
+		crate::wipe::wipe(&mut outer);
+		crate::wipe::wipe(&mut u);
+		crate::wipe::wipe(&mut seed);
+	}
+}
+
+/// Derive the seeds of the mnemonics with the same normalized passphrase into
+/// `seeds`, like [Mnemonic::to_seed_normalized].
+///
+/// Only as many seeds as there are both mnemonics and places in `seeds` are
+/// derived. Use multiples of [LANES] mnemonics for the best performance.
+pub fn to_seeds_normalized_into(
+	mnemonics: &[Mnemonic],
+	normalized_passphrase: &str,
+	seeds: &mut [[u8; 64]],
+) {
+	for (mnemonics, seeds) in mnemonics.chunks(LANES).zip(seeds.chunks_mut(LANES)) {
+		derive_lanes(mnemonics, normalized_passphrase, seeds);
+	}
+}
+
+/// Derive the seeds of the mnemonics with the same normalized passphrase, like
+/// [Mnemonic::to_seed_normalized]. See [to_seeds_normalized_into].
+#[cfg(feature = "alloc")]
+pub fn to_seeds_normalized(mnemonics: &[Mnemonic], normalized_passphrase: &str) -> Vec<[u8; 64]> {
+	let mut seeds = vec![[0; 64]; mnemonics.len()];
+	to_seeds_normalized_into(mnemonics, normalized_passphrase, &mut seeds);
+	seeds
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_batch_matches_scalar() {
+		// A short and a long phrase, which is hashed to get the HMAC key, in
+		// more than one batch.
+		let mnemonics = (0..6u8)
+			.map(|i| {
+				let len = if i % 2 == 0 {
+					16
+				} else {
+					32
+				};
+				Mnemonic::from_entropy(&[i * 41; 32][..len]).unwrap()
+			})
+			.collect::<Vec<_>>();
+		assert!(mnemonics[1].to_string().len() > BLOCK_SIZE);
+
+		for passphrase in &["", "TREZOR", &"long passphrase ".repeat(20)] {
+			let seeds = to_seeds_normalized(&mnemonics, passphrase);
+			for (m, seed) in mnemonics.iter().zip(&seeds) {
+				assert_eq!(*seed, m.to_seed_normalized(passphrase));
+			}
+		}
+
+		let mut seeds = [[0u8; 64]; 2];
+		to_seeds_normalized_into(&mnemonics, "", &mut seeds);
+		assert_eq!(seeds[1], mnemonics[1].to_seed_normalized(""));
+	}
+}
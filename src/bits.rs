@@ -0,0 +1,297 @@
+//! Low-level conversion between bytes and 11-bit word indices.
+//!
+//! These are the primitives used to convert entropy into word indices and back.
+//! They are exposed for protocols built on top of BIP39 word lists that need
+//! the same bit packing.
+
+use bitcoin_hashes::{sha256, Hash, HashEngine};
+
+use crate::{is_invalid_word_count, Error, MAX_NB_WORDS};
+
+/// The number of bits encoded by a single word.
+pub const BITS_PER_WORD: usize = 11;
+
+/// The mask selecting the bits of a word index.
+const INDEX_MASK: u32 = (1 << BITS_PER_WORD) - 1;
+
+/// Pack 11-bit word indices into bytes, most significant bit first.
+///
+/// Only the lowest 11 bits of each index are used. When the number of bits is
+/// not a multiple of 8, the last byte is padded with zero bits.
+///
+/// Returns the number of bytes required for all indices. If `out` is shorter,
+/// only the first `out.len()` bytes are written.
+pub fn pack(indices: &[u16], out: &mut [u8]) -> usize {
+	let mut acc = 0u32;
+	let mut nb_bits = 0;
+	let mut cursor = 0;
+	for idx in indices {
+		acc = (acc << BITS_PER_WORD) | (*idx as u32 & INDEX_MASK);
+		nb_bits += BITS_PER_WORD;
+		while nb_bits >= 8 {
+			nb_bits -= 8;
+			if let Some(b) = out.get_mut(cursor) {
+				*b = (acc >> nb_bits) as u8;
+			}
+			cursor += 1;
+			acc &= (1 << nb_bits) - 1;
+		}
+	}
+	if nb_bits > 0 {
+		if let Some(b) = out.get_mut(cursor) {
+			*b = (acc << (8 - nb_bits)) as u8;
+		}
+		cursor += 1;
+	}
+	cursor
+}
+
+/// Unpack bytes into 11-bit word indices, most significant bit first.
+///
+/// When the number of bits is not a multiple of 11, the last index is padded
+/// with zero bits.
+///
+/// Returns the number of indices required for all bytes. If `out` is shorter,
+/// only the first `out.len()` indices are written.
+pub fn unpack(bytes: &[u8], out: &mut [u16]) -> usize {
+	let mut acc = 0u32;
+	let mut nb_bits = 0;
+	let mut cursor = 0;
+	for b in bytes {
+		acc = (acc << 8) | *b as u32;
+		nb_bits += 8;
+		if nb_bits >= BITS_PER_WORD {
+			nb_bits -= BITS_PER_WORD;
+			if let Some(i) = out.get_mut(cursor) {
+				*i = ((acc >> nb_bits) & INDEX_MASK) as u16;
+			}
+			cursor += 1;
+			acc &= (1 << nb_bits) - 1;
+		}
+	}
+	if nb_bits > 0 {
+		if let Some(i) = out.get_mut(cursor) {
+			*i = ((acc << (BITS_PER_WORD - nb_bits)) & INDEX_MASK) as u16;
+		}
+		cursor += 1;
+	}
+	cursor
+}
+
+/// The number of checksum bits for entropy of the given length in bytes.
+pub fn checksum_bits(entropy_len: usize) -> usize {
+	entropy_len * 8 / 32
+}
+
+/// Get bit `i` of the entropy followed by its checksum.
+#[inline]
+fn entropy_bit(entropy: &[u8], hash: &sha256::Hash, i: usize) -> bool {
+	let nb_bits = entropy.len() * 8;
+	let (bytes, i) = if i < nb_bits {
+		(entropy, i)
+	} else {
+		(&hash[..], i - nb_bits)
+	};
+	bytes.get(i / 8).map(|b| b & (1 << (7 - i % 8)) != 0).unwrap_or(false)
+}
+
+/// Convert entropy into word indices, appending the BIP39 checksum.
+///
+/// The entropy must be a multiple of 32 bits and at most 8192 bits in length,
+/// otherwise [Error::BadEntropyBitCount] is returned.
+///
+/// Returns the number of indices required. If `out` is shorter, only the
+/// first `out.len()` indices are written.
+pub fn append_checksum(entropy: &[u8], out: &mut [u16]) -> Result<usize, Error> {
+	let nb_bits = entropy.len() * 8;
+	if nb_bits % 32 != 0 || checksum_bits(entropy.len()) > sha256::Hash::LEN * 8 {
+		return Err(Error::BadEntropyBitCount(nb_bits));
+	}
+
+	let hash = sha256::Hash::hash(entropy);
+	let nb_words = (nb_bits + checksum_bits(entropy.len())) / BITS_PER_WORD;
+	for (i, slot) in out.iter_mut().take(nb_words).enumerate() {
+		let mut idx = 0;
+		for j in 0..BITS_PER_WORD {
+			idx = (idx << 1) | entropy_bit(entropy, &hash, i * BITS_PER_WORD + j) as u16;
+		}
+		*slot = idx;
+	}
+	Ok(nb_words)
+}
+
+/// Convert word indices back into entropy, verifying the BIP39 checksum.
+///
+/// Returns [Error::BadWordCount] if the number of indices is not a multiple
+/// of 3, [Error::UnknownWord] if an index doesn't fit in 11 bits and
+/// [Error::InvalidChecksum] if the checksum doesn't match.
+///
+/// Returns the length of the entropy in bytes. If `out` is shorter, only the
+/// first `out.len()` bytes are written, but the checksum is still verified.
+pub fn strip_checksum(indices: &[u16], out: &mut [u8]) -> Result<usize, Error> {
+	if indices.len() % 3 != 0 {
+		return Err(Error::BadWordCount(indices.len()));
+	}
+	if let Some(i) = indices.iter().position(|i| *i as u32 > INDEX_MASK) {
+		return Err(Error::UnknownWord(i));
+	}
+
+	let nb_bits = indices.len() * BITS_PER_WORD;
+	let nb_checksum_bits = nb_bits / 33;
+	let nb_bytes = (nb_bits - nb_checksum_bits) / 8;
+	let bit = |i: usize| -> u8 {
+		let idx = indices.get(i / BITS_PER_WORD).cloned().unwrap_or(0);
+		(idx >> (BITS_PER_WORD - 1 - i % BITS_PER_WORD)) as u8 & 1
+	};
+
+	let mut engine = sha256::Hash::engine();
+	for i in 0..nb_bytes {
+		let mut byte = 0;
+		for j in 0..8 {
+			byte = (byte << 1) | bit(i * 8 + j);
+		}
+		engine.input(&[byte]);
+		if let Some(b) = out.get_mut(i) {
+			*b = byte;
+		}
+	}
+
+	let hash = sha256::Hash::from_engine(engine);
+	for i in 0..nb_checksum_bits {
+		let expected = hash.as_byte_array().get(i / 8).map(|b| b >> (7 - i % 8) & 1);
+		if expected != Some(bit(nb_bytes * 8 + i)) {
+			return Err(Error::InvalidChecksum);
+		}
+	}
+	Ok(nb_bytes)
+}
+
+/// The details of a checksum mismatch, see [checksum_mismatch].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChecksumMismatch {
+	/// The number of checksum bits, at the end of the last word.
+	pub nb_bits: usize,
+	/// The checksum bits computed from the entropy.
+	pub expected: u16,
+	/// The checksum bits found in the last word.
+	pub found: u16,
+	/// The index of the last word that would make the checksum valid,
+	/// keeping the entropy bits of the last word.
+	pub correct_last_index: u16,
+}
+
+/// Get the details of the checksum mismatch of word indices, or `None` if the
+/// checksum is valid.
+///
+/// Any word can be the wrong one. A mismatch means that either the last word
+/// is wrong, in which case [ChecksumMismatch::correct_last_index] is a likely
+/// candidate if only its checksum bits are damaged, or an earlier word is.
+///
+/// Only the standard word counts of 12 to 24 words are supported, otherwise
+/// [Error::BadWordCount] is returned. See [strip_checksum] for the other
+/// errors.
+pub fn checksum_mismatch(indices: &[u16]) -> Result<Option<ChecksumMismatch>, Error> {
+	if is_invalid_word_count(indices.len()) {
+		return Err(Error::BadWordCount(indices.len()));
+	}
+
+	let mut entropy = [0u8; MAX_NB_WORDS / 3 * 4];
+	let nb_bytes = match strip_checksum(indices, &mut entropy) {
+		Ok(_) => return Ok(None),
+		Err(Error::InvalidChecksum) => indices.len() * BITS_PER_WORD * 32 / 33 / 8,
+		Err(e) => return Err(e),
+	};
+	let nb_bits = checksum_bits(nb_bytes);
+	let entropy = entropy.get(..nb_bytes).ok_or(Error::BadWordCount(indices.len()))?;
+
+	let mut correct = [0u16; MAX_NB_WORDS];
+	let nb_words = append_checksum(entropy, &mut correct)?;
+	let last = indices.last().cloned().unwrap_or(0);
+	let correct_last = correct.get(nb_words.wrapping_sub(1)).cloned().unwrap_or(0);
+	let mask = (1 << nb_bits) - 1;
+	Ok(Some(ChecksumMismatch {
+		nb_bits,
+		expected: correct_last & mask,
+		found: last & mask,
+		correct_last_index: correct_last,
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use bitcoin_hashes::hex::FromHex;
+
+	#[test]
+	fn test_pack_unpack() {
+		let bytes = Vec::<u8>::from_hex("68a79eaca2324873eacc50cb9c6eca8c").unwrap();
+		let mut indices = [0u16; 12];
+		assert_eq!(unpack(&bytes, &mut indices), 12);
+		let mut packed = [0u8; 17];
+		assert_eq!(pack(&indices, &mut packed), 17);
+		assert_eq!(&packed[..16], &bytes[..]);
+		// The remaining 4 bits of the last index are zero padding.
+		assert_eq!(packed[16], 0);
+
+		assert_eq!(unpack(&[0xff, 0xff], &mut indices), 2);
+		assert_eq!(&indices[..2], &[0x7ff, 0x7c0]);
+		assert_eq!(pack(&[0xffff], &mut packed), 2);
+		assert_eq!(&packed[..2], &[0xff, 0xe0]);
+
+		// Short output buffers are filled as far as possible.
+		let mut short = [0u8; 3];
+		assert_eq!(pack(&[0x7ff, 0x7c0, 0], &mut short), 5);
+		assert_eq!(short, [0xff, 0xff, 0x00]);
+	}
+
+	#[test]
+	fn test_checksum_roundtrip() {
+		// "hamster diagram private dutch cause delay private meat slide toddler razor book
+		//  happy fancy gospel tennis maple dilemma loan word shrug inflict delay length"
+		let entropy =
+			Vec::<u8>::from_hex("68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c")
+				.unwrap();
+		let mut indices = [0u16; 24];
+		assert_eq!(append_checksum(&entropy, &mut indices), Ok(24));
+		assert_eq!(indices[0], 837); // hamster
+		assert_eq!(indices[23], 1024); // length
+
+		let mut out = [0u8; 32];
+		assert_eq!(strip_checksum(&indices, &mut out), Ok(32));
+		assert_eq!(&out[..], &entropy[..]);
+
+		assert_eq!(checksum_mismatch(&indices), Ok(None));
+		indices[23] ^= 1;
+		assert_eq!(strip_checksum(&indices, &mut out), Err(Error::InvalidChecksum));
+		assert_eq!(
+			checksum_mismatch(&indices),
+			Ok(Some(ChecksumMismatch {
+				nb_bits: 8,
+				expected: 0,
+				found: 1,
+				correct_last_index: 1024,
+			}))
+		);
+		// A damaged earlier word changes the expected checksum.
+		let mut damaged = indices;
+		damaged[23] = 1024;
+		damaged[0] ^= 1;
+		let mismatch = checksum_mismatch(&damaged).unwrap().unwrap();
+		assert_eq!(mismatch.found, 0);
+		assert_ne!(mismatch.expected, 0);
+		assert_eq!(checksum_mismatch(&[0; 36]), Err(Error::BadWordCount(36)));
+		// Valid phrases longer than 24 words aren't supported either.
+		let mut long = [0u16; 36];
+		assert_eq!(append_checksum(&[0; 48], &mut long), Ok(36));
+		assert_eq!(checksum_mismatch(&long), Err(Error::BadWordCount(36)));
+		indices[23] = 2048;
+		assert_eq!(strip_checksum(&indices, &mut out), Err(Error::UnknownWord(23)));
+		assert_eq!(strip_checksum(&indices[..23], &mut out), Err(Error::BadWordCount(23)));
+
+		assert_eq!(
+			append_checksum(&entropy[..17], &mut indices),
+			Err(Error::BadEntropyBitCount(136))
+		);
+	}
+}
@@ -0,0 +1,342 @@
+use alloc::string::String;
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::passphrase::Passphrase;
+use crate::Mnemonic;
+
+/// A mnemonic with its passphrase, which together determine the seed.
+///
+/// The passphrase is stored normalized, as a [Passphrase]. The [fmt::Debug]
+/// output doesn't contain the words or the passphrase, the comparison takes a
+/// constant time, and with the `zeroize` or `wipe` feature both are erased from
+/// memory on drop.
+///
+/// With the `serde` feature, the bundle is serialized as a struct with the
+/// language code, the entropy and the passphrase, all in plain text. Encrypt
+/// it before storing it.
+///
+/// Example:
+///
+/// ```
+/// use bip39::{Mnemonic, MnemonicWithPassphrase};
+///
+/// let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+/// let bundle = MnemonicWithPassphrase::new(m.clone(), "TREZOR");
+/// assert_eq!(bundle.to_seed(), m.to_seed("TREZOR"));
+/// assert!(!format!("{:?}", bundle).contains("abandon"));
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct MnemonicWithPassphrase {
+	mnemonic: Mnemonic,
+	passphrase: Passphrase,
+}
+
+impl MnemonicWithPassphrase {
+	/// Bundle the mnemonic with the passphrase, normalizing it.
+	pub fn new(mnemonic: Mnemonic, passphrase: &str) -> MnemonicWithPassphrase {
+		MnemonicWithPassphrase {
+			mnemonic,
+			passphrase: Passphrase::new(passphrase),
+		}
+	}
+
+	/// Bundle the mnemonic with a [Passphrase], which is already normalized.
+	pub fn with_passphrase(mnemonic: Mnemonic, passphrase: Passphrase) -> MnemonicWithPassphrase {
+		MnemonicWithPassphrase {
+			mnemonic,
+			passphrase,
+		}
+	}
+
+	/// Bundle the mnemonic with an already normalized passphrase.
+	pub fn new_normalized(
+		mnemonic: Mnemonic,
+		normalized_passphrase: String,
+	) -> MnemonicWithPassphrase {
+		MnemonicWithPassphrase {
+			mnemonic,
+			passphrase: Passphrase::from_normalized(normalized_passphrase),
+		}
+	}
+
+	/// The mnemonic.
+	pub fn mnemonic(&self) -> &Mnemonic {
+		&self.mnemonic
+	}
+
+	/// The normalized passphrase.
+	pub fn passphrase(&self) -> &str {
+		self.passphrase.as_str()
+	}
+
+	/// Whether the passphrase is not empty.
+	pub fn has_passphrase(&self) -> bool {
+		!self.passphrase.is_empty()
+	}
+
+	/// Derive the seed of the mnemonic with the passphrase.
+	pub fn to_seed(&self) -> [u8; 64] {
+		self.mnemonic.to_seed_normalized(self.passphrase.as_str())
+	}
+}
+
+impl PartialEq for MnemonicWithPassphrase {
+	fn eq(&self, other: &MnemonicWithPassphrase) -> bool {
+		// Both parts are compared, so that the time doesn't depend on which
+		// one differs.
+		(self.mnemonic == other.mnemonic) & (self.passphrase == other.passphrase)
+	}
+}
+
+impl Eq for MnemonicWithPassphrase {}
+
+impl fmt::Debug for MnemonicWithPassphrase {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("MnemonicWithPassphrase")
+			.field("language", &self.mnemonic.language())
+			.field("word_count", &self.mnemonic.word_count())
+			.field("has_passphrase", &self.has_passphrase())
+			.finish()
+	}
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+	use alloc::string::String;
+	use core::fmt;
+
+	use serde::de::{self, MapAccess, SeqAccess, Visitor};
+	use serde::ser::SerializeStruct;
+
+	use super::MnemonicWithPassphrase;
+	use crate::passphrase::Passphrase;
+	use crate::serde_compact::{self, Entropy, EntropyBuf};
+
+	const FIELDS: &[&str] = &["language", "entropy", "passphrase"];
+
+	impl serde::Serialize for MnemonicWithPassphrase {
+		fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			let (entropy, len) = self.mnemonic.to_entropy_array();
+			let mut s = serializer.serialize_struct("MnemonicWithPassphrase", 3)?;
+			s.serialize_field("language", &self.mnemonic.language().to_u8())?;
+			s.serialize_field("entropy", &Entropy(entropy.get(..len).unwrap_or_default()))?;
+			s.serialize_field("passphrase", self.passphrase.as_str())?;
+			s.end()
+		}
+	}
+
+	/// The deserialized passphrase, which doesn't need the `alloc` feature of
+	/// serde. It is not normalized yet and erased on drop.
+	struct PassphraseBuf(String);
+
+	#[cfg(any(feature = "wipe", feature = "zeroize"))]
+	impl Drop for PassphraseBuf {
+		fn drop(&mut self) {
+			// SAFETY: zero bytes are valid UTF-8.
+			crate::wipe::wipe(unsafe { self.0.as_mut_vec() });
+		}
+	}
+
+	impl<'de> serde::Deserialize<'de> for PassphraseBuf {
+		fn deserialize<D: serde::Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<PassphraseBuf, D::Error> {
+			struct PassphraseVisitor;
+
+			impl<'de> Visitor<'de> for PassphraseVisitor {
+				type Value = PassphraseBuf;
+
+				fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					f.write_str("a passphrase")
+				}
+
+				fn visit_str<E: de::Error>(self, v: &str) -> Result<PassphraseBuf, E> {
+					Ok(PassphraseBuf(v.into()))
+				}
+			}
+
+			deserializer.deserialize_str(PassphraseVisitor)
+		}
+	}
+
+	/// A field of the serialized struct.
+	enum Field {
+		Language,
+		Entropy,
+		Passphrase,
+	}
+
+	impl<'de> serde::Deserialize<'de> for Field {
+		fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Field, D::Error> {
+			struct FieldVisitor;
+
+			impl<'de> Visitor<'de> for FieldVisitor {
+				type Value = Field;
+
+				fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					f.write_str("a field name")
+				}
+
+				fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+					match v {
+						"language" => Ok(Field::Language),
+						"entropy" => Ok(Field::Entropy),
+						"passphrase" => Ok(Field::Passphrase),
+						_ => Err(de::Error::unknown_field(v, FIELDS)),
+					}
+				}
+			}
+
+			deserializer.deserialize_identifier(FieldVisitor)
+		}
+	}
+
+	/// Create the bundle from its deserialized parts.
+	fn bundle<E: de::Error>(
+		language: u8,
+		entropy: EntropyBuf,
+		passphrase: PassphraseBuf,
+	) -> Result<MnemonicWithPassphrase, E> {
+		let mnemonic = serde_compact::mnemonic(language, entropy)?;
+		// The passphrase can come from anywhere, so it is normalized again.
+		let passphrase = Passphrase::new(&passphrase.0);
+		Ok(MnemonicWithPassphrase::with_passphrase(mnemonic, passphrase))
+	}
+
+	impl<'de> serde::Deserialize<'de> for MnemonicWithPassphrase {
+		fn deserialize<D: serde::Deserializer<'de>>(
+			deserializer: D,
+		) -> Result<MnemonicWithPassphrase, D::Error> {
+			struct BundleVisitor;
+
+			impl<'de> Visitor<'de> for BundleVisitor {
+				type Value = MnemonicWithPassphrase;
+
+				fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					f.write_str("a mnemonic with passphrase")
+				}
+
+				fn visit_seq<A: SeqAccess<'de>>(
+					self,
+					mut seq: A,
+				) -> Result<MnemonicWithPassphrase, A::Error> {
+					let language =
+						seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+					let entropy =
+						seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+					let passphrase =
+						seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+					bundle(language, entropy, passphrase)
+				}
+
+				fn visit_map<A: MapAccess<'de>>(
+					self,
+					mut map: A,
+				) -> Result<MnemonicWithPassphrase, A::Error> {
+					let mut language = None;
+					let mut entropy = None;
+					let mut passphrase = None;
+					while let Some(key) = map.next_key()? {
+						match key {
+							Field::Language => language = Some(map.next_value()?),
+							Field::Entropy => entropy = Some(map.next_value()?),
+							Field::Passphrase => passphrase = Some(map.next_value()?),
+						}
+					}
+					bundle(
+						language.ok_or_else(|| de::Error::missing_field("language"))?,
+						entropy.ok_or_else(|| de::Error::missing_field("entropy"))?,
+						passphrase.ok_or_else(|| de::Error::missing_field("passphrase"))?,
+					)
+				}
+			}
+
+			deserializer.deserialize_struct("MnemonicWithPassphrase", FIELDS, BundleVisitor)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_bundle() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let bundle = MnemonicWithPassphrase::new(m.clone(), "caf\u{e9}");
+		assert_eq!(bundle.passphrase(), "cafe\u{301}");
+		assert!(bundle.has_passphrase());
+		assert_eq!(bundle.mnemonic(), &m);
+		assert_eq!(bundle.to_seed(), m.to_seed("caf\u{e9}"));
+
+		let empty = MnemonicWithPassphrase::new_normalized(m.clone(), String::new());
+		assert!(!empty.has_passphrase());
+		assert_eq!(empty.to_seed(), m.to_seed(""));
+		assert_ne!(empty, bundle);
+
+		let debug = format!("{:?}", bundle);
+		assert_eq!(
+			debug,
+			"MnemonicWithPassphrase { language: English, word_count: 24, has_passphrase: true }"
+		);
+
+		let same = MnemonicWithPassphrase::with_passphrase(m.clone(), Passphrase::new("caf\u{e9}"));
+		assert_eq!(same, bundle);
+		let other = Mnemonic::from_entropy(&[0x43; 32]).unwrap();
+		assert_ne!(MnemonicWithPassphrase::new(other, "caf\u{e9}"), bundle);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde() {
+		use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let bundle = MnemonicWithPassphrase::new(m, "caf\u{e9}");
+		assert_tokens(
+			&bundle,
+			&[
+				Token::Struct {
+					name: "MnemonicWithPassphrase",
+					len: 3,
+				},
+				Token::Str("language"),
+				Token::U8(0),
+				Token::Str("entropy"),
+				Token::Bytes(&[0x42; 16]),
+				Token::Str("passphrase"),
+				Token::Str("cafe\u{301}"),
+				Token::StructEnd,
+			],
+		);
+		assert_de_tokens(
+			&bundle,
+			&[
+				Token::Seq {
+					len: Some(3),
+				},
+				Token::U8(0),
+				Token::Bytes(&[0x42; 16]),
+				Token::Str("cafe\u{301}"),
+				Token::SeqEnd,
+			],
+		);
+
+		let json = serde_json::to_string(&bundle).unwrap();
+		let decoded: MnemonicWithPassphrase = serde_json::from_str(&json).unwrap();
+		assert_eq!(decoded, bundle);
+		assert_eq!(decoded.to_seed(), bundle.to_seed());
+		assert!(serde_json::from_str::<MnemonicWithPassphrase>(r#"{"language":0}"#).is_err());
+
+		// A passphrase that is not normalized is normalized when deserialized.
+		let composed = json.replace("cafe\u{301}", "caf\u{e9}");
+		assert_ne!(composed, json);
+		let decoded: MnemonicWithPassphrase = serde_json::from_str(&composed).unwrap();
+		assert_eq!(decoded.passphrase(), "cafe\u{301}");
+		assert_eq!(decoded.to_seed(), bundle.to_seed());
+	}
+}
@@ -0,0 +1,268 @@
+//! Backup of a mnemonic on three cards, any two of which restore it.
+//!
+//! The words are split in three consecutive parts of the same length and each
+//! card holds two of them: card 1 omits the first part, card 2 the second and
+//! card 3 the last one. A single card reveals two thirds of the words, so this
+//! is only a protection against losing a card, not against theft.
+//!
+//! Cards are printed with a header and one numbered word per line:
+//!
+//! ```text
+//! Card 1 of 3, 12 words
+//! 5. stadium
+//! 6. alone
+//! ...
+//! ```
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::{cards, Language, Mnemonic};
+//!
+//! let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+//! let [first, _, third] = cards::split(&m);
+//!
+//! let first = cards::Card::parse_in(Language::English, &first.to_string()).unwrap();
+//! assert_eq!(cards::combine(&first, &third), Ok(m));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{Error, Language, Mnemonic, EOF, MAX_NB_WORDS};
+
+/// The number of cards.
+pub const NB_CARDS: usize = 3;
+
+/// A card holding two thirds of the words of a mnemonic.
+///
+/// The [fmt::Debug] output doesn't contain the words, use [fmt::Display] to
+/// print the card. With the `zeroize` or `wipe` feature, the words are erased
+/// from memory on drop.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct Card {
+	/// The number of the card, from 0 to 2.
+	index: usize,
+	language: Language,
+	word_count: usize,
+	/// The word indices, [EOF] for words that are not on this card.
+	words: [u16; MAX_NB_WORDS],
+}
+
+#[cfg(all(feature = "wipe", not(feature = "zeroize")))]
+impl Drop for Card {
+	fn drop(&mut self) {
+		crate::wipe::wipe(&mut self.words);
+	}
+}
+
+impl fmt::Debug for Card {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Card")
+			.field("number", &self.number())
+			.field("language", &self.language)
+			.field("word_count", &self.word_count)
+			.finish()
+	}
+}
+
+/// Whether the card with the given index holds the word at the position.
+fn holds(index: usize, word_count: usize, position: usize) -> bool {
+	position * NB_CARDS / word_count != index
+}
+
+impl Card {
+	/// The number of the card, from 1 to 3.
+	pub fn number(&self) -> usize {
+		self.index + 1
+	}
+
+	/// The language of the mnemonic.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// The number of words of the mnemonic, not of this card.
+	pub fn word_count(&self) -> usize {
+		self.word_count
+	}
+
+	/// The words on this card, with their position in the mnemonic
+	/// starting from 0.
+	pub fn words(&self) -> impl Iterator<Item = (usize, &'static str)> + Clone + '_ {
+		let list = self.language.word_list();
+		self.words
+			.iter()
+			.take(self.word_count)
+			.enumerate()
+			.filter(|(_, i)| **i != EOF)
+			.map(move |(p, i)| (p, list.get(*i as usize).copied().unwrap_or("")))
+	}
+
+	/// Parse a card in the given language, in the format of its [fmt::Display]
+	/// implementation.
+	///
+	/// Returns [Error::InvalidCard] if the card is malformed or doesn't hold the
+	/// expected words, and [Error::UnknownWord] with the position of the word in
+	/// the mnemonic for words that are not in the word list.
+	pub fn parse_in(language: Language, s: &str) -> Result<Card, Error> {
+		#[cfg(feature = "alloc")]
+		let s = {
+			let mut cow = Cow::Borrowed(s);
+			Mnemonic::normalize_utf8_cow(&mut cow);
+			cow
+		};
+
+		let mut lines = s.lines().map(str::trim).filter(|l| !l.is_empty());
+		let (index, word_count) = lines.next().and_then(parse_header).ok_or(Error::InvalidCard)?;
+		if index >= NB_CARDS || crate::is_invalid_word_count(word_count) {
+			return Err(Error::InvalidCard);
+		}
+
+		let mut card = Card {
+			index,
+			language,
+			word_count,
+			words: [EOF; MAX_NB_WORDS],
+		};
+		let mut expected = (0..word_count).filter(|p| holds(index, word_count, *p));
+		for line in lines {
+			let mut parts = line.splitn(2, ". ");
+			let position = parts.next().and_then(|p| p.parse::<usize>().ok());
+			let word = parts.next().map(str::trim);
+			let (position, word) = match (position, word) {
+				(Some(p), Some(w)) if p > 0 && expected.next() == Some(p - 1) => (p - 1, w),
+				_ => return Err(Error::InvalidCard),
+			};
+			let idx = language.find_word(word).ok_or(Error::UnknownWord(position))?;
+			if let Some(slot) = card.words.get_mut(position) {
+				*slot = idx;
+			}
+		}
+		if expected.next().is_some() {
+			return Err(Error::InvalidCard);
+		}
+		Ok(card)
+	}
+}
+
+/// Parse a card header like `Card 1 of 3, 12 words` into the card index and
+/// the word count.
+fn parse_header(line: &str) -> Option<(usize, usize)> {
+	if !line.starts_with("Card ") || !line.ends_with(" words") {
+		return None;
+	}
+	let rest = line.get("Card ".len()..line.len() - " words".len())?;
+	let mut parts = rest.splitn(2, " of 3, ");
+	let number = parts.next()?.parse::<usize>().ok()?;
+	let word_count = parts.next()?.parse::<usize>().ok()?;
+	Some((number.checked_sub(1)?, word_count))
+}
+
+impl fmt::Display for Card {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Card {} of {}, {} words", self.number(), NB_CARDS, self.word_count)?;
+		for (p, word) in self.words() {
+			write!(f, "\n{}. {}", p + 1, word)?;
+		}
+		Ok(())
+	}
+}
+
+/// Split the mnemonic on three cards.
+pub fn split(mnemonic: &Mnemonic) -> [Card; NB_CARDS] {
+	let word_count = mnemonic.word_count();
+	let card = |index| {
+		let mut words = [EOF; MAX_NB_WORDS];
+		for (p, (slot, idx)) in words.iter_mut().zip(mnemonic.word_indices()).enumerate() {
+			if holds(index, word_count, p) {
+				*slot = idx as u16;
+			}
+		}
+		Card {
+			index,
+			language: mnemonic.language(),
+			word_count,
+			words,
+		}
+	};
+	[card(0), card(1), card(2)]
+}
+
+/// Restore the mnemonic from two different cards.
+///
+/// Returns [Error::InvalidCard] if the cards are not two different cards of
+/// the same mnemonic, checking that the words they have in common are the same.
+pub fn combine(a: &Card, b: &Card) -> Result<Mnemonic, Error> {
+	if a.index == b.index || a.language != b.language || a.word_count != b.word_count {
+		return Err(Error::InvalidCard);
+	}
+
+	let mut indices = [EOF; MAX_NB_WORDS];
+	for (slot, (x, y)) in indices.iter_mut().zip(a.words.iter().zip(b.words.iter())) {
+		*slot = match (*x, *y) {
+			(EOF, i) | (i, EOF) => i,
+			(x, y) if x == y => x,
+			_ => return Err(Error::InvalidCard),
+		};
+	}
+	Mnemonic::from_indices_in(a.language, indices.get(..a.word_count).unwrap_or(&[]))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_split_combine() {
+		for len in &[16, 20, 24, 28, 32] {
+			let m = Mnemonic::from_entropy(&[0x5a; 32][..*len]).unwrap();
+			let cards = split(&m);
+			for card in cards.iter() {
+				assert_eq!(card.words().count(), m.word_count() * 2 / 3);
+				assert_eq!(Card::parse_in(Language::English, &card.to_string()), Ok(card.clone()));
+			}
+			for (a, b) in &[(0, 1), (1, 2), (2, 0), (1, 0)] {
+				assert_eq!(combine(&cards[*a], &cards[*b]), Ok(m.clone()));
+			}
+			assert_eq!(combine(&cards[1], &cards[1]), Err(Error::InvalidCard));
+		}
+	}
+
+	#[test]
+	fn test_card_format() {
+		let m = Mnemonic::parse_normalized(
+			"cat swing flag economy stadium alone churn speed unique patch report train",
+		)
+		.unwrap();
+		let [first, second, third] = split(&m);
+		let text = first.to_string();
+		let mut lines = text.lines();
+		assert_eq!(lines.next(), Some("Card 1 of 3, 12 words"));
+		assert_eq!(lines.next(), Some("5. stadium"));
+		assert_eq!(lines.last(), Some("12. train"));
+		assert_eq!(third.words().last(), Some((7, "speed")));
+		assert_eq!(format!("{:?}", third), "Card { number: 3, language: English, word_count: 12 }");
+
+		// Inconsistent words in common.
+		let mut other = second;
+		other.words[0] = 0;
+		assert_eq!(combine(&third, &other), Err(Error::InvalidCard));
+
+		let parse = |s: &str| Card::parse_in(Language::English, s);
+		let missing = text.replace("\n6. alone", "");
+		assert_eq!(parse(&missing), Err(Error::InvalidCard));
+		let misplaced = text.replace("5. stadium", "4. stadium");
+		assert_eq!(parse(&misplaced), Err(Error::InvalidCard));
+		let unknown = text.replace("6. alone", "6. alon");
+		assert_eq!(parse(&unknown), Err(Error::UnknownWord(5)));
+		assert_eq!(parse(&text.replace("Card 1", "Card 4")), Err(Error::InvalidCard));
+		assert_eq!(parse(&text.replace("12 words", "13 words")), Err(Error::InvalidCard));
+		assert_eq!(parse(&format!("\n  {}\n\n", text.replace("\n", "\n  "))), Ok(first));
+	}
+}
@@ -0,0 +1,158 @@
+//! CBOR encoding of mnemonics.
+//!
+//! A mnemonic is encoded as a CBOR array of two items: the language as an
+//! unsigned integer and the entropy as a byte string. The encoding is
+//! deterministic as per RFC 8949, section 4.2, and decoding only accepts this
+//! deterministic encoding.
+//!
+//! The languages are encoded with their stable codes, see [Language::to_u8].
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::{cbor, Mnemonic};
+//!
+//! let m = Mnemonic::from_entropy(&[0x7f; 16]).unwrap();
+//! let (bytes, len) = cbor::encode(&m);
+//! assert_eq!(&bytes[..3], &[0x82, 0x00, 0x50]);
+//! assert_eq!(cbor::decode(&bytes[..len]), Ok(m));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{Error, Language, Mnemonic};
+
+/// The maximum length of an encoded mnemonic in bytes.
+pub const MAX_ENCODED_LEN: usize = 36;
+
+/// The initial byte of an array of two items.
+const ARRAY_OF_TWO: u8 = 0x82;
+
+/// The major type of byte strings.
+const BYTE_STRING: u8 = 0x40;
+
+/// The additional information for a length in the following byte.
+const ONE_BYTE_LENGTH: u8 = 24;
+
+/// Encode the mnemonic. Returns the buffer and the length of the encoding.
+pub fn encode(mnemonic: &Mnemonic) -> ([u8; MAX_ENCODED_LEN], usize) {
+	let (entropy, entropy_len) = mnemonic.to_entropy_array();
+	let entropy = entropy.get(..entropy_len).unwrap_or(&[]);
+
+	let mut header = [0u8; 4];
+	header[0] = ARRAY_OF_TWO;
+	header[1] = mnemonic.language().to_u8();
+	let header_len = if entropy_len < ONE_BYTE_LENGTH as usize {
+		header[2] = BYTE_STRING | entropy_len as u8;
+		3
+	} else {
+		header[2] = BYTE_STRING | ONE_BYTE_LENGTH;
+		header[3] = entropy_len as u8;
+		4
+	};
+
+	let mut out = [0u8; MAX_ENCODED_LEN];
+	let bytes = header.iter().take(header_len).chain(entropy);
+	for (o, b) in out.iter_mut().zip(bytes) {
+		*o = *b;
+	}
+	(out, header_len + entropy_len)
+}
+
+/// Encode the mnemonic into a vector.
+#[cfg(feature = "alloc")]
+pub fn to_vec(mnemonic: &Mnemonic) -> Vec<u8> {
+	let (bytes, len) = encode(mnemonic);
+	bytes.iter().take(len).cloned().collect()
+}
+
+/// Decode a mnemonic.
+///
+/// Returns [Error::InvalidEncoding] if the bytes are not the deterministic
+/// encoding of a mnemonic in one of the enabled languages and
+/// [Error::BadEntropyBitCount] if the entropy has an invalid length.
+pub fn decode(bytes: &[u8]) -> Result<Mnemonic, Error> {
+	let mut bytes = bytes.iter().cloned();
+	if bytes.next() != Some(ARRAY_OF_TWO) {
+		return Err(Error::InvalidEncoding);
+	}
+
+	let tag = bytes.next().ok_or(Error::InvalidEncoding)?;
+	let language = Language::from_u8(tag).ok_or(Error::InvalidEncoding)?;
+
+	let header = bytes.next().ok_or(Error::InvalidEncoding)?;
+	let len = match header {
+		h if h & !0x1f != BYTE_STRING => return Err(Error::InvalidEncoding),
+		h if h & 0x1f < ONE_BYTE_LENGTH => (h & 0x1f) as usize,
+		h if h & 0x1f == ONE_BYTE_LENGTH => match bytes.next() {
+			// Deterministic encoding requires the shortest form.
+			Some(len) if len >= ONE_BYTE_LENGTH => len as usize,
+			_ => return Err(Error::InvalidEncoding),
+		},
+		_ => return Err(Error::InvalidEncoding),
+	};
+
+	let mut entropy = [0u8; 32];
+	let entropy = entropy.get_mut(..len).ok_or(Error::BadEntropyBitCount(len * 8))?;
+	for e in entropy.iter_mut() {
+		*e = bytes.next().ok_or(Error::InvalidEncoding)?;
+	}
+	if bytes.next().is_some() {
+		return Err(Error::InvalidEncoding);
+	}
+	Mnemonic::from_entropy_in(language, entropy)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_roundtrip() {
+		for len in [16, 20, 24, 28, 32].iter() {
+			for lang in Language::ALL {
+				let m = Mnemonic::from_entropy_in(*lang, &[0xab; 32][..*len]).unwrap();
+				let encoded = to_vec(&m);
+				assert_eq!(
+					encoded.len(),
+					if *len < 24 {
+						3
+					} else {
+						4
+					} + len
+				);
+				assert_eq!(decode(&encoded), Ok(m));
+			}
+		}
+	}
+
+	#[test]
+	fn test_encoding() {
+		let m = Mnemonic::from_entropy(&[0x01; 32]).unwrap();
+		let mut expected = vec![0x82, 0x00, 0x58, 0x20];
+		expected.extend_from_slice(&[0x01; 32]);
+		assert_eq!(to_vec(&m), expected);
+	}
+
+	#[test]
+	fn test_invalid() {
+		let m = Mnemonic::from_entropy(&[0x01; 16]).unwrap();
+		let valid = to_vec(&m);
+
+		assert_eq!(decode(&[]), Err(Error::InvalidEncoding));
+		assert_eq!(decode(&valid[..valid.len() - 1]), Err(Error::InvalidEncoding));
+		assert_eq!(decode(&[&valid[..], &[0]].concat()), Err(Error::InvalidEncoding));
+		// Unknown language.
+		assert_eq!(decode(&[0x82, 0x17, 0x40]), Err(Error::InvalidEncoding));
+		// Not a byte string.
+		assert_eq!(decode(&[0x82, 0x00, 0x60]), Err(Error::InvalidEncoding));
+		// Non-shortest length.
+		let long = [&[0x82, 0x00, 0x58, 0x10][..], &[0x01; 16]].concat();
+		assert_eq!(decode(&long), Err(Error::InvalidEncoding));
+		// Bad entropy lengths.
+		assert_eq!(decode(&[0x82, 0x00, 0x41, 0x00]), Err(Error::BadEntropyBitCount(8)));
+		let huge = [&[0x82, 0x00, 0x58, 0x21][..], &[0x01; 33]].concat();
+		assert_eq!(decode(&huge), Err(Error::BadEntropyBitCount(264)));
+	}
+}
@@ -0,0 +1,135 @@
+//! Collaborative generation of a mnemonic by multiple participants.
+//!
+//! The participants use a commit-reveal protocol, so that the resulting mnemonic
+//! is random as long as at least one of them contributes a random share:
+//!
+//! 1. Every participant generates a random entropy share of the same length
+//!    and publishes its [Commitment], created with [commit].
+//! 2. Once all commitments are known, the participants reveal their shares.
+//! 3. Everybody can then use [combine_in] to verify the shares against the
+//!    commitments and derive the mnemonic from the XOR of all shares.
+//!
+//! The commitments prevent a participant from choosing its share after having
+//! seen the shares of the others.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::{ceremony, Language};
+//!
+//! let alice = [0x11; 16];
+//! let bob = [0x22; 16];
+//! let commitments = [ceremony::commit(&alice), ceremony::commit(&bob)];
+//!
+//! // ... once both commitments are published, both shares are revealed.
+//! let m = ceremony::combine_in(Language::English, &commitments, &[&alice, &bob]).unwrap();
+//! assert_eq!(m.to_entropy(), vec![0x33; 16]);
+//! ```
+
+use core::fmt;
+
+use bitcoin_hashes::{sha256, Hash, HashEngine};
+
+use crate::{Error, Language, Mnemonic};
+
+/// The tag prepended to a share when hashing it for its commitment.
+const COMMITMENT_TAG: &[u8] = b"BIP39/ceremony/commitment";
+
+/// The maximum length of an entropy share in bytes.
+const MAX_SHARE_LEN: usize = 32;
+
+/// The commitment to an entropy share.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Commitment(pub [u8; 32]);
+
+impl fmt::Display for Commitment {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for b in self.0.iter() {
+			write!(f, "{:02x}", b)?;
+		}
+		Ok(())
+	}
+}
+
+/// Create the commitment to an entropy share.
+///
+/// The share must be fresh randomness of a valid entropy length, see
+/// [Mnemonic::from_entropy_in]. It is committed to without a blinding nonce,
+/// so it must never be a low-entropy value.
+pub fn commit(share: &[u8]) -> Commitment {
+	let mut engine = sha256::Hash::engine();
+	engine.input(COMMITMENT_TAG);
+	engine.input(share);
+	Commitment(sha256::Hash::from_engine(engine).to_byte_array())
+}
+
+/// Verify the revealed shares against their commitments and combine them
+/// into a [Mnemonic] in the given language.
+///
+/// The shares must be in the same order as the commitments. Returns
+/// [Error::InvalidCommitment] with the index of the first share that doesn't
+/// match its commitment, or that is missing, and [Error::BadEntropyBitCount]
+/// if the shares are not all of the same valid length.
+pub fn combine_in(
+	language: Language,
+	commitments: &[Commitment],
+	shares: &[&[u8]],
+) -> Result<Mnemonic, Error> {
+	if commitments.len() != shares.len() {
+		return Err(Error::InvalidCommitment(commitments.len().min(shares.len())));
+	}
+
+	let len = shares.first().map(|s| s.len()).unwrap_or(0);
+	let mut entropy = [0u8; MAX_SHARE_LEN];
+	let entropy = entropy.get_mut(..len).ok_or(Error::BadEntropyBitCount(len * 8))?;
+	for (i, (commitment, share)) in commitments.iter().zip(shares).enumerate() {
+		if share.len() != len {
+			return Err(Error::BadEntropyBitCount(share.len() * 8));
+		}
+		if commit(share) != *commitment {
+			return Err(Error::InvalidCommitment(i));
+		}
+		for (e, s) in entropy.iter_mut().zip(share.iter()) {
+			*e ^= s;
+		}
+	}
+	Mnemonic::from_entropy_in(language, entropy)
+}
+
+/// Verify the revealed shares against their commitments and combine them
+/// into an English [Mnemonic]. See [combine_in] for details.
+pub fn combine(commitments: &[Commitment], shares: &[&[u8]]) -> Result<Mnemonic, Error> {
+	combine_in(Language::English, commitments, shares)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_combine() {
+		let shares: [&[u8]; 3] = [&[0x01; 32], &[0x02; 32], &[0x04; 32]];
+		let commitments = [commit(shares[0]), commit(shares[1]), commit(shares[2])];
+		let m = combine(&commitments, &shares).unwrap();
+		assert_eq!(m, Mnemonic::from_entropy(&[0x07; 32]).unwrap());
+
+		// A participant changing its share after committing.
+		let cheat: [&[u8]; 3] = [shares[0], &[0x03; 32], shares[2]];
+		assert_eq!(combine(&commitments, &cheat), Err(Error::InvalidCommitment(1)));
+		// A participant not revealing its share.
+		assert_eq!(combine(&commitments, &shares[..2]), Err(Error::InvalidCommitment(2)));
+		// Shares of different lengths.
+		let short: [&[u8]; 3] = [shares[0], &[0x02; 16], shares[2]];
+		let commitments = [commit(short[0]), commit(short[1]), commit(short[2])];
+		assert_eq!(combine(&commitments, &short), Err(Error::BadEntropyBitCount(128)));
+		// No participants.
+		assert_eq!(combine(&[], &[]), Err(Error::BadEntropyBitCount(0)));
+	}
+
+	#[test]
+	fn test_commitment_display() {
+		let c = commit(&[0; 16]);
+		assert_eq!(c.to_string().len(), 64);
+		assert_ne!(c, commit(&[0; 32]));
+	}
+}
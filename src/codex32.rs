@@ -0,0 +1,425 @@
+//! Conversion between mnemonics and codex32 strings (BIP93).
+//!
+//! codex32 is a hand-computable format for backing up a secret, optionally
+//! split into shares of which a threshold is needed to recover it. Here, the
+//! secret is the entropy of the mnemonic, so that a mnemonic can be moved to
+//! codex32 and back.
+//!
+//! Note that wallets importing a codex32 string use the secret directly as the
+//! BIP32 master seed. This gives a different wallet than the BIP39 seed of the
+//! mnemonic with the same entropy, so a codex32 string created from a mnemonic
+//! must be converted back with this module before use.
+//!
+//! Only short codex32 strings, up to 93 data characters, are supported, which
+//! covers all mnemonic lengths.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::codex32;
+//!
+//! let m = codex32::decode("ms10testsxxxxxxxxxxxxxxxxxxxxxxxxxx4nzvca9cmczlw").unwrap();
+//! assert_eq!(m.to_entropy_array().0[..4], [0x31, 0x8c, 0x63, 0x18]);
+//! let s = codex32::encode(&m, "test").unwrap();
+//! assert_eq!(codex32::decode(&s), Ok(m));
+//! ```
+
+// The checksum arithmetic indexes fixed-size tables with bounded values.
+#![allow(clippy::indexing_slicing)]
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{Error, Language, Mnemonic};
+
+/// The characters of the codex32 alphabet, by value.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The human-readable part and separator of codex32 strings.
+const HRP: &str = "ms1";
+
+/// The length of the header: threshold, identifier and share index.
+const HEADER_LEN: usize = 6;
+
+/// The length of the checksum of short codex32 strings.
+const CHECKSUM_LEN: usize = 13;
+
+/// The maximum number of data characters of short codex32 strings.
+const MAX_DATA_LEN: usize = 93;
+
+/// The value of the share index of the secret, `s`.
+const SECRET_INDEX: u8 = 16;
+
+/// The checksum generator of short codex32 strings.
+const GENERATOR: [u128; 5] = [
+	0x19dc500ce73fde210,
+	0x1bfae00def77fe529,
+	0x1fbd920fffe7bee52,
+	0x1739640bdeee3fdad,
+	0x07729a039cfc75f5a,
+];
+
+/// The target residue of the checksum of short codex32 strings.
+const CHECKSUM_CONST: u128 = 0x10ce0795c2fd1e62a;
+
+/// Compute the checksum residue of the values.
+fn polymod(values: &[u8]) -> u128 {
+	let mut residue: u128 = 0x23181b3;
+	for v in values {
+		let b = residue >> 60;
+		residue = (residue & 0x0fff_ffff_ffff_ffff) << 5 ^ *v as u128;
+		for (i, g) in GENERATOR.iter().enumerate() {
+			if (b >> i) & 1 == 1 {
+				residue ^= g;
+			}
+		}
+	}
+	residue
+}
+
+/// Append the checksum to the values.
+fn append_checksum(values: &mut Vec<u8>) {
+	let len = values.len();
+	values.extend_from_slice(&[0; CHECKSUM_LEN]);
+	let residue = polymod(values) ^ CHECKSUM_CONST;
+	for (i, v) in values[len..].iter_mut().enumerate() {
+		*v = (residue >> (5 * (CHECKSUM_LEN - 1 - i))) as u8 & 31;
+	}
+}
+
+/// Multiply two elements of GF(32), defined by x^5 + x^3 + 1.
+fn gf_mul(a: u8, b: u8) -> u8 {
+	let mut res = 0;
+	let mut a = a;
+	for i in 0..5 {
+		if (b >> i) & 1 == 1 {
+			res ^= a;
+		}
+		a <<= 1;
+		if a & 32 != 0 {
+			a ^= 0b101001;
+		}
+	}
+	res
+}
+
+/// Invert a non-zero element of GF(32).
+fn gf_inv(a: u8) -> u8 {
+	// a^30 = a^-1
+	(0..29).fold(a, |acc, _| gf_mul(acc, a))
+}
+
+/// Evaluate at `x` the polynomial going through the shares, position by
+/// position. The shares have their index at [HEADER_LEN] - 1.
+fn interpolate(shares: &[Vec<u8>], x: u8) -> Vec<u8> {
+	let index = |share: &Vec<u8>| share[HEADER_LEN - 1];
+	let mut res = vec![0u8; shares[0].len()];
+	for share in shares {
+		let mut coef = 1;
+		for other in shares.iter().filter(|o| index(o) != index(share)) {
+			let num = x ^ index(other);
+			let den = gf_inv(index(share) ^ index(other));
+			coef = gf_mul(coef, gf_mul(num, den));
+		}
+		for (r, v) in res.iter_mut().zip(share) {
+			*r ^= gf_mul(coef, *v);
+		}
+	}
+	res
+}
+
+/// The value of a codex32 character.
+fn char_value(c: u8) -> Option<u8> {
+	let c = c.to_ascii_lowercase();
+	CHARSET.iter().position(|x| *x == c).map(|v| v as u8)
+}
+
+/// Convert the values to a codex32 string.
+fn to_string(values: &[u8]) -> String {
+	let mut s = String::from(HRP);
+	s.extend(values.iter().map(|v| CHARSET[*v as usize] as char));
+	s
+}
+
+/// Parse a codex32 string into its values, verifying the checksum and header.
+fn parse(s: &str) -> Result<Vec<u8>, Error> {
+	if s.bytes().any(|b| b.is_ascii_lowercase()) && s.bytes().any(|b| b.is_ascii_uppercase()) {
+		return Err(Error::InvalidEncoding);
+	}
+	let data = match s.get(..HRP.len()) {
+		Some(hrp) if hrp.eq_ignore_ascii_case(HRP) => &s[HRP.len()..],
+		_ => return Err(Error::InvalidEncoding),
+	};
+	if data.len() < HEADER_LEN + CHECKSUM_LEN || data.len() > MAX_DATA_LEN {
+		return Err(Error::InvalidEncoding);
+	}
+	let values = data.bytes().map(char_value).collect::<Option<Vec<u8>>>();
+	let values = values.ok_or(Error::InvalidEncoding)?;
+	if polymod(&values) != CHECKSUM_CONST {
+		return Err(Error::InvalidEncoding);
+	}
+
+	let threshold = data.as_bytes()[0];
+	let valid_threshold = match threshold {
+		b'0' => values[HEADER_LEN - 1] == SECRET_INDEX,
+		b'2'..=b'9' => true,
+		_ => false,
+	};
+	if !valid_threshold {
+		return Err(Error::InvalidEncoding);
+	}
+	Ok(values)
+}
+
+/// Get the mnemonic from the values of the secret.
+fn to_mnemonic(language: Language, values: &[u8]) -> Result<Mnemonic, Error> {
+	let payload = &values[HEADER_LEN..values.len() - CHECKSUM_LEN];
+	let mut entropy = Vec::with_capacity(payload.len() * 5 / 8);
+	let mut acc = 0u32;
+	let mut nb_bits = 0;
+	for v in payload {
+		acc = (acc << 5) | *v as u32;
+		nb_bits += 5;
+		if nb_bits >= 8 {
+			nb_bits -= 8;
+			entropy.push((acc >> nb_bits) as u8);
+			acc &= (1 << nb_bits) - 1;
+		}
+	}
+	// The remaining bits are padding, of at most 4 bits as per BIP93.
+	if nb_bits > 4 {
+		return Err(Error::InvalidEncoding);
+	}
+	Mnemonic::from_entropy_in(language, &entropy)
+}
+
+/// Get the header values for the identifier, checking that it consists of four
+/// codex32 characters.
+fn header(threshold: u8, identifier: &str, index: u8) -> Result<Vec<u8>, Error> {
+	let mut values = vec![threshold];
+	if identifier.len() != 4 {
+		return Err(Error::InvalidEncoding);
+	}
+	for c in identifier.bytes() {
+		values.push(char_value(c).ok_or(Error::InvalidEncoding)?);
+	}
+	values.push(index);
+	Ok(values)
+}
+
+/// The payload values of the entropy of the mnemonic.
+fn payload(mnemonic: &Mnemonic) -> Vec<u8> {
+	let (entropy, len) = mnemonic.to_entropy_array();
+	let nb_values = (len * 8 + 4) / 5;
+	(0..nb_values)
+		.map(|k| {
+			(0..5).fold(0, |acc, j| {
+				let bit = k * 5 + j;
+				let b =
+					entropy.get(bit / 8).filter(|_| bit < len * 8).map(|b| b >> (7 - bit % 8) & 1);
+				(acc << 1) | b.unwrap_or(0)
+			})
+		})
+		.collect()
+}
+
+/// Encode the entropy of the mnemonic as an unshared codex32 secret with the
+/// given four-character identifier. The padding bits are set to zero.
+///
+/// Returns [Error::InvalidEncoding] if the identifier is invalid.
+pub fn encode(mnemonic: &Mnemonic, identifier: &str) -> Result<String, Error> {
+	// The threshold character `0` has the value 15.
+	let mut values = header(15, identifier, SECRET_INDEX)?;
+	values.extend(payload(mnemonic));
+	append_checksum(&mut values);
+	Ok(to_string(&values))
+}
+
+/// Split the entropy of the mnemonic into `nb_shares` codex32 shares, any
+/// `threshold` of which recover it.
+///
+/// The threshold must be between 2 and 9 and the number of shares between the
+/// threshold and 31, otherwise [Error::InvalidEncoding] is returned, as for an
+/// invalid identifier.
+#[cfg(feature = "rand_core")]
+pub fn split_with<R>(
+	rng: &mut R,
+	mnemonic: &Mnemonic,
+	identifier: &str,
+	threshold: usize,
+	nb_shares: usize,
+) -> Result<Vec<String>, Error>
+where
+	R: RngCore + CryptoRng,
+{
+	if !(2..=9).contains(&threshold) || !(threshold..=31).contains(&nb_shares) {
+		return Err(Error::InvalidEncoding);
+	}
+	let threshold_value = char_value(b'0' + threshold as u8).ok_or(Error::InvalidEncoding)?;
+	// The share indices in alphabetical order, without the secret `s`.
+	let indices = b"acdefghjklmnpqrtuvwxyz023456789".iter().filter_map(|c| char_value(*c));
+
+	let payload = payload(mnemonic);
+	let mut secret = header(threshold_value, identifier, SECRET_INDEX)?;
+	secret.extend_from_slice(&payload);
+	append_checksum(&mut secret);
+
+	// The secret and `threshold - 1` random shares define all other shares.
+	let mut defining = vec![secret];
+	let mut shares = Vec::with_capacity(nb_shares);
+	for (i, index) in indices.take(nb_shares).enumerate() {
+		let share = if i + 1 < threshold {
+			let mut share = header(threshold_value, identifier, index)?;
+			share.extend(payload.iter().map(|_| (rng.next_u32() & 31) as u8));
+			append_checksum(&mut share);
+			defining.push(share.clone());
+			share
+		} else {
+			interpolate(&defining, index)
+		};
+		shares.push(to_string(&share));
+	}
+	Ok(shares)
+}
+
+/// Decode an unshared codex32 secret into a mnemonic in the given language.
+///
+/// Returns [Error::InvalidEncoding] if the string is not a valid codex32
+/// secret and [Error::BadEntropyBitCount] if its length is not valid for a
+/// mnemonic.
+pub fn decode_in(language: Language, s: &str) -> Result<Mnemonic, Error> {
+	let values = parse(s)?;
+	if values[HEADER_LEN - 1] != SECRET_INDEX {
+		return Err(Error::InvalidEncoding);
+	}
+	to_mnemonic(language, &values)
+}
+
+/// Decode an unshared codex32 secret into an English mnemonic. See [decode_in].
+pub fn decode(s: &str) -> Result<Mnemonic, Error> {
+	decode_in(Language::English, s)
+}
+
+/// Recover a mnemonic in the given language from codex32 shares.
+///
+/// The secret itself, with share index `s`, is enough on its own. Returns
+/// [Error::InvalidEncoding] if a share is invalid, if the shares don't belong
+/// together or if there are fewer than the threshold.
+pub fn combine_in(language: Language, shares: &[&str]) -> Result<Mnemonic, Error> {
+	let shares = shares.iter().map(|s| parse(s)).collect::<Result<Vec<_>, _>>()?;
+	let first = shares.first().ok_or(Error::InvalidEncoding)?;
+	let header = &first[..HEADER_LEN - 1];
+	let consistent = shares.iter().enumerate().all(|(i, share)| {
+		share.len() == first.len()
+			&& &share[..HEADER_LEN - 1] == header
+			&& shares[..i].iter().all(|o| o[HEADER_LEN - 1] != share[HEADER_LEN - 1])
+	});
+	if !consistent {
+		return Err(Error::InvalidEncoding);
+	}
+	if let Some(secret) = shares.iter().find(|s| s[HEADER_LEN - 1] == SECRET_INDEX) {
+		return to_mnemonic(language, secret);
+	}
+
+	// Without the secret, the threshold is at least 2.
+	let threshold = (CHARSET[first[0] as usize] - b'0') as usize;
+	if shares.len() < threshold {
+		return Err(Error::InvalidEncoding);
+	}
+	to_mnemonic(language, &interpolate(&shares[..threshold], SECRET_INDEX))
+}
+
+/// Recover an English mnemonic from codex32 shares. See [combine_in].
+pub fn combine(shares: &[&str]) -> Result<Mnemonic, Error> {
+	combine_in(Language::English, shares)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use bitcoin_hashes::hex::FromHex;
+
+	#[test]
+	fn test_field() {
+		for a in 1..32 {
+			assert_eq!(gf_mul(a, gf_inv(a)), 1);
+		}
+	}
+
+	#[test]
+	fn test_vectors() {
+		// Test vector 1 of BIP93.
+		let s = "ms10testsxxxxxxxxxxxxxxxxxxxxxxxxxx4nzvca9cmczlw";
+		let m = decode(s).unwrap();
+		assert_eq!(
+			m.to_entropy(),
+			Vec::<u8>::from_hex("318c6318c6318c6318c6318c6318c631").unwrap()
+		);
+		assert_eq!(decode(&s.to_uppercase()), Ok(m.clone()));
+
+		// Test vector 2 of BIP93.
+		let a = "MS12NAMEA320ZYXWVUTSRQPNMLKJHGFEDCAXRPP870HKKQRM";
+		let c = "MS12NAMECACDEFGHJKLMNPQRSTUVWXYZ023FTR2GDZMPY6PN";
+		let secret = "MS12NAMES6XQGUZTTXKEQNJSJZV4JV3NZ5K3KWGSPHUH6EVW";
+		let m = combine(&[a, c]).unwrap();
+		assert_eq!(
+			m.to_entropy(),
+			Vec::<u8>::from_hex("d1808e096b35b209ca12132b264662a5").unwrap()
+		);
+		assert_eq!(combine(&[c, a]), Ok(m.clone()));
+		assert_eq!(combine(&[secret]), Ok(m));
+	}
+
+	#[test]
+	fn test_invalid() {
+		let s = "ms10testsxxxxxxxxxxxxxxxxxxxxxxxxxx4nzvca9cmczlw";
+		assert_eq!(decode(&s.replace("sxx", "sxy")), Err(Error::InvalidEncoding));
+		assert_eq!(decode(&s.replace("ms1", "MS1")), Err(Error::InvalidEncoding));
+		assert_eq!(decode(&s[1..]), Err(Error::InvalidEncoding));
+		assert_eq!(decode("ms1"), Err(Error::InvalidEncoding));
+
+		// A share below the threshold, or the same share twice.
+		let a = "ms12namea320zyxwvutsrqpnmlkjhgfedcaxrpp870hkkqrm";
+		assert_eq!(combine(&[a]), Err(Error::InvalidEncoding));
+		assert_eq!(combine(&[a, a]), Err(Error::InvalidEncoding));
+		assert_eq!(decode(a), Err(Error::InvalidEncoding));
+
+		let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+		assert_eq!(encode(&m, "tst"), Err(Error::InvalidEncoding));
+		assert_eq!(encode(&m, "tsti"), Err(Error::InvalidEncoding));
+
+		// An extra payload character leaves 7 padding bits.
+		let mut values = header(15, "test", SECRET_INDEX).unwrap();
+		values.extend(payload(&m));
+		values.push(0);
+		append_checksum(&mut values);
+		assert_eq!(decode(&to_string(&values)), Err(Error::InvalidEncoding));
+		assert_eq!(decode(&encode(&m, "test").unwrap()), Ok(m));
+	}
+
+	#[cfg(feature = "rand")]
+	#[test]
+	fn test_split() {
+		let mut rng = rand::thread_rng();
+		for len in &[16, 20, 24, 28, 32] {
+			let m = Mnemonic::from_entropy(&[0xa5; 32][..*len]).unwrap();
+			assert_eq!(decode(&encode(&m, "cash").unwrap()), Ok(m.clone()));
+
+			let shares = split_with(&mut rng, &m, "cash", 3, 5).unwrap();
+			let shares = shares.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+			assert_eq!(shares.len(), 5);
+			assert!(shares[0].starts_with("ms13casha"));
+			assert_eq!(combine(&shares[..3]), Ok(m.clone()));
+			assert_eq!(combine(&[shares[4], shares[1], shares[3]]), Ok(m.clone()));
+			assert_eq!(combine(&shares[..2]), Err(Error::InvalidEncoding));
+		}
+		let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+		assert!(split_with(&mut rng, &m, "cash", 1, 5).is_err());
+		assert!(split_with(&mut rng, &m, "cash", 3, 2).is_err());
+	}
+}
@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0xfcc6e2b0, 0x8d469043, 0x96b646f4, 0x51811114, 0x9846a194, 0x43400b97, 0xfcbfe1e2, 0xf168750e,
+	0x273fdf83, 0xdfa281f3, 0xb846d3f4, 0x37f6a0b1, 0xa746b931, 0x9746a001, 0xb746d261, 0x3e804794,
+	0xdcddc496, 0x403f5b47, 0x4bb8f246, 0x113f114a, 0x8dd4f974, 0xfcbc104b, 0xacae5fda, 0x373f4d1c,
+	0x77ae0c6b, 0x4150ee18, 0x0b4c7f62, 0x3980eb4c, 0x3b3ffeff, 0x454d22cd, 0xb21575d3, 0x7751431a,
+	0xc211bd6c, 0xddddc629, 0x0f505745, 0x50624ed6, 0xa646b79e, 0x6a6277c4, 0x4b2a99fd, 0xd5565442,
+	0xad638cd4, 0xc626de70, 0x4a43e833, 0xc3724c13, 0x96469e6e, 0xfda2b12d, 0x5f0ca465, 0x4aca39d4,
+	0x223fd7a4, 0xc9d1cb5a, 0x704074bb, 0x74788b7b, 0x88d8c32c, 0xaf638ffa, 0x22f67fa2, 0x9b10d470,
+	0x16c56aca, 0x490c81c3, 0x7119f051, 0xa7d99f90, 0x6d193e6e, 0xe8385d17, 0xe818b218, 0x2e5d9c22,
+	0x94469b48, 0x5e4407af, 0xe1a28519, 0xdac2db93, 0x41ea8d28, 0xce157c17, 0x372e4c18, 0xc71119b4,
+	0x91ae3559, 0xa456071f, 0x0a09ba2f, 0x62fb6190, 0x921978ad, 0x1759a656, 0x7e7837bf, 0x506709ae,
+	0x3d400225, 0x1eb67aac, 0x3d959e63, 0xf015d76d, 0x29a542dc, 0x1893def8, 0x9f10dabc, 0xdd5660da,
+	0x0b09bbc2, 0xd9158d68, 0xb146c8ef, 0x0e4c841b, 0x4750f78a, 0x0bbff97f, 0xdc34789c, 0x2a4cb02f,
+	0x998bafaf, 0x193f1de2, 0x874686d1, 0x19c00f89, 0x3c5e5dc3, 0x13e29859, 0x1ef02134, 0x41fa8206,
+	0x692e9ace, 0xa9337cbc, 0x9410c96b, 0xa9638688, 0xdea28060, 0x23801d13, 0x95ae3ba5, 0xc949756b,
+	0x46401050, 0x0e8657b0, 0x92521932, 0x26f685ee, 0x31f6973f, 0x06d1e658, 0x5c8076ce, 0x80d4e4fd,
+	0x5f10fbc3, 0x16d48328, 0x60440ad5, 0x363ff720, 0x442a8ef8, 0xad342e9f, 0x1ec7c3cd, 0xd7498b75,
+	0x32c596de, 0x6232e727, 0x8f408364, 0x9b46a64d, 0xcc497a24, 0xa563803c, 0x0909b89c, 0x91d8d157,
+	0x9815f591, 0x2a4c4cb5, 0xffd12fbc, 0x2334a06d, 0x1c3aed8a, 0x6e627e10, 0x1f4c9ede, 0x77b61627,
+	0xabc052ff, 0x605e966f, 0x736285ef, 0x5f440942, 0x4a50fc43, 0x6037eaa2, 0xdfddc94f, 0x014c6fa4,
+	0x509667e3, 0x7219464d, 0x28e9ba36, 0x92636253, 0x47102a64, 0xc2677f7a, 0x1c349568, 0x63626cbf,
+	0x00bc1697, 0x556256b5, 0x891de7b0, 0xf418c4fc, 0x49b84389, 0x4c32c485, 0x96fb6b78, 0xbd2fcaa1,
+	0xfdbfe375, 0xd3498529, 0x7bd4dd1e, 0x9c04b2bc, 0x1c4c36ab, 0xed3864f6, 0x20f3f5f1, 0x6b787d50,
+	0x05bff00d, 0x8bef7ed4, 0x3e3f5821, 0x12f3dfe7, 0xbd26d045, 0x3e0c4aa2, 0x492a96d7, 0x9bea2a36,
+	0x12c7b0e9, 0x3862033e, 0xe549a17f, 0xa3f37633, 0x1a185822, 0x554027ed, 0x65c3715b, 0x68d890cc,
+	0xcb9de549, 0xdf67ad21, 0x694069b6, 0xfc4c67c5, 0xda49902e, 0x3929d210, 0x7ed8b36e, 0x02eff520,
+	0x05351cca, 0x126ae798, 0xb03387c1, 0x0b233184, 0x46ce051f, 0x75628915, 0xa9e2e21b, 0x19a529ac,
+	0x313f43aa, 0x5b2e5ef4, 0x3a16af8e, 0x6f78839c, 0x44f6b528, 0xc58a1675, 0x0e955466, 0x43a6f0f6,
+	0xd11f049f, 0xb5904b1d, 0xa0160229, 0xd215a833, 0x41804c4d, 0x36188436, 0xf818cb48, 0x8ceabe30,
+	0xb1bfb0da, 0x19a6aed8, 0xe715c942, 0xe318aa39, 0x27b80e03, 0x5bce262e, 0x4c50ff69, 0x45fa8852,
+	0xb2d9b0e1, 0x18b7f666, 0xe6bfbf40, 0x854073a6, 0x42cdfed3, 0xe6a28cf8, 0x214c3e8a, 0x5ee5f898,
+	0x2b4cf9df, 0x4c43eb59, 0x4359eb9a, 0xc333a5aa, 0x07d1e7eb, 0x344c5c73, 0x45bcba6b, 0xb9c42ccc,
+	0x452a908b, 0x5d3fd10b, 0x163ae418, 0xe5ddd2c1, 0xf749bdd5, 0x61807ead, 0x5a511573, 0x263f3259,
+	0x69627631, 0x15e34716, 0x14f66998, 0xbb493991, 0x185d7980, 0xfe18d4ba, 0x48898f58, 0xf649bc42,
+	0x4632bb13, 0x3c5db22c, 0xc84798bb, 0x831dde3e, 0x1859a7e9, 0x540c6d44, 0x98cd0109, 0x2ab812bc,
+	0xa98c7476, 0x426212fc, 0x8e4691d6, 0xff1f4d09, 0x5c6261ba, 0x894689f7, 0xbf11b8b3, 0xcf7988a9,
+	0x4c5e76f3, 0x283b006e, 0x83468085, 0xfd09a5b8, 0xdef32a68, 0x383ffa46, 0xd61f0c7e, 0xd2495dc6,
+	0x811ddb18, 0xe8efcc32, 0x16c00ad0, 0x50c3504c, 0x253fdc5d, 0x6862749e, 0x5e77bd42, 0x1b3f2108,
+	0x60ce2e0d, 0xd2498396, 0xce497d4a, 0xe2bbe75d, 0xffd1db53, 0x57ca4e4b, 0x0cd1efca, 0x31055842,
+	0x270c4952, 0x324c594d, 0x1309c85a, 0x56a7ba76, 0x7fb6ce56, 0x1a5d7ca6, 0x6c6300b1, 0x785144ad,
+	0x9ad98b19, 0x95ccfc50, 0x28d17047, 0x3b0c45e9, 0x4388dbe2, 0x3729ceea, 0x8e10bff9, 0x4380fb0a,
+	0xd9af4474, 0x5ca7c3e8, 0xde4b8cf4, 0xb7c065e3, 0xde56626d, 0xc84973d8, 0x92469822, 0x5737dc77,
+	0x08c7a12b, 0x3966e579, 0x46a6f5af, 0x4295a642, 0xa7d195d4, 0x1ad48974, 0x56ce1e4f, 0xc2f3a9eb,
+	0xfce27424, 0x6ed89a3e, 0x9110c4b2, 0x1c08666a, 0xd44986bc, 0x93d0cac1, 0x315da0db, 0xae638e67,
+	0x88e20291, 0xb4d9b407, 0xadd19f46, 0xc933af1c, 0x944746df, 0x6e73a161, 0x5288f37f, 0x63ca612f,
+	0x140859d2, 0xfbd12970, 0x9cf416c5, 0xfc49c5b4, 0x3a4c65e5, 0x153f1796, 0x3450b3d1, 0x15052c2e,
+	0x4550ac47, 0x608128b1, 0x44ce01f9, 0x1d3496fb, 0x0d0c2064, 0x402a88ac, 0x9bbf8e38, 0x4cce0e91,
+	0x2c3f5e18, 0x7d3be9e0, 0x70de0b32, 0xa6af01ff, 0xfcdea28d, 0x5ec36656, 0xec386363, 0x54625522,
+	0x81467d5f, 0xa0f41d11, 0x28b80f96, 0xf29156bb, 0xe018a580, 0xbbc05e5b, 0xbe344962, 0xbfe304bd,
+	0xf94c630c, 0x64e6020a, 0xcf458776, 0x12505bfe, 0x4143da08, 0x142997d1, 0x0df65e93, 0xbe110b89,
+	0xc04595a9, 0xdf5f388c, 0xe54a2746, 0x596fd48b, 0x3c4022df, 0x6c7449d2, 0xe4a289d2, 0x94d8d610,
+	0x0d23e041, 0xda4de78c, 0xe7a28e8b, 0x97bf87ec, 0x2c5d98fc, 0xaf676191, 0x96ff8503, 0x24fba8a2,
+	0x19e2a1cb, 0x17f66e51, 0x355da727, 0x9f55ff40, 0x4040292b, 0xb5c062bd, 0x68512b7d, 0x4743e37a,
+	0x0e6ae14c, 0x9f160096, 0x16d1ff88, 0xb48c77f3, 0x4d624a1d, 0xa6fb84a8, 0xcc564617, 0xaa4b3b18,
+	0x25802039, 0xf538718e, 0xda04f171, 0x575e8844, 0x1329963e, 0xf449b91c, 0x144c8d8d, 0xc649f679,
+	0x3a27f8af, 0x4dc34b93, 0x612e8e36, 0x8ed16e79, 0x32f698d2, 0x494d2919, 0x9700d946, 0x3d3bcd14,
+	0x4862423e, 0x0f93d0cd, 0x56d4a2df, 0x1318f5c9, 0x6351239e, 0x2d0c2fdf, 0xbf40cef4, 0x1e4c39d1,
+	0x670cb0fd, 0x5a3bfabb, 0x6337ef5b, 0x2f3fec1b, 0x343b1352, 0x17d15584, 0x96d8d936, 0xc849f99f,
+	0x10c0acf5, 0x271691a5, 0xac861d83, 0x98c03516, 0x0393bde9, 0x5ce5f572, 0x8a407b85, 0xc4496d8c,
+	0x5ab5e880, 0x89120c5d, 0x08232ccb, 0x05183713, 0x0fa519ee, 0x93ff804a, 0x3b5db099, 0x7e4678a6,
+	0x8e9d9316, 0x5273d8c7, 0x535e81f8, 0x1d3fcfc5, 0x5cca562a, 0x360c3e0a, 0x21186327, 0x678133b6,
+	0x06598b93, 0x0b931eea, 0xfa4c649f, 0xd1a1c052, 0x750801e5, 0x405e640f, 0x07183a39, 0x2f87373a,
+	0x7d6295ad, 0xa31606e2, 0xe118a713, 0x96335ed3, 0xcd63bf34, 0x3bca2237, 0x053ce21c, 0x323f453d,
+	0xb046c75c, 0xb256a2f0, 0x6a2e9c61, 0x1427bcdd, 0x9d10d796, 0xc8382ab7, 0x0b4e34af, 0x61aa1832,
+	0x3584f441, 0x49f6bd07, 0xd515acec, 0x0b347aa5, 0x4ace0b6b, 0xcd04dcfa, 0x4bfa91c4, 0xf29ffdcb,
+	0x99ead2a7, 0x87b62f57, 0xd8a1cb57, 0x85120611, 0xf8d82585, 0x408982c0, 0xa56751d3, 0x2e29c0bf,
+	0xcc63bda1, 0x24507854, 0xeabfc58c, 0xb911af41, 0x80d9622b, 0x2b510920, 0xe15f3bb2, 0xd6496412,
+	0xab46bf7d, 0x1bb675f3, 0x3f8503ff, 0x7f195ac4, 0xc0563333, 0xcd6790cb, 0xd5bbd2e6, 0x4b6246f7,
+	0x43512ee8, 0x1dc015d5, 0x2f29c252, 0x54a7b750, 0x90de3d92, 0x474011e3, 0x09298680, 0x37803c8f,
+	0x9c1a3402, 0xe2a286ac, 0x580c9960, 0xefbbfbd4, 0xa3eae265, 0xce4b73c4, 0x7b1dd1a6, 0xfcefebae,
+	0xe215c163, 0x59625d01, 0x84d8bce0, 0x504d341e, 0xad40b29e, 0x9008d7fd, 0xf7efe3cf, 0x22349eda,
+	0x903cb360, 0x60dba597, 0xcbf3b816, 0x88853940, 0x1e34988e, 0x3d804601, 0x496243d1, 0x5e857ce9,
+	0xa21a3d74, 0x2cb815e2, 0x203f28e7, 0x3f964d20, 0x1ffba0c3, 0x1da48461, 0xc926e329, 0xa6efa955,
+	0x74d4d219, 0xe8bfc266, 0xc41114fb, 0x0a18e79e, 0x49a7a5ff, 0xf8bfdb96, 0x6bca6dc7, 0x7b63184e,
+	0xfa49c28e, 0xca4976fe, 0x6a089c2b, 0x55510d94, 0xfde275b7, 0x43623a5f, 0xb990fd00, 0x1bc0be46,
+	0xe418abcc, 0xd126efc1, 0x3a66e70c, 0x06bc2009, 0x94ae3a12, 0xed4a33de, 0x05a01bb4, 0x879d8811,
+	0x25962432, 0x444d213a, 0x09840366, 0x0018d7e0, 0xcb497891, 0x29508033, 0x084c7aa9, 0xa58566e7,
+	0x81b6d17c, 0x29a6c808, 0x99474ebe, 0x0cd14433, 0xd18a2959, 0x33f69a65, 0x1ff67ae9, 0x350c3c77,
+	0x00e97b3e, 0xc78c95dc, 0x4f5dd015, 0x4c62488a, 0x5a9677a1, 0xa4637ea9, 0xaf119f83, 0x90b63d82,
+	0x5677b0aa, 0x2fb81a9b, 0x3116a163, 0x9bae4517, 0xa05600d3, 0xb726c6d3, 0xe59096ad, 0x47627e55,
+	0xe1bbe5ca, 0x7c6319e1, 0xe441b4ca, 0xb9639fb8, 0xd65655d5, 0x285d92b0, 0xd74965a5, 0x4f401e7b,
+	0x5e511bbf, 0x5c511899, 0x2ee2c2da, 0x717886c2, 0x2ce2bfb4, 0x908545d8, 0x30ea7265, 0x576259db,
+	0xf93877da, 0x21e359fa, 0x1bc012af, 0xd79080a3, 0xb1af1350, 0xbe38a0c0, 0x85b6d7c8, 0xa7efaae8,
+	0x5996760e, 0x7def68ca, 0xc8e312e8, 0xc6a25a98, 0xd138bea9, 0xb052486c, 0xbe1588b7, 0x1e29a78f,
+	0xe515c61c, 0xdb7fe965, 0x334c5ae0, 0x86d96b9d, 0xaaa190c1, 0x3f3f59b4, 0xfa4a4855, 0xdb496bf1,
+	0xb410fbcb, 0x2793f695, 0x6f627fa3, 0x4dca3e8d, 0xef915202, 0xcf3835bc, 0x53ce1996, 0x8eeac156,
+	0xe12708f1, 0x5589a3cf, 0x4ea7adde, 0x85d96a0a, 0xc763b5c2, 0xab16137a, 0x2cc7d9d7, 0xcc4b709e,
+	0x0d50541f, 0xe45f406b, 0xa2e22b7f, 0x47a7a2d9, 0x533befb6, 0x06de06b4, 0x61ee911f, 0x9f855d75,
+	0x8a00c4cf, 0x13d14f38, 0x8daedaa4, 0xb310fa38, 0xe3c46eea, 0x384c62bf, 0xe2727ce0, 0xfa18ce6e,
+	0x61c36b0f, 0x98855270, 0x95521deb, 0xdb1f145d, 0x88468864, 0x305d9f48, 0x4fca41b3, 0x463b2fa8,
+	0xf24a3bbd, 0x183f1c4f, 0xb01249c2, 0x36c7e995, 0x74628782, 0xfb231854, 0xf52b7fcb, 0x4c851876,
+	0x1cf67630, 0x6251220b, 0x93d17658, 0x3161f839, 0x440c5414, 0x724077e1, 0x0f23e367, 0x5937df9d,
+	0xa9bfa442, 0x26c724ce, 0xef18bd1d, 0x6077c068, 0x05165c1f, 0xf0bbfd67, 0x5040200e, 0x2d6b1219,
+	0x79d4d9f8, 0xe3909387, 0x545dd7f4, 0x633fda7d, 0x8e8c49f5, 0x2ef69286, 0xa01187e6, 0xaf8c7de8,
+	0xfba2ae07, 0xcbf30c7f, 0x4b8516e3, 0x3f5db6e5, 0x0423267f, 0x3c50e639, 0x17decd0e, 0xec11b796,
+	0x5b0c9e19, 0xa7c8566f, 0x7aae1124, 0xb91103aa, 0xfa38796d, 0x86407539, 0x9d40996e, 0xf2d11b45,
+	0xa44b31a6, 0xc863b755, 0x92ae36ec, 0x06051491, 0x64441121, 0xa27b127c, 0xb4676970, 0x7ed4e1d7,
+	0x6d2b3326, 0x1e0168c2, 0x5d967c5a, 0x26e361d9, 0x44621622, 0xdf49723d, 0x7338088b, 0x75b2416a,
+	0x9bf3699b, 0x5043f1a5, 0x6cfb714e, 0x594928be, 0xf6ef36a5, 0x90d97b5b, 0xd43c94f9, 0x33ca159f,
+	0x80e62e1e, 0xb004af53, 0x8d10be66, 0x06deb24b, 0x502e7373, 0x9915f724, 0x4459ed2d, 0x9b117d1c,
+	0x4e50dcbf, 0x98636bc5, 0xe838e2de, 0x595113e0, 0x556fce3f, 0x20f0245a, 0x4a6f1157, 0x35ca18c5,
+	0x57ce1fe2, 0x86fb5248, 0xb610fef1, 0xe64a28d9, 0xcd7f27c4, 0x3e8456d5, 0xbc89e27a, 0xa700f276,
+	0x9dd8e43b, 0x560c963a, 0xc76e80c8, 0x8be2074a, 0x3f73bade, 0x8973f1b2, 0x41921b35, 0xb20d8ab1,
+	0x1ea485f4, 0x1e053a59, 0x0ac557e6, 0x04351b37, 0xfa45a903, 0xb5eafebb, 0xf4345670, 0xa11e0d78,
+	0x5b511706, 0x0ad1eca4, 0xe1a1d982, 0x18c7ba5b, 0x54ce1b29, 0x61b221ee, 0x8300b9ca, 0x3843cbdd,
+	0xfb826830, 0x73bf4f40, 0x5943ffd0, 0xdfbbe2a4, 0xb57fad93, 0x9610cc91, 0xa189b7f9, 0x4b966004,
+	0xfb3900c7, 0x2e8735a7, 0x41ca2ba9, 0x099798f2, 0x3a509af6, 0xc156ba8d, 0x163f1929, 0xd1a26be9,
+	0x56625848, 0x9d46a973, 0xc28a11bc, 0x6ce9e039, 0xcf497edd, 0xf0de8fa9, 0xc733abf6, 0xd95d53fd,
+	0xb11e26a8, 0x683fe25c, 0x97f40ee6, 0x8ed97835, 0x2eea6f3f, 0x5743fcaa, 0xcfa1bd2c, 0xe05f3a1f,
+	0x778c25c0, 0x8e1168a5, 0xf6387321, 0xc06e75c3, 0xa174177a, 0x05bc1e76, 0xe26eab49, 0x33803643,
+	0xab337fe2, 0xf249b5f6, 0xf2729610, 0x66b5fb64, 0xa7d0ea3d, 0x3743ca4a, 0x1ac0bcb3, 0x5440265a,
+	0xa44e1bfd, 0x91f79202, 0xf0efd8ca, 0x55dd351a, 0x4e3be7d7, 0x00d8321d, 0xffc6e769, 0xb6477c65,
+	0x39401e26, 0x2fc59225, 0x5aca5304, 0x140cd700, 0x1b738232, 0xbb90548f, 0xbd158724, 0x29c588b3,
+	0xc21111d5, 0x93d98014, 0xdd41a9c5, 0x0d298ccc, 0x4150a5fb, 0xb8cc42c9, 0xb3862888, 0xc3d1c1e8,
+	0xa110dde2, 0x7c19560b, 0x27fa5918, 0xfbc30f86, 0x76e61e60, 0x7fc39a49, 0x1b3fcc9f, 0x1ac0111c,
+	0xab10eda0, 0x572e7e78, 0x7f115108, 0xfba4fa72, 0x4cb84842, 0x8db638c9, 0x6350fdce, 0xfc2b8ad0,
+	0x6dca70ed, 0x382e4dab, 0x0a183ef2, 0x2ae2bc8e, 0xc0c0663a, 0xe4728006, 0xc367810d, 0x990033c0,
+	0x76b242fd, 0xb2005b1b, 0x1818fda8, 0x7db24e02, 0x5b5e8e90, 0x37c7eb28, 0x2bc58bd9, 0x6596d10f,
+	0x660caf6a, 0xb92fc455, 0x3e5db552, 0xcc725a3e, 0x0c184218, 0x4843e50d, 0xa146afbf, 0xe44a25b3,
+	0xdc5f33d3, 0xb8eb0374, 0xc7678759, 0x0793189e, 0x2e192335, 0x9bead5cd, 0xe6bbeda9, 0x60786bff,
+	0xb8525504, 0x0193bac3, 0x3f95a189, 0xb9905169, 0x366b2044, 0x4c0c867c, 0x44ca3062, 0x304d01be,
+	0xdf727827, 0x3d620b1d, 0x08352183, 0xea18b53e, 0x385dabe0, 0x028f7971, 0x0a504f66, 0xcc4bf665,
+	0x603bbc39, 0x861965c9, 0xa810e8e7, 0x1d84ce79, 0xde270438, 0x698b641f, 0x5619622d, 0xba33977f,
+	0x6450ff61, 0xf7bc086c, 0x2ffa65b0, 0x871de48a, 0x5e3facce, 0xddbbdf7e, 0x93eac935, 0x6507e8b5,
+	0x232e06cc, 0x6d558aba, 0xe31f20f5, 0xba8c8165, 0xfdefed41, 0x3c959cd0, 0x6c2e9f87, 0x42f6b202,
+	0x1f507075, 0x0d932210, 0xa6f37aec, 0x400c4dc8, 0x05931578, 0x29957ee7, 0xdd347a2f, 0x4f624d43,
+	0xff8ec921, 0x14cd7f34, 0x3ec7f62d, 0xbfc064a7, 0xd14a07ca, 0x482e66db, 0x506f1ac9, 0xda347576,
+	0x927af94c, 0xa8160ec1, 0x603c042d, 0xc104ecfb, 0x30c7e023, 0x742b3e2b, 0x2ee9c3a8, 0xbdd1b876,
+	0xd356511c, 0x82ee1c66, 0xe08a40f6, 0x7bc393fd, 0x2027cfc1, 0x3a2a7f3a, 0x0b6adc93, 0x77ef5f58,
+	0xf781b64d, 0x31625bb3, 0x891a1619, 0xe76eb328, 0xd6f3c967, 0x23d914cd, 0xff351358, 0x4d1033d6,
+	0xb24b47b0, 0xd311d82f, 0xf1d119b2, 0xc43452d4, 0x2b625241, 0x65d88c13, 0x30439975, 0x322e4439,
+	0x9415ef45, 0xc233a417, 0x50555d13, 0x2d50867f, 0x21e2ae63, 0xf29c2c34, 0x0701448d, 0xaebae9ea,
+	0xcd7fd35b, 0x10b5b90b, 0xa0eef737, 0x532e782c, 0x29ea6760, 0x0def5ada, 0xfeddfa1c, 0x45ce038c,
+	0x9aeeedc5, 0x37fa7248, 0x2b0c2cb9, 0x0945c0a0, 0x715139a8, 0x52d49c93, 0xbfa24f93, 0x4e5dce82,
+	0x77194e2c, 0x53103d48, 0x283f357f, 0x5a37e130, 0xa11a3be1, 0x340c3ae4, 0xdf22ec40, 0x7dc768ba,
+	0x129329ef, 0xb6343cca, 0x195d7b13, 0x5d786746, 0x0ff661b9, 0x1cded4ed, 0x223af6fc, 0xfa3cd0cb,
+	0x26987230, 0x86e1ff6b, 0x393ffbd9, 0x321da3c4, 0x71814374, 0x5050dfe5, 0x3e4d17c8, 0x233fd937,
+	0x04e280bc, 0xefe58ee4, 0xd1495c33, 0xd0495aa0, 0x8c468eb0, 0xbb1583fe, 0xc3a255df, 0x86ee22b2,
+	0x3250b0ab, 0xf4a2a302, 0x6b19e6df, 0x14a47636, 0x04466458, 0x5b1049e0, 0xd9a1ccea, 0xc963b8e8,
+	0x0c09bd55, 0x2a98787c, 0x6ed4c8a7, 0x27fbad5b, 0x703feef4, 0xc7497245, 0xa25689c0, 0x22fba57c,
+	0xa0d18acf, 0xda6e9eb1, 0x760c80a6, 0x1fa48787, 0xac9db47c, 0x3e3b2310, 0x9fd992f8, 0x19f3eaec,
+	0x95eee5e6, 0x43102418, 0x9dfb767d, 0x14c007aa, 0x86b6d95b, 0x9d15fd70, 0x8000b511, 0x3f3bd03a,
+	0xdb15b65e, 0x73aa3488, 0x2a46a02a, 0xb2a18f85, 0x33fa6bfc, 0x53966c9c, 0x2edcf7b5, 0x9c637211,
+	0x18167a08, 0x5cd4ac51, 0x26d91986, 0x48741126, 0x35c7e802, 0x2ce36b4b, 0xa0336e91, 0x8dbab5f7,
+	0xc552697b, 0x55966fc2, 0x7c3be84d, 0x0809b709, 0x53ca47ff, 0x203fd47e, 0xf1f3f3e8, 0xdb5f3240,
+	0xa210df75, 0x375daa4d, 0xd915b338, 0x5732d5d6, 0x78e9f31d, 0x868b91c6, 0x7b3be6ba, 0x514c8a1a,
+	0x7d3c9577, 0x67fb696f, 0xbcd0fd78, 0x1b190261, 0xeade8637, 0xb563996c, 0x033efb40, 0xf61f3ede,
+	0x6d106636, 0xe538585e, 0x71f6b40b, 0x7ec76a4d, 0xbda1a0d6, 0x24957708, 0x99e2c8eb, 0x044e29aa,
+	0x5ace249b, 0x8fae3233, 0xba63a14b, 0x2e802e64, 0x9015e8f9, 0x296edd64, 0x5b967934, 0x8c10bcd3,
+	0x1393d719, 0xd326f2e7, 0x5b786420, 0xa9856d33, 0xc17fc077, 0x4932bfcc, 0x060142fa, 0xea2b6e7a,
+	0x2a4cf84c, 0x5f7389c4, 0x012b92af, 0x20d163af, 0xf0ef2d33, 0xba52582a, 0x5e4d4a28, 0x21d911a7,
+	0xdb8f3c0c, 0xf3dde8cb, 0x948ba7d0, 0xd79c01b3, 0x9b15fa4a, 0x99d0d433, 0x602e8ca3, 0x3bc7f174,
+	0x0aa5120f, 0x841158e7, 0x4b4d2c3f, 0xf3c302ee, 0x79b6194d, 0xdd047063, 0x3927f71c, 0x2cea6c19,
+	0xc578cd54, 0x99ea2710, 0x4519476a, 0xeebfcbd8, 0x592e819e, 0x1dc575cf, 0x153f9d5d, 0x99ff89bc,
+	0x40964eb3, 0xa26d9af2, 0x4afa9031, 0x396fa22b, 0xf6f3b3d3, 0x4f43f012, 0x0c50528c, 0xf9ef3b5e,
+	0x0959904c, 0x9215ec1f, 0xb5d9b59a, 0x33a6d7c6, 0x41783b32, 0xb5343b37, 0x4966fea9, 0x2a3fe43c,
+	0xd3b31a6d, 0x0ce28d54, 0xcf6e8d60, 0xbed1ba09, 0x38511d97, 0xaf477160, 0xaa124050, 0xa68c61e9,
+	0x682e993b, 0xb8743baf, 0x8e15e5d3, 0xc311beff, 0x2f169e3d, 0x63e60077, 0x1c234c47, 0x429651d9,
+	0x4832be39, 0x17c0b7fa, 0xeea43a64, 0xa78c7150, 0x11233af6, 0x93f35d03, 0xe5f3356d, 0x55a7b8e3,
+	0xe8571dc2, 0xb044d0e2, 0x6a07f094, 0x5d5e91b6, 0xab4bc272, 0x4188d8bc, 0x3573ab20, 0xae857512,
+	0xfcc08d79, 0xc37fc39d, 0xc56dd20b, 0x353f49f6, 0x60d4b29d, 0xbf158a4a, 0x5da7c57b, 0x354c5e06,
+	0x579672e8, 0x9bcd05c2, 0xec9ff459, 0x8d521153, 0xd738c81b, 0xd556ffd9, 0x370c3f9d, 0xfc4e1d12,
+	0x76194c99, 0xc9157438, 0x431d15db, 0x122994ab, 0x17052f54, 0x1a506896, 0x5aa7c0c2, 0x183fc7e6,
+	0x6137ec35, 0x9dcc1848, 0xd17f2e10, 0x3b50e4a6, 0xfa1f452a, 0xb6d1ad71, 0x7fe9fe22, 0x94408b43,
+	0x4a3fb322, 0x4f6f1936, 0x4373c12a, 0x24191377, 0x7cae144a, 0xa51a422d, 0xf9c30c60, 0xa1efa176,
+	0xde3ca4b7, 0x1c168054, 0xc44947bc, 0x14505f24, 0x3f16b76d, 0x938c4400, 0x2fa54c4e, 0xf218c1d6,
+	0x988bae1c, 0x456b37e1, 0x1fdce018, 0x0e18edea, 0xfabfdebc, 0xfd23c711, 0xd715b012, 0xd063c3ed,
+	0xd34a0af0, 0xffc09232, 0xcf6793f1, 0xe3385538, 0x3c62098a, 0x9a1a30dc, 0xa9f37fa5, 0x233af88f,
+	0x911a22b1, 0xa34b0a43, 0xddde71c0, 0xe822fa6b, 0xb9eb0507, 0x3e2e314d, 0x21a6bb70, 0xd0f3bff5,
+	0x072b9c21, 0x51966976, 0x206240f0, 0xd033ba21, 0x90c786a3, 0x941a276a, 0x432a8d65, 0xb82fc2c2,
+	0xeb15cf8e, 0x6feea729, 0x9315edb2, 0xa5337670, 0xe24df424, 0x29a91473, 0x57191bcc, 0xac10ef33,
+	0x9aff8b4f, 0x333f691d, 0x38ba7531, 0x13f00fe3, 0xc51593bc, 0x10b664a2, 0xcb382f70, 0x96ea2257,
+	0xa869d2cd, 0xe3a2883f, 0x41f6b06f, 0xc26dcd52, 0x7d114de2, 0x0e97a0d1, 0x9ff41b7e, 0xf641d120,
+	0x64c36fc8, 0x9def9b2a, 0xa71e16ea, 0xb14100de, 0x1418f75c, 0x193ae8d1, 0x6211007c, 0xae524546,
+	0x3f2a8719, 0xce1effe6, 0xb740c25c, 0x10dec209, 0xf9a4f74c, 0x56967155, 0x18d4864e, 0x7007fa06,
+	0xc038a3e6, 0x9a15f8b7, 0x7d11f979, 0xf8de9c41, 0xbe6824c5, 0x8b73f4d8, 0xeec4803b, 0xdc8a3aaa,
+	0xc756e9cf, 0xb51251a1, 0xfe863e80, 0xa40cc910, 0x18506570, 0xb4f2e84a, 0x14353467, 0x7a114929,
+	0x9bff8ce2, 0x6b513036, 0x51555ea6, 0x2a3f38a5, 0x28fa5aab, 0xf93ccf38, 0x5866b2cc, 0xa846bac4,
+	0xae904018, 0xfea010af, 0x6fe6135b, 0xdc3ca191, 0x92d97e81, 0xa274190d, 0xd9c45f2c, 0x858b9033,
+	0x19167b9b, 0x2a2a660a, 0x3eca26f0, 0xc833ad89, 0xce56493d, 0x0d45c6ec, 0x48fa8d0b, 0xf5230ee2,
+	0x18933361, 0xa58c6056, 0x562236b9, 0x558526a1, 0x48ce0845, 0x08e28708, 0x4d37ccb9, 0xb9fc4e28,
+	0xad119c5d, 0x81b2544e, 0xe78a4bfb, 0xb711ac1b, 0x0d1843ab, 0xc9906a99, 0x0d051f96, 0x74e61b3a,
+	0xa446b478, 0x4a89927e, 0x2396210c, 0x20d91014, 0xdfde74e6, 0x15a477c9, 0x01ddfed5, 0x7dde1fa9,
+	0xf0459945, 0x4788e22e, 0xec81a4fc, 0x2e9586c6, 0x220540a5, 0x2e6b13ac, 0xf3d11cd8, 0x3361fb5f,
+	0x2afa5dd1, 0x013cdbd0, 0x2d3f3d5e, 0x14622e0c, 0xacf3845e, 0x3dfb2751, 0x5a440163, 0x2997cb52,
+	0x93ae387f, 0x943cb9ac, 0x6510599e, 0xfe49c8da, 0x124e3fb4, 0x4255aa83, 0x0e3f0c91, 0xab67e10c,
+	0xec2b71a0, 0x5ab216e9, 0x78194fbf, 0xc3496bf9, 0x004e235e, 0x25d917f3, 0x39959817, 0x1bded35a,
+	0x3b3b1e57, 0x21738ba4, 0x9d118042, 0x1da52ff8, 0xfbe27291, 0x255d8df7, 0xc826e196, 0xc3f3ab7e,
+	0xfb49c421, 0xe3bbe8f0, 0x55ce1cbc, 0x60ca5c76, 0x0fd8f551, 0x2dd878f4, 0x16c0b667, 0xe55f41fe,
+	0xca26e4bc, 0xa5bf9df6, 0x7bf6c3c9, 0xe692e4ab, 0x349206be, 0x4350a921, 0x22d867a3, 0xc411c092,
+	0x9a3cc31e, 0xe03ca7dd, 0x4c810935, 0xe7ef1f08, 0x07ef5168, 0xb51554bc, 0xc9911630, 0xf5e2691f,
+	0xf0800a74, 0xc46dd078, 0x4c966197, 0x1fc0c492, 0x80081336, 0x3b98933f, 0xb0492840, 0x1ea6b6b7,
+	0x3bfb242b, 0xcbef3ae8, 0x0dcd742f, 0xb541072a, 0x18ea4c9d, 0x5532d2b0, 0x77d95400, 0x2bfbb3a7,
+	0x7ade1af0, 0x460c573a, 0xd6ddbb24, 0xab6389ae, 0xd44a0c83, 0x155060b7, 0x8373e840, 0x2fa91de5,
+	0x20b5d23b, 0xd9046a17, 0x23e2b189, 0x68672f76, 0x7fc76be0, 0x0e3ad780, 0x4462799c, 0xc75c8c10,
+	0x5710ef2b, 0xc67250cc, 0xd226f154, 0x81e62fb1, 0x393f985f, 0xac338175, 0x894d45e5, 0xcb564484,
+	0x3643c8b7, 0x6f1114f3, 0xaa63881b, 0x1c3f229b, 0x948c5367, 0x6ede080c, 0xb7d1af04, 0x5b4402f6,
+	0xd08ca407, 0x31508ccb, 0xfab59669, 0xcd1efe53, 0x84468218, 0xbbbafe61, 0x2182a402, 0x892ab3a3,
+	0xfac08a53, 0xcda2659d, 0xafbaeb7d, 0x06a50bc3, 0x9fd1893c, 0xfc72a5ce, 0x3cca23ca, 0x00078ebf,
+	0xf5b3edb6, 0x1fb5d0a8, 0xbfa1a3fc, 0xc9678a7f, 0xbf110d1c, 0xad04cd7f, 0xeb22ff24, 0x13a6a566,
+	0x71fb3139, 0x78ef60eb, 0xf94e1859, 0xebefd0eb, 0x7900aa0c, 0x66441447, 0x0d18ec57, 0x3b73b492,
+	0x060843c8, 0x16d153f1, 0x4428086d, 0xf181acdb, 0xfec6e5d6, 0xb34b4943, 0x75f6ba57, 0xa9eaebd7,
+	0x163fc4c0, 0xa11603bc, 0xf608ef1c, 0xc4a1b9af, 0xfd2b8c63, 0x1c23f7de, 0xefef2ba0, 0x24a53afd,
+	0x543f9d10, 0x08a46352, 0x15955f6b, 0x320c37be, 0x2193ed23, 0xd54a0e16, 0xf5d820cc, 0xacd19db3,
+	0xe91f2a67, 0x789b2409, 0xc63455fa, 0xc3345141, 0x50f68018, 0x6996d75b, 0xf1915528, 0x274cf393,
+	0x6fb237f8, 0xb6af1b2f, 0x14a6a6f9, 0xa5d192ae, 0x3a5daf06, 0x8d1212a9, 0xf745a44a, 0x668b5f66,
+	0x7d080e7d, 0xdb41a69f, 0x59738052, 0x33ea771e, 0x321cfb18, 0x134c2880, 0x3a6fa3be, 0xc011ba46,
+	0x67ca677b, 0x081f5b34, 0x7d1a0335, 0x3e29d9ef, 0xe5a28b65, 0x9de223a0, 0x3ea93582, 0x4d96632a,
+	0xb70d9290, 0x97f3634f, 0x454c7736, 0x4a080628, 0xff72aa87, 0x3588c5d8, 0x34a5542d, 0x64626e52,
+	0xf4615015, 0x59faa7ce, 0x6796d435, 0x52195be1, 0x230c4306, 0x2e4cfe98, 0x9f46ac99, 0x9eeef411,
+	0xff18d64d, 0xd504e992, 0x5755cb92, 0x20c7c6f3, 0xdd2b5a03, 0x662e9615, 0x11ea4198, 0x3c4c690b,
+	0x1fa6b84a, 0x7bc76594, 0xb7c429a6, 0x43ea904e, 0xe1de780c, 0x9d44b2f9, 0x8cd4f7e1, 0xda38ccd4,
+	0x7b96f3b1, 0xc00da0bb, 0xb21e283b, 0xadc05625, 0x47a943ad, 0x0323d083, 0xf241cad4, 0xbff44dde,
+	0xb5e24968, 0xc54577b8, 0x580c7390, 0x94d981a7, 0xc14b5f4d, 0x524d3744, 0x8be63f6f, 0x3fd940e1,
+	0x894079f2, 0x828b8b7a, 0xf6bc06d9, 0x6af6a906, 0xab5697eb, 0xa25603f9, 0xd09ded28, 0x47a6f742,
+	0xe4571776, 0x5b96c151, 0x7dd4e044, 0xb063918d, 0xe7de817e, 0xafa198a0, 0xd48a2e12, 0x12a6a3d3,
+	0x98ead114, 0x6c558927, 0x79c80e05, 0x6e744cf8, 0x26a6c34f, 0xc156e05d, 0x8ad971e9, 0x35509317,
+	0xb94bd87c, 0xf518c68f, 0x5c50f2c9, 0x95469cdb, 0x10c0015e, 0x88ff6ef9, 0xe6de7feb, 0x9810cfb7,
+	0x2ba6cb2e, 0xf54a4076, 0x4150c848, 0x403fa364, 0x9a4afc18, 0x781e14e1, 0xad5243b3, 0x223ba293,
+	0x5a5e8cfd, 0xd16842ae, 0x54737873, 0x5543d3b4, 0x4850f91d, 0x9cd8e2a8, 0x84aecc79, 0x199565b7,
+	0x35fa6f22, 0x064c7783, 0xcf1f0179, 0x9b0d667c, 0x87b6daee, 0xaed9aa95, 0xf938fda1, 0x9b3cc4b1,
+	0x7dd95d72, 0x0f3f0e24, 0xeaa29344, 0xc2478f49, 0x3a3ffd6c, 0x9e6d94a6, 0x6f4444bf, 0x154201ed,
+	0x4eb8f6ff, 0x9e3cc96a, 0x4df67b5f, 0x6762730b, 0xe7cd385d, 0x83746e07, 0xdb57094b, 0x81d4e690,
+	0x821ddcab, 0x4b0c84e9, 0x916360c0, 0x600ca5f8, 0x0e052129, 0x0f1846d1, 0x16a8f68a, 0x5dfb59b1,
+	0x27f400f6, 0x2b3f3a38, 0xa6a18a75, 0xfe72a8f4, 0xe0a1d7ef, 0x22e2aff6, 0x253f5313, 0xa93cdabb,
+	0x18190093, 0xdc78f189, 0x3ac5a376, 0x2fc7de90, 0x1f053bec, 0xd56e96d2, 0x1c956a70, 0x3627f263,
+	0x78ae0dfe, 0x8cb6e2cd, 0xc1494303, 0x18737d79, 0x2a6b0d60, 0x4e5e7a19, 0x938c51d4, 0xa5160a08,
+	0x5137d305, 0x95b64561, 0x3f50a2d5, 0x5faa150c, 0x28872c35, 0x426238cc, 0xe938e471, 0x78c80c72,
+	0x168417dd, 0x18e9a106, 0xd304e66c, 0x383f96cc, 0x9c336845, 0xe12b604f, 0xe9ef222e, 0x0fbc2e34,
+	0x9e855be2, 0xd78e8a29, 0x1b0ce205, 0x8f15e766, 0x62626b2c, 0x95a16fb2, 0x4a0c5d86, 0xab1241e3,
+	0xfb18d001, 0xea41be3c, 0x28e2b968, 0xb469e5b1, 0x54484f4f, 0xc511c225, 0x5474240a, 0xd0f468a1,
+	0x374c612c, 0x76c38c1e, 0x314c57ba, 0xb91580d8, 0x434d1fa7, 0xe218a8a6, 0xb0857838, 0x1ca52e65,
+	0x14866122, 0x52a7b42a, 0x033519a4, 0x52b20a51, 0x2ecbae94, 0xd63840c1, 0xe92b6ce7, 0xe22b61e2,
+	0xb246ca82, 0x77d4d6d2, 0xd72b5091, 0xb98631fa, 0xa9c85995, 0x98d987f3, 0x80112fb6, 0x1c3fce32,
+	0xeb050c34, 0xeea29990, 0xb28c74cd, 0xde6ea4fd, 0xd404e7ff, 0x6032e401, 0x383b199e, 0xb804bbeb,
+	0xdd5f3566, 0x160c2e8f, 0x1a27c64f, 0x475dc37d, 0x123f12dd, 0xd04a0637, 0x25a6c1bc, 0xfcf3bd45,
+	0xf4bc03b3, 0xa4ff9b0d, 0x2a7399cf, 0x38e37e2f, 0x7e631d07, 0xc40da707, 0x6fb6098f, 0x0c3527cf,
+	0xbf63a92a, 0xac154691, 0x9f8c64b8, 0x3916adfb, 0xba6772e2, 0x52556039, 0x2d510c46, 0x3150af18,
+	0x6a110d14, 0xf7a2a7bb, 0xf1825872, 0xdbcd2579, 0xc3ffcbda, 0x3e959ff6, 0xc9111cda, 0xb08c7f7b,
+	0x3b3bc9ee, 0x2129ac48, 0x70d89d64, 0x073ce542, 0x73d4d086, 0xe308d133, 0xc94fc343, 0x5e55731d,
+	0x2b50a5a6, 0xbfffc58e, 0xf7de9aae, 0x8a40c379, 0xcb6ddb7d, 0x49fb3a35, 0x94ea1f31, 0x5c967ac7,
+	0xedde8af0, 0x1a349242, 0xcb4fc669, 0xc87253f2, 0xaf46c5c9, 0xf91f4397, 0xc2382145, 0x6bb60343,
+	0xcc864fe3, 0x84e6346a, 0x2eb81908, 0x52966b09, 0x7ff775ac, 0xa111868e, 0x12167096, 0x73f762c8,
+	0x504c8887, 0x40ca2a16, 0x9eff919b, 0xdaa1ce7d, 0x394d0fe9, 0xdc565f47, 0x32ca140c, 0x7078852f,
+	0x76b61494, 0xcaaf2cd7, 0x2ec7dcfd, 0x81746ae1, 0x5343f65e, 0x9408de49, 0x8646853e, 0x4d5dccef,
+	0x00e27a70, 0x6332e8ba, 0xd56848fa, 0x4f510422, 0x5c73850b, 0x6f1137d8, 0x66f6a2ba, 0x5fc73980,
+	0xf83ccda5, 0x7eeaa826, 0x4362148f, 0x3c2a8260, 0x2fc0ddc2, 0x03f3c84a, 0xfbf3bbb2, 0x2e4cb67b,
+	0x223f2c0d, 0xb86dbd94, 0x75eeb09b, 0x1e27cc9b, 0xa65238ae, 0x8fbba9bd, 0xa1ee4d33, 0x881a1486,
+	0xbb4fad39, 0x82120158, 0x293f595f, 0x25e36046, 0xf4729936, 0x3384f11b, 0x7c080cea, 0x9c475377,
+	0x3ce3847b, 0x20016be8, 0x89de328d, 0x3a3f7422, 0x5510ec05, 0x0c50d853, 0x27a9114d, 0xf623bc0c,
+	0x58104527, 0xe281953e, 0xab00f8c2, 0x7e8c30c5, 0xc0110eaf, 0x7b3c9251, 0xd5ddb991, 0x57737d2c,
+	0x2507cbe9, 0xabc0452b, 0xde384d59, 0xa5e2dbcf, 0x2fe37004, 0x4473c2bd, 0xd8cd20c0, 0x59b21556,
+	0x34a6d959, 0x873bf99e, 0xca7f230b, 0x71c755d6, 0xa0c84b6a, 0xd104e346, 0x10184864, 0x23a90b01,
+	0x136ae92b, 0x57485408, 0xb5ee6caf, 0x931df76e, 0x11c703bf, 0xadaf0d04, 0x5b626027, 0xc549494f,
+	0xcb7f249e, 0x01d132e2, 0x2ea6cfe7, 0xc938b211, 0x71aa3162, 0xa285622e, 0xb826c866, 0x5b556e64,
+	0x12d84e73, 0x854683ab, 0x0c93cc14, 0x660895df, 0x83b6d4a2, 0x5f0c7e95, 0xb234367e, 0xde15bb17,
+	0x405db878, 0x4cfb3eee, 0x7d89e2c7, 0x9f6376ca, 0xc4457625, 0x15f01309, 0xa8bfa2af, 0xff3467c1,
+];
@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0xfcc6e2b0, 0x8d469043, 0x96b646f4, 0x51811114, 0x9846a194, 0x43400b97, 0xfcbfe1e2, 0xf168750e,
+	0x273fdf83, 0x3d0c490f, 0xb846d3f4, 0x37f6a0b1, 0x9a8bb142, 0x9746a001, 0x4c3be4b1, 0x7481482d,
+	0xdcddc496, 0x403f5b47, 0x4bb8f246, 0x113f114a, 0x49b1fc26, 0xd34b7ba3, 0xacae5fda, 0x523bee23,
+	0x77ae0c6b, 0x4150ee18, 0x0b4c7f62, 0x3980eb4c, 0x85d8be73, 0x454d22cd, 0xb21575d3, 0x7751431a,
+	0xee15d447, 0xddddc629, 0x02bfeb54, 0x50624ed6, 0xa646b79e, 0x96ccfde3, 0x4b2a99fd, 0xd657016c,
+	0xad638cd4, 0xc626de70, 0x4a43e833, 0xc3724c13, 0x96469e6e, 0x4c103243, 0x5f0ca465, 0x3ad6ec97,
+	0xa2ae501c, 0x86f3488c, 0x704074bb, 0x74788b7b, 0x88d8c32c, 0xf1386b42, 0x22f67fa2, 0x9b10d470,
+	0x16c56aca, 0x470c7e9d, 0x7119f051, 0xa7d99f90, 0x6d193e6e, 0xe8385d17, 0xebf6d1b9, 0x2e5d9c22,
+	0x94469b48, 0x5e4407af, 0x660c899a, 0xdd4fe2bf, 0x41ea8d28, 0xce157c17, 0x372e4c18, 0xc71119b4,
+	0x5f50f782, 0xa456071f, 0xdfc09707, 0x62fb6190, 0x921978ad, 0x1759a656, 0x7e7837bf, 0x506709ae,
+	0x3d400225, 0x1eb67aac, 0x3d959e63, 0xf015d76d, 0x29a542dc, 0x1897b08f, 0xd911e1a1, 0xdd5660da,
+	0x0b09bbc2, 0xd9158d68, 0xd64989e2, 0x82783e0b, 0x4750f78a, 0x672a7e1d, 0xa0aef88d, 0x2a4cb02f,
+	0xfca00d89, 0xdf384eec, 0x15467f1b, 0x19c00f89, 0x3c5e5dc3, 0x13e29859, 0x1ef02134, 0x41fa8206,
+	0xb4fc4649, 0x4f2ef7a7, 0x9410c96b, 0xa9638688, 0x84113602, 0x23801d13, 0x95ae3ba5, 0xc949756b,
+	0x46401050, 0x0e8657b0, 0x92521932, 0x26f685ee, 0x31f6973f, 0x06d1e658, 0x5c8076ce, 0x80d4e4fd,
+	0x5f10fbc3, 0x16d48328, 0x020c0f13, 0x363ff720, 0x442a8ef8, 0xad342e9f, 0x1ec7c3cd, 0xd7498b75,
+	0x32c596de, 0x372ed1df, 0x6b2b3000, 0x9d8c53be, 0x7a330cef, 0xa563803c, 0x0909b89c, 0x91d8d157,
+	0x34fb163b, 0xd8498d08, 0x07d13c54, 0x2334a06d, 0x1c3aed8a, 0x6e627e10, 0x1f4c9ede, 0x77b61627,
+	0xabc052ff, 0x605e966f, 0x736285ef, 0xdc9c0992, 0x4a50fc43, 0x6037eaa2, 0xdfddc94f, 0x014c6fa4,
+	0x389a13b2, 0x7b195478, 0x28e9ba36, 0x92636253, 0x47102a64, 0xc2677f7a, 0x1c349568, 0x72ce4a63,
+	0x2545ecb4, 0x556256b5, 0x9c1e0599, 0x7b00019b, 0x49b84389, 0x506fc660, 0x96fb6b78, 0xbd2fcaa1,
+	0xfdbfe375, 0xd3498529, 0x05864985, 0x9c04b2bc, 0x93ae2aab, 0xed3864f6, 0x20f3f5f1, 0x6b787d50,
+	0x05bff00d, 0x8bef7ed4, 0x3e3f5821, 0x12f3dfe7, 0xbd26d045, 0x3e0c4aa2, 0xb346cc15, 0x9bea2a36,
+	0x12c7b0e9, 0x0e5f8289, 0xce9fc51f, 0xa3f37633, 0x1a185822, 0x554027ed, 0x65c3715b, 0x68d890cc,
+	0xcb9de549, 0x326b19f8, 0x694069b6, 0xfc4c67c5, 0xda49902e, 0x3929d210, 0x7ed8b36e, 0x1a19ac65,
+	0x05351cca, 0x126ae798, 0xb03387c1, 0x0b233184, 0x33db5ec0, 0x75628915, 0xa9e2e21b, 0x19a529ac,
+	0x313f43aa, 0x532e525c, 0x3a16af8e, 0x6f78839c, 0xc95b4453, 0xc58a1675, 0x5d9a4df1, 0x86113928,
+	0xd11f049f, 0xb5904b1d, 0x3afbcb44, 0xd215a833, 0x57811a86, 0x36188436, 0x9a003268, 0x8ceabe30,
+	0xb1bfb0da, 0x69aba9f6, 0xe815cad5, 0x6effeeb7, 0x51b8fbb8, 0x58db98ff, 0x56510f27, 0x45fa8852,
+	0xb2d9b0e1, 0x18b7f666, 0xe6bfbf40, 0x854073a6, 0xdcd5ad06, 0x4f1036fc, 0x4aa6fbfb, 0x5ee5f898,
+	0x412a8a3f, 0x4c43eb59, 0x2f5d9db5, 0xb8339459, 0x07d1e7eb, 0x02165766, 0x45bcba6b, 0xb9c42ccc,
+	0x3e2a8586, 0x5d3fd10b, 0x163ae418, 0xfdddf889, 0xf749bdd5, 0x61807ead, 0x985222a4, 0x263f3259,
+	0x69627631, 0xa9cc2b2c, 0x2f62588d, 0x064e2cd0, 0x185d7980, 0x9100243d, 0x48898f58, 0xf649bc42,
+	0x482eeca2, 0x3c5db22c, 0xc84798bb, 0x831dde3e, 0x1859a7e9, 0x540c6d44, 0x98cd0109, 0x55b90204,
+	0x0981d2a3, 0xfc5b949c, 0x8e4691d6, 0xff1f4d09, 0x5c6261ba, 0x10bc2fc7, 0xef15d5da, 0xcf7988a9,
+	0xe3f3ddde, 0x283b006e, 0x83468085, 0xfd09a5b8, 0xdef32a68, 0x09a464e5, 0xe711afb7, 0x0a4e331c,
+	0x811ddb18, 0xe8efcc32, 0x19c56f83, 0xfd465953, 0x253fdc5d, 0x6862749e, 0x35777cb7, 0x1b3f2108,
+	0x17d6b57e, 0xd2498396, 0xce497d4a, 0x1c3540ff, 0xffd1db53, 0x9bd0c985, 0x0cd1efca, 0x31055842,
+	0x270c4952, 0x324c594d, 0x90eac47c, 0x3ba78ff5, 0x7fb6ce56, 0xaf742d84, 0x68624ece, 0x785144ad,
+	0x9ad98b19, 0x95ccfc50, 0x28d17047, 0x3b0c45e9, 0x4388dbe2, 0x4729e81a, 0x8e10bff9, 0xb4ee6b1c,
+	0xd9af4474, 0x98ae328a, 0xde4b8cf4, 0xb7c065e3, 0xda5707b8, 0xc84973d8, 0x3451174b, 0x5737dc77,
+	0x08c7a12b, 0x3966e579, 0x461028d1, 0x4295a642, 0xc5f45750, 0x1ad48974, 0xd2d1201a, 0x38778170,
+	0xb1ee6663, 0x7166da27, 0x9110c4b2, 0xfc5f6633, 0xd44986bc, 0x5850ec7d, 0xb57b3065, 0xae638e67,
+	0x1ad906a2, 0xb4d9b407, 0xadd19f46, 0xb6339133, 0x944746df, 0xcc56cbde, 0xeee58d51, 0x4bdad8f1,
+	0x140859d2, 0xfbd12970, 0x9cf416c5, 0xfc49c5b4, 0x851a0fcd, 0x153f1796, 0x3450b3d1, 0x9308dcb6,
+	0xbc41122f, 0x608128b1, 0x4fdb8ad4, 0x1d3496fb, 0x0d0c2064, 0xa42fa346, 0x9bbf8e38, 0x68dbb22f,
+	0x2c3f5e18, 0x7d3be9e0, 0x70de0b32, 0xa6af01ff, 0xfcdea28d, 0x5ec36656, 0xec386363, 0x54625522,
+	0x81467d5f, 0xa0f41d11, 0x26b80c70, 0xf29156bb, 0x3f1db83b, 0xa3c03893, 0xbe344962, 0xbfe304bd,
+	0xf94c630c, 0x64e6020a, 0xcf458776, 0x96c031f0, 0x8bb6e13a, 0x142997d1, 0x0df65e93, 0xbe110b89,
+	0xed49ae17, 0xec5b7b6c, 0xf74e1533, 0x596fd48b, 0x3c4022df, 0x33734480, 0x570c71fd, 0x8ed8cc9e,
+	0x0d23e041, 0x5750eaea, 0xe7a28e8b, 0xfed45d60, 0x2c5d98fc, 0x650c8807, 0x96ff8503, 0x24fba8a2,
+	0x19e2a1cb, 0xa640ef8d, 0x355da727, 0x3ea6e917, 0x4040292b, 0xb5c062bd, 0x68512b7d, 0x3d0d178b,
+	0xdb459a5a, 0x40fbd4b6, 0x16d1ff88, 0xb48c77f3, 0x4d624a1d, 0xa6fb84a8, 0xe15712bd, 0xaa4b3b18,
+	0x61812a44, 0xf538718e, 0xda04f171, 0x0f3480f1, 0x1329963e, 0xf449b91c, 0x144c8d8d, 0xc649f679,
+	0x3a27f8af, 0x734d2343, 0x612e8e36, 0x9df36cc1, 0x32f698d2, 0x7008a59d, 0x9700d946, 0x3d3bcd14,
+	0x256248cf, 0x0f93d0cd, 0x56d4a2df, 0x25f72d07, 0xbc562ce7, 0x621054e5, 0xa52b8b4e, 0xd411d9c2,
+	0x670cb0fd, 0x5a3bfabb, 0x6337ef5b, 0x32b8caeb, 0x343b1352, 0x17d15584, 0x96d8d936, 0xc849f99f,
+	0x10c0acf5, 0x271691a5, 0xac861d83, 0x98c03516, 0x0393bde9, 0x5ce5f572, 0x8a407b85, 0x6651ae1e,
+	0x5ab5e880, 0x89120c5d, 0x08232ccb, 0x2b1872e5, 0x0fa519ee, 0x93ff804a, 0x445dbec4, 0xe915cc68,
+	0xc6a1bcd5, 0x5273d8c7, 0xa32fa1b3, 0x1d3fcfc5, 0xaad0e122, 0x71106c82, 0x33187f7d, 0x5c812265,
+	0x06598b93, 0x0b931eea, 0xfa4c649f, 0x45d6fde8, 0x87081e3b, 0x2c19c8bb, 0x07183a39, 0x2f87373a,
+	0x7c62941a, 0x41fbd649, 0x14fb8f72, 0x96335ed3, 0x4777990d, 0x48dad438, 0x6133912b, 0xba44e0a0,
+	0x497c874e, 0xb256a2f0, 0x6a2e9c61, 0x1427bcdd, 0x9d10d796, 0xc8382ab7, 0xe84dfd96, 0x61aa1832,
+	0x3d66402e, 0x49f6bd07, 0x16a4795c, 0x0b347aa5, 0xe1d1e34e, 0xcd04dcfa, 0x4bfa91c4, 0xf7a005aa,
+	0x99ead2a7, 0xeb5f4b70, 0xedd5c7c9, 0x85120611, 0xf8d82585, 0x233fb367, 0xa56751d3, 0x2e29c0bf,
+	0xcc63bda1, 0x24507854, 0xeabfc58c, 0xb911af41, 0x80d9622b, 0xf78261e4, 0xf15b834b, 0xd6496412,
+	0x978c4a4c, 0x1bb675f3, 0x3f8503ff, 0x7f195ac4, 0xc556e6a9, 0xcd6790cb, 0xd5bbd2e6, 0x4b6246f7,
+	0x43512ee8, 0x1dc015d5, 0x4c2a9b90, 0x52ab85c1, 0x90de3d92, 0x4b40182f, 0x2c29bd99, 0x6e813ebb,
+	0x9c1a3402, 0x7a107aad, 0x580c9960, 0xefbbfbd4, 0xa3eae265, 0xce4b73c4, 0x7b1dd1a6, 0x7af76dcd,
+	0xe215c163, 0x59625d01, 0x84d8bce0, 0x23054238, 0x852b58ee, 0x9008d7fd, 0xf7efe3cf, 0x22349eda,
+	0x903cb360, 0x60dba597, 0xcbf3b816, 0x88853940, 0x1e34988e, 0x3d804601, 0xf3500561, 0xb867f583,
+	0xa21a3d74, 0x5eb9102f, 0xb644da54, 0x499ada0c, 0x24fafd0b, 0xaafb8af4, 0xc926e329, 0xa6efa955,
+	0x74d4d219, 0xe8bfc266, 0xc41114fb, 0xc5163c68, 0x5bab93ec, 0xf8bfdb96, 0x5cdaf3b4, 0x7b63184e,
+	0xfa49c28e, 0xca4976fe, 0x6a089c2b, 0x55510d94, 0xcdd1183b, 0x43623a5f, 0xb990fd00, 0x1bc0be46,
+	0x12fae0b5, 0xd126efc1, 0x3a66e70c, 0x06bc2009, 0x94ae3a12, 0xed4a33de, 0x05a01bb4, 0x879d8811,
+	0x649b048d, 0xb9cc445c, 0x09840366, 0x171d7943, 0x988c4bdf, 0x29508033, 0x7451c428, 0x85ee211f,
+	0x81b6d17c, 0x40ab696b, 0x99474ebe, 0x0cd14433, 0xd18a2959, 0x33f69a65, 0x38f6a244, 0x350c3c77,
+	0x40ea8b95, 0xc78c95dc, 0x134c8bfa, 0x4c62488a, 0x639b02fa, 0xa4637ea9, 0xaf119f83, 0x90b63d82,
+	0x3d77894f, 0x42b8e41b, 0xa40d74a7, 0x83aecae6, 0xa05600d3, 0xb726c6d3, 0xe59096ad, 0x4450cd01,
+	0x3a50bd43, 0x7f631e9a, 0xfc309171, 0xb9639fb8, 0xd65655d5, 0x6eb607fc, 0xdd4a1aae, 0x4f401e7b,
+	0x5e511bbf, 0xca52715a, 0x2ee2c2da, 0x717886c2, 0x2ce2bfb4, 0xe0f3d925, 0x30ea7265, 0x576259db,
+	0xe63859f1, 0xa2ea353b, 0x1bc012af, 0xd79080a3, 0xb1af1350, 0xda2783b3, 0x85b6d7c8, 0xa7efaae8,
+	0x589af1a9, 0x7def68ca, 0xfe078b99, 0xc6a25a98, 0xee165a0e, 0xb052486c, 0x05c79c72, 0x1e29a78f,
+	0xe515c61c, 0xdb7fe965, 0x27c5858d, 0x86d96b9d, 0xa29d06fb, 0x3f3f59b4, 0xfa4a4855, 0xdb496bf1,
+	0xb410fbcb, 0xa0b2ca24, 0x3d50c1fc, 0xf9d5daad, 0xef915202, 0xcf3835bc, 0x69ce3c38, 0x8eeac156,
+	0xe12708f1, 0x5589a3cf, 0xc6ae7af4, 0x85d96a0a, 0xc763b5c2, 0x44fbdb02, 0x2cc7d9d7, 0xcc4b709e,
+	0xb3458132, 0xf65b8b2a, 0xe6d43798, 0x6fabb368, 0x533befb6, 0x06de06b4, 0x61ee911f, 0x9f855d75,
+	0x8a00c4cf, 0xfcd12b03, 0x8daedaa4, 0xb310fa38, 0xe3c46eea, 0x384c62bf, 0xde570e04, 0x7e000654,
+	0x61c36b0f, 0xacef0a1b, 0xae5616dd, 0x1a05340d, 0x9d003721, 0x1b5d7e39, 0x4cdb861b, 0x463b2fa8,
+	0xd74a113c, 0x183f1c4f, 0x2e0c5457, 0xdd9b5f8e, 0x74628782, 0xfb231854, 0xeb165555, 0x4c851876,
+	0x1cf67630, 0x6251220b, 0x93d17658, 0xdf5b66f5, 0x440c5414, 0x724077e1, 0x0f23e367, 0x5937df9d,
+	0xa9bfa442, 0x26c724ce, 0x6cffeb91, 0x6077c068, 0x05165c1f, 0xf0bbfd67, 0x685105ad, 0x2d6b1219,
+	0x79d4d9f8, 0xe3909387, 0x21c57c1b, 0x633fda7d, 0x8e8c49f5, 0x2ef69286, 0xa01187e6, 0xec825093,
+	0xfba2ae07, 0xcbf30c7f, 0x4b8516e3, 0x3f5db6e5, 0x0423267f, 0x3c50e639, 0x17decd0e, 0xec11b796,
+	0x5b0c9e19, 0xa7c8566f, 0x7aae1124, 0xb91103aa, 0xfa38796d, 0x86407539, 0x9d40996e, 0xf2d11b45,
+	0xa44b31a6, 0xc863b755, 0x92ae36ec, 0x5010388f, 0x64441121, 0xa27b127c, 0xb4676970, 0x9a8c4f05,
+	0x6d2b3326, 0x1e0168c2, 0x5c9af7f5, 0x98c83ed2, 0x045ba134, 0xdf49723d, 0x7338088b, 0x75b2416a,
+	0xa9f42b3c, 0x5043f1a5, 0xc204f179, 0x594928be, 0xf6ef36a5, 0x8fcc023e, 0xb02695fe, 0x26d6cd1b,
+	0x75e9ee64, 0xb004af53, 0x8d10be66, 0x06deb24b, 0x502e7373, 0x3dfbcffd, 0xcf08b1b7, 0x9b117d1c,
+	0x4e50dcbf, 0xb174dc41, 0xf9099f6c, 0xb6562375, 0x556fce3f, 0x20f0245a, 0x4a6f1157, 0x30d6dcd9,
+	0x71dbc05a, 0x025f6fa5, 0xd211d69c, 0xe64a28d9, 0x8c9ce459, 0x3e8456d5, 0xea6c15d0, 0x90049fd8,
+	0x9dd8e43b, 0x560c963a, 0xb16e5e26, 0x8be2074a, 0x3f73bade, 0x8973f1b2, 0x2d91fbb9, 0xb20d8ab1,
+	0x1ea485f4, 0x8e0d5205, 0x0ac557e6, 0x21349d47, 0xfa45a903, 0xb5eafebb, 0xf4345670, 0xa11e0d78,
+	0x9c5228f0, 0x0ad1eca4, 0xcad1bf19, 0x18c7ba5b, 0x56db95d9, 0x61b221ee, 0x8300b9ca, 0x3f40054b,
+	0xfb826830, 0x73bf4f40, 0x5943ffd0, 0x8c3c017d, 0x2b3fbfff, 0xd811e00e, 0xcb68136c, 0x509ae511,
+	0x7c33bbac, 0x2e8735a7, 0x7cce5a21, 0x099798f2, 0x3a509af6, 0xc156ba8d, 0x163f1929, 0xd1a26be9,
+	0x56625848, 0x9a9d9826, 0xc28a11bc, 0x6ce9e039, 0x536662d0, 0xafcc349e, 0xc733abf6, 0xd95d53fd,
+	0xb11e26a8, 0x683fe25c, 0x97f40ee6, 0xc88a1b2e, 0x2eea6f3f, 0x8c2b63f3, 0x44d6fc55, 0xf25b84de,
+	0x31873a60, 0x2719c0dc, 0xca382ddd, 0x6f673a7b, 0xa174177a, 0x05bc1e76, 0xc16dcbbf, 0x33803643,
+	0x9aaeef1b, 0xf249b5f6, 0xf2729610, 0x66b5fb64, 0xa7d0ea3d, 0x4d9ae058, 0x1ac0bcb3, 0x5440265a,
+	0xa44e1bfd, 0x91f79202, 0xf0efd8ca, 0x55dd351a, 0x4e3be7d7, 0x00d8321d, 0xffc6e769, 0xb6477c65,
+	0x3e402605, 0xa2c03700, 0x2fdaacdd, 0x140cd700, 0x1b738232, 0xf0b3e5d7, 0xbd158724, 0x29c588b3,
+	0xcf11d1e3, 0xa1ea33a8, 0xdd41a9c5, 0x0d298ccc, 0xb8410be3, 0xb8cc42c9, 0xb3862888, 0xc3d1c1e8,
+	0xa110dde2, 0x731947e0, 0x27fa5918, 0xfbc30f86, 0x76e61e60, 0x7fc39a49, 0x1b3fcc9f, 0x1ac0111c,
+	0x1400ad6d, 0x7df77286, 0x06facdd1, 0xfba4fa72, 0x3eb83238, 0x8db638c9, 0x6350fdce, 0xe326e647,
+	0x53dae589, 0x382e4dab, 0x0a183ef2, 0x2ae2bc8e, 0xc0c0663a, 0xe4728006, 0xc367810d, 0x990033c0,
+	0x76b242fd, 0xb2005b1b, 0x18f71890, 0x7db24e02, 0x5b5e8e90, 0x37c7eb28, 0x2bc58bd9, 0x3c50c069,
+	0x540c9314, 0xb92fc455, 0x3e5db552, 0xac69d919, 0x0c184218, 0xa503f26b, 0x4e670688, 0xe24a228d,
+	0xf35f5808, 0xb8eb0374, 0xc7678759, 0x06827981, 0x123485aa, 0x9bead5cd, 0xe6bbeda9, 0x60786bff,
+	0xb856269b, 0x0193bac3, 0xe3913f1e, 0xb9905169, 0x366b2044, 0x4c0c867c, 0x42d6f92f, 0x3a4d117c,
+	0xdf727827, 0x0d5f80f6, 0x08352183, 0x1dfb9d9d, 0x385dabe0, 0x088f82e3, 0x6e3febce, 0xcc4bf665,
+	0x603bbc39, 0x861965c9, 0xa810e8e7, 0x1d84ce79, 0xde270438, 0xfca4506e, 0x5619622d, 0xba33977f,
+	0x6450ff61, 0x3a46b95a, 0x2ffa65b0, 0x871de48a, 0x5e3facce, 0x873ca535, 0x93eac935, 0x6507e8b5,
+	0xea1ad0f0, 0x4b62ccbe, 0xeb11b603, 0xba8c8165, 0x8efc0a77, 0xdf9138d2, 0x342e475f, 0x54fa9fef,
+	0x1f507075, 0x4a4038e9, 0xb1f38c3d, 0x400c4dc8, 0x5107c939, 0x439a2503, 0x083475ec, 0x4f624d43,
+	0xff8ec921, 0x14cd7f34, 0x02c797b9, 0xbfc064a7, 0xe64dfa70, 0x482e66db, 0x506f1ac9, 0xd0162ad4,
+	0x927af94c, 0x30fbbb86, 0x603c042d, 0xc104ecfb, 0x994afa85, 0x932ac361, 0x2ee9c3a8, 0xbdd1b876,
+	0xd356511c, 0x82ee1c66, 0xe08a40f6, 0x794d2cb5, 0x7c0c8a18, 0x3a2a7f3a, 0x0b6adc93, 0x77ef5f58,
+	0xf781b64d, 0x4855085e, 0xee126653, 0xcc6ddd10, 0xd6f3c967, 0x23d914cd, 0xf0345024, 0x4d1033d6,
+	0x3b3f9b85, 0xd311d82f, 0xf1d119b2, 0xbe381af9, 0x95f7984e, 0x65d88c13, 0x30439975, 0x322e4439,
+	0x49fbe2e1, 0xd73470bd, 0x5a62e45b, 0x2d50867f, 0x21e2ae63, 0xf59c30ed, 0xe111a645, 0xaebae9ea,
+	0xcd7fd35b, 0x10b5b90b, 0xa0eef737, 0x532e782c, 0xe5e57f26, 0x04f3c9dd, 0xffddfbaf, 0x3adb69c5,
+	0x9aeeedc5, 0x37fa7248, 0x2b0c2cb9, 0x0945c0a0, 0x715139a8, 0x52d49c93, 0xa7903513, 0x4e5dce82,
+	0x77194e2c, 0x53103d48, 0x283f357f, 0x5a37e130, 0xa11a3be1, 0x340c3ae4, 0xdf22ec40, 0x89cbf8cc,
+	0x129329ef, 0xb6343cca, 0x419a21dd, 0x5d786746, 0x0ff661b9, 0xbecc4c3b, 0x223af6fc, 0xb5269ddd,
+	0x939cef5e, 0x86e1ff6b, 0x393ffbd9, 0x321da3c4, 0x71814374, 0x5050dfe5, 0x3e4d17c8, 0x233fd937,
+	0x04e280bc, 0xefe58ee4, 0xc749f80c, 0xda4a15f5, 0x8c468eb0, 0xbb1583fe, 0xc3a255df, 0x86ee22b2,
+	0x3250b0ab, 0xf4a2a302, 0x6b19e6df, 0x14a47636, 0x9b2b7b90, 0x5b1049e0, 0xc8d1bbf3, 0xbf796f79,
+	0x0c09bd55, 0xd69df69a, 0x6ed4c8a7, 0x27fbad5b, 0x703feef4, 0xc7497245, 0x44517898, 0x22fba57c,
+	0xa0d18acf, 0xda6e9eb1, 0x760c80a6, 0x12a47310, 0xac9db47c, 0x3e3b2310, 0x9fd992f8, 0x407358f7,
+	0x95eee5e6, 0x601051bf, 0x9dfb767d, 0x14c007aa, 0x86b6d95b, 0xaf1619c6, 0x8000b511, 0xbf4116e8,
+	0xdb15b65e, 0x73aa3488, 0x2a46a02a, 0xe3d1e674, 0x50fa99a3, 0x429a2370, 0x2edcf7b5, 0x9c637211,
+	0x18167a08, 0x5cd4ac51, 0x26d91986, 0x48741126, 0x03c7994c, 0x2ce36b4b, 0xa0336e91, 0x8dbab5f7,
+	0xc552697b, 0x599af33c, 0x7c3be84d, 0x0709b576, 0x24da9b8c, 0x203fd47e, 0xf1f3f3e8, 0x075ba5ed,
+	0xc911c871, 0x375daa4d, 0xb9ef1e92, 0x6477c6b4, 0x78e9f31d, 0x868b91c6, 0x6a3bcbf7, 0x514c8a1a,
+	0x732a9101, 0xb90437b7, 0xbcd0fd78, 0xf4f6dfe4, 0xeade8637, 0xb46397d9, 0x033efb40, 0xf61f3ede,
+	0x6d106636, 0xe538585e, 0x71f6b40b, 0xafc86307, 0xbda1a0d6, 0x329638a9, 0x99e2c8eb, 0x044e29aa,
+	0x62dba8bd, 0x8fae3233, 0xba63a14b, 0x5b8120d2, 0x3bfbccd7, 0x296edd64, 0x1ed16089, 0x8c10bcd3,
+	0x1393d719, 0xd326f2e7, 0x5b786420, 0xa9856d33, 0x784dd6b9, 0x512efacd, 0x03931252, 0xe51a1d7a,
+	0x2a4cf84c, 0xbe5a876b, 0xe71a20a0, 0x20d163af, 0xf0ef2d33, 0xa2523262, 0x5e4d4a28, 0xde9b6121,
+	0x008f764b, 0xf3dde8cb, 0x41875390, 0xd79c01b3, 0x25fbaa35, 0x99d0d433, 0xa52fa4d9, 0x3bc7f174,
+	0x0aa5120f, 0x05facc3e, 0xf423b8e6, 0xf3c302ee, 0x79b6194d, 0xdd047063, 0x3927f71c, 0x2cea6c19,
+	0xc578cd54, 0x99ea2710, 0x4519476a, 0xeebfcbd8, 0xb27efd43, 0x1dc575cf, 0x153f9d5d, 0x99ff89bc,
+	0x459ad3c0, 0xa26d9af2, 0x4afa9031, 0x396fa22b, 0x92f79395, 0x4f43f012, 0x35f69d8b, 0xf9ef3b5e,
+	0xbc56d87e, 0x2ffbb9f3, 0xb5d9b59a, 0x35a6daec, 0x41783b32, 0xb5343b37, 0x4966fea9, 0xd545b6b8,
+	0xd3b31a6d, 0x0ce28d54, 0xd06e8ef3, 0xbed1ba09, 0x5343d08e, 0xaf477160, 0x0af70286, 0xa68c61e9,
+	0x682e993b, 0xcb79825d, 0x1e19b2b1, 0xc311beff, 0x2f169e3d, 0xaaeaed6a, 0x1c234c47, 0x5e9a4f84,
+	0x472eeb0f, 0x17c0b7fa, 0xeea43a64, 0xa78c7150, 0x11233af6, 0x93f35d03, 0x10a51b81, 0xa5ae4701,
+	0xe8571dc2, 0xb044d0e2, 0x86081ca8, 0x5d5e91b6, 0xab4bc272, 0x4188d8bc, 0x3573ab20, 0xae857512,
+	0xfcc08d79, 0x0cc7a777, 0xc56dd20b, 0xd945bd04, 0x60d4b29d, 0xbf158a4a, 0x99ae341d, 0x354c5e06,
+	0x629b0167, 0x9bcd05c2, 0xec9ff459, 0x8d521153, 0xdd3825f6, 0xd556ffd9, 0x370c3f9d, 0xfc4e1d12,
+	0x76194c99, 0xc9157438, 0x431d15db, 0x542aa828, 0x17052f54, 0x1a506896, 0x92ae2918, 0xb5af199c,
+	0x8caed911, 0x9dcc1848, 0xd17f2e10, 0x3b50e4a6, 0xfa1f452a, 0xb6d1ad71, 0x7fe9fe22, 0x94408b43,
+	0x4a3fb322, 0x4f6f1936, 0x4373c12a, 0x491d1f4d, 0x7cae144a, 0xa51a422d, 0xf9c30c60, 0xa1efa176,
+	0xb637e891, 0xf7012b5d, 0x652f1a49, 0x14505f24, 0x3059cdb1, 0x938c4400, 0x2fa54c4e, 0x391daec9,
+	0x988bae1c, 0x456b37e1, 0x1fdce018, 0x1719a7ac, 0xfabfdebc, 0xfd23c711, 0xd715b012, 0x606fdf90,
+	0xd34a0af0, 0xffc09232, 0xcf6793f1, 0xe3385538, 0x0b5f7dd0, 0x22240150, 0xa9f37fa5, 0x233af88f,
+	0x911a22b1, 0x133f9a37, 0xddde71c0, 0xe822fa6b, 0xb9eb0507, 0x5d1dc490, 0x21a6bb70, 0xcff3be62,
+	0xc827675d, 0x5d9af988, 0x21624283, 0xd134674b, 0x90c786a3, 0x941a276a, 0x432a8d65, 0xb82fc2c2,
+	0xeb15cf8e, 0x6feea729, 0x09f700f3, 0xa5337670, 0xd84de466, 0x29a91473, 0x57191bcc, 0xaf10f3ec,
+	0x9aff8b4f, 0x333f691d, 0x38ba7531, 0x13f00fe3, 0xc51593bc, 0x10b664a2, 0xcb382f70, 0x96ea2257,
+	0xa869d2cd, 0x4510273e, 0x3ef6abb6, 0xc26dcd52, 0xfbfabc80, 0x0e97a0d1, 0x86eecb5e, 0xf641d120,
+	0x64c36fc8, 0x9def9b2a, 0x6508944c, 0xb14100de, 0x0a1d64cc, 0x1000a721, 0x6211007c, 0xae524546,
+	0x3f2a8719, 0x870d4700, 0x22354a71, 0xc4cc55ad, 0xf9a4f74c, 0x559aecf0, 0x86d0b64a, 0x0611e084,
+	0x6d21af57, 0x4bfbe607, 0x90121762, 0xf8de9c41, 0xbe6824c5, 0x8b73f4d8, 0xeec4803b, 0xdc8a3aaa,
+	0xc756e9cf, 0x8b120f83, 0xfe863e80, 0xab0cd415, 0x18506570, 0xb4f2e84a, 0x14353467, 0x2419bc23,
+	0x9bff8ce2, 0x6b513036, 0x5962e2c8, 0x2a3f38a5, 0x889cde0d, 0xd5277bd4, 0x5866b2cc, 0xc39be237,
+	0xae904018, 0xfea010af, 0x6fe6135b, 0x461da05b, 0xb2eafa02, 0xa274190d, 0xd9c45f2c, 0x858b9033,
+	0x19167b9b, 0x2a2a660a, 0xc6d58a64, 0xc833ad89, 0xb656cf0c, 0x0d45c6ec, 0x48fa8d0b, 0xac0cd5a8,
+	0x15932ea8, 0xa58c6056, 0xc938067a, 0x558526a1, 0x65dbad76, 0x08e28708, 0x4c37cb26, 0xb9fc4e28,
+	0xad119c5d, 0x81b2544e, 0xe78a4bfb, 0xb711ac1b, 0x09183d5f, 0xb7af0eee, 0x0d051f96, 0x74e61b3a,
+	0xa446b478, 0x4b899411, 0x679b0946, 0x20d91014, 0xdfde74e6, 0x15a477c9, 0x01ddfed5, 0x14d8fd30,
+	0x3af6a56a, 0x4788e22e, 0xec81a4fc, 0x2e9586c6, 0xc50da89a, 0x2e6b13ac, 0xf3d11cd8, 0x145f8bfb,
+	0x2afa5dd1, 0x7f227744, 0x2d3f3d5e, 0x14622e0c, 0xacf3845e, 0x3dfb2751, 0x5a440163, 0x2997cb52,
+	0x93ae387f, 0x943cb9ac, 0x6510599e, 0xfe49c8da, 0x124e3fb4, 0x4255aa83, 0x4a3be18b, 0xab67e10c,
+	0xdf26dffb, 0x9cb6fbfd, 0x78194fbf, 0xc3496bf9, 0xe14df291, 0xfdd45bcd, 0x39959817, 0x0ccd729c,
+	0x3b3b1e57, 0x1f73887e, 0x9d118042, 0x15a52360, 0x8fc830a7, 0x255d8df7, 0xc826e196, 0x863bf80b,
+	0xfb49c421, 0xe3bbe8f0, 0x5adb9c25, 0x34d6e325, 0x0fd8f551, 0x2dd878f4, 0x16c0b667, 0xf55b8997,
+	0xca26e4bc, 0xa5bf9df6, 0x7bf6c3c9, 0xe692e4ab, 0xf18f5eae, 0x2c3bb251, 0x22d867a3, 0xec15d121,
+	0x9a3cc31e, 0xf027a655, 0x5c77ba1c, 0xe7ef1f08, 0x07ef5168, 0xc9042b17, 0xc9911630, 0xf5e2691f,
+	0xf0800a74, 0x546fa6dc, 0x489ad879, 0x1fc0c492, 0x80081336, 0x3b98933f, 0x6832f099, 0x61ab9d5e,
+	0x3bfb242b, 0xcbef3ae8, 0x0dcd742f, 0xcb45a6fa, 0x1a07b7ad, 0x7366dd4d, 0x85cbf280, 0x2bfbb3a7,
+	0x7ade1af0, 0x3c101913, 0xfbddf563, 0xab6389ae, 0xd44a0c83, 0x155060b7, 0x51518d0f, 0x74ccc85d,
+	0x20b5d23b, 0xd9046a17, 0x12cd7c0e, 0x68672f76, 0x7fc76be0, 0x0e3ad780, 0x5c59a508, 0x25c953fe,
+	0xcc042fd0, 0xc67250cc, 0x6b443e73, 0xa3cc21ba, 0x393f985f, 0xe041ae7e, 0x894d45e5, 0xe2571450,
+	0x3643c8b7, 0xc30421a5, 0xaa63881b, 0x1c3f229b, 0xf3a44243, 0x3cdd0dbf, 0xb7d1af04, 0x878c3ef0,
+	0x5c89aed4, 0x31508ccb, 0xfab59669, 0x0223cef0, 0x84468218, 0x92babdd6, 0x2182a402, 0x892ab3a3,
+	0xfac08a53, 0xcda2659d, 0xafbaeb7d, 0x06a50bc3, 0x9fd1893c, 0xc76a039a, 0x49dad5cb, 0x00078ebf,
+	0xf5b3edb6, 0x1fb5d0a8, 0xa6d0dad6, 0xc9678a7f, 0x719a6d6d, 0xad04cd7f, 0xeb22ff24, 0x63aba084,
+	0xfe008acb, 0x90ef86b3, 0xf94e1859, 0xebefd0eb, 0x7900aa0c, 0x66441447, 0x2cfb09a3, 0x3b73b492,
+	0x060843c8, 0x16d153f1, 0x97eee90c, 0xf181acdb, 0xfec6e5d6, 0x3a3f99f2, 0x83fbf926, 0xa9eaebd7,
+	0x163fc4c0, 0x9904ae03, 0xf608ef1c, 0xc4a1b9af, 0x021af6b8, 0x1c23f7de, 0xefef2ba0, 0x24a53afd,
+	0x543f9d10, 0x08a46352, 0x649a58f6, 0x320c37be, 0x2193ed23, 0xd54a0e16, 0xf5d820cc, 0xacd19db3,
+	0xe91f2a67, 0x789b2409, 0xc63455fa, 0xc3345141, 0x50f68018, 0xe47983e8, 0xd4912781, 0xd78ab892,
+	0x6fb237f8, 0xb6af1b2f, 0x4fab8108, 0xa5d192ae, 0x3a5daf06, 0x8d1212a9, 0xf745a44a, 0x668b5f66,
+	0xfa930427, 0xdb41a69f, 0x457360d6, 0x05e5b186, 0x3e1d0dfc, 0x134c2880, 0x3a6fa3be, 0xbdf3a20c,
+	0xb5d0f273, 0x081f5b34, 0x7d1a0335, 0x3e29d9ef, 0x68105e57, 0x99bf8b12, 0x3ea93582, 0x4c9adec5,
+	0xb70d9290, 0x97f3634f, 0x454c7736, 0x4a080628, 0xdb6ea044, 0x3588c5d8, 0x34a5542d, 0x64626e52,
+	0x6f59c2f1, 0x57faa4a8, 0xd28ab0b3, 0xbb22fb88, 0x230c4306, 0xf1007654, 0x9f46ac99, 0x9eeef411,
+	0x0d1d6985, 0xd504e992, 0x5755cb92, 0x20c7c6f3, 0xe7164f09, 0xa02f9cfa, 0x0708455b, 0x4e4d30f8,
+	0x5cab957f, 0x28d21bde, 0x40bcb28c, 0x43ea904e, 0xe1de780c, 0x9d44b2f9, 0x8cd4f7e1, 0x8a228895,
+	0xe18ac850, 0xc00da0bb, 0xb21e283b, 0xadc05625, 0x47a943ad, 0x0323d083, 0xf241cad4, 0xbff44dde,
+	0xb5e24968, 0xaf455516, 0x580c7390, 0x94d981a7, 0xa244bad8, 0x0627a6d3, 0x8be63f6f, 0x3fd940e1,
+	0x894079f2, 0x828b8b7a, 0xf6bc06d9, 0x6af6a906, 0x5a519b3a, 0xbb56d6eb, 0xd09ded28, 0x5e104e99,
+	0xe4571776, 0xd7796f71, 0x7dd4e044, 0xb063918d, 0xe7de817e, 0xafa198a0, 0xd48a2e12, 0x4bab7abc,
+	0x98ead114, 0x5862e135, 0x79c80e05, 0x8e12143c, 0x37a6de12, 0xc156e05d, 0x8ad971e9, 0x35509317,
+	0x5948572e, 0x83000e33, 0x533f9b7d, 0x95469cdb, 0x10c0015e, 0x88ff6ef9, 0x97cc0ed6, 0x9810cfb7,
+	0x55ab8a7a, 0xfd4a4d0e, 0x4150c848, 0xe045c809, 0x9a4afc18, 0x781e14e1, 0xad5243b3, 0x223ba293,
+	0xb42fbc76, 0xd16842ae, 0xb75a7c66, 0x5543d3b4, 0x4850f91d, 0x9cd8e2a8, 0x84aecc79, 0x3c9a19fe,
+	0x35fa6f22, 0x064c7783, 0x10129bd9, 0x9b0d667c, 0x87b6daee, 0xaed9aa95, 0x47217385, 0x9b3cc4b1,
+	0x7dd95d72, 0x0f3f0e24, 0xeaa29344, 0xc2478f49, 0x3d626e97, 0x9e6d94a6, 0x6f4444bf, 0x154201ed,
+	0x4eb8f6ff, 0x9e3cc96a, 0x4df67b5f, 0x6762730b, 0xe7cd385d, 0x3d6f82a7, 0xdb57094b, 0x81d4e690,
+	0x821ddcab, 0x4b0c84e9, 0x916360c0, 0x600ca5f8, 0x0e052129, 0x0f1846d1, 0x16a8f68a, 0x5dfb59b1,
+	0x27f400f6, 0x2b3f3a38, 0xa6a18a75, 0xbf6e7430, 0xcdd59569, 0xfade9f67, 0xe29fe49b, 0xa93cdabb,
+	0x681dfbb1, 0xdc78f189, 0x3ac5a376, 0x3ac7efe1, 0x8208165c, 0x65672abd, 0x4a9a3008, 0xef011ec5,
+	0x78ae0dfe, 0x8cb6e2cd, 0x592f0765, 0x18737d79, 0x2a6b0d60, 0x435e68c8, 0xa28c6971, 0x07facf64,
+	0x5137d305, 0x95b64561, 0x423fa68a, 0x5faa150c, 0x28872c35, 0xd778e9aa, 0xf6099ab3, 0x78c80c72,
+	0xae7ef6f7, 0x2308716f, 0xd304e66c, 0x383f96cc, 0x9c336845, 0xbc160b58, 0xddef0f4a, 0x9e40e2f5,
+	0x9e855be2, 0xd78e8a29, 0x1b0ce205, 0x10f70bf8, 0x27d16eb4, 0x7e9cce4f, 0x4a0c5d86, 0xab1241e3,
+	0x8a001938, 0xea41be3c, 0x28e2b968, 0xb469e5b1, 0x54484f4f, 0xc511c225, 0x3b735118, 0xd0f468a1,
+	0x374c612c, 0x76c38c1e, 0x2b4c4e48, 0x12184b8a, 0x434d1fa7, 0x13fb8ddf, 0xb0857838, 0x7f9d7b79,
+	0x14866122, 0x25a76d53, 0x033519a4, 0x50b2072b, 0x2ecbae94, 0x7ed0a9b2, 0xec1656e8, 0xf216605a,
+	0xab742738, 0x77d4d6d2, 0xf71a39d0, 0xdff3d792, 0xa9c85995, 0xfbcd57d9, 0x80112fb6, 0x1c3fce32,
+	0xeb050c34, 0x81113149, 0xb28c74cd, 0xde6ea4fd, 0xd404e7ff, 0x362ed04c, 0xe40061dd, 0xb804bbeb,
+	0x055ba2c7, 0x160c2e8f, 0x940d5b77, 0x475dc37d, 0x123f12dd, 0xd04a0637, 0x5032cad1, 0x89fc0298,
+	0xf4bc03b3, 0xa4ff9b0d, 0x2a7399cf, 0x38e37e2f, 0x6262f0f3, 0xc40da707, 0x6fb6098f, 0x0c3527cf,
+	0xbf63a92a, 0xac154691, 0x9f8c64b8, 0x3916adfb, 0xba6772e2, 0x5c62e781, 0x37445011, 0x3150af18,
+	0xd11580d0, 0x63105678, 0xf1825872, 0xdbcd2579, 0xc3ffcbda, 0x3e959ff6, 0xc9111cda, 0x21872130,
+	0xba410f09, 0x4429e361, 0x70d89d64, 0x502181b0, 0x73d4d086, 0xe308d133, 0xc94fc343, 0x6151a63f,
+	0x3f50c522, 0xbfffc58e, 0xf7de9aae, 0x8a40c379, 0xcb6ddb7d, 0x49fb3a35, 0x94ea1f31, 0x409a204a,
+	0xedde8af0, 0x1a349242, 0xcb4fc669, 0xc87253f2, 0x8a468b8a, 0xf91f4397, 0xc2382145, 0x6bb60343,
+	0xd2f31784, 0xbacc45ef, 0x47b8ebfa, 0x5a9af4cf, 0x7ff775ac, 0x241d8dba, 0x12167096, 0x73f762c8,
+	0xca56c8b8, 0xc4d5873e, 0x9eff919b, 0x48d702a1, 0xf849bf68, 0xdc565f47, 0x31d6de6c, 0x7078852f,
+	0x76b61494, 0xcaaf2cd7, 0x2ec7dcfd, 0x2a6f64be, 0xb17430aa, 0x9408de49, 0x5d62634d, 0xffc79300,
+	0x00e27a70, 0x7ff6ca15, 0xd4d5a06e, 0x4f510422, 0xc15a8c24, 0xb5162338, 0x66f6a2ba, 0x5fc73980,
+	0x60224677, 0x91cc0564, 0x035b9fa1, 0x3c2a8260, 0x2fc0ddc2, 0x03f3c84a, 0x67f74fe4, 0x2e4cb67b,
+	0x223f2c0d, 0x5e671fb8, 0x89eed017, 0x5807d43e, 0xb2561d29, 0x8fbba9bd, 0xa1ee4d33, 0xb10cdd87,
+	0x5b485a54, 0x82120158, 0x293f595f, 0xf1cd481b, 0xf4729936, 0xc84bf019, 0xfd11d259, 0x473bdcd2,
+	0x3ce3847b, 0x20016be8, 0x89de328d, 0x3a3f7422, 0x5510ec05, 0x0c50d853, 0x43cbcfa3, 0xf623bc0c,
+	0x58104527, 0xe281953e, 0xb904e063, 0x7e8c30c5, 0xc0110eaf, 0xee307b67, 0xd5ddb991, 0xcd5a9f08,
+	0x2507cbe9, 0xabc0452b, 0xde384d59, 0xa5e2dbcf, 0x2fe37004, 0xca6dd9ea, 0xd9cd2253, 0x77b6c1be,
+	0x34a6d959, 0x873bf99e, 0xb44b2506, 0x11d1f7a9, 0x1bd20767, 0xcc08acfe, 0x10184864, 0x23a90b01,
+	0x136ae92b, 0x57485408, 0xb5ee6caf, 0x931df76e, 0x1ac711ea, 0x9bbacc01, 0x42a93bce, 0x672f1d6f,
+	0x1a50ee5d, 0x01d132e2, 0x3ea794ae, 0x66224fe9, 0x71aa3162, 0xc9f3b4f0, 0xb826c866, 0x6f51bc49,
+	0xa5d0e717, 0x854683ab, 0x0c93cc14, 0x660895df, 0x83b6d4a2, 0x8b114107, 0xb234367e, 0xe879046d,
+	0x405db878, 0x4cfb3eee, 0x7d89e2c7, 0x9f6376ca, 0xa745487e, 0x15f01309, 0xa8bfa2af, 0xff3467c1,
+];
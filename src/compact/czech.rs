@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0xf3656d25, 0x75c0299e, 0xcc043dd5, 0x40a095a0, 0xe2e9bf95, 0xedb26952, 0xf3a50f4f, 0xc9ee89f5,
+	0xdd752cbf, 0x06527ff9, 0x960ab6c7, 0x6f6b2d94, 0x02dbcd25, 0xecc21629, 0xecc47d47, 0xb8579d78,
+	0xab54a9d3, 0xfaf7cd60, 0x3d547d98, 0x2666f7e7, 0x9aaaac79, 0xffa77192, 0xcb17b3cc, 0xf69e8e6c,
+	0x04744a6b, 0x193f5828, 0x290bf517, 0xd2a5df76, 0xbe0f5616, 0x468b5330, 0xdf346bdc, 0xe503cdac,
+	0x14f635bb, 0x3caaf679, 0x7cdc70ab, 0x08e6214a, 0xc0c284dc, 0x53a5445d, 0x059f609c, 0x444c91bd,
+	0xb608ca35, 0xd65da29d, 0x1d238463, 0x588e8d2a, 0xf161859b, 0xa40e7adb, 0x8f13ed36, 0x96cd23d9,
+	0x86e081ff, 0x095b2f47, 0x01f92cd7, 0x6332179b, 0xfb7fcad7, 0xf0939b56, 0xfa6c66a1, 0x1f191345,
+	0x36d22bc1, 0x2e6104d3, 0x8090379d, 0xf262f125, 0x6f9c4c3d, 0x51be97b3, 0x4bbf6362, 0x30d20833,
+	0xc902b771, 0xa3da3646, 0x7cc7009d, 0x82e81733, 0xf448349f, 0x21c5360d, 0xac985ea9, 0x8c421473,
+	0x4a791e42, 0x5d004b01, 0x53701128, 0x06483fa2, 0x22f04070, 0xac1bf3e2, 0x148de3bd, 0x30a4cb9d,
+	0xf9239108, 0x81fbf43a, 0x9c6e7cd8, 0xd0aee552, 0x32c9fe38, 0x968f6404, 0x266b354f, 0x7f2e42ee,
+	0x838a2f50, 0xaed65a0a, 0x204ab80e, 0xe6a17110, 0x31a8bee0, 0xf381fccd, 0x0d73c0b8, 0xa3e10e6f,
+	0x746b18ca, 0x607e8106, 0x55c12254, 0x524c9a85, 0x514bd2ff, 0xa25790c9, 0xad9ab2d2, 0x95085a9e,
+	0xf048e147, 0x6cba793f, 0x6d80ec0f, 0xc4497f9c, 0x1a7fabce, 0x734e6aff, 0x03bc3d13, 0x06853c96,
+	0x87105fae, 0x5f5c052c, 0xf0b181d0, 0xaedd8524, 0x6a19704c, 0x3a89c1e0, 0xa578fd7c, 0xc8530684,
+	0x6b233a63, 0xdef1ba91, 0x342518b0, 0x7b15b672, 0xeff0f7a5, 0xf155e441, 0xd0355ca3, 0xfc47482f,
+	0xe1a75e7c, 0x9a02ddd1, 0x7aeb8c94, 0xee9c4095, 0x662dfcd2, 0xcfc58cdd, 0x2b6590e2, 0x9e6a41e0,
+	0xc4872d13, 0x4ac8e5c3, 0xcf598afd, 0x57bfd346, 0x46eb71b9, 0x6bdca16e, 0xe05c1fd8, 0xd5a10b86,
+	0x18cbc2f2, 0x58dcb6d9, 0xd6c28571, 0x60c01944, 0xde367901, 0x8b2017c8, 0xc03fa545, 0x9afdfccb,
+	0xf7368c35, 0x8897848f, 0x10a3aa1b, 0x479a38fe, 0x7c4ca9bf, 0xdcdad446, 0x49826825, 0xd1950183,
+	0xb2f2f2d3, 0x8e492080, 0x174f8074, 0x45dea788, 0x0cd7a199, 0xa70eee90, 0xee38a2eb, 0x4a03ea7c,
+	0x42705c8d, 0xadcd2b4e, 0xb2e294cc, 0xa29c7db9, 0x9429515f, 0x5d7c1396, 0xdcf99805, 0x906c3100,
+	0xd0541b59, 0x7667d925, 0x44888dff, 0x486aa646, 0x32271d4d, 0xf6ef1ea9, 0x455d9b42, 0xc4e1c25c,
+	0xcb616947, 0xc044412e, 0x10404087, 0xfcb48e8b, 0x74fe958f, 0xb7cd02ac, 0x75dad319, 0xbb29852e,
+	0x66fabe84, 0x5fcd1e0a, 0xc2d16c1f, 0x7152cb12, 0xc29d23bd, 0x27885536, 0x77414d74, 0x246dd645,
+	0x42b88436, 0x5d63bc26, 0x2d5f8dbf, 0x0c0de4be, 0x88a347e8, 0x9f118d3c, 0xd4e7d8f2, 0x53181ac1,
+	0x5404949c, 0x9ccb4410, 0x4461a801, 0x0902c753, 0x4789ca7e, 0xa5d0a76f, 0x84f915ec, 0xeb9a3912,
+	0x75d035ec, 0x2bb62084, 0x17e2cacd, 0xb05487c9, 0x3e118c2f, 0x91edfd05, 0x6f2c6aaa, 0x53f4befa,
+	0xabffcc97, 0x3402d6fd, 0xaecd399b, 0xaf2fba4d, 0xba7113d7, 0x30ffc1b1, 0x32e611dc, 0x4acd656e,
+	0x456040a4, 0x4a748c50, 0xb208b384, 0xf5b271ee, 0x090d3fbf, 0x5f233054, 0x3b13d96b, 0x951f450f,
+	0xbaa5ffed, 0xb129eaef, 0x135c12ea, 0x8b18098b, 0x142f233a, 0xc5ecf709, 0x1e6101fa, 0x3e66efa9,
+	0xffc4bbc0, 0xd8893a93, 0xb09ccd56, 0x31f1524d, 0xe6f1de6a, 0x00061adc, 0x88b42e9b, 0xcfaa5576,
+	0x9f044052, 0x42efdaee, 0xa20c7eb1, 0xcda7845f, 0xf78612be, 0xf6e62052, 0x0cad46e9, 0x0d99c0c4,
+	0xbbec4977, 0x594770ef, 0xe4287688, 0xc2d22cb9, 0x55a92dbc, 0xf2f10178, 0x25c1e212, 0x3a9ac86c,
+	0x7a5191ce, 0xe37a9d66, 0x4eb769fc, 0x6aeeca13, 0x4c50098b, 0x635abb1c, 0x83d22267, 0x4627ce3a,
+	0x7795994c, 0x5cdd93ff, 0x4fcaa2ae, 0x46748059, 0xca9daffb, 0x7de1af80, 0xc48c2027, 0xc522af2c,
+	0xeaf12614, 0xa66be093, 0xd2b17e06, 0xb22191f6, 0x70f4eb5c, 0xfbb66f6a, 0x36ed7970, 0x08ea62f0,
+	0xe5199ffb, 0x3bbea473, 0xbc5ceb03, 0xcc26fc6a, 0xcd48161d, 0xf112fd27, 0xf2dea48e, 0xd9e538f8,
+	0xf3528ed8, 0xa05b22f5, 0x43ef890e, 0x42528237, 0xf3eb5590, 0x03f0554e, 0x5958498b, 0xbc471c99,
+	0x8a8753c7, 0xdc7b430a, 0x5aa6cebc, 0xf21c9b22, 0x337463e3, 0xb188ab85, 0x17773804, 0x788dfb5a,
+	0xa4e5ea8a, 0xbed85d26, 0x49642514, 0x0818a0dd, 0xc1fb9c04, 0x09c536e4, 0x844d7bd8, 0x479ad86f,
+	0x3174aab7, 0x55e42a34, 0xd6ea6fb4, 0xfb080cb3, 0x84305396, 0x0d3c5cfd, 0xa6b974f0, 0x3b08823f,
+	0x1f297373, 0x2535e3ff, 0xed3a03d7, 0x11d2077a, 0xe8657f30, 0x81dacd32, 0xe2027816, 0xd06dddda,
+	0xafd11c59, 0x625f918f, 0x39f8a744, 0x3dbdf0f6, 0x953445ed, 0x1c7a7a11, 0x13091637, 0x71f81b0c,
+	0xa98ea905, 0x2e9cacdc, 0x71914b88, 0x174df960, 0x874dbd06, 0xcfc6332d, 0x071ad61e, 0x22258dfb,
+	0x90518cd9, 0xcd30b299, 0xabd4e43b, 0x64eb3552, 0xad015413, 0x19c5b6d1, 0x1b57e71f, 0xa74c3206,
+	0xd15fb347, 0x99062be8, 0xe7191b6a, 0x17be4735, 0x301abb3f, 0xffe38d97, 0x0f6521e2, 0xd8f03c0c,
+	0x9d9a85f3, 0x490e08da, 0x39ec12a5, 0xbb9027d1, 0xa7870df9, 0xac8298aa, 0x481a7117, 0x41a49a77,
+	0x5d116039, 0xd2ab3aa0, 0xd5bec711, 0x80d2b9a5, 0x1627a689, 0x8f376247, 0xa88f28d9, 0x08deaf96,
+	0xe9c4006d, 0x6b37cba9, 0x1d2c9bd8, 0x28fadff3, 0x77d88424, 0x1f55cf5e, 0x71a1a0c8, 0x1db41d4c,
+	0xf8810778, 0xf26fb4e5, 0x61523692, 0x6d3a96ac, 0xc4d79e6c, 0x3a7cead9, 0xb5d39196, 0x3e457c19,
+	0xddac9cfd, 0xed4db506, 0x475a1519, 0xeb6749c6, 0x0f78c39f, 0x4ff9b47a, 0x8c048e2c, 0x0f25eb57,
+	0x990032ab, 0xa4c65dec, 0x97154a3d, 0x21a0847f, 0xdf1b8c3e, 0xd3d9f3cd, 0x9bc97ac8, 0xce9bfb0b,
+	0xc3d7b57b, 0xf5a6dec6, 0xe183f95d, 0x7480b31c, 0x1dd22868, 0xbda6a952, 0xfa758a5a, 0x0109fadb,
+	0x4023c30c, 0x6b4f935b, 0xe9f64b43, 0x0dad63a2, 0xdb52f68a, 0xc066f9a3, 0xcf5a454d, 0x5a858104,
+	0xc14ef650, 0x11a0754d, 0x91c289a4, 0x94ad853a, 0xae0655de, 0x8921db06, 0xfc8801f3, 0xf08d042f,
+	0x708c4469, 0x14d6811a, 0xa2030c93, 0xe0848458, 0x9d896a3c, 0x70b6a647, 0xfa7a694b, 0x8f14cd33,
+	0xc3f5c8f6, 0x401db0f7, 0x9b5c9630, 0x3f18ff79, 0x93c79fa8, 0xf1aadafd, 0x4fec587b, 0xedb94012,
+	0x5f6afeb9, 0x988a7dcc, 0x59e5b480, 0x8a08c5fd, 0xacd039a0, 0x4eeb4fc6, 0x1d78b2dd, 0x0b02b597,
+	0x1e437bca, 0x88cd6837, 0x42fb1494, 0x52a68b39, 0xb9e1a529, 0xb7a4f0c1, 0xa9851b4a, 0xc92fea88,
+	0xd3eeba71, 0x256ae428, 0x0c10d330, 0x49acf80a, 0x4ed988e1, 0x370d5f9f, 0xfa628ceb, 0xfa6fa956,
+	0xb4623b20, 0x12216890, 0x1b5926bb, 0x1de431bc, 0x98c30e8a, 0xae91f409, 0xa2707f3c, 0x2c852e83,
+	0xc1c436e5, 0x9b149477, 0xdab6f649, 0xa3989a34, 0x8e39d5b8, 0x288bc3fa, 0x9e9b6180, 0x2937cce1,
+	0x7feca8a5, 0xfe9749dd, 0xab8261eb, 0x2a2a7847, 0xf4063b46, 0x1517b85a, 0x0cd51f50, 0xe8f02ffb,
+	0xeb6d8a66, 0xbcf1465b, 0xf9ba34a7, 0xf43af042, 0x7c8c83a3, 0x09ca3f02, 0x6e11ca5f, 0xc41f60ba,
+	0xdee37df3, 0x486484de, 0x44825c2f, 0x24ade09f, 0x941607c7, 0x1d6a29de, 0x85e596ea, 0xe0b85cda,
+	0xd2ab7add, 0x294bc520, 0x45a94163, 0x53c346cb, 0xffd774d7, 0xab2358d7, 0x107c7ac3, 0x20eebe0c,
+	0x29dcfbed, 0x8c7052ef, 0x2f4ce978, 0xb4fc89eb, 0x365da427, 0x2468217e, 0x1aaac5bf, 0xbf4a1640,
+	0x94dad471, 0xf45a66fb, 0x4c965f56, 0x5c7b6056, 0xccd69a6b, 0x6629212d, 0xed51e49e, 0x83d061fb,
+	0x27a5b1e6, 0x422b7f59, 0x13d1a29d, 0x9171cf47, 0x4798c9b9, 0x5112102a, 0xdf184190, 0x22fc82b1,
+	0x7d79f5e4, 0x8b7aa40b, 0x8f8bf378, 0x9cb46853, 0x2d881a93, 0x605f048c, 0x3b2e1fb2, 0xff615206,
+	0x93cac51e, 0xdc59e5f2, 0x268be125, 0x68801770, 0xd3a2f15f, 0xf766f428, 0xc777f1b9, 0x80d9e574,
+	0x4d9a741a, 0xf7c151bb, 0xf16d73a0, 0x051b55ca, 0x19515399, 0x731ae315, 0xde67f360, 0x5ba377d2,
+	0xe213f06e, 0x59a6019e, 0xede87c91, 0x0592354c, 0xd381d178, 0x74617291, 0x0766010e, 0x239cf1e2,
+	0xfba0a768, 0x9802296a, 0xe5518018, 0xfea744ff, 0x4b638eff, 0xab48ca0b, 0x8a2320bc, 0xeb5f79e3,
+	0x449d8dfc, 0x4592569c, 0xc8dba199, 0x3570b197, 0x909bd0d8, 0x7546c04a, 0xe5e20275, 0x1e715404,
+	0x617dcd7c, 0x454bc417, 0x5582f489, 0xa0880588, 0x1ceb8fff, 0xc60d5c13, 0x93c349d9, 0x849b7a2a,
+	0xa0e842cf, 0x12054f04, 0x616eabce, 0x49db17c1, 0xc251f171, 0x54a7fd93, 0x51834dd1, 0x9b2a4cd2,
+	0x3bbcea30, 0x539a6e72, 0x786d1e92, 0x6b5972a4, 0xe943759e, 0xe1b523ae, 0x9a89fd93, 0x106c92d6,
+	0xf15af80b, 0xc0ee4680, 0x1b773183, 0xaa7a5c86, 0xd9b2c3fa, 0xece08683, 0xf8ae9324, 0xd31e0ed3,
+	0x7e5212f1, 0xe44444d6, 0x524e0cdb, 0xc8a4e4e3, 0x08d7bf30, 0x057ca316, 0x81912580, 0x1de5fda6,
+	0x0485b9af, 0xf40679f9, 0xf34a909b, 0x0a6bbb4f, 0x86e59666, 0x44abb76d, 0x12f1b8c0, 0x970eec70,
+	0x8a9d75d5, 0x2a46ff63, 0x4949901a, 0x8b14df62, 0xeda82d27, 0xf39ff2bd, 0x72befb32, 0xc90802fa,
+	0x18d348b9, 0x43eb426e, 0x53d411f0, 0x41e2edf3, 0xdd7d109d, 0x98fe4653, 0x213dc149, 0x82a753fc,
+	0x73b5bc5f, 0x22dcf631, 0xe1acfa39, 0x6926b199, 0x2817ed3d, 0xdbb1614a, 0xf9e0322a, 0xe2d26a2e,
+	0xe514cee9, 0x07ec9285, 0xb4648c0a, 0xf09215c8, 0x73353912, 0x3b031073, 0x8c90a845, 0x4a174178,
+	0x5e03eac1, 0xf0b365f0, 0x310341c5, 0xebea12b1, 0x59582a5b, 0x20f17bf2, 0x3e14b4e1, 0xbb7e2ef5,
+	0xcac44902, 0xafb19df9, 0xfc910dad, 0x0fbc9ba1, 0x04656e03, 0xbbfc21cd, 0xca8a5e8b, 0x57a41b4a,
+	0x84e01525, 0xcc9e43e8, 0xf2a75222, 0xc1e3571b, 0x3b482d14, 0x20467e8e, 0x45b75117, 0x6d05f693,
+	0x03978631, 0x2a63c934, 0x86854317, 0x96d45af3, 0x159648aa, 0x6cde999f, 0xb9985a5a, 0x64f70f51,
+	0x6d5d6cce, 0x4504d6f0, 0x2d0c3bfa, 0x7cfb975a, 0x06dcc10f, 0xff4d295b, 0x4648caae, 0x93fa5fad,
+	0x5f0f4fba, 0x78e1f928, 0x94a03d99, 0x88dd140d, 0x80c5e002, 0x188e77f8, 0x25a7238f, 0xc50f00e9,
+	0x3b761903, 0x6e2e9fb3, 0xfa1a953e, 0x4c08bbdf, 0x61cbf44d, 0xc1a870fa, 0xb2918d4e, 0x50a2cb8f,
+	0x7e9b817c, 0x78aa82de, 0xfa3770d9, 0xaf11e269, 0xd39503e9, 0xb9812e86, 0xe1b42b72, 0x7ddc116a,
+	0x91c8a92e, 0x6e4f28ef, 0x540d4149, 0xd19eadac, 0xf6fe8dab, 0x49a5e881, 0x6b65bd9a, 0x62e238b2,
+	0x9a42fade, 0x49f5c6db, 0x70cfc7cb, 0xad7981fe, 0x23e82678, 0xcfc5f4d2, 0xd7ca7e98, 0xa0cd5c30,
+	0xee0ea6bb, 0x5c3c80a8, 0xf3d76282, 0xd20e552d, 0x8d3fbe0c, 0x922f43c8, 0xa7cd615f, 0x91e88ba4,
+	0x1d03fc3c, 0x91f091fc, 0xa53b6b60, 0x4a0fe6c8, 0xf43250fa, 0x5859152f, 0xde2323ce, 0x54f157d8,
+	0xea257549, 0xd0f0cb63, 0x81b61784, 0x3f4bee36, 0x0c4d6993, 0x951bff19, 0xc8b3be27, 0xe60b5b7f,
+	0xd6e8b1a0, 0x626b0b80, 0xa3015f4a, 0xc9767ed7, 0x49b92067, 0xfaeb027f, 0x2ec3b86b, 0x50a81774,
+	0x8ff7c79e, 0x76224c38, 0x2493f1ba, 0x0da24457, 0x65ac827a, 0xcbe1fc98, 0x92401eac, 0xb304bf4a,
+	0xe433b95c, 0x7f9e735d, 0x8b21da8d, 0x35904984, 0x514a2849, 0x9bc66846, 0xee6a4019, 0x37a0c477,
+	0x50cfa746, 0xc8db8172, 0x878a4a19, 0x2985bbf8, 0xdf1cbd5c, 0x73b52996, 0x8e40a2bc, 0xc396dce5,
+	0xf9885b08, 0x0f278bb0, 0xad6e668c, 0x8438c567, 0x511234fb, 0x4bf13856, 0x1aaa6668, 0x0e4bece9,
+	0x9a8c7c8a, 0xe8500dda, 0xad59b5b9, 0x94fe6d41, 0xda06b1e7, 0xd89e7119, 0x9e972887, 0x41365a9f,
+	0x53b90e02, 0xbb15ca7a, 0xaf7b9142, 0xeb1f6ee0, 0xb4115811, 0xb137ce55, 0x385bf448, 0x17008c1b,
+	0x1930dd8e, 0x2ea60fcc, 0x263a5b40, 0x4f00b03a, 0x7362fd61, 0x6eeb34d5, 0xd47276a7, 0xbe712a05,
+	0xa66fefa6, 0x1e863540, 0xd2b5405a, 0x821018ba, 0xc90d444f, 0x3821a868, 0x7bf6ea76, 0x094274e6,
+	0x2efa5dfd, 0x84bdc7b9, 0x35329355, 0x9b591b71, 0xd2ba8519, 0xf17a856c, 0x34c6c26d, 0x2887be32,
+	0x4d47df17, 0xb8c1efd6, 0xedfdbb29, 0x831d93b6, 0xfb532132, 0x0b2e06c7, 0x78cbe44e, 0xdd5047ca,
+	0x25f62126, 0x63950440, 0xd22a1d9f, 0xb06c7879, 0xd55da82e, 0x14ce0dfd, 0xb4583a8e, 0x107974b8,
+	0x9558082e, 0xda692ead, 0x9f3fa731, 0xacfbfe8b, 0xc8c09646, 0x851ef628, 0x42a2a2bc, 0xc4d17fa0,
+	0x53ea2b1e, 0x8ea6c26d, 0x01a7587c, 0x26fac49c, 0xc7b65ee4, 0x1b992fb3, 0x0180f985, 0xef313ecd,
+	0xa240aedb, 0xf0c3464b, 0x0642b487, 0x2259cbe9, 0xad7d27c2, 0xba509f4c, 0xf8a9008c, 0xdcd7a587,
+	0xf0900099, 0x89c1abe6, 0x8e643591, 0x36ce55d3, 0xeca0a617, 0x4ea01fd3, 0x51f75aaf, 0x40888ef8,
+	0x9f137d31, 0xb487ad20, 0x328e25dd, 0x8f6f2a63, 0x1e09e1bf, 0xd40fa787, 0xa27eeefb, 0x4d881425,
+	0xf8fcb018, 0x6b17d7d0, 0xfeee8933, 0x9eca399e, 0x877c4c63, 0x7d20b0d1, 0xba037db8, 0x6bdd3eb8,
+	0x75234c83, 0x992cdcd9, 0x021b85f0, 0x17f3eb1f, 0x82798ba2, 0x0a615d9b, 0xe5e0d9c5, 0xc2e52c23,
+	0x72c2c090, 0x6a1335dd, 0x71f5c34d, 0xca0526ca, 0x0487c371, 0x1f3ddaba, 0xf276231b, 0x037d8dfd,
+	0x75536d54, 0x630956bd, 0x29d494e2, 0x21ad7871, 0x3cccae11, 0x66f7f116, 0x185d608b, 0xbcb352a4,
+	0xfe11aba5, 0x74d6a8c8, 0x51644051, 0x5bdf7f1b, 0x2d8029f0, 0x5ece95f4, 0xc3e89a8c, 0xe2fea84a,
+	0xc6da1857, 0x7f9405b6, 0x1e926f60, 0xc3ab319f, 0x17b9e8b1, 0x479ece8a, 0xae81439f, 0xc68e355a,
+	0x1b1e286f, 0x4cf773b4, 0x73873ee7, 0x7bd03728, 0xb24f2504, 0xc8e2d90d, 0xa98b277b, 0xde18e5c4,
+	0x5ab4d53f, 0xd1d31697, 0xca2538c0, 0x48d0a186, 0x8f7fadfa, 0x336ad8c1, 0x9c1b0d9b, 0xb88a349b,
+	0xed118e98, 0x6ab11c25, 0xa558a358, 0xd587f520, 0x7986187e, 0xa9f2a619, 0xc1d84d7e, 0x05322f2e,
+	0xd70f20d7, 0x9ae99420, 0xdb702610, 0x660edca6, 0x39f31fa5, 0x4866da35, 0xd12ea788, 0x3c6c4b85,
+	0xa8b26bd2, 0x995ba771, 0x5146c3d4, 0x4b1787b4, 0xcb8adb46, 0x810b7262, 0xe022e868, 0x0418c960,
+	0xc7c9d273, 0xf013d3f5, 0x81b02df8, 0x5d6245c5, 0x984be66f, 0xca90ed4c, 0x822275c6, 0xeb79138c,
+	0x548dc6f5, 0xcfcf66a9, 0xafc112bf, 0xde5e87ed, 0x472f5fbc, 0xd462f55d, 0xe144e86d, 0x6709adcf,
+	0x01be0137, 0x1735c273, 0x142cc35e, 0xc2a431d5, 0x1ea2fc2b, 0xf466e325, 0x6f59c7dd, 0xb9f1f960,
+	0x7c077701, 0x6a347a49, 0xcd9f6a66, 0xf2d3e58d, 0xcf35d2bc, 0x012a2ebb, 0xc48552be, 0xb942f5cc,
+	0xd8586f7b, 0x2273f02c, 0x10de1f0d, 0xd37d2172, 0x6dc97ec0, 0x61eb4158, 0x35cc0a51, 0x80898025,
+	0x0aba89d7, 0x0a4ed6b5, 0xfcf3f5df, 0x19e09bce, 0x6c2bffb6, 0x22e52727, 0xffc66f07, 0xef059ed3,
+	0xbc2c31d7, 0x34aec39c, 0xc18c994c, 0xb826d108, 0x6f747688, 0x4ab4e39f, 0x50749fc8, 0x02b54508,
+	0x485b55de, 0xc4a6871b, 0x67ba59fa, 0x1b2ec2d2, 0xcbeaa36e, 0xf9b03386, 0x97aa1c9e, 0x1451236c,
+	0xbf0350e0, 0x5c3e909b, 0xc19ef64e, 0x09db0b77, 0x9d7f82e0, 0xcc7eb3f4, 0xf5e3f66c, 0x07af8ba1,
+	0x0fb1b676, 0x5aefe34f, 0x0a715d10, 0x74171a1a, 0x7239f76b, 0xaaeeb924, 0x262d6e5a, 0x3fb1dbbd,
+	0xe1177e8d, 0xef706da9, 0x6b358d18, 0x7c9e5087, 0xd5eba96c, 0x5750f85e, 0xc580843b, 0xf083f6f4,
+	0x417c017e, 0xf87712ea, 0x5a0978f6, 0x22cc6290, 0x52db6d51, 0xde91fa67, 0xa377d69a, 0x4256be42,
+	0xac721702, 0xba1fdec4, 0xa5aa575e, 0x0e149872, 0xcabd1419, 0xb21f02af, 0xd315151f, 0xe251ca4d,
+	0x26f78639, 0x6c6aa949, 0xf84dec3a, 0xb3806de6, 0xbd73b1b1, 0x8425aed9, 0x76696269, 0x045c72f8,
+	0x215b2f29, 0x4609380d, 0x5d94e58d, 0xc04ace91, 0xe8625bb4, 0x6048836e, 0x9f7c9d8b, 0xb740ee13,
+	0xc4e40083, 0x262be847, 0xfef1d918, 0x4433047b, 0x04f94fa9, 0xbf7dce11, 0xddecb2b2, 0x95423f2e,
+	0xd396261e, 0xd1f4e045, 0x3457e901, 0x39ed644b, 0x30f6507c, 0xa04154d2, 0x733afcc5, 0x42d2291f,
+	0x252e231c, 0x1a0b341d, 0xdeba96b3, 0x6189c93d, 0xe7f9f383, 0x723fcdc5, 0x491e6bb6, 0xc1baf802,
+	0x3ac14dee, 0x928a172c, 0x30f9551e, 0xd3307274, 0x9d360147, 0x54071f87, 0xc7ecfb45, 0x666bfe00,
+	0x00e8c708, 0x12094b1e, 0xf184d3df, 0xa0b6bb93, 0xe1a7ea11, 0x663dba2f, 0x997e4e9f, 0x99fd2756,
+	0xa74fa246, 0x2de29104, 0x9a679acc, 0x7ea35cf7, 0x6d5df260, 0x2a6ecb95, 0x13ce8fb1, 0xbca8abee,
+	0xc2350f5f, 0xdbef347c, 0x05a9eb79, 0x81751642, 0xb0964348, 0xa61c32fe, 0xab9fa162, 0x6a637541,
+	0xba37ce8a, 0xb2f03691, 0xc9a000ad, 0x58fcc9be, 0xd8aac205, 0x1d20e6c4, 0x0e6664c4, 0xa2bf0127,
+	0x08c652d6, 0x0c57d09d, 0x5589e6ca, 0xe9e97950, 0x99d64ddb, 0xe8c88e2c, 0x9060a22e, 0x7604473b,
+	0xb2c5a9de, 0x62361422, 0x7be4b40f, 0x8b8c0a49, 0x35f068bc, 0x565f8acd, 0xdc67cd52, 0x0fc8a225,
+	0x4a91aad4, 0x0db58e80, 0x42942ed6, 0x9c22de2e, 0xe3cfb969, 0x3e0d428a, 0xc3cbd9f6, 0x883501c7,
+	0xcc515069, 0x66f26a7d, 0xc4d4d5e5, 0x99867418, 0x43699bef, 0xad0d4aa7, 0x491ea4a0, 0xd8c8296e,
+	0xbb20e2c0, 0xd14661d4, 0xc7af08e4, 0xde5a3e53, 0xbcadb9dd, 0xb78f9cea, 0xa553109c, 0x1aac8a9b,
+	0x77061140, 0x753f3aec, 0xc2c0a970, 0xe79ee139, 0xf02adf2f, 0x1ec4ae13, 0x286403e1, 0x81467836,
+	0x973e208e, 0x8fc64f05, 0x4cb7ad82, 0x878d1160, 0xfd60b6d8, 0x1edfaa61, 0x69f47cdd, 0x77e59dd5,
+	0xc8be9534, 0x0dae5ea5, 0x3d871b97, 0x73e68ceb, 0xfa6a7730, 0xa930c6d1, 0xa63bea23, 0xca957dd0,
+	0xb8965d6e, 0x116daf43, 0xe32448d3, 0xa79ddb8f, 0xf20c5e70, 0x88348e2b, 0xfaa2936b, 0xf5d64ac2,
+	0x64c6206c, 0xfa3e650f, 0x1f1b7023, 0x073260ce, 0xb7dd2013, 0x4ed1474d, 0x970ba180, 0x83e9d34d,
+	0xb69721d1, 0x8dfa570b, 0xb0e8fcec, 0x92ecbf53, 0xb25ec44f, 0x721615e1, 0xe9819e08, 0xfb17d939,
+	0xe138b304, 0x1c933284, 0xa8908a65, 0xeb48f314, 0x22f108c1, 0x22c3039f, 0xdb39005e, 0xfc422ead,
+	0xd7249229, 0xea2a0d71, 0xef57ac89, 0x35056da0, 0x25446b9f, 0x005d3c20, 0x81771de8, 0xcfda1e4e,
+	0x2531551f, 0xd9911037, 0x0bee576c, 0x410e7ae0, 0xb3e6757d, 0xb74c3036, 0x5fbd6ced, 0x9870a0c0,
+	0x49194c0c, 0x95d82c80, 0x2dbaf346, 0xa1a65b97, 0x1949d8ea, 0x394e1922, 0x1ddec1a6, 0xa3b452e8,
+	0xf003694e, 0x9a8f5357, 0xaab24233, 0xc1a45e18, 0x81b1be3e, 0x3de4c2e8, 0x39d13ceb, 0xf4a5a498,
+	0xe96904f1, 0x3f2c3353, 0x71411a8d, 0xf979fcd3, 0x18198abe, 0xead9ca7c, 0x0ba3e49a, 0x6361a805,
+	0xb2a79ea3, 0xb484d502, 0x03fb7b9b, 0x5d5a6dc4, 0x48258a43, 0x115843c2, 0xc2b44b5b, 0xc006110b,
+	0x5f9e478d, 0xa7f62de5, 0xe4a9e1d6, 0xadd2fef1, 0x325f658d, 0xda25204b, 0x6c09f5ad, 0x18901618,
+	0x8afe1b58, 0x1652b00d, 0xe886160d, 0xdb7219b1, 0xb6ededea, 0x82d83a4b, 0x6aedf29f, 0xd1579d46,
+	0x653f837b, 0xf5e608c0, 0xaf90fc33, 0x7170d4be, 0x2ecc1423, 0xbd4824b4, 0x2c9f77ac, 0xa7478fcd,
+	0xa140ca96, 0xf9c4da70, 0x9754bcf1, 0x94125bcc, 0x892d1d16, 0xbda580be, 0x74287ed9, 0x352f9459,
+	0x7e6ff1b2, 0x9f1d55ac, 0x408363d1, 0xb9841c4a, 0x4d069981, 0xc86267f2, 0xca12c738, 0x7035ec4b,
+	0xa493e489, 0xb7c7316c, 0x7b638c5e, 0xa41d4d99, 0x8f4ef871, 0xa1b0a78e, 0xe0465700, 0x6d09af77,
+	0x86aa7a12, 0x51e4eaa1, 0x23e8590e, 0x1ade7dc9, 0xc3b0c0ba, 0x537f1222, 0x6d657e31, 0xd03fa7db,
+	0x22bdc72d, 0xfe8ce6f5, 0x0a28fb92, 0x7a4d9e20, 0x86ee50cc, 0x06ed0d78, 0xc9dc2e0d, 0x9a3b550a,
+	0x7b6b0f3f, 0xf130a842, 0xb9d65874, 0xd0dd34e4, 0xef905320, 0xc8e51d96, 0x9d18d534, 0x009128ec,
+	0xaf9cff24, 0xe3b6f096, 0x6be23a84, 0xf10d031e, 0x8946eadb, 0x7f7c939e, 0x7999d7f0, 0xbaf15abb,
+	0xd0ba7ce4, 0xdb2f6aea, 0x7f9f7db4, 0x3a5369a8, 0x02ca92d5, 0x37e558a8, 0x1e1a127c, 0x471c5edf,
+	0xe3540c3f, 0x3fda41d0, 0xfcee8dae, 0x72bd913a, 0x1b7db9ff, 0x193da903, 0x2a163558, 0x1249bd52,
+	0x6b470a19, 0xc858d927, 0xfb00849c, 0x3861bc8d, 0x1f5fedd2, 0xf70be77b, 0x8868a66b, 0x229c650b,
+	0xc41407d2, 0x2e7fac5a, 0x2e0dd73c, 0x2f6c3481, 0x50f0ace6, 0x6b7d36f4, 0x742decba, 0x3009cc52,
+	0x243fbdc5, 0xfbba4cf7, 0x114ba7e3, 0xa42d0f79, 0x94399ad2, 0x3784e589, 0x1871bbe4, 0x514381fd,
+	0xbe4a0891, 0x561cd8c0, 0x9cce156a, 0xcc8e4720, 0x7d69cb54, 0xc147cbd6, 0xc707c39b, 0x22590888,
+	0xd406548c, 0x695b3512, 0xa4ef6470, 0x90dc4744, 0x45830755, 0x91469723, 0xb650bc40, 0x65df6d15,
+	0x7e28497d, 0x58919d41, 0x392ed4b2, 0x2d65b835, 0x34e086b3, 0x2812d9ae, 0x8dc2aa66, 0xfee7118e,
+	0xddf49474, 0xd51e7d34, 0x3b8e1021, 0x7fe842b2, 0x3345b1fc, 0x5017a52c, 0x7b61d6da, 0x398e1db9,
+	0x9e43925a, 0x59133c26, 0x21ef90a5, 0x4622455f, 0xb7d99143, 0x852707f9, 0x56d27196, 0x73487196,
+	0x3968e3e3, 0x695f2f3d, 0xf8d7a929, 0x7f796b7a, 0x4183bfe2, 0x18a23b1f, 0xa908b92c, 0xf0f9fc62,
+	0x43aa3ec0, 0x54176b0e, 0xe086fc55, 0x286d606a, 0xcb77d0bc, 0x6c308b3d, 0xff26a5a9, 0x7e9cfce4,
+	0xf504e55d, 0x1712065f, 0x274888aa, 0xd70918ba, 0x3f1c131d, 0xef0dab51, 0x3476a03e, 0xd2cd48fe,
+	0x883fbc31, 0xbf0187e6, 0xa266f48f, 0x79e03809, 0x41a3d453, 0xe1ed8540, 0xbce9f5ff, 0x539dde36,
+	0x97c11ed0, 0x46385d7f, 0x8ffaf9a5, 0xcf73ef36, 0x56ca26ed, 0xd61b423b, 0xf0c8454f, 0x8aca68f0,
+	0xdc338e0d, 0x7a8f4701, 0x77830dbc, 0x5f6a6710, 0x769550e3, 0xa4e71cc2, 0x38fa44ac, 0x45a1dce6,
+	0x768ad0ba, 0x825c8631, 0x81d4abbf, 0x67af7cc3, 0xcfdfca0a, 0x53c048d6, 0xc8982374, 0x9f75b82a,
+	0xda6d33e3, 0x3300ac30, 0xe664f802, 0x67ba6ad3, 0xe0902748, 0x7085b013, 0xa7744481, 0xa3fe1e3c,
+	0x91832c69, 0xf4cc2107, 0xbc6bc6c7, 0xb73519ec, 0xf8273ad7, 0x103f6931, 0x7fd68414, 0x64e40769,
+	0xbdcae3a2, 0x98c90fee, 0xdfead86e, 0xdd03a0ab, 0xcbb80d19, 0xa624fcea, 0xab03712e, 0x58a0b8d0,
+	0x6927b5c9, 0xea5fc5e3, 0x0f05d116, 0x2415029a, 0x99cbd1f1, 0x7b918ec9, 0xf8682bc0, 0x1a775020,
+	0x251e1996, 0xe0534cf7, 0x07ce0114, 0xb9c5db2a, 0xf2457993, 0xf6123158, 0x3bd2cf9b, 0x4cc36558,
+	0x54f34aa1, 0x9507cf8a, 0xd1c9a220, 0xe5234b23, 0x45b04d17, 0x5bb4ece7, 0x95ae0df0, 0x34767944,
+	0xa3ce2066, 0x91b3f404, 0x8a590bdc, 0x291a0049, 0x49712a21, 0x51770965, 0x97ebedcc, 0xf2b79404,
+	0x65de9717, 0xa19351b8, 0x02122654, 0xeaa612c6, 0xe429fc00, 0x83a6d006, 0xbd6685a2, 0x74d05c86,
+	0x974c8dbf, 0xb8759f3e, 0x99098fc7, 0x796240ef, 0x70f27b8d, 0xec195113, 0x9fa1e489, 0xaf29b22a,
+	0x82b8b0db, 0x889efff3, 0x8144b862, 0xeaaa567d, 0xad2e6c1d, 0xc4bbb03d, 0x4e5fb0f0, 0x828e3557,
+	0x17963cb7, 0xa8acff45, 0xdbc87e01, 0xc0353457, 0x2616404d, 0x102f8109, 0xd41b540d, 0xab11f015,
+	0xbd461b79, 0x442cd0d4, 0xa55a5636, 0x117a8cf2, 0x7e52bcde, 0x083cc4ff, 0x1d6186a7, 0xd9c1fadd,
+	0xbe372a30, 0xd6ac10f0, 0x630b25c9, 0xdf92bdc5, 0xdd4f9e88, 0xc6677f39, 0x65b8012c, 0x409bc5a0,
+	0xb4eb6330, 0x872ed68e, 0x49fa7d21, 0x059f16ac, 0x67ba7642, 0x8754ade6, 0xddb0ac33, 0x47b07940,
+	0xda73b22c, 0x085c8005, 0x9d7f29ec, 0x42111dfc, 0x8a19b019, 0x2eddb02f, 0x44af1d1b, 0xd0a6cf69,
+	0x0d0c83dc, 0xdd8eaefb, 0x7e6325c1, 0x6154688f, 0xcf9ca36d, 0xcf7906db, 0xb3b5bdb6, 0xf0ec26d7,
+	0x21034397, 0xd4ec3593, 0x87fef50f, 0x3381a1cc, 0xbd1eca5b, 0x645cb1f2, 0x0ec1f7fb, 0x2a9d9243,
+	0x409baaa3, 0xbd9da10b, 0xb590181f, 0x8f6cbce5, 0x82a3f4b0, 0x1fe8e4f1, 0xd0bd4c4d, 0xe0bd3090,
+	0x5a4c42d3, 0x6564590a, 0x5a557a32, 0xf318e741, 0x16edc5c5, 0xc9b7d185, 0x5eba3294, 0xfe2995b7,
+	0xab8f72e6, 0x5a7bccd1, 0x955f0f21, 0xf73cfb76, 0xbdd12561, 0x204f7555, 0x126e7114, 0x7e29d774,
+	0x0fc7cd6a, 0x6b5cc39d, 0x57b221a3, 0x85fb211a, 0x80321fd1, 0xfd08efd4, 0xfb0aee2c, 0xe9322835,
+	0xaeb66e1f, 0xca70b1f1, 0x7c6f29f1, 0xdb804af7, 0x87ad8487, 0xe5f55e35, 0x1b042ac7, 0x3bf3f4db,
+	0x5a1d0768, 0x165f4105, 0x23e8a122, 0x263f1599, 0x791f4521, 0x2f5d38d8, 0x1287caf3, 0x45067354,
+	0x1456c546, 0x0dd472a6, 0x06973c06, 0x5dd3a8c6, 0x1d4b727f, 0x3df5f473, 0x91817bd2, 0x2a9a6e61,
+	0xd14753cf, 0x255743d9, 0xf5e2f4c5, 0x55ffb18b, 0x52247c18, 0xb3b66440, 0x367e9868, 0xda9fe360,
+	0x0322fcb6, 0x9ebc811c, 0xa46e779b, 0x84c08688, 0xdeed1a1d, 0x5d551531, 0x793f92ac, 0xe98cd651,
+	0x9b292d88, 0xa175160e, 0x7805d7b8, 0x6aa998e7, 0xe3f99faf, 0x42d9414f, 0x4451fd3e, 0x18e0561c,
+	0x11e8c635, 0x5f4a99f5, 0x8ac2cac8, 0xa94df24b, 0xf723792f, 0x712be358, 0x6e66a77a, 0x2144bce2,
+	0x676a8ec8, 0x8e28d1d6, 0x6523b806, 0x4eb89077, 0x20937e35, 0x92663628, 0x4361cbb5, 0xe5fb58b8,
+	0xa961237a, 0x7f357a8e, 0x20c9c0f9, 0xcfaedd9f, 0x4432263f, 0xf9cae7d9, 0xed073964, 0x39856b1a,
+	0x6e3f8273, 0xc20b7fc9, 0x5d0c6501, 0x7b17ef3b, 0x7f27244f, 0x59870c4a, 0x0eab0efa, 0xec892a8b,
+	0x82649e74, 0xd233ad79, 0x35ef454c, 0x788b8f02, 0x734a6926, 0xbed0ae77, 0x41cfc6c4, 0x9ce0d0f9,
+	0x476c374c, 0x9913796b, 0x3b500c96, 0xce429c77, 0x5f83926d, 0xeab3c60b, 0x67a4af63, 0xf01fed21,
+	0x16c2d046, 0xc4e1a25f, 0xbdb60edf, 0xe35cb1b1, 0x34ecc0b0, 0x3512e0af, 0xcdd8ba51, 0x024cf57b,
+	0x4734006a, 0xf7618b6b, 0xb363060e, 0xbd25c260, 0x01547cc3, 0xd4c3080b, 0x11d279a5, 0xd8dd7b80,
+	0xec40d3fc, 0xe540a9b2, 0x647ef320, 0x2c327b59, 0xda0354c1, 0x47aa37d9, 0x68cb3a19, 0x051db55f,
+	0x25f16622, 0x3356aef0, 0x561d7ba9, 0x64cc1be3, 0x4af3f78b, 0xd023802b, 0xe5186847, 0xee1ab509,
+	0xd3d97645, 0xce0a730c, 0x22de82bc, 0x3f11b395, 0xc67cc50e, 0x14710780, 0x38ebf9b8, 0x7c987247,
+	0x61f2f608, 0x4591c0dd, 0x3ee0cbbe, 0xfdc8c8a3, 0xb7605619, 0x4bbfaf66, 0x12894f8b, 0x5b807cc3,
+	0x30a8aa2e, 0x2cfc850c, 0xbafd3e99, 0xee69f903, 0x2b09e749, 0x25e50e91, 0x7ec50fac, 0xdaa54c60,
+	0xc29b3d46, 0xd83f572e, 0xfa200d06, 0x68a3f202, 0x2e9d8971, 0xb467a6df, 0x65507f73, 0x6f057d25,
+	0x2f8a0cf9, 0xe2b12ebd, 0x1a850b7d, 0x3354e6e9, 0x81eca9e1, 0x3da8669c, 0xfc976c83, 0x16b34c03,
+	0x6de63656, 0xe36f91b8, 0xada539f4, 0x6cc863ac, 0x81643bad, 0xc283b888, 0xfcdf271e, 0x3c5ecb35,
+	0xb3fa671f, 0x63e3082c, 0xa9b646c1, 0x58b9aee2, 0xb4616b55, 0x6239c211, 0x8b09b6ad, 0x93cf0772,
+	0x07832df7, 0x7f85bf4c, 0x533bc5f1, 0x1f108d5e, 0xd81ff4b5, 0xbedfb7b3, 0x599c0255, 0x5d0a8a30,
+	0xecf838ae, 0x975cb235, 0xb36d9af4, 0x6440b766, 0x37f8de8a, 0x424bc58d, 0x4687ac8e, 0x4fff749d,
+	0xc20e825c, 0x369000ce, 0xc69c020e, 0xad914d57, 0x5ab0febe, 0x33e61a05, 0xe4190e20, 0x8a651e2f,
+	0x5ad0a3ef, 0x01306d49, 0x5b1d5f76, 0x7d0f4819, 0xebcb4fb2, 0xed16f272, 0x61f3011d, 0x48919317,
+	0xfb168f2b, 0xfe8dfef3, 0x352cdb63, 0xe9d05056, 0x701e8c00, 0x6a605b5d, 0x3f3ea2cd, 0x9a194e70,
+	0x1838ebbf, 0x41a29d2f, 0x3ae31740, 0xee54948c, 0x7cce0c00, 0x98d2dd8c, 0x505c402d, 0xc0ada09e,
+	0x72518836, 0x4b8fb215, 0x89d99ed7, 0xf3694826, 0x8263fac6, 0xb4a2c2ee, 0x217a30cc, 0x61ed7a58,
+	0xf01e80ed, 0x1c27f2e8, 0x81a0900b, 0xb614fdcc, 0x688b8898, 0x5172f034, 0x154fa9e5, 0x7cf4923e,
+	0x8ca35e6a, 0x4dc4134d, 0x77ea0db7, 0x18b5b782, 0xa711cd86, 0x8eb6c93d, 0x39f8ec8f, 0x385570ec,
+	0xe29799af, 0x288d13b1, 0xf07207d2, 0x399adb8c, 0xeee7e7b7, 0x1dd2cbfd, 0x7e5a762f, 0x65588d38,
+	0x24e02237, 0xcf196c16, 0x7467ac6a, 0x55444e66, 0x52410f51, 0x5ab30e9c, 0x070a9491, 0xcae6640f,
+	0xfcfa1cab, 0x1bd4d10a, 0x32f73479, 0x8f58eddb, 0x43d27e63, 0x56332054, 0xd2240d29, 0x095e4313,
+	0x43108b91, 0x9e890a62, 0x74e8ae72, 0x5ab094e1, 0x6203659e, 0xa0178c62, 0xc4f27181, 0x466f4719,
+	0xe799a97e, 0x80133485, 0x743733eb, 0xbf54e364, 0x42de00ec, 0xd6d63a95, 0xbc639417, 0xc09fa507,
+	0x6929a78f, 0xcbbd396f, 0xbfd0105a, 0x60b4680a, 0x9c918a8a, 0x344a2d53, 0xb41664b3, 0x975a0ca2,
+	0xac6ac13e, 0x55706782, 0xcf0dac61, 0xb98662fc, 0x81a80c0c, 0x53add18c, 0xca51b533, 0x55eba48f,
+	0x563f25c4, 0xaac4fb36, 0xee7599a6, 0x00c19f67, 0x7fd0fbcc, 0x0f2836e4, 0x6e7042b3, 0x7cea8ef9,
+];
@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0xcacdfee6, 0xceeafd91, 0xdd2c4295, 0xb323923e, 0xa4213c0a, 0x0836ef18, 0xb65dd078, 0x8a9e6b73,
+	0x2a9cd424, 0xd3f01acd, 0x1126caeb, 0x682028f4, 0x294fc75c, 0x39a93b7d, 0x75c1b614, 0x550683a8,
+	0xf3afe562, 0xd2a72e13, 0xb567c124, 0x2d4a458b, 0xc4642eff, 0x0e8cd7ba, 0x2d4873c8, 0xab99c477,
+	0xd18fea7f, 0x3b391274, 0x47b91d68, 0x2c6a2953, 0x8ec1277e, 0x1ec81372, 0xb6517d0f, 0x27cc737d,
+	0x3febe311, 0x7d68235e, 0x89439fdc, 0xb9dd9c03, 0x82475d92, 0xb33fe54f, 0x2c41499c, 0x4fe7f1a6,
+	0x20792743, 0xca24650e, 0x383251f6, 0x33324a17, 0x96972cb0, 0xb42eb167, 0x19ebecda, 0x64fb286c,
+	0x762d041d, 0xb7c358f9, 0x6030e10c, 0x13254bc4, 0xb49819be, 0xaeb1a832, 0x72ba176f, 0xf29c5364,
+	0x5d8b6dab, 0x8467c7f7, 0xcec76752, 0x22ce6f6b, 0x6736afe4, 0x91b19a62, 0x82bb9434, 0x2187f8ab,
+	0xf785ce49, 0xf745ccb2, 0xb15ff1f1, 0x42edcab4, 0x6377f0ff, 0xaa3e7910, 0xad544418, 0xb50aaefe,
+	0xc238e05d, 0xb636446c, 0xe109826a, 0x8408686a, 0x23176214, 0x848019df, 0x39199710, 0x708b8e22,
+	0xe6c9b42b, 0x2c29f04d, 0x6011bca9, 0x1b920f4c, 0xf6a635a4, 0x10bc2abf, 0xcf903bba, 0xf31ea7f7,
+	0xb00037c9, 0xa09f16cd, 0x9b0f2144, 0x65bba5e8, 0x84c0e109, 0x386f27b5, 0xf22fe37c, 0xf84984d4,
+	0xa2fba624, 0xbed2118c, 0x062b37e7, 0xdc2fd3f8, 0xb5ce175e, 0x84542686, 0x2f6f198a, 0xc546f8b7,
+	0x22244cde, 0x2941d1cf, 0x1a72ca12, 0xaea44051, 0x66557e0c, 0xdce3a09b, 0x8bf316ce, 0xd46fb0cf,
+	0x32ad24d7, 0x29c04700, 0x037448d8, 0x4595b8fd, 0xaa590989, 0x8cba386f, 0x1ae73fbe, 0x903a8c22,
+	0xf5615aa8, 0x17c6537c, 0x8d4ea641, 0x4f7aba56, 0x923fa396, 0xc5e12a13, 0x175eb228, 0xb880535e,
+	0x2f55069e, 0xd71034dc, 0xdb218449, 0x54ea875b, 0xd6264bbc, 0xd85f8ed4, 0xd3e5ce20, 0x6d2badf4,
+	0x45b1c069, 0xbcd8a34b, 0x4886d928, 0x35648278, 0x63b75f31, 0x989a92eb, 0x57102327, 0x348f1364,
+	0x1909e631, 0xd9889f50, 0x416bc18f, 0x76b77d1a, 0xe49075de, 0xd3967b1d, 0x123c95d1, 0x3ddc94d8,
+	0x0535023d, 0x44a59093, 0xa8249c37, 0xf4b2c848, 0xa6527867, 0x2a7a1411, 0xe330335b, 0xbf055208,
+	0xae5b7f5b, 0x3845ff3e, 0x68348a7e, 0x42e7a8f6, 0xdced8a09, 0x82d4d3ff, 0x9253c0f2, 0x8a6e22d0,
+	0x626ebeb9, 0x4b04da84, 0x9c7f8847, 0xc4a97ab8, 0x3a4a268f, 0xa4ee076f, 0xc275ec36, 0xd7b8f81a,
+	0x50cac900, 0xc4602668, 0xc7535f2e, 0x9d74b89c, 0x977128b2, 0x214a2c3e, 0xb5a431bb, 0x568f4ba4,
+	0x5c870651, 0x5875b6e4, 0xfefb9588, 0x5d66b439, 0x22cdae2e, 0x1af033d8, 0x106183b4, 0xedeead07,
+	0xcb8c1e06, 0x77b6cfc7, 0x82fbf5cd, 0x6bfbd198, 0xd36d9279, 0xef92fe2b, 0xdcb746fb, 0xdbaa7975,
+	0xe4a238db, 0xca991591, 0xdb90e18f, 0xa0eb1d44, 0xc1948a38, 0xf8e9242c, 0x1f523237, 0xb2a0fab4,
+	0x65950d80, 0xd18a160c, 0x4ea76b2a, 0x3363663d, 0x70c67e32, 0x71c67fc5, 0x6f74a773, 0x832891f3,
+	0x9b21fbf6, 0x9e19b1ea, 0xac0cfc01, 0xc8647fe5, 0xbf12767e, 0x0cd947da, 0xeab80610, 0xe1c9f99f,
+	0x25094f85, 0xf0dfd78b, 0xecb0730a, 0xfa27abc2, 0x786dd8a0, 0x61fb3e31, 0x31820e9a, 0x35bc1205,
+	0x30be372f, 0xfac46df3, 0x943fda6f, 0x957cb5e3, 0x39baa088, 0x4efe238a, 0xc39bf2a3, 0x6e39e402,
+	0x7739f22d, 0xe894a379, 0x12295f05, 0x92e54360, 0x751f4e35, 0xeae69190, 0xfd3600a1, 0x5fd56df9,
+	0x2ab24e4b, 0x5cfb6080, 0x4fbffedf, 0xef672de8, 0x660cb7cc, 0xc2631658, 0x97cbb392, 0x92d6e4a6,
+	0xd8701ee6, 0x9b07239d, 0x8aea8859, 0xb3f184a9, 0xb2f18316, 0x9ebe984e, 0xa9ef3654, 0xf0743965,
+	0xe0df0560, 0x066e9c1b, 0xf1d8645e, 0x27a2df1c, 0x0dee56c1, 0x83172271, 0x4622d7dd, 0x48b46887,
+	0xe12037ab, 0x55d722eb, 0x047458e1, 0xdff2dde4, 0x8827595f, 0x85f3a527, 0xde013252, 0x7013d630,
+	0x9827728f, 0x9b2538b1, 0x962530d2, 0xab30a69a, 0x9ac2a459, 0x0a261784, 0x06745c07, 0x75f68d18,
+	0x4288e94c, 0xcf2f4271, 0x6df0dd6e, 0x5e227625, 0xc8aed220, 0x8f1f0066, 0x9731fe58, 0xb9e59880,
+	0xd205ca73, 0x019b5efd, 0xb8f1e89e, 0x864c86f7, 0xdf9db44d, 0x3f55c869, 0x5cb49d8a, 0x3dadb0f8,
+	0xea595ca6, 0x729d01bd, 0x4db008bf, 0x29f4e852, 0xc0ca2371, 0x4ba70b3d, 0xa24bf9ab, 0x906ad500,
+	0xb3711c47, 0xa20c85a8, 0xa4430275, 0x497ae878, 0x98484f56, 0xf161ff1c, 0xd18a3a92, 0xe5a0205d,
+	0x31a89c14, 0x8f735a56, 0x46ff9c40, 0x04fa428d, 0x196d992c, 0x80bf3dc2, 0x5dc80105, 0x8ae7d181,
+	0x162cfc0a, 0x28217089, 0xcb566bab, 0x0dc48142, 0xcf861b28, 0xbd24906f, 0x4324fd83, 0x4a9ccc31,
+	0x48250562, 0x4a250888, 0x506dff3e, 0x559d6552, 0x4c8134ba, 0x5c7ea86f, 0x8f929c1e, 0x618b7c41,
+	0x436f768a, 0x517584c5, 0x53118efb, 0x15a40f5d, 0x4815fad8, 0x27cb3b23, 0x0cc8d20b, 0x1ac6a97e,
+	0x10c21c92, 0x6d072a8d, 0x85221f80, 0xbfc1474d, 0xa2897150, 0x0ab55e51, 0x168e61c5, 0x794ddd9c,
+	0xf9316cf4, 0x8e2c7aa1, 0xd44d0932, 0xd24d060c, 0x8efe6e31, 0x3d7e6258, 0xf5f8d227, 0x3884038a,
+	0xfd42bc61, 0x7eb3c233, 0xda053bc2, 0x4d5b0474, 0xaa62e8bc, 0xb733df61, 0xab7f2c4f, 0x70daee4f,
+	0x70cfc0c3, 0xaae0ccf9, 0xa48cad06, 0x529ee39e, 0x310a6308, 0xef3e2929, 0xf03e2abc, 0x9db2c854,
+	0xe562ea44, 0x6d9b9752, 0xdd5e607e, 0xd45e5253, 0x15e39af1, 0xe85c3338, 0x7c3c09a2, 0x2bab0c25,
+	0xa59115d1, 0xcf15988d, 0xd1f81558, 0x3799e184, 0xbacd0480, 0x3cbf75ec, 0x3d749c61, 0xf0cff1fa,
+	0x3c8166c1, 0x9d4088da, 0x1b94ba86, 0x1a4d55d8, 0xfbecbcb6, 0x1e565680, 0x2f633d36, 0xf5d7dd55,
+	0xe95ad084, 0xd7cd20bf, 0x93497378, 0x3f93a590, 0xa20702f5, 0x99341468, 0x3a0c801d, 0x96631017,
+	0x29f5189b, 0x4e4fa58b, 0x3eeaac4e, 0xe7a5856a, 0xc4758536, 0xe596ee62, 0x74edcd3f, 0xc398c3c8,
+	0xc4a2a4d4, 0xf944e95b, 0x4594fdc9, 0x6b91d120, 0xc4eda0d1, 0xfa56340f, 0xdae922ed, 0x3ce2519d,
+	0xd965bbda, 0x1927afd9, 0x99380614, 0x8837f2c0, 0x2057306e, 0x6fa9dc52, 0x9ec703f1, 0xe74f26b6,
+	0x59e94c40, 0xddafc043, 0xf815289c, 0x219901ae, 0x87ac48cc, 0x0179def5, 0x0ba81191, 0xfb6f4827,
+	0xe44f21fd, 0xaddf1e8f, 0x71d02fa0, 0xd493795a, 0x77801309, 0x16ff797e, 0xee171321, 0xd24a1a99,
+	0xb4330f66, 0xd6819a41, 0x9be98f95, 0x761a8c53, 0x6a9f8552, 0xa0e2dbaf, 0x98d3be69, 0x4ed1f1d8,
+	0xc2b4e50e, 0x93c5e9be, 0x49ffd248, 0xd91cf50a, 0x3f14f4e4, 0xa0f66367, 0x0dded643, 0x4b9faad3,
+	0x28a1b7b5, 0xfe759eea, 0x9ade0cea, 0x66b5c436, 0x511659e8, 0x2cb41ebc, 0xfb31c5cf, 0x8b030cb8,
+	0xc6db41e3, 0xc45b6745, 0x60345894, 0x00879092, 0xa942e91a, 0xd07076f3, 0xfb7a4226, 0xd8b80606,
+	0x603dd8e3, 0xb16f10a7, 0x1f57838c, 0x5b37c0d6, 0x90a7a6ef, 0x483325bf, 0xc1406a8d, 0xaa4183fb,
+	0x860f3b21, 0xa1a8f786, 0x57b20f7d, 0x774a915a, 0x5fe7049c, 0x465e03a0, 0xe9464899, 0x52780bce,
+	0x9e7e7a65, 0x4860455d, 0x11bd3353, 0x20ba6cb9, 0x4569f715, 0x2eb31462, 0x818ac791, 0x61526270,
+	0x7f668e05, 0x39e8b4d1, 0xa1f02512, 0x96234bd4, 0xe668bd09, 0x19ed5bcc, 0x0f93019f, 0xe79397f1,
+	0x32af800a, 0xfc948035, 0x543a7fd3, 0x21f5b729, 0x12c402c0, 0xa0eb0f08, 0x16b73d19, 0xb576858c,
+	0xf810361e, 0xa2923e20, 0xc6f19d71, 0xa88bf413, 0x82c1b59c, 0x68e41ef0, 0x2e87eea4, 0x1a78c49e,
+	0x15743f91, 0xbda12fda, 0x1faee677, 0xa9a58d8c, 0xc26f9aed, 0xdc25c75e, 0xbb323378, 0xcc54e07d,
+	0xd54e32e3, 0x7bb48a88, 0xca0b3ba5, 0xf76fb331, 0xcd167951, 0x6eefea9b, 0x4f5296ae, 0x4dc7bdab,
+	0x4eb1f8ef, 0x3530569c, 0x1c55dce7, 0x25f414d7, 0x9d41a8b4, 0x0053723c, 0x05537a1b, 0xd49dd484,
+	0xb709bd23, 0xb945e5f7, 0x56f6d83c, 0x5be7d591, 0x05d41cd8, 0xd29c2eaf, 0x597d11c6, 0xd4fa8470,
+	0x9b80f214, 0xfed68570, 0x883a4df7, 0xddc6b3be, 0xccd220ed, 0x4f4d3bf7, 0xe5f12404, 0x1608c7b7,
+	0x16d89054, 0xbdbf5bf0, 0xf4ed5ed9, 0x1636deaf, 0x6ddf99b2, 0x3197f49c, 0x4fb8b382, 0xfc3078cf,
+	0x796ca308, 0x18a7beee, 0xaf8bb8ce, 0xb4356e06, 0x6a8e75aa, 0x6bbdb093, 0x265623ab, 0x69ea6dab,
+	0x29cc5095, 0x2c526191, 0x8ae6ed2e, 0xee05d83b, 0xdc07f8a9, 0x10ab982c, 0x9475cdc6, 0x8003a189,
+	0x408cfca8, 0xb8067665, 0x5a7abf77, 0xddfde10d, 0xb339b34c, 0xcac3d793, 0xfa10a755, 0xf107bbd2,
+	0x2f7508ef, 0x13886493, 0x7f979115, 0x3c41ddd5, 0x019fb038, 0x8c2f16fe, 0x21918751, 0xbb38afad,
+	0x9e212406, 0x8178276c, 0x6055eefd, 0xb4b8e275, 0xa3242ee2, 0x6e7ccdd5, 0xc21f2024, 0x08e748c7,
+	0x9b34cda3, 0xc97787d0, 0x946895a4, 0x8bf23ea1, 0x4fb4abea, 0x64e3eb60, 0xfe405283, 0x665b72a9,
+	0x6c370998, 0xa01e3d98, 0xf56719fb, 0x26a7b745, 0x687ebb96, 0x1c7bfef8, 0x1b301d1c, 0xcded1a85,
+	0xd75a2d47, 0xf7faaee1, 0x96da6b58, 0x80459822, 0x1271ab5c, 0xee05b9a5, 0x11c112ef, 0xaa7d7949,
+	0xa8901de9, 0x8ba8ed1a, 0x20f00cec, 0x67e4a83c, 0x116b77dc, 0x1f16eebf, 0xf16d8413, 0xca791c49,
+	0xc242f5b7, 0xf85a0760, 0x0b069958, 0x255c8cce, 0x521d0429, 0xd27adb64, 0xa8f7fa72, 0xec039cce,
+	0xaea63c35, 0xfd902939, 0xc266953f, 0xbef81d14, 0x3eb7f679, 0xb0fee263, 0x82591a5e, 0xaf07e37f,
+	0x2ca135cd, 0xe4ba733d, 0x5b8cc813, 0x2f73999a, 0xc002ac03, 0xab356625, 0xb33572bd, 0x928ef299,
+	0xbc814616, 0xe29c4bb5, 0xad7ebfe3, 0x809e3f91, 0xb20295f9, 0x1021e0c1, 0xf41adfe3, 0x67826267,
+	0x61a6d509, 0xaaea5743, 0xb2ea63db, 0xc7e16877, 0x159ac2b7, 0xbdf0855a, 0xbef086ed, 0x9ea5de9c,
+	0x59167492, 0x8eab16d9, 0x96ab2371, 0x4881d841, 0x37795683, 0xafad8963, 0xaee47c2c, 0x950a9777,
+	0xb2e48278, 0xbdeaa8d7, 0x86584d00, 0xaf85ad29, 0xceeac39a, 0xe00c5c89, 0xdfe05101, 0xe94aebc3,
+	0xbad71c66, 0xa6c45d85, 0xb7ca8876, 0xb8e70c1d, 0xd1df8006, 0xa2bdcb73, 0xb2f3fe9d, 0xbff162e2,
+	0x4038739c, 0x14204413, 0xa1f3723f, 0x414bfce7, 0x2b52960a, 0x5b6c4f70, 0x3d5073c9, 0x2d505a99,
+	0x79a98884, 0x4f292a60, 0x282c316c, 0x3e58c421, 0xe6a5f23e, 0x95858f56, 0xb45fa81a, 0x53972c15,
+	0x2dc3b666, 0x64ff7b9f, 0xb6f3934e, 0x377be7f7, 0xd20a71a6, 0xa57e17d3, 0xad28c52f, 0xcba09f8d,
+	0xb0535b3e, 0x9bcd89e7, 0xe179dbd8, 0xe75e0ecd, 0xed68a59b, 0x286e8983, 0xd76a9191, 0x0568738d,
+	0xa8db785e, 0x42abb7cb, 0x42bc02a3, 0xf09c2de1, 0x55563696, 0xcda9645d, 0x1b5426fe, 0x7efbbb11,
+	0x7acd603d, 0x4249d707, 0x481721cf, 0x94636d6c, 0x32baf22c, 0x775718f4, 0xdb6f49cf, 0x650bb45d,
+	0x49172362, 0x216c3556, 0x1660eb12, 0xdd85598a, 0x3ed99f70, 0x22839038, 0x24b9216b, 0x0e8d5c60,
+	0x766c79c0, 0x948625b8, 0xad6e844e, 0x655cb894, 0x784003d0, 0xf907064e, 0x8a7324ef, 0x33b3dba0,
+	0x8534ce9d, 0xec22362f, 0xa25535a3, 0x994c2d1c, 0x695ab86b, 0xcd9a6551, 0xe40764b8, 0xf203a92b,
+	0x7138bdf2, 0xfa7b46cc, 0x03374982, 0xe752f3eb, 0xf75fd90e, 0xf368cd1e, 0x6a3827bc, 0x7829333c,
+	0x0e6f5bde, 0x2fca8828, 0xec66d793, 0xfa6031d8, 0xc2a805b8, 0xdbe24ce5, 0x8d5b39a1, 0xc0d5c618,
+	0x75d0cc36, 0x149af1ee, 0xa19a8c3f, 0x9c487a6b, 0x9560744c, 0xb155676d, 0xac77f1d0, 0xb26fac7d,
+	0xd209ff45, 0xfb1355ee, 0x011decbc, 0xaf871a91, 0x56abb748, 0xbf8733c1, 0x67c04030, 0x5fb91e8c,
+	0xbc82b1da, 0x2f3aee91, 0x2bd1616e, 0xc35ce3d2, 0xa1c9924b, 0xa0dd1870, 0xaf274f0f, 0x3e352725,
+	0x3b51a480, 0xffc8ed4d, 0xd4b676b9, 0xd6bec8a4, 0x74ed5ac7, 0xfd67f4cd, 0xc4ba2f20, 0x99863551,
+	0x502955cb, 0xe4c35bdc, 0xcd6004e3, 0xc427a112, 0xf2bf17c2, 0xf5ce03fb, 0xd3d00d0c, 0x91132d6d,
+	0x32694bc3, 0x6b98ed8f, 0x0eaa25e3, 0x119f9d40, 0x73d9a69c, 0xd5bdbb42, 0x4f9f2cab, 0x8d7a7c20,
+	0x3871a3fa, 0x08b4ad08, 0x1b3b5c59, 0xf618f139, 0x3dddb9fd, 0x37cc676a, 0x4bc809b8, 0xfed2b25e,
+	0x5caa0f67, 0x4a7181df, 0xe3b7f297, 0x71b89398, 0xadc649b8, 0xaec64b4b, 0x2b3c411b, 0x79247b1e,
+	0xd2c8c28e, 0x316a8164, 0x9fccef73, 0xaef7e817, 0xa7fc5a40, 0x3b00b169, 0x9b8951a6, 0x4f9cd7b3,
+	0xaffea56f, 0x7d8c66dd, 0xb6032c0f, 0x70ceab2d, 0xe48d64ec, 0x678ea762, 0x714daa7a, 0xc18d6dce,
+	0x657029d8, 0x7ee93563, 0x10f5d87c, 0x629fe8a4, 0x48b9ed0b, 0x362587a9, 0x62bdfdb8, 0x9d2ee900,
+	0x8d32fe63, 0x72e0f880, 0xe64015f0, 0xfaf0de9a, 0xb570d41d, 0xc301cf93, 0x7e3f1843, 0x99e3747c,
+	0x911e3dd9, 0xe0135907, 0xb35135fa, 0x91c5f742, 0x0a7c7149, 0xd120791c, 0x1e71b297, 0x262cf07a,
+	0x47e6744b, 0x4cbb5054, 0x16c0ddfd, 0x75597a67, 0x789bf05e, 0xff37c82d, 0x090aa9ab, 0x30b24226,
+	0xcb2d2452, 0x129abbbf, 0xae478ff1, 0x93c20be0, 0xd8539828, 0x633f267e, 0xca04efe0, 0x2b251de3,
+	0xb7cbeef4, 0xe3299366, 0xb9943dd3, 0xd8de6a27, 0xf97970a7, 0xf9d86f7b, 0x0c3b0c8e, 0x5c224973,
+	0xccce80a1, 0xf749db6d, 0x0b2cbc85, 0x8463e1a0, 0xb799be1a, 0xc3516bd3, 0x2f91a723, 0x2b68c498,
+	0x13860110, 0x1674f1b6, 0x4bafc27b, 0xcf6262ec, 0x543ac352, 0xba4d2440, 0x9f3833e6, 0x9dea06b6,
+	0x100e165b, 0xe111a487, 0xcdedae92, 0x153ddef0, 0x4fc94586, 0xaa2a6164, 0x76fc123b, 0x0662265c,
+	0xddd42fdc, 0xc922bc79, 0xc21e3446, 0xce7ce693, 0xd8d427fd, 0xaae2e5a4, 0xebb76fff, 0xa73f5c0d,
+	0xf0ef0c24, 0x8b944998, 0x8441638b, 0x8f259e5f, 0x9f8658ab, 0xdc7b77f2, 0xee7b9448, 0xc4085cbd,
+	0x6815c86c, 0xc61c131f, 0x7cf7d467, 0xaf4a01c9, 0xd913e243, 0x074c76c2, 0xddf39677, 0x6cf7bb37,
+	0x33e6572f, 0xdbfbe216, 0x33cfde0e, 0xbffdf499, 0x385aba2a, 0x7183f6da, 0x7266707f, 0x464bc5aa,
+	0x626092f4, 0xf69717fd, 0xf8b0b309, 0x0060bba9, 0x6f023d15, 0x62f55e3e, 0x6bf0ef3b, 0xb9ef387b,
+	0x5b9dcc2d, 0x4bd02db4, 0x523ab9a9, 0x6e305861, 0xb695ccbe, 0x7b173de6, 0x7ad5305b, 0x4f60750b,
+	0x59d8cdff, 0x03ba611a, 0x07a640f6, 0x6acc1ccf, 0xb0d588e4, 0x2648cd55, 0x7f697715, 0x5473c0c0,
+	0x43c18e91, 0x124aec70, 0x3f6ae937, 0x38b82c0a, 0x0214fc64, 0x86bc0274, 0xc8c141d0, 0x123764b8,
+	0x83d03615, 0x293788ed, 0x51090802, 0x1c90ef81, 0x0964bc5d, 0x9b99e7dd, 0xfed8b035, 0xd983fccc,
+	0x90f6fbd0, 0x083e3344, 0xe7c742df, 0xf6c75a7c, 0xe29d1e2f, 0x0be7cf36, 0x06e34a29, 0x32dad934,
+	0x0ddb0669, 0xf2dead7f, 0x5e4daa9d, 0x0cfb5881, 0x0c6175c1, 0x07f017af, 0xa0a32c99, 0xe60759e9,
+	0xec07635b, 0xc407e9ee, 0x9c436708, 0xef0d7842, 0xce7f3711, 0xf2115ec2, 0xc2ecdf53, 0xddef486b,
+	0x8d5d6aae, 0xf625882d, 0x3ba2aa47, 0xd52792d1, 0x0dab8e32, 0xf71b972b, 0x2451943b, 0x951e7846,
+	0xd91a88af, 0xcf1ef61f, 0x05dd0988, 0x2bcc78e5, 0xe103566e, 0xf3a30f2d, 0xe4eb258c, 0x003a87bf,
+	0xe490d896, 0xec90e52e, 0xea90e208, 0x473ec6e2, 0xe18c56af, 0x61c8af28, 0xe9a2ff6f, 0x03a168f6,
+	0xe08d16ab, 0x0e74ffd5, 0x09bcf1a2, 0x219101e3, 0xa8dd14a6, 0x645e7d80, 0xee89e0f0, 0xd7713c7b,
+	0x9a1e84a7, 0xb5f82e71, 0x9b12d8e9, 0xe7774569, 0xdf7738d1, 0xca8dbf33, 0x7e202f96, 0xd2e4d060,
+	0xee88998f, 0x15c2f8ec, 0x89d95a28, 0x5bf2f681, 0xe565f922, 0x443d6780, 0x9ede2954, 0x10bf34df,
+	0x88de06b2, 0x7c38b8cf, 0xe7ae45ac, 0xfc9adf03, 0xfb7232d9, 0x86eacf7f, 0x2ae5e1c3, 0x84e9f1ae,
+	0x304a4b35, 0x99e4dd3a, 0xa580b407, 0xb9764627, 0x9c719b52, 0xa9ab9b5a, 0xa100bebc, 0x24f208e4,
+	0xd756aedc, 0xab45f730, 0xc982a718, 0x05ba6b01, 0x888e7aa2, 0x52719e77, 0x7c648dae, 0x7f89ef49,
+	0x938122f7, 0xff3062ae, 0x38e70f69, 0x14a99ec4, 0x2fc941c2, 0xe59a2b3a, 0x689219d9, 0x648512e3,
+	0xd78f5b61, 0xe53403f8, 0x8c08ee83, 0x69fb8c77, 0xb08b665a, 0xbca35d83, 0xe69e7d88, 0x0c5bcd9d,
+	0x763e0219, 0x6a164f08, 0xd8042091, 0xd67a0605, 0xf184ae40, 0x4a40bcc0, 0xe85541e0, 0x7c3f812f,
+	0x06f59862, 0x23e3d71a, 0xbd73a627, 0xcaf08472, 0x49cbe0a2, 0x592318c4, 0x184b0014, 0xaef39ccf,
+	0xd12fe57c, 0x255dc664, 0xa6427dec, 0xff942445, 0x17c0fbd2, 0xb4816015, 0xced2c937, 0x9f9c4fd4,
+	0xc508c328, 0xf3347889, 0x256fc1bd, 0x31b98836, 0x9a25e1b7, 0x409601b6, 0x8d39bde6, 0x4b329dd2,
+	0xe6a879fa, 0xe16686ca, 0x3957bd3a, 0xa6563e8a, 0x54f3570f, 0x4bf95912, 0x72ea8bed, 0xfe93863a,
+	0x2f017e61, 0x992a04a8, 0x7db2b0ac, 0xd0357343, 0x5ed0d48b, 0x25999158, 0x521886c5, 0x8c4b1ad8,
+	0x0ac95089, 0x4bc7bc46, 0x5cb68de8, 0x1231a1fc, 0xe6de9f61, 0xad30f06b, 0x904416d1, 0xc1e3c1bc,
+	0xe5c782e6, 0xe68b9c52, 0xdaef9304, 0x918d404a, 0xcc1f5fce, 0x919a0c3d, 0x527dae8a, 0x9860a5b1,
+	0xaec4e56d, 0x28b19af7, 0x722e8b21, 0x1bd670a0, 0xf79f39aa, 0x257281e8, 0xa84bce72, 0xb4e9bd0e,
+	0xb8c60cba, 0xfb91e29b, 0xe7482c70, 0x86eeeedf, 0x5318bd00, 0xe753de7e, 0xc1bd21cb, 0xd4705991,
+	0xbc493c67, 0xd2a18353, 0xab3a8a0a, 0xc1d9c9dd, 0xaa8eba31, 0x9a6320f7, 0xc338713b, 0xba9eeb49,
+	0xbd2b9bd6, 0x835cd3cc, 0x85509458, 0xd85cc1bc, 0x9188b0c4, 0xba2719ef, 0x721c6d16, 0x8f73308a,
+	0x9d9e89f7, 0xd35ec4c9, 0x8e254614, 0x5e53e41d, 0xbc98fcdf, 0xe7e19b94, 0x45b473eb, 0xeb3c9f4f,
+	0x121a8fbc, 0x732c1097, 0x44b175d3, 0x9b326632, 0xa69cd1f4, 0xa92b8b72, 0x64f7a98b, 0x218148db,
+	0xc87d8df5, 0x3e4ecd77, 0xa20bc574, 0x79a94f04, 0xc38eecc4, 0x47ed7a55, 0x25f63f2b, 0x31f6520f,
+	0xa515afbf, 0xf5674cd4, 0xa92e48b1, 0x24704a15, 0xcd2facb0, 0x82536ead, 0xab164f15, 0x815dfa76,
+	0x7c445ab1, 0xddf69b61, 0x894cbded, 0xebfc3e7b, 0xccfe4c45, 0xf5f3ff74, 0x639e6a57, 0x2cacaa39,
+	0x43756428, 0xeacdfcfd, 0x63780eb9, 0x6260b525, 0x5e3dad97, 0x7b7a7318, 0xf9100aa9, 0x84874d36,
+	0x80ee595e, 0xbcc33d1b, 0x873d0129, 0x7084d38d, 0x7d82c503, 0xb3a22171, 0xadd52add, 0x8edd7090,
+	0xd01fc26d, 0x734779cb, 0x9774a84d, 0x6f4b93f0, 0xae1c3f96, 0x78627a04, 0x963335ee, 0xce1ad479,
+	0x7459deae, 0xe8d82516, 0xd397b280, 0x694b8a7e, 0x773611af, 0x812716e3, 0x8aa9b648, 0x86193260,
+	0x45e7afde, 0x9fa6ec7b, 0x312fd389, 0xf4c5f285, 0x6840fb8f, 0x76fb2937, 0x1f627be2, 0x10066ae1,
+	0x225e036d, 0xe912c95d, 0xf81d19f9, 0x12019e56, 0xbd324ab1, 0x97a76a65, 0xc8d632fc, 0x849133f3,
+	0xae28b123, 0xc9011403, 0xc2cbd863, 0x4dd58d63, 0x2f203090, 0x9b679f6a, 0xb0ada6f9, 0x1912941a,
+	0x94c3e656, 0x8dc3db51, 0x18ae6c91, 0x62b04d77, 0x68d67d33, 0xe2c119fb, 0x7bdb184a, 0x80db2029,
+	0x91dd7983, 0x5fa1811e, 0x8c87e588, 0x934f4e0a, 0x13fe35aa, 0x89e7fa47, 0x25d4a652, 0xba0abb42,
+	0x28d1701e, 0xc406d740, 0xf54f2346, 0x9abd3270, 0xc3ac6729, 0x1c4d962a, 0x0a3bda77, 0x3cc870dc,
+	0x1e801cec, 0xa9fabeed, 0x88f7e185, 0x0b44452a, 0x0b4f7e1d, 0x5a2e5fd9, 0x16378a88, 0x94e4e309,
+	0xee2cd616, 0x62cb0d0c, 0x1755a12f, 0xbdeb87dc, 0x9ce94d7a, 0xf6485bef, 0xb5a63c47, 0x3d8466cb,
+	0xf4bd82be, 0xa2e72bfb, 0x8cdc0989, 0xd6b302c8, 0x3f386cd0, 0x48c0ed8c, 0x8ec274c9, 0xc61f9aa6,
+	0xcc909380, 0xd10f8262, 0x812d107e, 0x8d2d2362, 0x550bfe9e, 0xd9b9523f, 0x8cbf70a3, 0x33117c1f,
+	0x49fa4705, 0x95d58f62, 0x51ced310, 0xfb120b01, 0x50d3cfc0, 0x876fffdd, 0x697299ee, 0xc2d8738d,
+	0xe8a937e7, 0x9ad55026, 0x83641f82, 0x59c2cae7, 0x06d5a845, 0xf739c3bc, 0x47878736, 0x518796f4,
+	0xb2887bd7, 0xcb619ce5, 0xae9ab57a, 0x8ebbc298, 0x8cbbbf72, 0xa353951b, 0xae401eb4, 0x8ba3b8f9,
+	0x89a3b5d3, 0x5ef9ed23, 0x39d6ecf7, 0x8f9931e9, 0x07e6aea5, 0x144f0c62, 0xfadc0cd2, 0x90731809,
+	0x92927add, 0xae839c67, 0x88ba4b47, 0x9b49ae1d, 0x43ff12f1, 0xf50f2bef, 0x660ec0f4, 0xd6dfb05d,
+	0xf8a35e19, 0xbfe4559f, 0x7e809e90, 0x5a81bdb0, 0xfdd7c5a2, 0xec7e97cf, 0x593058cc, 0xdde3f6ee,
+	0x77548ee7, 0x92c778aa, 0x1de9a356, 0x55b76015, 0x031afe7d, 0x181095dc, 0xb6a81717, 0x8534cac6,
+	0x17b48355, 0x1036ae7e, 0xc8a0e700, 0xe5fa0b77, 0xf992d25d, 0xc84b99e2, 0x6404eeb8, 0xdb9215fd,
+	0xeef97a0d, 0xf4667bee, 0xed0026aa, 0x6b91d258, 0x29520bb4, 0xd99ba82a, 0xa13884c3, 0x19bb34eb,
+	0xf035b504, 0xdfeba98c, 0x039dc0f0, 0xbe6b5297, 0x29df7ff5, 0x595dc1de, 0x0a377624, 0x1ce900f6,
+	0x499ee510, 0x85ee37bf, 0xab74ce37, 0x758f29d0, 0x225772e1, 0x73985cda, 0x6e18a6d1, 0x47129f62,
+	0x42b4cb29, 0x0552c2b0, 0x1252d727, 0x0541798f, 0x3c177b70, 0x0bb9a971, 0x78e32de5, 0x62e0ccac,
+	0xf75afb6b, 0xf65d386f, 0x651e0f17, 0xf32b96ea, 0x234b4712, 0x306b41cb, 0x4660d711, 0x3d1fcc9b,
+	0x25f3c29a, 0xce1430df, 0x8c6f368c, 0xe67c0055, 0x73975f92, 0x2efd244f, 0x025069e2, 0x37fd327a,
+	0x10421806, 0xa5f4fd0a, 0x44f60c2e, 0x4f0be23b, 0x321abf1e, 0x90ad4210, 0x32ffac7b, 0x0c1ad24b,
+	0x23cd43c5, 0xfc2e40d3, 0x2acd4eca, 0x0fee3171, 0x45e097bb, 0xe61cac07, 0x9487e43e, 0x6e75eef8,
+	0xad27ee58, 0xc40341b4, 0x73a6862a, 0x39b3175f, 0x6983e42a, 0xc20e7781, 0x772d0451, 0xcd0c4a3b,
+	0x96e382a7, 0xbe148163, 0xa4913bea, 0x465bcd46, 0x8d318000, 0xe99e5a8c, 0xccff7e48, 0xc91c7e60,
+	0x82971c71, 0xece838e8, 0x7e5f692b, 0xcab7a341, 0x2063cb13, 0x567d0982, 0x9f519cc9, 0x02c65ebd,
+	0x09f0bcf6, 0x8b188ce8, 0xc322ad05, 0x48fb22b2, 0x55c54c11, 0x203e6faa, 0x4edd1710, 0xb926f28c,
+	0x803328a9, 0xcc628958, 0x70507c68, 0xabf8d4dd, 0x667c6911, 0xfcdd0ccc, 0xc92cc9bb, 0x5045bcac,
+	0x5745c7b1, 0xf3c342e6, 0x11c2662d, 0x5e5b9905, 0xcf5bceb0, 0xd6e12155, 0xc9bf9e99, 0x59e02574,
+	0xdcc341b0, 0x56dfde64, 0xc35f4cf7, 0xd94c387a, 0x627c3236, 0x3583acfe, 0x3a43c561, 0xaa9860ff,
+	0x4c778643, 0xa9c9fc38, 0x985fcc5f, 0x11e1fc01, 0x5c1a961c, 0x337519b0, 0x54019347, 0x3314e70c,
+	0xac56f17f, 0xa1a7a172, 0xaaf77331, 0x9b47cc32, 0xb4159670, 0xa847e0a9, 0xba226bd5, 0xb20619fd,
+	0xb92b649e, 0xbcbcc31a, 0x85ff540c, 0x790c294e, 0xd52dda65, 0x7acc5059, 0x31b23887, 0x17ba5e5e,
+	0xf2aa916c, 0xf0ca27be, 0x0cbc8ba4, 0x63f23804, 0x11cddca4, 0xdc1064b8, 0xb554f920, 0x6c185226,
+	0x1666807f, 0xb536a99d, 0x15d2601e, 0xe19f02ce, 0xfe691321, 0x8c4b011a, 0xca3007ab, 0x23a0d95c,
+	0x3a0ffee1, 0x5488dd89, 0xbd2b7606, 0xca15d036, 0x336b65b8, 0xbe2f5538, 0x933dbeea, 0x6aa44889,
+	0x73a456b4, 0x89eabb08, 0x66c2758c, 0x6789b051, 0x5791e5e6, 0xd98ca4c6, 0x738f3bfc, 0xd6f3fe1f,
+	0x70954771, 0x6d9542b8, 0x65c91eb6, 0xa2c4f48c, 0xae97e7d2, 0x5505928f, 0x60063106, 0x0174f6af,
+	0xae0af9ed, 0xaff5e25b, 0x9b74319d, 0xec13b75f, 0x848317f2, 0xee568736, 0x04a9735e, 0xb2ecd6d2,
+	0x0d523ad5, 0xeb4fc6b8, 0xf449c4b5, 0x8f2cbdb6, 0x9ccd1fb5, 0xb1407af4, 0x11165f7e, 0xa2653430,
+	0xb450d31b, 0x115d7a14, 0xe85afaf2, 0x44f1708a, 0x042bc8d1, 0xea24e41e, 0x0e0d9594, 0xf624f702,
+	0x1bcf29d8, 0x0e272d7a, 0x3553e285, 0x312f0b60, 0xf9f0dac3, 0x3a224d98, 0xa2780a89, 0xd940d530,
+	0x7b80c780, 0xa96fc6c9, 0x7d7591b3, 0xafd98518, 0xabeae29d, 0x69185f08, 0xbbd77415, 0x0914d819,
+	0xc62fd1e8, 0x87b82de3, 0x356aaeae, 0x21a385a9, 0x336f28b6, 0x2f54deed, 0x2305f67d, 0x3b96486a,
+	0x494a00ce, 0x63f1336c, 0xd541621d, 0xb4b61246, 0xa6518f67, 0x26dd8f5e, 0xbc9cb896, 0xdae3b260,
+	0x675ca4d1, 0x885a9a2d, 0xffd47faf, 0x77427a84, 0x75493323, 0x652b04df, 0x783132f6, 0xc63b1e20,
+	0x140aaa65, 0x0d01a504, 0xba440598, 0xc7441a0f, 0xcc87a64d, 0xe8e87d47, 0xc8028cd1, 0xcd08a4de,
+	0xc20c8395, 0x0a876116, 0xc81958fa, 0x9b16d38c, 0xb3e79cb2, 0xb7dd15a2, 0x96878724, 0x7665043e,
+	0x25b4af4f, 0xc51f5d7a, 0x04f9b052, 0x00313120, 0xe9500e3d, 0xc0870189, 0xac307ed6, 0x891cfe4f,
+	0xf7eb0225, 0xa3c91014, 0x3a5fbf10, 0x2622055e, 0xc2bdf20a, 0xea98d4ce, 0x71430585, 0x763b7145,
+	0x2e2f8980, 0xfce32734, 0xe04e3f61, 0x494372ec, 0x34db4107, 0xf77e01d4, 0x95947a2e, 0x6fd1c80c,
+	0x1efb9680, 0x1959cefc, 0xaf130df9, 0xcd2a0f9a, 0xc48b59db, 0x5d222127, 0xb2f48e4e, 0xc1cd888a,
+	0x99ad0288, 0x0dbc6c0f, 0x64ed874e, 0x00e7389f, 0x6b8d562d, 0x5584392f, 0xb13260d8, 0x71da140d,
+	0xb445ed49, 0x93e05f71, 0x27535296, 0xf3fb51d1, 0xcfb05ae8, 0x7eba86ac, 0x491e0a9c, 0x4a9c9bdf,
+	0x42116aaf, 0x95f72993, 0x0f39a863, 0x87102c2b, 0x142ce44f, 0x0c285a89, 0xea4ab745, 0x32608848,
+	0xf448886c, 0x8767f88c, 0xcd95d52c, 0x0e5e77a9, 0x1d1a14a2, 0xa2fd7d0c, 0xc617f7a2, 0xbd01f454,
+	0xf9e185a5, 0x1c11f8a2, 0xaa134e60, 0xa2ce1ed7, 0xafd071e5, 0xbde64e3e, 0x165985e3, 0xcb18332c,
+	0xa8f277f2, 0xe522e976, 0x58818a08, 0xb8ebd55d, 0xd822d4ff, 0x78c7c40f, 0xda2bd281, 0x0a7f32f2,
+	0x888603c3, 0x97f29955, 0x7a78762f, 0xf3ac9ae3, 0x931e8091, 0x6517b3e5, 0x614deb51, 0xf270b0b8,
+	0x603a6206, 0x74bfbeea, 0x5d3c9be4, 0x6135e66b, 0xbb0aeb8a, 0x720c3fd5, 0x37e2d6fa, 0x9865b509,
+	0x5a91e6d0, 0x26fc8758, 0x420372be, 0x847c11b7, 0x9410be53, 0xa2825d2f, 0xa6ca5e08, 0x9479ea32,
+	0x35a2e5c3, 0xac3c2a26, 0x5c714e81, 0x25a7329d, 0x396efb6a, 0x51750ec7, 0x0a0a25b5, 0xa710dc3c,
+	0xc294a9c4, 0x4d9aba87, 0xae349521, 0x741c818a, 0x698f1eca, 0x3e9172f2, 0x04d092fd, 0xdf9f8ff9,
+	0xf728828e, 0x54c74462, 0x33886745, 0xb010ea67, 0x7b3d6c3c, 0x51366e59, 0x318202c6, 0x8c056377,
+	0x78556c69, 0xe2857f86, 0x60809302, 0x5e644e05, 0xe66176f9, 0x6980a12d, 0x231af847, 0x6d8b34d5,
+	0x2130bb70, 0xbd77f9d3, 0xd46fcf43, 0xc2738484, 0x75413203, 0x75943bc9, 0x709433ea, 0x3bbde2ff,
+	0x341f7318, 0xf542bdc8, 0x4db211e5, 0xe762fb69, 0x98e54786, 0xe46b4575, 0xd677fb5e, 0xac1db00e,
+	0x6824827f, 0x541247db, 0x4c009bc2, 0x6c410adf, 0x0a62fa57, 0x995fc0cf, 0x914bd27a, 0xb5bda71f,
+	0xf5352bb4, 0x00500da8, 0xb4a7d5f3, 0x9a8704f3, 0xc3cefd00, 0xbe248829, 0x5127f14d, 0x463d56b3,
+	0xe5819366, 0xf590e1e5, 0x5d7eb69e, 0x068e93dc, 0x60e2ae30, 0x50857c09, 0x89193acd, 0xf1d0feaf,
+	0xa4572da0, 0x49f38209, 0x9545304c, 0xde375a53, 0x3dbe3b26, 0xe8b51a07, 0x78ebc7f6, 0x9b759fb9,
+	0x56661f55, 0xbab5214f, 0xc3510118, 0x83eff526, 0x280f9474, 0xdc97cc77, 0xc8d49b4d, 0x30810749,
+	0x0a8c6a47, 0x3a785bf0, 0xa2324d9b, 0x027df322, 0xce53aea8, 0x5791c4f4, 0x6a786eb0, 0x54d4daaf,
+	0xf85c7a8d, 0xafd62829, 0xbfc6b95f, 0xa5d7639a, 0x112a10d2, 0xed317db7, 0x437cea71, 0x309b1b82,
+	0x626144c1, 0x9625faa2, 0xf6a6ffea, 0x2c9656e5, 0x4bb79476, 0x31ae053f, 0x45496baf, 0x71cd882f,
+	0xfb16326b, 0x97b0c453, 0x69c89b94, 0xb39aa8fc, 0xd23518a0, 0xe4ce2924, 0x4671ae97, 0xed354321,
+	0x78329d3b, 0xaf537014, 0xab1c6ce8, 0xffc0b30d, 0xe5675f41, 0x38ed5bd5, 0xcef90b6c, 0xdba4f4f8,
+	0x471a1a63, 0x650b0a35, 0x917807f0, 0x5d967ebc, 0xd76f265e, 0xc5c76498, 0x34d03e9c, 0xe2df3579,
+	0x9fcf3c11, 0x8eee0f2f, 0x46d04a3e, 0x7f9bc9f9, 0x48b5725f, 0x9848fdbd, 0x2ee0698f, 0x6b81ce15,
+	0x63e2fef0, 0x9e14d5c9, 0xa1db92cf, 0x892e4ca0, 0xa0262210, 0xa7262d15, 0xc7adefee, 0x8b2c112f,
+	0x6d06d891, 0x81fa6638, 0x59596f09, 0xa0f6c56e, 0x88f69fa6, 0x7f46acb5, 0x49c69a10, 0xa9f017d4,
+	0x1ce2cb64, 0x82722cb0, 0x6f332041, 0xe4bc209e, 0xb32e3bf0, 0x36404793, 0x0fd9bc91, 0x4b7b97f7,
+	0x9ad1bb62, 0x97413796, 0x27e17df3, 0xeae949ae, 0x21d9d8e7, 0x670001f6, 0x856e942f, 0x043cb490,
+	0x1c324cfc, 0x7f778519, 0x056c1458, 0x91598c5b, 0xee925bed, 0xf723cdea, 0x95876e1f, 0x03285dfc,
+	0x081060f5, 0x10106d8d, 0x0bf7cb59, 0xa172b7dd, 0x1314ef74, 0x1514f29a, 0x0914dfb6, 0xfefb3906,
+	0xc4bb620c, 0xf359e198, 0x2e0e79e6, 0x1757dbad, 0x0a57c736, 0x8d126f2c, 0x78bb9249, 0xe96898d5,
+	0x83b48faa, 0x84b4e968, 0x7cb4dcd0, 0x6a98e9fd, 0x5b98d260, 0x37a3e893, 0x4ed12c66, 0x43e0259f,
+	0x7fdbf4a5, 0x7889b669, 0xab9bcab7, 0xad28c330, 0xbe269f5c, 0xe7252e76, 0x537b9519, 0xae7f4d1c,
+	0x05bf6449, 0x50b8d09c, 0x58446843, 0x592c64f0, 0x7499948f, 0x8b6fe763, 0x0390dc47, 0x927c3c2b,
+];
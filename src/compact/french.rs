@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0x09ad40a1, 0xcacdfee6, 0x3071820c, 0x90e32ea3, 0x0a73e636, 0x7c6529e2, 0x0e01ce85, 0x8cdee605,
+	0xdb58290f, 0xba8e8687, 0x8a8322f6, 0xcf88b2b1, 0xe87a99d3, 0x4bb327f8, 0xb962525f, 0x55e22253,
+	0x4ddbe61d, 0x588f795a, 0x9da67f65, 0x174fcbce, 0x60e4cea6, 0x559c99c4, 0x31c053e2, 0x934084ab,
+	0x6bd2c2f3, 0x9db05981, 0xd467f89d, 0xe535cd53, 0x2591805a, 0x0d2e1309, 0xe2ad8f6a, 0xb543d1eb,
+	0x244b8030, 0xd3dc4356, 0x906f0e5d, 0xfbfff1ed, 0xea8821e0, 0xaba451d3, 0xad70c422, 0x65c6b387,
+	0xa7cd4a9a, 0x0142e8e9, 0x4a55d4ed, 0x5e96b8d3, 0x1fc9b1f7, 0x1602afea, 0x772289b6, 0x967d4366,
+	0xee801bee, 0x84aaba7e, 0x6c486dde, 0x969ead38, 0x90d8b2ed, 0x232f8d0c, 0xce74703b, 0xa4a56f4f,
+	0xbe04c517, 0x30cfba9c, 0xeae8c2a2, 0xcaa56502, 0xd297bc44, 0xfeb64e0b, 0x98da5dc3, 0x476e691d,
+	0xc72cdfd7, 0x8700a32a, 0x0a12975a, 0x80a6e5ed, 0x5a005e97, 0xe81f389a, 0x382adc5f, 0x415690b1,
+	0x714bba35, 0x76156f0d, 0xf586a2bf, 0xe4847d94, 0x833c7a37, 0x9b9b904f, 0x95694616, 0x0d57ae0f,
+	0x7049c332, 0xb70d346e, 0x4c82c5bb, 0xe6031d1d, 0x5a300a35, 0x162e8c57, 0x9e44b03c, 0x5e073648,
+	0x91b19a62, 0x8765d16a, 0x66f08712, 0x031da352, 0x524c9365, 0x14253cf7, 0xbca5c6d9, 0x187a2f60,
+	0x1e4b1e33, 0x0e5c0c02, 0x4725a0c6, 0x2bf7b019, 0x60a331d4, 0xc060098e, 0x2a856ab3, 0x4d3e37d0,
+	0xa882a5b9, 0xbc06a611, 0x4b10da4c, 0xad544418, 0x1c982e68, 0x66bc8050, 0xc238e05d, 0xc3a25d9e,
+	0xa43267f9, 0xa3fe0d6e, 0x82bb2bb6, 0xe0dad465, 0xec4f5e98, 0x11708c2f, 0x3d199d5c, 0x8f40c837,
+	0xebcd8da1, 0x44008610, 0xf94f97bc, 0xba5fe8d0, 0xb0151f5b, 0x4711546b, 0xcfb17796, 0x58d7d854,
+	0x41571e71, 0x9c9fa4aa, 0x074824ff, 0xcbf37f10, 0x87faa867, 0xa188836e, 0x2a1413a2, 0xa6682604,
+	0x618a5b78, 0xa3da3bae, 0xac7a0b84, 0x7f0dab50, 0x80b96129, 0x6307ee28, 0xa80fe1d7, 0x5f6e7a44,
+	0xdd440e25, 0x5329ca34, 0xedce5a96, 0x17676759, 0xaea44051, 0x0ae1d5e3, 0x86fe7d3f, 0xee8e7498,
+	0x39c9509e, 0xb492a22b, 0x989cddac, 0xb271f9ee, 0x95e6d51c, 0xae8a784a, 0x14115fea, 0x181b25af,
+	0x2d0e8187, 0xbe755da7, 0x28ea6b8f, 0xcbf1ab88, 0x17520442, 0xebce8344, 0x81a258f8, 0xec189afc,
+	0x88ff8b58, 0x6bc2f820, 0x0676ddb5, 0xcff246f0, 0x4d9b6dcd, 0x25415028, 0x6a574bea, 0x70d59840,
+	0x35e106a9, 0xd1b2bd78, 0x2191599a, 0x47e621a1, 0x6b4cb1c6, 0x2bd92e5c, 0xcda19e22, 0xd2b8ff50,
+	0x0039b6f5, 0x49c0135f, 0xdeea37a5, 0xa5afba71, 0x579f87f2, 0x92aeb5d6, 0x35648278, 0xa8af3995,
+	0x54617870, 0xa625945a, 0xc4fec376, 0xd454c6db, 0x7edfa4e4, 0xea306bb3, 0xa46409f3, 0x380599d0,
+	0xbb585aac, 0x8c26a679, 0xe73724ae, 0xa7567a83, 0xc6a2b1a8, 0x6cd2f410, 0x8bf1d2ff, 0x8df65353,
+	0x5a87398b, 0xb7999803, 0x72eb8a85, 0x24330def, 0x05386e49, 0x4b31a0b5, 0xf8c9e5e1, 0x2e033cfb,
+	0xf0d1e3d8, 0x99b899b2, 0xac43da37, 0x00d6084e, 0x20b73e80, 0x98adfd88, 0x6fcc54e0, 0xb6001c7c,
+	0x18883e2b, 0x7e3894ac, 0x55daab02, 0x81ce4e75, 0x2d478c5e, 0xb515cfd0, 0xd7b8f81a, 0x6694d214,
+	0xd7750a3e, 0x511bcc8f, 0x9593148e, 0x9b4bf7d1, 0xd9d1248d, 0xb7965b2b, 0x0a3417ea, 0xc7f98939,
+	0xa873b9e0, 0x900c5a40, 0x28ec13ae, 0x27719d69, 0x8c7bd0cc, 0x6d055b4d, 0x642d811d, 0x21c27074,
+	0xe6ffd161, 0xac7357e3, 0xbfec2606, 0x167dc941, 0x3dba0ee1, 0x86629a79, 0xfe55ecea, 0x753374a0,
+	0xed56fa45, 0x2a0ad34a, 0x260cdacf, 0xfb236347, 0xc7503b9c, 0x0550727d, 0xa0eb1d44, 0x4de28466,
+	0xfcb75a01, 0x216d19c1, 0xabb27fd1, 0x8c232bc9, 0x6bf91e3c, 0x6f3360a2, 0x9f1ded74, 0x6a9eb121,
+	0x087fd3b3, 0xa6e20123, 0x7b8699f6, 0x76deed60, 0x8c390a6b, 0xac0cfc01, 0x8928b9fa, 0xf64644ce,
+	0xe087e0fa, 0x8d6d17f1, 0x481f4e63, 0x2a75ec1d, 0xaee83bfd, 0x4fe9f281, 0x98b961d1, 0x9d18b189,
+	0x81830379, 0x758db80e, 0xea6b4a97, 0x19b59436, 0x8519bb26, 0xaf36b3f1, 0x580ea35c, 0x6247925f,
+	0x7de4e8dc, 0x0eccc9fa, 0xd70c48c7, 0x0326ff89, 0xd98608ed, 0x38a89260, 0x575066c6, 0x0261c706,
+	0x17aaa040, 0x7d899f73, 0xecac4dd5, 0x2c5da4d0, 0xf7ef0f80, 0x9bbc222e, 0x6a1ecfdb, 0x0d1ead60,
+	0xc512f047, 0xf2bd28be, 0x04d18585, 0x1ba809e9, 0x089a6ba1, 0x0e0ce4f9, 0x06e62ba0, 0x90a74a6d,
+	0x23a44b41, 0xe5b7d39a, 0x936c4965, 0xe0df0560, 0x272034a5, 0x02ee4570, 0x9df281ef, 0x43dc3727,
+	0x48b46887, 0xc934ece7, 0x8210018e, 0xf32fd6cc, 0xfdb37e2e, 0xc25c2e74, 0x54d9b064, 0x0c52b213,
+	0xe64f8cad, 0x1f01c9b2, 0xeb02a524, 0x2308781d, 0xed0f2c1e, 0x88ad264a, 0x0a253055, 0x8e163576,
+	0xc81bcb17, 0x2500048b, 0x3d351716, 0x8f1f0066, 0x9b110b40, 0xd7e3df6f, 0x9cbd517f, 0x752e5fd0,
+	0x708705c4, 0x37fb9801, 0x338a64f3, 0x2de7c28d, 0xec8d40ca, 0xdd19f55a, 0x74bfb9cd, 0x983a8e3d,
+	0xee3936b0, 0x86ca972a, 0x272824de, 0x24f172b0, 0x0b857e0b, 0xfb5709e8, 0x164b54b5, 0x2310efab,
+	0x9292a6c1, 0x70f57c37, 0x99c7ded8, 0x0db9087d, 0x27b960c6, 0x10e0325f, 0x753473d9, 0xfba957fb,
+	0xb32634b3, 0xd8f4032c, 0x1e12b4c5, 0xf0d4e740, 0xd98a472a, 0xb1b16818, 0x5d7f5121, 0x0c2f6b72,
+	0xf3a274fe, 0xd9d6d4b7, 0xf96890c6, 0xce5d3f28, 0x42ff537f, 0x7a686380, 0x1018be33, 0x66ce1f5e,
+	0x8eef69ec, 0xa54dd71d, 0x6a33a302, 0xdfbd5c7d, 0xc3c61c98, 0x0a5e361c, 0x08a3e122, 0xfc7268c6,
+	0x01dd2ea1, 0x36c49f5c, 0xd5b7f222, 0xcf861b28, 0xe49597f7, 0xb5a01f60, 0xdbd5e174, 0x2cac436e,
+	0x8685fac3, 0x8f929c1e, 0x8b0e403b, 0x18746a39, 0xa44fc4d4, 0x193cedd3, 0x43363412, 0x474f2ba7,
+	0x9c6fc222, 0xbac8ad22, 0x125df558, 0xfa6d96ad, 0xcecdd6f2, 0xef6ec8ca, 0x5e5b8d46, 0xe1e82b9d,
+	0x1ebcadba, 0x0e08ea91, 0x960e1c0e, 0xee688bb9, 0x9575ec17, 0x95618b6b, 0x32437c7a, 0xe57d1c55,
+	0x1ce36e21, 0x72388f6c, 0xcc90d075, 0xa14286e6, 0xb733df61, 0xd0e37ed2, 0x7013454d, 0x86a1b63e,
+	0x23710f7b, 0xddf4e138, 0x4303b923, 0x77536f6d, 0xe492c513, 0x5bddd3c3, 0xc7e857af, 0xd21c1360,
+	0x59ddc1d0, 0x017f4fd8, 0xf9a30581, 0x15e39af1, 0xbcf5b5a0, 0xfca7c8a7, 0x9766c891, 0xb268b5b4,
+	0x3ea2db49, 0x2f03a190, 0x0dae0e2f, 0x1ed72987, 0xda30f6ad, 0x3cbf75ec, 0x1b76a572, 0xac2a029b,
+	0x24e9d37d, 0x3feec137, 0xa13e3ea1, 0x64871fc2, 0x3f56282b, 0x6a7c5e4c, 0x9e80443d, 0x2c239496,
+	0x9b1a7fa8, 0xf6d23e19, 0xff9fa255, 0x7894e65d, 0x9116b800, 0x13514169, 0xe7a5856a, 0xc4758536,
+	0xe1414334, 0x21047a91, 0x268918fc, 0x5f9c4c06, 0x75d152b1, 0xbe325ceb, 0xa562df4e, 0x66042619,
+	0xb7c4eada, 0x23805265, 0x06374f9f, 0x839290f6, 0x4e4b0748, 0x9ec703f1, 0xc9ef8f61, 0xc60d9489,
+	0x6eccb087, 0xd819aaef, 0x219901ae, 0xdb1a4acb, 0x4ce2569a, 0x86da0e19, 0x335006eb, 0x1ed88c5b,
+	0x7ac59b43, 0xbc2ebb49, 0x0fb47498, 0xaff1f0f7, 0xd3cb6f1a, 0x40349728, 0xbe11662e, 0xee8d4ea8,
+	0x5d0313b6, 0x6b484436, 0xc68575b5, 0x81a82902, 0x1bb8f99d, 0x5a1d5780, 0xeef46edf, 0xe6caef44,
+	0xb07e2aad, 0xd54bfea6, 0x5ead0d0c, 0x0ce51a94, 0x474f2a64, 0xb753d56a, 0xba73295e, 0x355532c9,
+	0x60415644, 0xf17d7a58, 0x86cc28e4, 0xfd9e7582, 0x68a6e1be, 0xe8f5d8b3, 0x372c4468, 0x29091ec6,
+	0x4888bd42, 0xdfdb2756, 0xd7682f16, 0x5eafa224, 0x15b2ba37, 0x516cfc6f, 0x044940f0, 0xcfe80dff,
+	0xee33e017, 0x7db32756, 0xdf48b304, 0x23a5ceb3, 0x00120262, 0x6e128d02, 0x781af6ec, 0x61193273,
+	0xf728e3af, 0xb5f19a40, 0xf19218f0, 0x0432ed36, 0x2112e8c2, 0xae3b2953, 0xa1037679, 0x96420652,
+	0xe425aabb, 0xee3854da, 0xaa4183fb, 0xa9650d0e, 0x1372a9e2, 0xf8d7385c, 0x5800aa2e, 0xd39ac803,
+	0xf007f36b, 0x26cff257, 0x40a6a7ee, 0xfa35fe7c, 0x96e0799e, 0x2eb31462, 0x5727b9ee, 0x88ad8977,
+	0x1937fe57, 0xc9599c85, 0x5afeba2b, 0x99bfb5fc, 0x21579dfc, 0xce58428d, 0x56323c54, 0xfa35bf4d,
+	0x9aa49b75, 0xe46a0017, 0xad0e7afc, 0x5b3a4c48, 0x98c84a30, 0x5aba487c, 0xad18dd27, 0x8cf764ea,
+	0xc7977ead, 0x285df990, 0xa0eb0f08, 0x734df934, 0x3b801356, 0xe6968e48, 0xf810361e, 0x577e4345,
+	0x854e3a47, 0x0e30f58d, 0x541d11d5, 0x0d03cf37, 0xf789c5ba, 0xe6e99608, 0xc9454146, 0xc98f67ce,
+	0xa7660aaa, 0x81e72a42, 0x458225b0, 0xd97a40b2, 0xed02ebab, 0xba69fec1, 0xc8310830, 0x3359da3b,
+	0x7bb34936, 0xa13fad47, 0xabb3e4a1, 0xf0a8c04f, 0x531188d9, 0xd095f632, 0xac2dd153, 0x7d626856,
+	0x7c43726d, 0x351efe68, 0x00fb99c2, 0xd80ba991, 0x2acbc8bb, 0x7d218db3, 0xc3d40a12, 0xd29c2eaf,
+	0x3478a8ef, 0x4476944e, 0xb2807e06, 0x16411078, 0xbbae6be2, 0x570ae8f0, 0x7de9c30c, 0xb96c413e,
+	0x1491f205, 0xeb0ab37c, 0x277f99ad, 0x05bff96d, 0xec1be41a, 0xd9f62efe, 0xb2592e27, 0x7739ec47,
+	0xc7e85dad, 0x62c35f15, 0xabb398d3, 0x87e2483c, 0x65e8e4bf, 0xa21d361e, 0x47c9ba05, 0x7a581b9e,
+	0x848c1eb2, 0x04b6fc84, 0x39d49fe7, 0xb7e444a0, 0xe392ee82, 0x751dede3, 0x6c943b01, 0x2f8e9f31,
+	0x27c767be, 0x2e38b02e, 0x9146aac6, 0x31936f70, 0x0571918d, 0x5b31d353, 0xba9fa65f, 0x879d76cd,
+	0xab868fb7, 0x0587a7d4, 0xa5ed97dc, 0xf04fe1cf, 0x448496e5, 0xca1ae95f, 0x18939fde, 0x63c3b832,
+	0x6d0fb3c1, 0x70f958f4, 0x17c95673, 0x0dcffa7c, 0x7c4470eb, 0x8a9cff54, 0x351d759e, 0xc8150382,
+	0x328d7f43, 0x6584080c, 0xfabb3294, 0x5593a722, 0xf8e21f23, 0xe5c5a81f, 0xa9d3a6d5, 0xd36f7398,
+	0xaf8119ca, 0x9ee822c2, 0x217afc49, 0x1bf738f8, 0x86cf7570, 0x030beeed, 0x348303cb, 0xc14217fc,
+	0x3971c289, 0xcba20d09, 0xbee4cc3a, 0x0e6b9a0f, 0x1017d3d2, 0x91cfceee, 0xd26cdd19, 0xcaefdf13,
+	0xbb84b963, 0x919f9a5c, 0xc951c501, 0x96d215d8, 0x8615397c, 0x5abd55d9, 0x8a59d9f7, 0xdf98632e,
+	0x72619289, 0x075fee27, 0x46aedb36, 0x05441f83, 0x2ef4951b, 0x6055eefd, 0x7222584f, 0xea2352b0,
+	0xf7655700, 0x8a88f9cb, 0xdea201bd, 0x434bed9d, 0xdc510b03, 0x59381ac4, 0xa31abb98, 0x18f26520,
+	0x8497b8cc, 0x01d1e6c5, 0xfa02f963, 0xecfbebaf, 0x20a8b1d8, 0x016e3879, 0x7cbcacc1, 0x1b39960e,
+	0x9302528f, 0x9e50940c, 0xd99a57fe, 0x080beabf, 0x353b7627, 0x18000548, 0x96709ed2, 0xa5553f13,
+	0xe523c3fe, 0x23f955a5, 0x95bdbc81, 0xaba205fa, 0x12d40d61, 0x946895a4, 0x0eb88e35, 0xd7ddde18,
+	0x94965343, 0xc1e26a05, 0x572ef2cc, 0x6c370998, 0x26cdcd67, 0xd47735e5, 0xbd294683, 0xcf71895e,
+	0xdc86e768, 0x4e4f2f93, 0x6131b33e, 0xd3b90d2b, 0x489bb207, 0xdc7cffd4, 0x8fa4f4ca, 0x8a038d75,
+	0xcb67adcf, 0xa725f7de, 0xe28f897b, 0x31f30b69, 0xec0f69f4, 0xacc0fc89, 0xe1ef5b63, 0x040a4613,
+	0x74cbc44b, 0xe829d513, 0x92d1b72a, 0x2143936a, 0x017c655b, 0xa38d8f75, 0xfd7cc6f1, 0x39e1a10c,
+	0x3fcf12fc, 0xd14feddc, 0x23c010a2, 0x3eb7f679, 0x82591a5e, 0xa0237395, 0xd7096752, 0xb3b697ee,
+	0x647eeeaa, 0x00224931, 0x74b161d4, 0x003789b4, 0x299c9a3e, 0x302eee65, 0xcb27f8b5, 0xf946c484,
+	0x6d0465f5, 0x9548ebfe, 0x4f956ab8, 0xbca1e8b8, 0xe29c4bb5, 0x90904d61, 0x17949a70, 0x542e2885,
+	0x2630c8f2, 0xc7f84b4f, 0x6411e524, 0xbddbf034, 0x61a6d509, 0xfaf72b7d, 0xc802808c, 0x41131a87,
+	0xa23b7fde, 0x5f27efb8, 0x4100db9c, 0x3cb33f81, 0x8049994d, 0x2b875da1, 0x9cc23182, 0x4368387c,
+	0xcdda1094, 0xc5f5784f, 0x423b9e0a, 0x1215a947, 0xbc72175c, 0x5b8a79be, 0xc594844b, 0x6844242c,
+	0xe0f79599, 0xc9479b0c, 0x4bde98c4, 0xb5ef5629, 0x893652b3, 0x46c6b3a2, 0xe3966c0e, 0xbde72654,
+	0x7f056a6d, 0xc25bd711, 0xd73c480f, 0x7be29b42, 0x8271067f, 0x743c1dd9, 0xe6a5f23e, 0x06faf450,
+	0x86080052, 0x80fc6138, 0x29be0d1b, 0x77fd7877, 0x6626e1a3, 0x377be7f7, 0xe38f7267, 0x74908856,
+	0xa24eafe7, 0x6c9fdd4d, 0xf36a0314, 0x4006c830, 0x44b008d9, 0xd7e33011, 0xcb3af814, 0x6e289739,
+	0x3c35fa80, 0xa51be2bb, 0xe67d60cf, 0x6bade094, 0xd3778737, 0x79cabbec, 0xd72374f5, 0x3fbfa2cd,
+	0xd76a9191, 0xb66468bb, 0xa68d6df4, 0x68d580e6, 0x94e202e9, 0xe2303235, 0xfac5cdbd, 0xe31ae4b7,
+	0xae604fef, 0x3a6ef4ac, 0xa9a74f42, 0x9c4f19b8, 0xdf27b1dd, 0x0ed45add, 0xada176fe, 0x60cd70f3,
+	0xbb1f058f, 0x8b0cfa78, 0x0c38cdbb, 0x10c65e0b, 0x6397cc8a, 0x292c0a92, 0x8739c297, 0x24b5c14e,
+	0x9a9c10a1, 0x8f7951e6, 0x0de08d55, 0x4d8bb3ca, 0x2f9e9668, 0x7b3b0aa3, 0x5c878b70, 0xc856c722,
+	0x7ba5501b, 0x2e44c295, 0xd1bf4ec9, 0xcbc0b474, 0xe7dc89fd, 0x3c7e4cf1, 0x941f3830, 0x03374982,
+	0xc651c493, 0x615f5e4c, 0xa852dc13, 0x9bc78ed8, 0xc9f4aac6, 0xc1675923, 0xd7e24699, 0xe4887e41,
+	0x5ef8ddab, 0x88dc3f99, 0xf5e60170, 0x7b77b478, 0x415b4fcd, 0x6a4b840d, 0x45f64821, 0x4fd6dc8b,
+	0xa1554e3d, 0x8921a724, 0x1cce2729, 0xace7e5e4, 0xbaaaae7a, 0xfce2f61b, 0x1dcf5e44, 0xd7238795,
+	0x5012293b, 0xff7c49d0, 0x05f86544, 0x996ba8cb, 0x369f4893, 0x6d4d6b3b, 0xfd28f5f8, 0xe0d69ba6,
+	0x94439958, 0xa1c9924b, 0x4b702514, 0x24e203de, 0x26ff02c0, 0xe0b21ca9, 0x3423b0fc, 0x93650bd3,
+	0xb1f3d711, 0x0381e64d, 0xdaf4189a, 0x9175352e, 0x503df8ca, 0x6609fa83, 0x023ae45c, 0xb2d14f89,
+	0x9a0f9c08, 0x8a005d34, 0x0ecf4954, 0xfe8ddcdd, 0x06c38323, 0x87e58c3f, 0x62edf668, 0x834274ac,
+	0xbe986a89, 0x87b38240, 0xbad60950, 0x2041778a, 0xc8203888, 0xa0dbe953, 0xed49cc14, 0x22b83889,
+	0x01a01449, 0xe590ddb0, 0xb9c584c4, 0x8b06c868, 0xcb5a3f01, 0xb78a2d18, 0x096481d3, 0x363edad3,
+	0xfdf38be4, 0x55dbe009, 0xa975f568, 0xa4c517eb, 0x88791d67, 0x733787af, 0xc18d6dce, 0xaec15ef3,
+	0x63a2ef7d, 0xf938f0a7, 0x3adeeb51, 0x24439be0, 0x1010d97c, 0x1e4d3b7d, 0x52ccc33a, 0xee531523,
+	0x2133ab52, 0xdd94db74, 0x67afbece, 0xb35135fa, 0x3ada479b, 0x3cb95841, 0x0a7c7149, 0x1fe2acbd,
+	0x376a3575, 0x1e71b297, 0xd65d3d63, 0x3c10eeb9, 0x20bddc98, 0x43259a8c, 0xcd5161af, 0xf77b235d,
+	0x149646c6, 0x6399a267, 0x36bc55e5, 0xf2b02dec, 0x9fe7b528, 0x67820775, 0x569add91, 0xce06026f,
+	0xa374cb43, 0x7c148359, 0x32d7976a, 0x845987ff, 0x3d783f9f, 0x1fc47b0a, 0xee386eea, 0x4868e4e4,
+	0x5d9b13e2, 0xe377d723, 0xff29bf7a, 0xf97970a7, 0xbb3b3892, 0xd37350a2, 0x16a33c5c, 0x2d14b48c,
+	0xe8561c6a, 0x154dd21c, 0x8e6d78d5, 0x629dd1dd, 0x258c5ee9, 0xb799be1a, 0x92996e5d, 0x3e073677,
+	0xba405a6e, 0x6a91db93, 0x11d85a5d, 0x77f8f3de, 0x3f39a57e, 0x1df42d46, 0x5d7df818, 0x3c58fbcf,
+	0xfcbcda13, 0xcceda861, 0x22b2a2ae, 0xa9652671, 0x29a8188b, 0x3360b4ac, 0xe111a487, 0x66d201e8,
+	0xc5fe729a, 0xd55d5510, 0x0ec38d0d, 0xe00674cb, 0x61b823a6, 0xd03f445c, 0x3d54c28c, 0xa28f7abd,
+	0x2a35cc90, 0x3aac94a7, 0xee35281e, 0xca2a8458, 0xaeb90dc0, 0x5eafe7f3, 0xd665f709, 0xc1875f78,
+	0x2cb92656, 0xce1d1033, 0x164c5bed, 0xd680fae8, 0xd26e938c, 0x8b944998, 0x43dc1f01, 0xa3310ed5,
+	0xb414bfe2, 0x98237b7e, 0xfec648e8, 0x9fbf8e90, 0xdb1f511e, 0x12a94614, 0xf69717fd, 0xde29d2fa,
+	0x3776d47d, 0xf4c5857e, 0x9f68d0e2, 0xab803ceb, 0xcb080a8c, 0x14c6eb30, 0x6fcfcfd9, 0x184ea956,
+	0x206b1a5b, 0x4a48d0fa, 0xb00dc843, 0x616a5a4c, 0x6c908f7e, 0x1fefaef5, 0x1db26f73, 0x2fdf0c2a,
+	0x60408487, 0xe9de18bc, 0xfbc52a52, 0x43c18e91, 0x2225d2a7, 0x20305cdd, 0x0153497f, 0x64ba8a7e,
+	0xf27a5e77, 0xab8cc55c, 0xb91c9862, 0xd02f20ce, 0x27d1e992, 0x7269476d, 0x4da5eecd, 0x28175634,
+	0x6f9e9387, 0xa3adc931, 0xad91fc14, 0x7fc6dc8c, 0x51ae2e97, 0xf567cdb5, 0x38d0554a, 0x5f841a83,
+	0x7e9b4094, 0xbd4acf21, 0x7ca06d13, 0xaf1c2cf6, 0x0e126a29, 0x6c442468, 0x48a61aa4, 0x573ba2ba,
+	0xf7537ffb, 0x644dd661, 0x63aae046, 0x151b7f42, 0x2ef9685d, 0xd8d6d3b3, 0x103bf53f, 0xb1e8e347,
+	0x6952bf05, 0xd2135f38, 0xc9df773d, 0xae290de8, 0xd8e88936, 0x0dab8e32, 0x9a3f4202, 0x3a1f0169,
+	0xa2b23529, 0x8dbba58e, 0x649d53bd, 0xca6837a7, 0x15aa48de, 0x3d872c97, 0xc60d9ed3, 0xd1a620c1,
+	0x1f79f86d, 0xcff6e081, 0xe103566e, 0xe68a342b, 0x63e629e8, 0x6bee0770, 0x74f5a4f2, 0xdf26011f,
+	0x1ea42f58, 0x1d0a72ee, 0x7eddd59a, 0x2c6a76d5, 0xb16a9d1b, 0x57f1326e, 0xe0c25e21, 0x87963130,
+	0x20434d6d, 0x1790caad, 0x571af79b, 0xb67aadc6, 0x9380286e, 0xac89c6a6, 0x21867487, 0xa591f4bb,
+	0x988beefa, 0xc2796fc9, 0xf79b383c, 0x9d86d21f, 0x6f58a12b, 0x5b23a0d6, 0xad87912b, 0xf1eef13d,
+	0x605e2f4a, 0xa839d82b, 0x8b9439e6, 0xcefba9e1, 0xa60c343c, 0x886f8bd8, 0x0a6409e3, 0x5ad672da,
+	0x1f1e1dd4, 0xadfc5b01, 0xa061f44d, 0x8d55b6e7, 0x8ce7a1ba, 0xdd990a77, 0xcfd1614e, 0xa069701b,
+	0x785595c5, 0x3fcbbf55, 0xfba02a72, 0x745923bc, 0x8b2148e8, 0xe0a3ca26, 0xf89ed4a5, 0xd13d319c,
+	0x99f3e24e, 0x2621780a, 0xb96b0d34, 0xbf038422, 0xcbf6bb9a, 0x17597541, 0x1787d69c, 0xaf36bdc6,
+	0xa426f4b4, 0xba385b6f, 0x231c6b14, 0x72b6cc5a, 0xf4556695, 0x79fe071f, 0x7f3e592d, 0x8c627c6a,
+	0x52719e77, 0xec7ba336, 0x1225f33b, 0xf5009628, 0xb2b2f090, 0xf383c459, 0x38e70f69, 0x14a99ec4,
+	0xd940da92, 0xa4ef6883, 0xea5eecde, 0x69fb8c77, 0x5b10b07d, 0x6fd457fa, 0x1bb89a4f, 0x3cda7620,
+	0x323e4186, 0x0d9989a2, 0xe92d895b, 0x7ad29bba, 0x3c494860, 0x18f4acfd, 0xc3ea644b, 0x8714ab80,
+	0xc46aca09, 0xf76a8e65, 0xc6ec00f8, 0x29f62772, 0x9f9fce6d, 0x23ffc4bb, 0xfee852a7, 0x6b08bd65,
+	0xb877c233, 0x139443c1, 0xd24e5948, 0xc29145e4, 0x7c9644c3, 0x11f197dc, 0x17c0fbd2, 0xf0baffa2,
+	0xdd3bd778, 0x098d9342, 0x126c88d2, 0xf329eb2d, 0xb0a244fa, 0x29e8695b, 0x4bd1df26, 0xe9ca8d6b,
+	0x1ae78c57, 0x9b961bef, 0xae7041dd, 0xe3b77fcb, 0x1b0ddd79, 0x3957bd3a, 0xa6563e8a, 0x73d5c6da,
+	0xd4f011bd, 0x2a601c04, 0xdcf31ef0, 0x4e0a8814, 0xb63ec9f8, 0x07f64d19, 0xef71d593, 0xd1686c56,
+	0xda4672a7, 0x4140ba0e, 0x35167faf, 0xa9052b29, 0x8e318618, 0x0bc9521c, 0x412e361a, 0x7bf84607,
+	0x7cbe2026, 0xbb958983, 0xad30f06b, 0x2b0b3af0, 0xa3e710af, 0xd1784342, 0x59245a1c, 0x743d4a97,
+	0xcf799715, 0x2f8cdb7f, 0xfee42fbd, 0xcc1f5fce, 0xae0485bb, 0xae95ac0f, 0x9eaf8e6a, 0x6c718e96,
+	0x9aa85bdb, 0x44b47511, 0x60c3fb5b, 0xc9a287ef, 0x9eb839bf, 0x647567d6, 0x078792f4, 0x8ed16962,
+	0xa353d836, 0x9afd7ba9, 0x1a4a4c88, 0x9e7772af, 0xbcfe0c5d, 0xefa834cb, 0x78c0a7b5, 0x1721ba57,
+	0x69c8d544, 0xc91efe44, 0xd1085c7c, 0x6d7a00fa, 0x3f29db8a, 0x55d3f53e, 0x9919a719, 0x8da9bf42,
+	0x492fab57, 0x7e11861d, 0x0b13d61a, 0x1f4e5e34, 0xe0a35759, 0x2b1352f6, 0x93c9b3b3, 0xf200be5f,
+	0x8005bc4b, 0xa71aea0c, 0xf71b6b0b, 0xf62f12ef, 0x26aa8388, 0xf0e4e74d, 0xb8e87c04, 0x70c1f3b6,
+	0xe11d951c, 0xe541f6ff, 0xac087a86, 0x6f15601e, 0xd742440b, 0x5e53e41d, 0x4ae3621a, 0xbe5d4f69,
+	0xe23ac5ad, 0x861af146, 0x1e1888da, 0x45b473eb, 0x7a6e8f1e, 0x4d5a46f6, 0xe3001ac1, 0xa7571af5,
+	0x87400b26, 0x249db526, 0x4d1f0449, 0x4f275711, 0x4e0250fa, 0x268f3b44, 0xe90e173f, 0xff4a2305,
+	0xf79a0dab, 0xcf9eddce, 0x97f78357, 0x8802a22a, 0xebe12794, 0xba662bcc, 0x4b0b97c5, 0xbb2ee1de,
+	0x46c11fb8, 0xcd2facb0, 0xaef075ba, 0xddf69b61, 0x2a7a3766, 0xd32439e7, 0x8c5e4d71, 0x536bdddb,
+	0xebfc3e7b, 0x4db14a8d, 0x87f39abe, 0xb6f0b107, 0x349a7a92, 0x6eb0b5b0, 0x448d494b, 0xda8896b6,
+	0x697364d6, 0x56503bf9, 0x51043ffd, 0xf5009b83, 0x2ba58a58, 0xf08caa77, 0x1144d7fb, 0x4e46a46a,
+	0x3be2edf6, 0x75b7e910, 0x55c3af2f, 0xa9942e1e, 0xe58951c6, 0x9c45adbe, 0x57788ed1, 0xf49f2d48,
+	0x02658a86, 0xbec34041, 0x6b8a1462, 0xfa62f9ea, 0x386e0242, 0x0317f791, 0x0be96cb7, 0x19ac4b26,
+	0xdda10629, 0xbfe537ac, 0x2625d771, 0x4ef0003a, 0x6bf76995, 0x5c68d760, 0x137cec66, 0xd80fa619,
+	0xc5334222, 0x42bd5b74, 0xfa45bd45, 0xd8cbf8bb, 0x22af1a2f, 0xe2c21ef8, 0x7d631d3e, 0x7f72329d,
+	0x5fa38690, 0xf19df1e8, 0xa431bb0f, 0x05ef576c, 0x15e73831, 0xb9f8ac53, 0xecdad834, 0x77dd8cb8,
+	0x2c0dbfcc, 0x7d1c8366, 0x211b84c7, 0xdd887ff7, 0x8aa9b648, 0x87d466d1, 0x45e7afde, 0x7353be3b,
+	0x90677e39, 0xf2d7a6d0, 0x9b0f986f, 0xbe19adcc, 0xc9143e22, 0x0df2d2d4, 0xc3b31e73, 0x7fd5012c,
+	0x82544c1e, 0x10019b30, 0x032eac4c, 0x72055dd7, 0x97a76a65, 0x8eb03226, 0x7f926e85, 0x2fb2757b,
+	0x8a913d65, 0x9aa9093d, 0x5f0b4e62, 0x96ebf249, 0xdad7b190, 0x68578e70, 0x4a963e6b, 0x0446f26d,
+	0xcd928552, 0xb176cc96, 0xf13915da, 0x9d2848f5, 0xb7359097, 0xdf740ff8, 0xce95841c, 0xd6af6ea1,
+	0xeea7e9cc, 0xf928bdfe, 0x73b6390f, 0x1232ae64, 0x9cb3ac2b, 0xf8371b84, 0x0e9407da, 0x6c084a73,
+	0xbc46e7c1, 0x04b7c7ab, 0x30e95d2c, 0x8d0300b2, 0x934f4e0a, 0xeeb2ef15, 0xf0f686cb, 0xb2176ed7,
+	0x8b7abc01, 0xbdfad7ea, 0x0f24f853, 0x2fabc427, 0xb201bc9f, 0x2909a260, 0x5a5e693e, 0x4c557867,
+	0xb09f10df, 0xf98490ee, 0x6f650ed1, 0xe65414bb, 0xe099576f, 0x8799e5c5, 0x8d04e27d, 0x15dc0811,
+	0x7aad3809, 0x9c468cb1, 0xbfff21c7, 0x06588c28, 0xee2cd616, 0x19ad6623, 0xd0df66ef, 0xfdea04c6,
+	0xbedb743f, 0x108b4215, 0xb7d490a5, 0x6dccce32, 0x1799943c, 0xd8dd7cf8, 0x968cc1c7, 0x6ae52341,
+	0x9d08a413, 0x5b2f79b2, 0x904e6833, 0xff96bc38, 0x7f862255, 0x2e62a6f5, 0x467bd871, 0xcc909380,
+	0x909ba4b5, 0x765194b5, 0x450e2405, 0xc5eb3b48, 0x67c59126, 0x93f78ade, 0xed66d75e, 0xe6cb5099,
+	0xc2d8738d, 0xc46261a6, 0xe860bfd0, 0x19b68416, 0x06d5a845, 0x5ce0b5da, 0x989e8c11, 0xde43e070,
+	0x3fecf059, 0x2b71c513, 0x38d37417, 0x55d43d37, 0x125b4fbb, 0xd08ea54b, 0x71dd3ae2, 0xc666aa11,
+	0x376fb623, 0xd12f7204, 0x83cdccc4, 0x955627b2, 0xbe305289, 0xbb40e621, 0xb149970b, 0xcbc5f9fe,
+	0xa9c52c18, 0x06579041, 0xa7ffff61, 0xf37b0ccc, 0x0058fa72, 0xa6508335, 0xfe4d0be2, 0xf7253fef,
+	0x39b0903f, 0xe9dd3794, 0xd67354ce, 0x2ae5d377, 0x46ad6f19, 0xb8f3ccf7, 0xdcb762a6, 0x20849e30,
+	0x55d57529, 0xb82810d7, 0xf85d93de, 0x0df6af7f, 0x52b7264f, 0x33eeb783, 0xee89f434, 0xb8cb8d45,
+	0x1045aa1a, 0x344889a4, 0xc8a0e700, 0x0858dfe5, 0xfb0d722d, 0x8939045a, 0x4ef4d915, 0xc7fdadec,
+	0x2668eb01, 0x93041bb7, 0x4a3e3087, 0xc99fc220, 0xb0dddd67, 0x0d3f2f7f, 0x45022d48, 0xfccadd2c,
+	0xaff0e768, 0xb3f4a34d, 0x1c8c37f4, 0xd309a2bd, 0x7ad65bde, 0x70e0574f, 0xd32d1c11, 0x6b68448d,
+	0x6d0708cc, 0x1a82210e, 0xb75fbaa5, 0x5400fdd0, 0x5398fbcf, 0x4b58cbed, 0xbd73fcf1, 0x5743c206,
+	0x06d33ca7, 0xb5325251, 0xa91fa8e0, 0x4e8e314b, 0x8422d8d8, 0x9ee11b03, 0xd8d2c20d, 0x97f77390,
+	0x70e2606b, 0xd22038ad, 0xf4d64dec, 0xcb25f869, 0x67f4626d, 0xb68ad6aa, 0x4340ec16, 0x306b41cb,
+	0x3da68c25, 0xe745eebd, 0x73975f92, 0x5e42a13a, 0xd19b5d14, 0xe23c9343, 0xd08b5bf0, 0x008a1bbd,
+	0x9c8cc2c0, 0xc0812ed3, 0xd1156e96, 0x41f60775, 0x4783c3af, 0xa88397e0, 0x9b76c143, 0xfc3ef3d5,
+	0x579a60f1, 0x72ae132a, 0xbd69f852, 0xe3c14eee, 0xe1f959e2, 0x9c94da74, 0x9bbd7186, 0x9e2e2062,
+	0x089d3d1b, 0x4bef948e, 0x41f35e2c, 0x3c10c70d, 0xfe3c9fe4, 0x2de0a197, 0x0abcded1, 0x7380948a,
+	0x45211ec6, 0x5e256e5d, 0xf0e6f88b, 0x6983e42a, 0x72082b99, 0xfb3a4fec, 0x0b3b693c, 0xb5084116,
+	0xd8dcda58, 0xabbd2043, 0x77da69ab, 0x652e5419, 0x8e9019a6, 0xa6fdace7, 0x09008149, 0x1021588e,
+	0x13b40f05, 0x4f470953, 0x5c5c74c4, 0x86bf56f0, 0x657572ba, 0x5f50e97b, 0x62a12c41, 0x02c65ebd,
+	0x5ab52d5b, 0xd22f9095, 0x128f941d, 0x0c7c4989, 0x635d2b71, 0xcae3d263, 0xd89dc4ae, 0x33235c61,
+	0x5048bbd0, 0x2e05b320, 0x97fe9d81, 0xa49050d7, 0x96011192, 0xb3308789, 0x8f4473f7, 0xd803a931,
+	0x7e425ea7, 0x871394c7, 0x5db8bd77, 0x666df4a2, 0x59e02574, 0x0c7adf52, 0x4044eb87, 0xde730d2f,
+	0x94c535c3, 0xc6a6e3b3, 0xefb94d59, 0x8f49adea, 0x56dfde64, 0x022e8d28, 0xe058bf43, 0x262ef920,
+	0xb38349ff, 0x4244f680, 0xa858aa0e, 0x3a0c19db, 0x14eab4b4, 0xe1d49d7d, 0xbd7bba09, 0xb773918c,
+	0xa4fa0fac, 0x1666807f, 0x820bbf1c, 0x2de5fdca, 0xb3d6f6ac, 0xc78fcf56, 0x3642d39d, 0xd9837c29,
+	0xbe047431, 0xb2ecd6d2, 0xa86119ef, 0x2029a806, 0xde9231ef, 0x56fdff6a, 0xf40808e9, 0x188a5e60,
+	0xe5ed8045, 0x2a458fe5, 0xb780af11, 0x627e32c0, 0xb72e6278, 0x91c758f0, 0x53b730e1, 0xb06705bd,
+	0xef38ae1c, 0xa70d522e, 0xec266ace, 0x58633bea, 0x677afaf8, 0xf236e70c, 0x089e5f8d, 0xad8113e6,
+	0x754a5b2a, 0x1bcf29d8, 0x97d0ecde, 0x2319a2f4, 0xf6854989, 0xf9f0dac3, 0xba937a7b, 0xbc641b2c,
+	0xae4333d8, 0xbc9cb896, 0xaed72c13, 0xe81cbfa3, 0x8483a741, 0x47c2edcd, 0x24c83574, 0x80e6f5ac,
+	0xdef04d09, 0x2703af78, 0x663f92cc, 0x0a2bd765, 0x269067e6, 0xf7f87155, 0x28274f57, 0x2e9d26ef,
+	0x40f54c8b, 0xfd866262, 0x917dfca2, 0x3a92c235, 0xfbec0ea5, 0xb51c3109, 0x1959cefc, 0xca4f2657,
+	0x2f5a3a88, 0xcd2a0f9a, 0xb16c7cb0, 0x410572ff, 0xb2f48e4e, 0xf11f9079, 0xc8a19e5c, 0x13e2d928,
+	0x7e188458, 0xceefeb15, 0x480ed52f, 0x70bd2b5c, 0xaa33a1a8, 0x8a91f1ab, 0x9ac1c1ee, 0x87102c2b,
+	0xb5bfbf9e, 0xbbddd8d8, 0xf14ccbb5, 0x37cdbd7a, 0xf731d635, 0x01a8eac6, 0x696b6614, 0xe274643f,
+	0x2b76b74b, 0x7ca54656, 0xa76a6983, 0xb6822593, 0x5227e085, 0xdfc8481a, 0x2b7bf526, 0x81d4cf6d,
+	0x40ff0f12, 0x3a08c657, 0x9736d905, 0xca3b1899, 0x3fd4af30, 0xd8acec2f, 0x07d9cfad, 0xabe999ab,
+	0xec7c779d, 0xae184492, 0xda3f7863, 0x4c8d5941, 0x4ae62ec6, 0x1a3fec9c, 0xdf7a913c, 0x59bf6f19,
+	0x25702ed2, 0x09b34672, 0xb315662b, 0xef36fe6d, 0x761606ae, 0xd9ca913b, 0xfbc19174, 0x745671d3,
+	0xe8515bc2, 0x10c80146, 0xe30aee5f, 0x3fa2f581, 0x55dd443a, 0x891b294d, 0xea493d9a, 0x80c6fa0c,
+	0x9f2ca4ff, 0x6a1c71cc, 0xbf50da58, 0x8ea90105, 0x895b6a52, 0x8f347630, 0x03db1ec6, 0x64883daf,
+	0x31ff800e, 0xddea55b2, 0x6c7104ea, 0x4fe8d3f1, 0x17246b58, 0xe38f4cb7, 0x1c9a8191, 0x78556c69,
+	0xe146e2c8, 0x8dc57d96, 0x3f221a9b, 0x04a3c27c, 0x780ab4ab, 0x4ce5d758, 0x09f3f901, 0xe94ff69a,
+	0x89b4b373, 0x964eb226, 0x506bf815, 0xe8f46345, 0xfebd4b52, 0x39c70bde, 0xaf2c2ff9, 0xc58ea587,
+	0x59e55a89, 0x148a631e, 0x8837d5c6, 0xae4cbfaa, 0x97b30387, 0x0a62fa57, 0xab841454, 0x5a151b46,
+	0x28db6e9d, 0x63bfcbcf, 0x31af7430, 0x3cfbdacf, 0x356e25c6, 0x0ba77913, 0x5af00fca, 0x9e3771d5,
+	0x90a384eb, 0xcde58572, 0xce8ae30b, 0xdbded6f4, 0x3dbe3b26, 0xcb298424, 0x9fc76997, 0x6ddeafbe,
+	0x483bee7c, 0xcde0a1db, 0xce53aea8, 0x6bce172d, 0xafcb9acd, 0x8700c8a5, 0xa09d43c4, 0x8d8f0b79,
+	0x91c4d80c, 0x86f937e9, 0x596ae5fd, 0xed317db7, 0xf2eaa824, 0xf6fc9f1d, 0xb5520a8e, 0xf22b5504,
+	0x51cf5e83, 0x1bb49259, 0x626144c1, 0x55fe35ed, 0x603013bc, 0x5351088c, 0x6a6611e6, 0x3864ef09,
+	0x819aad6b, 0x8ef26cae, 0xe267aeb5, 0x1f820d0e, 0x1ed5de2d, 0xc1d39b07, 0x92978110, 0xc1f65e76,
+	0x73dd4aa4, 0xdf2467f6, 0x3715e8d8, 0x6939923a, 0xc2b11052, 0x60a462b0, 0xdd694478, 0x4e9870ff,
+	0x0a04b108, 0x632e1600, 0xc2cc3795, 0x571c6f4e, 0x41ba175c, 0xae30f7ce, 0x2845cac5, 0x491f9223,
+	0x06d051b9, 0xc663d4b4, 0xae3e5d02, 0x79345c23, 0x37e1c059, 0x4b54d4b3, 0x8d939614, 0x1e1b6b84,
+	0x471a1a63, 0x9d3c26e8, 0x717c52be, 0x7825528a, 0x4f0c99b1, 0x479ab562, 0xd76f265e, 0x63e75398,
+	0x14b5b323, 0x17c64e29, 0x687f5ca3, 0xe2d4a81d, 0x9fcf3c11, 0x5f9b85dc, 0x639e6be6, 0xee9976d0,
+	0xe1a872d1, 0xe6d1c8ab, 0xb57e6d98, 0x3ffafc99, 0x9fb63fd6, 0x27296cf9, 0xaebc9703, 0x2ce7d62f,
+	0x57c3d500, 0xc43608ed, 0x2ee0698f, 0xcc32bbef, 0xd8760e19, 0xa457ec25, 0x53ccb0f7, 0x63e2fef0,
+	0x193025e3, 0xa1db92cf, 0x8c211124, 0x8c9569d0, 0xfa20e14f, 0x84198cfa, 0x2374638e, 0xf2fd43c9,
+];
@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0x47c39d7b, 0x5886b614, 0xb9868757, 0xe3ecc6ca, 0xa56198a8, 0xc77394a9, 0xcba0b210, 0x26abc804,
+	0x05446f03, 0x27e123ee, 0x9f543ad0, 0x2367ae68, 0x44335205, 0xa0417245, 0x2f35f8e6, 0x55f9b948,
+	0x161ee205, 0xfede7ab8, 0x84bde051, 0x0fd77cb8, 0xf5acee93, 0x4faceb33, 0x8e70ea4f, 0xbfcbc9bf,
+	0x69a95024, 0xf771da4d, 0x3250a2e9, 0xaf16a0e1, 0x90dfa868, 0xea536092, 0x64505658, 0x90f8dc9d,
+	0x304735ed, 0x9fcdf6cc, 0xac107c15, 0x8d2195f9, 0x2a9dca28, 0xfea6b9ba, 0x02ec6e51, 0xfa98fca6,
+	0xd8e04afc, 0x2a604fef, 0x82d92e8f, 0xc702e025, 0x09dd432b, 0xf7fac1de, 0x24c13ce4, 0xc5a1f099,
+	0xce9e194b, 0x0060062b, 0xf4a093b4, 0x25124153, 0xddb40b4d, 0xe4a73649, 0xff39d207, 0x6da02b2b,
+	0x5f241386, 0x61078120, 0x5aa4dde6, 0x711611ff, 0xd8965cfd, 0xedff43df, 0x77cbb6a8, 0xde286b25,
+	0xa144e766, 0xfe256c33, 0x5cc869c9, 0xe228c195, 0x149da110, 0xe8903ff1, 0x138c3a2a, 0xa272ea78,
+	0x0e190b3b, 0x046af168, 0x223ae1d8, 0x79eca8ac, 0xd583317a, 0x8d410a03, 0xc25dd41b, 0x7fe89353,
+	0xb4d52a4b, 0xacec1e15, 0x604841a8, 0x252d42b0, 0xfbc536f2, 0x14f0b1a9, 0xa1210f73, 0x33567835,
+	0x98b79d2d, 0x90869c2c, 0xbc8ea15c, 0x3ffeca50, 0x575b52e2, 0xdd79a860, 0x513e3e1c, 0x85df8e06,
+	0xd5c4a69a, 0xc516502d, 0x2b6eefe5, 0x1c1512d0, 0xb436c38e, 0xbf757c42, 0x5f53005f, 0x10d22efa,
+	0x9b48ab9e, 0xadb48c23, 0x737fcce2, 0xe8269165, 0xc7ccdf55, 0x1bfb9194, 0xd37d5556, 0x66c22fac,
+	0xc9fe7f47, 0x68aa5dea, 0x2f2b9395, 0x0c60a8ce, 0xa96dc3af, 0x1a826f25, 0xa932a9ff, 0xc56d99ba,
+	0x4239946a, 0x4b41cad6, 0x47d3a6b8, 0x0cdc0da6, 0xc6f63c5b, 0xf74f5448, 0x78a0a6f2, 0x321ecc6f,
+	0x88243cea, 0xd5f38ece, 0xa1ea845a, 0x732fb498, 0x906fd5c3, 0x60f47a71, 0xb2c2fdeb, 0x6177f28b,
+	0x92f0ff98, 0x74a87cde, 0x92b72ba7, 0x7c5f2fa1, 0xed589f03, 0xf0194b07, 0x45c98d41, 0x90d8f78c,
+	0x0b7d0f6c, 0x9e0c8c8c, 0x0ae715d9, 0xc428d978, 0xf26c3508, 0x8ad3d1c5, 0x2317ce9d, 0xb5b1966b,
+	0x0aa5dd6d, 0x009f14a6, 0x59124700, 0xcf552d47, 0x29ef69ad, 0x3afc07cd, 0xbf744cfc, 0x25e2976e,
+	0xab4e2706, 0xc66e873e, 0x8d0bd2c3, 0xcb05304c, 0xeb76a8c7, 0xbc32d1fd, 0x2716c6da, 0xa15fb723,
+	0xe035e37b, 0x48879f3e, 0x68ad5d80, 0x55981e91, 0x01759c29, 0xa342d090, 0x96c253de, 0x0abf8375,
+	0x4c73d9f1, 0x87b75bd1, 0x8e613c74, 0xd499c1eb, 0x8d083ffd, 0x1390de62, 0x5f8b347b, 0x1f0a16e2,
+	0x4a1250cf, 0x8b952e18, 0x82a0bff2, 0xcac7f1d6, 0x6c58bbbb, 0x57b41b56, 0xd81284f4, 0x3d990053,
+	0x23a1c8ad, 0xfbab83d0, 0x3b0ff713, 0xc8277299, 0x4ef3e6e6, 0x233464f0, 0x40f63612, 0xefd107ad,
+	0x1f415fe8, 0x999c5d3b, 0xf085df1c, 0xb2ff87ee, 0xedbeb8b7, 0x1e6271f7, 0x61a4011e, 0x6c4c92d5,
+	0x1974360f, 0x227b6e99, 0x1f81185e, 0x81d7f7b0, 0x45cfd57d, 0xc43befb3, 0xb4ebc1f3, 0x26e06a47,
+	0x9047606d, 0xa8652cb5, 0x697b95b1, 0x59da24c5, 0x5db09729, 0x72eb410a, 0x9f7bbb69, 0xb2001630,
+	0xaf81e4c7, 0xe4b6174a, 0xa04c6604, 0xb9325ca4, 0x7fe608bc, 0xc0420b08, 0x6d7ad7c2, 0x9c5c74cc,
+	0x4dfd28a4, 0x8b749c46, 0xeeec4158, 0xf00cd267, 0xb2c53624, 0x363b42c3, 0xa8773808, 0xef97df88,
+	0x332871cb, 0x4175903b, 0x19fecaab, 0x26deead4, 0xa6daa229, 0x27363acd, 0x1796b384, 0xe8413a4c,
+	0xd29f12fd, 0x5f887fa9, 0x73f0bd52, 0xa20e5faa, 0xa7a5a18a, 0xeeeda0a2, 0x706bff22, 0x781f8646,
+	0xbd87444a, 0x7b913868, 0x232945be, 0xa3ebcabe, 0x103b4917, 0xe28709b9, 0x4fa340e8, 0xd200fef8,
+	0xf427a250, 0x2f97d1c3, 0x3497d210, 0x650752b5, 0xffb7a9df, 0x9e64fcc0, 0x5523f658, 0xb7277d94,
+	0x2529c110, 0x7333301c, 0xf2229797, 0x59f54982, 0x46a12ff6, 0x46104e04, 0xaa045fd4, 0x6cd88822,
+	0x6dba4b3b, 0x441d89b0, 0x2ceb2f3f, 0x8a8ffdc8, 0xbd1c60db, 0x21a8135b, 0x2b31f77b, 0xb319a7f4,
+	0x04ad5801, 0x9e2be2fd, 0x8ec42072, 0x74e4ff1d, 0xf92a92aa, 0xaf8e8354, 0x285a440f, 0xa9f03851,
+	0x86f5bb00, 0x94d92f83, 0x4c2a4d83, 0x99f27ba3, 0xb3459216, 0xbe521c3f, 0x054d3e7e, 0x667ad203,
+	0x94bd5d9f, 0x932a60be, 0xef2fd080, 0x0d5e7e3f, 0xb23b93a6, 0x85f16690, 0xcbdd6e2f, 0xdbcf70d6,
+	0x2e819859, 0x4ceb060e, 0x0ad0c7db, 0xe64c0719, 0x55cf1927, 0x912528f3, 0xd9618685, 0xc65b9d1a,
+	0xdaf79733, 0x1261ed2c, 0x2970a8db, 0x8b2f4980, 0xd29d98bd, 0x25fc2b91, 0x213fcf7c, 0x97029b35,
+	0x848b27a9, 0x752c4971, 0x1e82df16, 0x37e25fc0, 0xf63a95eb, 0x0c40a38e, 0x170480e2, 0xb54507ac,
+	0x26779604, 0x8488ba66, 0x9ad2cf49, 0x109849d1, 0xb9e9468b, 0x65a200d4, 0x8d8b23f2, 0x8c1618af,
+	0x3b150c06, 0x4982055a, 0x280dab0a, 0xeca4a890, 0xd9920684, 0xc4523491, 0xf790833d, 0xd3ff3646,
+	0xd5d0229d, 0x95ff3c21, 0x82e34ab3, 0x894bdbd2, 0x2b9f9b0d, 0x695736f6, 0x4afd010c, 0x0c0094e4,
+	0xa06b42d5, 0xebe5daeb, 0xfd1cfa37, 0xcd57f574, 0xdfffce2c, 0x11cd5718, 0x501722f6, 0x4f8447c3,
+	0xf83f8304, 0xd511d94c, 0x4cb9e889, 0x1eac763f, 0x8244d56a, 0x0af4a607, 0x4691c81e, 0x324ecb52,
+	0x3c4f0290, 0x0142c2ad, 0xa32bbc2a, 0xd290d9e7, 0x553d891e, 0xb1514998, 0xcdeed738, 0xa0c3316d,
+	0xd2e3db73, 0xeb705696, 0xd01b3725, 0x5def1ddd, 0x6361ce69, 0x01aaa548, 0x9f647103, 0xab7274d2,
+	0x8266e258, 0xe0453f1b, 0xe3808d88, 0x78a800b9, 0xd39b8421, 0x2873fdad, 0x4dae50b7, 0xffd51e1b,
+	0x0beaa336, 0x7a4bed5b, 0x6cc676c6, 0xfadb21ab, 0x9badd112, 0x2d9f31b8, 0x4dc94918, 0x4a9634ac,
+	0xab9c5197, 0x6dab70fb, 0x68ad4029, 0x343db2a1, 0x1cbb499f, 0xba69356d, 0x9915b875, 0x0380e183,
+	0xe5a865f0, 0x7ceada92, 0x2cf2c0a5, 0x8233f033, 0xd3ae3376, 0x401c4aad, 0xa3ec5760, 0xe1a4f14a,
+	0xb890769d, 0x40fc7cfc, 0x423e3b84, 0xba14aeac, 0xc56379d2, 0xb9cd31a5, 0x958db61f, 0x1cf1a940,
+	0x18515cde, 0x9337fca2, 0x2b26101d, 0xf02f51e4, 0xfa9a279b, 0xfc42a0a2, 0x8bbcccaf, 0xf3b3ab7e,
+	0xe437bc11, 0x401d36e9, 0xc935b500, 0xbbd94704, 0x90365560, 0xa1b8ec8e, 0xbb0ec440, 0xe180b58e,
+	0xc4462f38, 0xd84a240b, 0xbcf4d7f9, 0xae3ce7f7, 0x558aa284, 0x0ef1a023, 0xd2256b23, 0x71ccff64,
+	0x5161ca88, 0xfc7af83c, 0xfaf9b8e5, 0x6b017c21, 0x98302a3b, 0xed0fff3f, 0x1d745e6b, 0xd0946c2e,
+	0x0ffeef5f, 0x822671ef, 0x083a0d6d, 0x8ce35356, 0x328c8629, 0x6f2fa18d, 0x8caab931, 0x5cffab2e,
+	0x017a4b98, 0xbe959612, 0x4dbd331c, 0x8515b458, 0x576cfb49, 0x4a6e1a7f, 0x1b655022, 0xa32231ba,
+	0x3ffbc939, 0xc1a58703, 0xf6734fd4, 0x8029a224, 0xd056169d, 0xdc4d71c2, 0xdf5d82d9, 0x575e1e63,
+	0x4c28ef0a, 0xad977aa2, 0xf8d8e907, 0x650f2b9c, 0x4f3d0dcb, 0x1c877c32, 0xef60ce32, 0x16eb472b,
+	0x7d9fb2df, 0x4157eb93, 0xd44a2a30, 0x2671fd20, 0x5a835479, 0x3fa33795, 0x6f102a32, 0x08f1371d,
+	0x9e00229e, 0x5efec077, 0x89b8ad5c, 0xe6c16193, 0x8c0237bf, 0xb13e331a, 0x6e5ea736, 0x1cf370b3,
+	0x719c5d6d, 0x12b2b99f, 0x61ce172c, 0x5bc609b8, 0x808d078a, 0xb078bc44, 0x01346650, 0xc3de52a6,
+	0xe6bb4915, 0x279db261, 0xd94e392f, 0xe2706743, 0x13176205, 0xc3447e0b, 0xd8213d7a, 0x2d004680,
+	0xd4b39ad3, 0x398a0ccb, 0x1f30db32, 0x668243ee, 0x5a1ddf22, 0x92093da4, 0x9aa83f06, 0x29c54afa,
+	0x2d867dc0, 0x86f41306, 0x503ebba6, 0x1b9d5081, 0x9806a63c, 0x4c00c84a, 0xf6c93618, 0x279e9f22,
+	0x6c2b4504, 0x68d2b907, 0xfadd73af, 0x1b01e4a7, 0xa79ca70c, 0xc1bf623c, 0xc56436ed, 0x594dbf3a,
+	0x646bd84e, 0x8851ae11, 0xf2792715, 0x30cfdc04, 0x593f1627, 0x831f4bfb, 0x09739843, 0x021c0ee5,
+	0x3e9b4fa6, 0xa07a9b4c, 0x81b241f7, 0x5c4a9024, 0xdc5afbaa, 0x539f0cb9, 0x51ed06ff, 0x33f7a86c,
+	0x2c3717e3, 0x34392706, 0x2fd37566, 0x0f6d2291, 0xe1fd8191, 0x5f91c407, 0x601a48f3, 0x879818b6,
+	0xa0a1d1b0, 0x8c81f904, 0x00b2ab6d, 0x5f9e8ffa, 0x8d44dee0, 0x4b1ec008, 0xed4ac295, 0xd3112bfe,
+	0x1385aa23, 0x31e3ac09, 0xcfe1b10d, 0x597c0a35, 0x989110f3, 0xbf72bf8c, 0x31e2d30d, 0x8bca16fb,
+	0x8214ef89, 0x5aa9ddfd, 0x61a99bd2, 0x0d45bfb4, 0x46ca42f2, 0xd2ce88f5, 0x706bce7c, 0xa2087813,
+	0xe6240f20, 0xbe7f696d, 0x076315b0, 0xe02824f2, 0x8a56c2a6, 0xaa887212, 0xf5a6b73f, 0x9ec2759c,
+	0x2e94bb55, 0x641291fd, 0xe907187b, 0x62c35d03, 0xea0805cd, 0x6210f43f, 0x9e13b935, 0x42a571ae,
+	0x381101c5, 0x21d5e256, 0x9a4aef39, 0x24de1fd6, 0xfc5c1033, 0x2d9b814e, 0xd647c96a, 0xde0e1420,
+	0x4b7f6808, 0x059035d1, 0x1dc7d30e, 0xb11a6c4c, 0xeb2978ba, 0x21019914, 0x26a89fd2, 0x0ccb9f81,
+	0x468adf8e, 0x1b9668a1, 0xd8759ea4, 0x6a66fd69, 0x00bccc45, 0xc631bf0f, 0x009b80b1, 0xcb7bdaab,
+	0xecac5467, 0x2ab34b04, 0x26a25d84, 0xc27ff4e2, 0x5c702b37, 0x2050ddfd, 0x190bedb6, 0xfde3034c,
+	0x5209e7d5, 0xbca82587, 0x1f68020e, 0xa43d2536, 0x4ffcaac6, 0x8adcad59, 0x7b1f1194, 0xb4ea6701,
+	0x64fa0ecc, 0xc11b6ceb, 0x4a189b8c, 0x890d46b8, 0x3a03cc12, 0xd63dbf52, 0x8d524189, 0x7874a2f9,
+	0x8222c9cc, 0xfc22a121, 0x6c6d9cd7, 0xe54008ac, 0x6626251a, 0xbf3bd794, 0xcdd20595, 0xf6bac4a2,
+	0x688484fe, 0x1f10da85, 0xd50d6bfd, 0xa2535afa, 0xd01fe133, 0xca2b4231, 0x1f5dcb30, 0x33d08ffe,
+	0xdcdf9582, 0x57ce57a2, 0x77a3f9bc, 0x1d6a2439, 0x9ecd1f93, 0xbcab537e, 0x038396d5, 0x4258ca6d,
+	0x2213bfee, 0x5f671a38, 0x5d02b45f, 0x00094e4d, 0xd48e1f2b, 0xfe0cf581, 0x0b9bc22a, 0x9887198c,
+	0x8d921024, 0x13133396, 0xf7447c6f, 0x5e9289d6, 0xcb130b8e, 0x8ffd5a97, 0x3d8fff63, 0x2a45656f,
+	0x04d54cf4, 0xe3b5c410, 0x0602c796, 0x16e4ddc3, 0x5f860ebf, 0x677748eb, 0x1463cf3f, 0xd81756c6,
+	0x0c7e44e1, 0x291f666d, 0xc518433b, 0x5398d09d, 0xa9b5450d, 0x1d947f2e, 0x029e88ce, 0xd37e20b0,
+	0x0549fd24, 0xa71fa578, 0xf6afa9aa, 0xae1de82d, 0xb0496f99, 0xae62a322, 0xd029140a, 0x1fed2e02,
+	0x431c322e, 0xe70a1fa0, 0x68cd0957, 0x1eb67f98, 0x717c88f5, 0xf2eaaec5, 0xb7bc03ce, 0xa7a039a0,
+	0x6a7536d3, 0xc4efe5cb, 0x680b5e73, 0xeaad8fcc, 0xc506a521, 0xaed73ddc, 0x243d423b, 0x7e04c550,
+	0x5f282a68, 0xe5dab6ec, 0xd97e31e0, 0x652bdd77, 0xb375dd49, 0x2bffc0a6, 0xc5a0420d, 0x4db902d9,
+	0x2d55e20c, 0xf89c443f, 0x40a91a37, 0x5d81f62d, 0x1e38646b, 0x95fc301f, 0xb793993d, 0x7e044217,
+	0x6d880ae3, 0x95bec4ff, 0x7497c2dc, 0xa6de50d0, 0xfe1ce4af, 0xd96576f3, 0xc82b2ee5, 0xe393529e,
+	0xee66dab9, 0x1d8c5020, 0x234bff47, 0x5d1b415b, 0x1f3cf7c6, 0xd0b1b01b, 0x5b1d2d04, 0xb64664c2,
+	0xc61adac8, 0x980c4392, 0x514a5488, 0xbb0bd81f, 0xb774a2ee, 0xb357cf11, 0xe40eb48b, 0xa1f9df84,
+	0x11dc0759, 0xbe208024, 0xc9ca7ede, 0x01f85ef8, 0x92b4d4c4, 0x8f122ed7, 0x02e3fd04, 0x16a88537,
+	0x5a7a4c88, 0x57c09396, 0xdd5638ee, 0x2ca31679, 0x07e1f343, 0xbf7dafce, 0x5c56db0b, 0x2052d664,
+	0xeb111915, 0x98a8733f, 0x57cb4c08, 0x6a2698c2, 0xda917ddf, 0x2d9d99ca, 0x05cc12aa, 0x88c782b5,
+	0xcf047ff3, 0x2dabec91, 0xb649a413, 0xfa16611e, 0xa1b03e2f, 0x39c6211f, 0xcc7b3640, 0x6e76ea37,
+	0xd9e56819, 0xc51cddb3, 0xbc5ee505, 0x703830dd, 0xc101d661, 0x7a01f613, 0xc3529d0b, 0xc3e53eb4,
+	0x2c0e5983, 0x3986873d, 0x1a965038, 0x9660893b, 0xe46abafd, 0x4f919726, 0x5a7a658d, 0xc17ac0ed,
+	0xd0de3529, 0x214573bc, 0xf801948c, 0xc6356b38, 0x7d610297, 0x066475d6, 0x031e9dc5, 0x0f7722b0,
+	0xcb8eb408, 0x441e898c, 0xeb8e1954, 0x51994c1f, 0xdef5fdcf, 0xe92c98ce, 0xe2f76f73, 0xde4d2523,
+	0xf97f3a71, 0x66225931, 0x4157307b, 0xad5fbfbf, 0x74310dd2, 0x9a59a980, 0xd2f2bec0, 0xe9eb5472,
+	0x721408b4, 0x7f36fb41, 0x9f61b559, 0x3a85448d, 0x1c419d8b, 0x1d8610ce, 0xa6d61f9f, 0xf76387c6,
+	0xbe6e6a72, 0x7040f807, 0x480b765e, 0xce90a97e, 0x7409fb00, 0x6092182a, 0x2ee40ce5, 0x5a3accc4,
+	0x6e13e722, 0x3a1a3f05, 0x2114ea80, 0x85b20cb6, 0x5ee9f847, 0x5d8bd9ff, 0xe2ff534d, 0xc08bafbd,
+	0x6c5923f7, 0x8c4a2792, 0x3c586504, 0xfef3b58b, 0x2607c4de, 0x79000e3c, 0x4048c13c, 0xef29edb7,
+	0x709095ca, 0x58a09e9f, 0x47d02768, 0x945e3523, 0xca9a2930, 0xdec1784c, 0x841a9e0a, 0x3b48df39,
+	0x2f37771d, 0xf0913d9e, 0xb8edf2bd, 0x7952daf8, 0xa9b8c23e, 0x68438422, 0x5a560e99, 0xcb00e672,
+	0xd594c023, 0x3b23edbd, 0xe1a5c316, 0x730c9096, 0x1224f157, 0x27721cef, 0xa9b26b3e, 0x0f6047dc,
+	0xf1cd62cb, 0xe1e19b15, 0xcf625e35, 0x54c65bbe, 0x73460ec6, 0xb34abf63, 0x1dc8ffbf, 0xa8523beb,
+	0x66441af6, 0x1bf93187, 0x9eb21fdf, 0x5786915f, 0x0bf49b29, 0x147f300e, 0x57aba768, 0x7be146cd,
+	0xf0fc3247, 0x82444550, 0x4b13ed1b, 0x99560f27, 0x9e173289, 0x90a4b4a5, 0xd01fc1c2, 0xcb2305e5,
+	0x78e6b3cd, 0x0510097a, 0xe2ee03f4, 0x56ba4c64, 0x83528f9d, 0xcc14640a, 0xfaeb28e3, 0x2e0e9c12,
+	0xe991a2c4, 0x1f8a8005, 0x5e424dcf, 0x7a8f281c, 0x375a02eb, 0x38154ad0, 0x5a6134c7, 0xa81ab7be,
+	0x412ca459, 0x36017e56, 0x3623dc2e, 0x08b172a7, 0xd6bf22bc, 0x5453c7de, 0xfddd22d2, 0xd575660b,
+	0x1264e77e, 0xe9fda8d4, 0x50b69871, 0xd997830a, 0x2c05ea22, 0x0e58dc67, 0xb585a51b, 0x3c0c3151,
+	0x0641f399, 0x27be90e3, 0x336d9308, 0x6d69b8a3, 0x5a0a07da, 0xc4981c36, 0x816ef622, 0x24965c72,
+	0x07d9ad71, 0xf5856ec7, 0xe70a80a1, 0xd2529a0b, 0x98b447ee, 0x5249e8dc, 0x0b77acfc, 0xa3ca48cd,
+	0x02276f99, 0x2a66f5a3, 0x1f141d28, 0x92cda90b, 0xb9a74927, 0xc1571abd, 0x06fc7ccc, 0x54ebd76e,
+	0x71ced7f3, 0x2f1c532d, 0x67d34181, 0x6cee47b8, 0xe5677e58, 0x7fe4b44c, 0x642df9fe, 0x943fdf23,
+	0xa0396f87, 0xb36b03c2, 0x977b7a91, 0xa600c69b, 0x76c44611, 0x29a4727c, 0x8e2e34ed, 0xb759c577,
+	0x341a631b, 0xc2eddb1a, 0x56695b13, 0x7242089d, 0x6593413c, 0x68b6bc9c, 0xade03fb3, 0x1bfd0e6d,
+	0x2aa36e8d, 0xdb8afcb3, 0x98394147, 0x6648143f, 0x47bdfd8c, 0x8a8a009a, 0x842b8591, 0x4865230d,
+	0x46347099, 0xe1c9999b, 0xa4b94e89, 0x340085b9, 0xa4fe188f, 0xb1398277, 0x6ea16e21, 0x284b4e0c,
+	0x9fdf63cf, 0xd9bc71aa, 0x134f5097, 0xa6d67063, 0xcd9f80df, 0xdf84628a, 0xfc7fa5a6, 0x1c82b35a,
+	0x50ca0abf, 0xde6f3069, 0xcf4c2fcb, 0xf6ce265f, 0xb7368bfb, 0xaa69acdb, 0x13999314, 0x9792a9fe,
+	0xb562fe6f, 0x1d073d1e, 0xec55482c, 0x0d18790f, 0x94b78654, 0x6471087d, 0xe5662386, 0x910cf02c,
+	0xbfbc97be, 0x0ee7d047, 0x825455b1, 0xb4419dbe, 0x4b8150c4, 0x7a4f289d, 0x2969bb7a, 0x523d2028,
+	0xed98ac75, 0xaad7e015, 0x5a827fbe, 0x09943403, 0x02880243, 0x827bcad6, 0x9e056cf8, 0x204212e5,
+	0xba08b1d7, 0x6eb55273, 0xf81179ef, 0x674f6f80, 0xd00695e2, 0xe226fe8e, 0xd6d468b9, 0x198e7a70,
+	0xe09fed24, 0xd7a172ed, 0xcfe6e772, 0xf44ffc74, 0x7d5694a7, 0xa5d8c009, 0x9a1a5230, 0x3549a252,
+	0xe188913c, 0x58da1f52, 0xf493767c, 0x6284129c, 0x7b66ce52, 0x8a6496db, 0x85507bc6, 0xda358301,
+	0xae8ca742, 0xafc57288, 0x973b1787, 0x71c42681, 0xfb3c2934, 0xa9197fb8, 0x227a6c47, 0x4d3fee62,
+	0x663e1bf8, 0x4186067d, 0xa7c13e4f, 0x9dafe570, 0xe2ec42fd, 0x62636f19, 0x24c6ea95, 0x123d49b9,
+	0xdf727a03, 0x1ad2e7d4, 0xe898df23, 0x85cd1712, 0xd2e6fcf6, 0x263f1047, 0x257c471f, 0x5797340d,
+	0x915a6556, 0x8a3fa690, 0xdc638604, 0x3f4a9c63, 0x0a919ea9, 0x7666bc23, 0xb35f8caf, 0xfe10a02f,
+	0x46f96c54, 0x8c9ef628, 0x14429b58, 0xf60efa4d, 0xd1ea1130, 0x0a8e38a9, 0xd1f90775, 0x1707a66b,
+	0x215aa30a, 0x9bcd79ec, 0x6f986e25, 0x101ca9cb, 0x54c0d5de, 0x7c8619e2, 0xa3216c33, 0x60587974,
+	0x81024247, 0xb47431dd, 0x3a7e6a26, 0x2d746c94, 0x2d527f6d, 0x7f5ccd80, 0xa8046ed6, 0x5dd9de9b,
+	0x6cf55b8e, 0xbf461f05, 0x1b5d6988, 0x8a3fbe28, 0x1f7dc56f, 0x450c4cf3, 0x98787de6, 0x5926d317,
+	0xed7af929, 0x03a6ae24, 0x9563e637, 0x0c93e75d, 0xf610a8e8, 0x94fa99e9, 0x5fe06a08, 0x955461dc,
+	0x023a9ece, 0xc082f4dd, 0x1dbc6632, 0xcbce891a, 0x7dcb66cb, 0x8ddfb01e, 0x9a61bfb5, 0xdf30069e,
+	0x42178c1c, 0xc27be1bd, 0xfeb81dd9, 0xf0cd6f00, 0xeb486c73, 0xe3049f3e, 0x449733cb, 0x16c8d42a,
+	0xf9640aef, 0x6c84412f, 0x80c4d423, 0xed0e1d8b, 0xc9b569ac, 0x806b060f, 0x4fdade77, 0xcff8e9e9,
+	0x87d7e017, 0x6bd8cbf4, 0x42263562, 0xf3ec68f3, 0x97ea08e8, 0x41ff86c5, 0xf27e0ae2, 0x6ad75990,
+	0x1bbffc98, 0x0dc543e8, 0x427d6dc3, 0x9c9bd7b0, 0x1d4fc73c, 0xed808bd1, 0x9000ca90, 0x3f0f1e20,
+	0x715353ea, 0x331bed55, 0x2bc4e1f4, 0xc488649c, 0x8307217d, 0x855e00c2, 0x81460187, 0x47235731,
+	0xb925234c, 0xbe9f37a8, 0x874cbac7, 0xb27b3f02, 0x11f249be, 0x3f1dca02, 0x36cde9f2, 0xa8044bbf,
+	0x01716557, 0x553fd113, 0xf900a1cf, 0xf99c77ce, 0xad757e93, 0x61780b93, 0x7569e942, 0x4a469e1e,
+	0xae72233e, 0x04692ee8, 0x4980cd87, 0x9d5d08a0, 0x647d7371, 0x546377da, 0x47f26c59, 0x296cc9df,
+	0x2c6202c8, 0x069f2342, 0x1b9e3d5b, 0x59c9da2d, 0xdb925398, 0xbca893d5, 0x9c6c3a32, 0x77db2033,
+	0xc46f290e, 0x85ed136c, 0x4830d267, 0x2fe0d3f1, 0x4cf3bf4a, 0xbcf4c37d, 0x1f8818c2, 0x1369a0ec,
+	0x436cfc4e, 0x79fa1bf1, 0xd288a283, 0xbc158af3, 0x82db6d71, 0x94d72bd5, 0xae22c043, 0x8e78f19e,
+	0x0e75e4fd, 0x992bd8f4, 0x7df86e07, 0xaa29ae0e, 0x2120f604, 0xd2f2a788, 0x2404874e, 0x65219114,
+	0x995db5ec, 0xea4e7d3f, 0x27963457, 0x78125ec4, 0x92ca9aa6, 0x0e5fc15f, 0x92fd26d0, 0x947b94d3,
+	0x18bd30d5, 0x65151456, 0x148cb6c2, 0x0f213177, 0x3bd97988, 0x21b7bbf8, 0x246283c1, 0xb79e79d3,
+	0xeb086120, 0xba19a780, 0x8d83b13f, 0x80b1653c, 0x29f3993d, 0xc8ddca88, 0x25f4a5df, 0x9462f1a1,
+	0xd099f856, 0xf742915a, 0x2b497ad8, 0xc5d5881a, 0xb98b258a, 0xc6731d4f, 0x6836394f, 0x7095918c,
+	0x65eeb31f, 0x5f28e894, 0x89e75809, 0x5a3eaba5, 0x01a1c696, 0xbde0baec, 0x2b8a0f22, 0x86120475,
+	0xfe3bfe18, 0x16ef28a2, 0xcfd7451f, 0xc4b5fa6a, 0x30b7999e, 0x65dd71ce, 0xbd8517fa, 0x542b80ec,
+	0xc5e7577a, 0x253888c2, 0x490e53cd, 0x9bf6395e, 0x9d54db26, 0xfdf5e235, 0xcb88c7ea, 0xd70e190b,
+	0xfc8b45e6, 0x74e7d938, 0xb48deabc, 0x44bc9b60, 0x472b1bec, 0x2f43af69, 0x7b5154ca, 0x16594e5f,
+	0x93e4442b, 0xd8a859a5, 0x16c33bde, 0x294daaa1, 0x2f631aea, 0x37d9f84c, 0xadeb48e7, 0xd1a0879d,
+	0x72c9e2eb, 0x11ebda39, 0xb4bc352d, 0xa5de5a73, 0x23846e3f, 0xfb3e1bcc, 0x965a9473, 0x6ccb1cca,
+	0xb6c6ae87, 0x54646bf1, 0xecc9a502, 0x8825354d, 0xbf05a06d, 0x544d9d8b, 0x3dda4e17, 0xfd5df7f1,
+	0x2feb0029, 0x0bc8ad57, 0x4399d63d, 0x79c49251, 0x5fa03372, 0xc61da722, 0x9e8cc9fa, 0x07b32acb,
+	0x2a7245c3, 0x1de0daf9, 0x1e71b1fc, 0x652794d0, 0x74e5218e, 0x2554feec, 0xb11448b7, 0xf4b1c9be,
+	0x08a20a2a, 0xb1616eb8, 0xa06de8a1, 0x8d3bcd81, 0xd3d09818, 0x60476f1a, 0x0affbcc0, 0xae88758b,
+	0x1811a6d8, 0xb9abd7cd, 0x1362ffa8, 0x13b25843, 0x42cf1fd3, 0x7c2ccd49, 0xa877890c, 0xf6648eb6,
+	0xf6cdf2bf, 0xfdeb99ae, 0x889926e4, 0x2660a606, 0x1b37c58d, 0x81f5a89a, 0xf13d1bee, 0x1657a6df,
+	0xc7d9e3d2, 0x0a3ccd4d, 0x60d3f5d5, 0xb144eb33, 0xf0e0255f, 0xaa83961b, 0xeb43740d, 0x7a226828,
+	0xda81c5e6, 0x323f206f, 0x70acfd0d, 0xf8358b16, 0x2fd46afc, 0x5ca3bd3e, 0x493deb5c, 0xbca9fe6e,
+	0x2a844384, 0x26bd07e5, 0xf3e428f3, 0x876d20ca, 0xee4fdb1f, 0xd18d8911, 0x064c54c7, 0x1bcf8b27,
+	0x9d63f796, 0x913d9329, 0xf5bd4ee8, 0x14a14d4c, 0x4f5db220, 0x4cfa62fe, 0xd9bf616b, 0xf8f1c2a6,
+	0x0ef3d4d0, 0xe4666e66, 0x9569cf62, 0x2294579f, 0x3c528777, 0xa7577c00, 0x97d4f7a0, 0xfb2994fd,
+	0x20579d37, 0x2fbd8920, 0xbe177453, 0x8b39052d, 0xbbb1fd76, 0xfe12a1ec, 0x8d58e62e, 0x1f29584c,
+	0x5fd118e0, 0x59543da9, 0x4687c492, 0xfbebc421, 0xb7d51dc2, 0x84948de7, 0x6e986769, 0xe172f00b,
+	0xf4ad1776, 0xfa2df8e1, 0xda017312, 0xb5c229cd, 0x8a48ad95, 0xc26b400d, 0xf2684dc6, 0xe29ab91a,
+	0x0d5d1f60, 0x09205cda, 0x921ddf12, 0x49963fd8, 0x28b2e6eb, 0x84eaf4bf, 0x2dd815c1, 0x82d7edbd,
+	0xb12b69a7, 0x5362ad01, 0xbdea3895, 0xe10b0bcb, 0x6e22d7be, 0x0a4a5913, 0x7937c2d6, 0xef2b909e,
+	0x0a0fbe03, 0x1579ab77, 0x070b49de, 0x0542b61f, 0x45cdb4ce, 0x4fb3ff47, 0xdc0822fc, 0x04d86768,
+	0x00d0ff72, 0xed1ec185, 0x2220dd8b, 0x94194396, 0xbf337ba4, 0x606803d5, 0x1018c9ff, 0x996934d4,
+	0xf6136759, 0x82da90ca, 0x361b4468, 0x97785e1b, 0xd75d9064, 0x02486215, 0xab960ddb, 0x1be8c14e,
+	0x0e4e3b4b, 0xf1533017, 0x1c71e41e, 0x07cd5a1e, 0x21407b0e, 0xe2140e26, 0x5a36bb62, 0xd51574e2,
+	0x605698e9, 0x9a1d3ac6, 0xebcf4e7f, 0x00217b2c, 0x89eb3908, 0x8ab502db, 0x41dda57d, 0xdecf7ea4,
+	0x1c376d8f, 0xa4c4e948, 0xc8b6022c, 0xd156de09, 0xc7366e32, 0x3b96cc20, 0xd655c605, 0x135e57d5,
+	0x0a9e4bde, 0x8d4627b8, 0x1e633e2e, 0x0eed904e, 0x69a0685d, 0xb4cb3989, 0x6d2cf493, 0x8ac0eddc,
+	0x002e5197, 0xde92b14b, 0x481b2098, 0xea934503, 0xd2dcd0e6, 0x4ac55950, 0x6a5a11aa, 0xbebeacea,
+	0x456f579b, 0x80ad16dd, 0x82c931db, 0x0521473d, 0xfd293c69, 0xe90de26e, 0x1eb99657, 0xf4effeee,
+	0x7b90e562, 0x266588f6, 0x86b62b5f, 0xa62dd447, 0x8c7ba30d, 0x2e5f18cb, 0x9ee2f68f, 0x5660d1d4,
+	0x53a48e71, 0x76f8ef3b, 0xdcdd632f, 0x34f05071, 0x2821e7e4, 0x869722bd, 0xf4e465f2, 0x565c6b52,
+	0xb64e3270, 0x3468d006, 0x38d10f1d, 0xcc6c7dbe, 0x54faa96f, 0x16b5c469, 0x7ddea50d, 0x5e8e5f3b,
+	0x5e340a3e, 0xa2b6c0a2, 0x12dc3e3c, 0x0936389a, 0x626b6b03, 0xb26ffdd2, 0x0cc473cc, 0x5d74dfd8,
+	0x1a9ca72a, 0x0204cb1e, 0xcad5fbdb, 0xfd23f524, 0xe1531fec, 0xd0b648c9, 0xcfa4fe5a, 0x2e647bc3,
+	0x01111f3f, 0x8e40048e, 0xc18bb489, 0xa684c10f, 0xa5381b56, 0xa8a63b90, 0x5cf0e87d, 0x1f793bcb,
+	0x6a9d33c2, 0xd54559df, 0xaabbc110, 0x6ab6d436, 0xaadbf0a5, 0x0c9f7584, 0xac4e0415, 0x9d91d54a,
+	0x89300409, 0xe7a64f7b, 0x64688536, 0xee32786c, 0xb405dc44, 0x08688a23, 0x36587286, 0x81599187,
+	0x2c958137, 0x8927e5c1, 0xcfbfa80b, 0x3b401d0f, 0x5cd00a0d, 0x0c54ecf9, 0x9188d2fb, 0x648ef995,
+	0xdd7e448f, 0x08eb95a1, 0x98df4edb, 0xcc117c9f, 0x4ab67d79, 0x82f99964, 0x249fb59b, 0x4d6cf593,
+	0x4e53040e, 0x98d6e187, 0x400bfd2c, 0x02b32962, 0x15a226f1, 0x7f6efe8e, 0x9707cd80, 0x1b60f351,
+	0x76883b4b, 0xe567746c, 0x6be2d5de, 0xebef488f, 0x4f0556e1, 0x4db122d9, 0x9e2f542a, 0x30cbd18d,
+	0x3075f189, 0x7369bcbc, 0x46e8a335, 0xe8377008, 0x7d84608c, 0xa6392b5f, 0xfd4c1270, 0xe2d13f6e,
+	0x186d718c, 0xe4380371, 0x6aabec76, 0x7fe67f2b, 0xddd49731, 0x6328ed74, 0x2ad638d6, 0xd0147575,
+	0x4b9f740a, 0x85caa972, 0x29382aad, 0x804c072a, 0x5dc79f76, 0x23e5ee0c, 0x0dd25386, 0xb8d573e6,
+	0x70be2aa6, 0xcf8c5df0, 0x67be02d0, 0x6ebf2770, 0x824af15c, 0xb5df8488, 0xe12e969d, 0xdbbe1d19,
+	0x4c55bac6, 0x23567ce4, 0x9ea7c4e0, 0xc7d683fe, 0xd7aa9283, 0xa254dba6, 0x206577b4, 0x852e4264,
+	0x339c9323, 0xded7ac7f, 0x17845da0, 0x17abab2d, 0x20305eff, 0x72eb3eb6, 0x61d60215, 0xab21c29b,
+	0x13fd9f17, 0xfdca40af, 0xf6d874df, 0x8eb2a9a7, 0xa3810201, 0x1ea56f9b, 0xe9634b4a, 0x00d9164e,
+	0x83ad0c3d, 0x3e3c8ed0, 0x41cbbea8, 0xbb2e68c4, 0x15f8122f, 0x1540100c, 0xf2cd5d85, 0xce1763e8,
+	0x24554efc, 0xfe72852e, 0x98431617, 0x754cab4c, 0x2eef0f51, 0x47e3022a, 0xece1353e, 0x9107ce80,
+	0xc4558a0b, 0x48837665, 0x3148a346, 0x3f86fe5c, 0x8eb24dbc, 0xb6278f8b, 0x8da92133, 0xa576f055,
+	0x9821ce30, 0x931d4e07, 0x6999955a, 0xa67579e4, 0x7eee9263, 0xebf33da1, 0x5f8a0313, 0xd29e38f1,
+	0x1214e644, 0xedb11a10, 0x2f1b416d, 0xed500c24, 0x10ca7287, 0x1808a42b, 0x3ede9a73, 0x34511535,
+	0xd735826f, 0xb4ef73ea, 0x947336b5, 0xc7008dec, 0x4c129f5d, 0xf93e6e38, 0x9b54876d, 0x78d1c26e,
+	0xc898bcbb, 0x5634d668, 0x66d74110, 0x1700f9fe, 0x00068a9a, 0x03623893, 0xb56ec2e1, 0xcc904218,
+	0xe4c6951b, 0x1017700c, 0xdd8ccb64, 0xcc8567b6, 0xacd0270b, 0xdf00726f, 0x74641e4a, 0xd858a83f,
+	0xf905974c, 0x9492fd53, 0xc08b9134, 0x4540ba5c, 0xff629fb6, 0x9cde8cd9, 0x7d855135, 0x3b161df0,
+	0x4cf1bdeb, 0xe0500012, 0xddd10539, 0xcd9cca25, 0xc420b83e, 0xdf16fe05, 0x57ea3286, 0x4917af1b,
+	0x2c00a113, 0x0d0171e2, 0x01ec1817, 0xc9c09c85, 0xe154590f, 0x6a2592c3, 0x9eea58db, 0x6390bbb3,
+	0x823c05e2, 0x0aeaa7dc, 0x2deb8f34, 0x1c0b0c7d, 0xf30f7eb3, 0xff0d453d, 0xeb201b51, 0x0359f313,
+	0xe787e41f, 0xacbe2710, 0xa7684f7a, 0x29b0e3e6, 0xe49718d5, 0xb9334ebf, 0x090e15da, 0x1a2cedc1,
+	0x0ddb2863, 0xb9bc53f1, 0xbf737746, 0x1a44f2a7, 0xb1d46d10, 0xe2ace829, 0x62565f82, 0x1fdf0036,
+	0x4d725ab2, 0x9168084a, 0x93f8968f, 0x97d54e9e, 0xf0a1b265, 0x918a4111, 0x9af8f346, 0xf154703c,
+	0x93f18e69, 0x610a4cd9, 0x13aeb578, 0xf2174921, 0xac7a4c54, 0x3cc97184, 0x23e217d8, 0xe3f01ef4,
+	0xb7c3f1f8, 0xfc7a5236, 0xceffa9fe, 0x866ed13a, 0xc2d08fce, 0x01ade2d7, 0x5cd72e45, 0x70346cfd,
+	0x32aacff1, 0xf4bb46ba, 0xef4bd3d1, 0x799678c2, 0x0bcddf59, 0x8fc7baf3, 0xa8a72baa, 0xd1ecf849,
+	0x572b4951, 0x6c9aeb6d, 0x015a9716, 0x8a7d28c5, 0x106ad370, 0x8b719b3a, 0x7db421cc, 0x18fe72d3,
+	0x9b1953b1, 0xdcd90ad3, 0x326ecdcc, 0x7456f1e5, 0xe0e82671, 0xa5061d3f, 0xff4d214f, 0xb07ec71f,
+	0x81320fb3, 0x8dfa1836, 0xebf39c74, 0x9cb09f5a, 0x9ff64eae, 0xd91c1304, 0x77ec1ae0, 0x26523ea8,
+	0x0fb85fc8, 0x70f7ff03, 0x7f524d2c, 0xd31a1260, 0x368c785f, 0x159befd5, 0x6c0bade1, 0xea57c40e,
+	0x9f8f4b1d, 0xf9eff134, 0xf2f3a964, 0x6d592caf, 0xbcda9a1e, 0x9208ffae, 0x2d742d3f, 0xaa8d207e,
+	0xb6e698ba, 0x553e348e, 0x584ef0d3, 0xf37996ff, 0x739438a3, 0x47e9a8e3, 0x8ef478c1, 0x97ac95d2,
+	0x80a5fb5e, 0xb98e92a3, 0x29aa3ef1, 0x6a99bcaa, 0x242127e0, 0x2f6ae2ce, 0x12140965, 0x12ec6cdc,
+	0x6b19836d, 0x91b2fa15, 0x8c6114fa, 0x14e25480, 0xccc8d39d, 0x685a9c79, 0x5d03d6de, 0x0cd44a70,
+	0x80282baa, 0xa03e49be, 0xe54644a6, 0x5df3dfff, 0x74961437, 0xa3569018, 0xecfd7d98, 0x3938df68,
+	0xf12babf8, 0xb03a9981, 0x10aee4b5, 0x616fd506, 0x3f746d08, 0xa01cca3b, 0x661aa679, 0xe5746ea8,
+	0xd46ade6d, 0x3fa45858, 0x48bd744c, 0xdba8399d, 0x3e31fc2a, 0x18c74b24, 0xabd229fa, 0x07db9181,
+	0x089555e3, 0xe331aa3b, 0xc873f8e1, 0xda553244, 0x78b50ec7, 0x2b3b346d, 0x9c8c148c, 0xa3853ce9,
+	0x918f11c5, 0x11126a6d, 0x80d82971, 0x554764d6, 0x5821ddac, 0x9809595f, 0x388be3aa, 0x3ba82c71,
+	0xae83fb2a, 0x6e0a9d9c, 0x70015b65, 0x97340154, 0xa485ec83, 0x8b383327, 0x7b94c3a2, 0x2ac9d0b7,
+	0xcae639bf, 0x41933b9e, 0xb0aa2b80, 0xf06ac382, 0x3719af27, 0xfda2cc59, 0x8312ad75, 0x2382135a,
+	0xc7021130, 0x42b57cca, 0x6f4bdbf8, 0x590cf670, 0x6bee0412, 0x47993502, 0xc2b737c6, 0xb1895a05,
+	0xd19416dc, 0x630e4e15, 0xbd987a23, 0x762a69b1, 0xd411cdd8, 0x7771ee20, 0xc195f41c, 0xdf352d17,
+	0x36d1976f, 0x24dae2fa, 0x6d4a6055, 0x9f131d6a, 0x0cf908f1, 0x7b5a11a1, 0x66c60f98, 0x9099c58d,
+	0xe5a35a35, 0x045a6cb8, 0x1ee5f56d, 0xc228bfce, 0x47a8e76c, 0xc63bd080, 0x87673bc6, 0xdbf06555,
+	0xd2c849f3, 0xb9b60893, 0x3108aea0, 0x8955684f, 0xfc549faa, 0x740881a6, 0x2cf9c892, 0x5d53fdbb,
+	0x4913340c, 0x66e67959, 0xf6d6912d, 0xb92d41a4, 0x500d3edc, 0x15c0a0c0, 0xf5ba8289, 0xedc9e227,
+	0xcd25827c, 0x8111a2f1, 0x8ab3f4c6, 0x40c201c0, 0x82b5b38d, 0x4b9d7232, 0x5fa68c0a, 0x94e1036d,
+	0xec5b9178, 0xf8012ce1, 0x30a3a302, 0xdad016cf, 0x4dcc180e, 0x41aefc83, 0xee2dac3a, 0x472e8cee,
+	0x7e755b0e, 0xdce9004a, 0x6b8ccb58, 0x8b87ec49, 0xc34c7710, 0x9e75a48c, 0x61948fa6, 0xea4d5a70,
+	0x9e6c0309, 0x45b6baa1, 0xbc1007d5, 0x2d3f5abf, 0x20385c06, 0xdd538c12, 0x5130135b, 0x66bbb88b,
+];
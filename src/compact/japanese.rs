@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0x29b35a38, 0xaca10126, 0x4d1f60d4, 0xb93b8734, 0x12ff7ebd, 0xa8d82346, 0x82ce2734, 0x812be6f2,
+	0x355e06d6, 0x4ccb9f44, 0x3acb82ee, 0xbc313968, 0xa1d54a06, 0x794e0771, 0x47e30d03, 0x96747451,
+	0x8c1a6e8c, 0xa923f4a5, 0x1016f1f1, 0x6af0c1fc, 0x3fcbc915, 0xc4c2af02, 0x68829606, 0xc29993f0,
+	0x4382d4a1, 0xf5979685, 0x2960fc92, 0x08b018e1, 0x90481800, 0x92481b26, 0xcf853dc3, 0x39105a43,
+	0x7d6b0c49, 0x748c2194, 0xc508e7e2, 0x7b1079fb, 0x1ebe5659, 0x422ced60, 0x7387a09d, 0x35c91448,
+	0x063776f8, 0x67f2fd0d, 0xb083979e, 0xbe6f8da2, 0x7db3d76a, 0x3c7354eb, 0xa637ca98, 0xada7e0d5,
+	0x529b403f, 0x6595b746, 0x5f9b54b6, 0x70750cf0, 0x4ad68f17, 0x102dd943, 0xf8e80936, 0x85708af5,
+	0xb8e8028b, 0xa361ea48, 0x9e61e269, 0x6496fe9c, 0xf41e8739, 0x20eccbdb, 0x3e52bd3c, 0xb3f1c9fb,
+	0x01d5e71d, 0x7f116539, 0x1ae1d3d0, 0x007e9826, 0x097ea651, 0x1e7ec760, 0x4a1410cb, 0x19cef46c,
+	0x64c0a9cf, 0xcac44d4e, 0xb1d6b838, 0xf9a9a598, 0x4ec24b7f, 0x21be3311, 0xdd0606c8, 0xb5f23fee,
+	0x66e0da67, 0xd605fbc3, 0xd9b0b7e2, 0xba026c0a, 0x2e85648e, 0x5dce2494, 0x58ce1cb5, 0xa3829f0b,
+	0xf2ae825d, 0xfbfdcacd, 0x978fd7de, 0xf33ee2ed, 0x8d1e72f6, 0x5ed0eb15, 0x47743e97, 0x5e073b79,
+	0x5b09e002, 0x38037cbf, 0x386d8ebf, 0xb6eebd47, 0x1aa684fd, 0xf206764f, 0x2c90032a, 0x0da4e384,
+	0x1b74b62e, 0x94c7651f, 0x195472af, 0xeec9cd56, 0xf9fd9cb9, 0x02fdaae4, 0x259ca296, 0x4fa67d0e,
+	0x4daa4b7f, 0xc25dd1cc, 0x4b78aa3e, 0x4163c27e, 0x2333d6a8, 0xbeef9d73, 0x75501aff, 0xbb931071,
+	0x08ff090e, 0xbe36696e, 0x38a67ffa, 0x2cea37ec, 0xc29e5599, 0xe88c3705, 0xe56435f5, 0x7581d95f,
+	0x592b825f, 0xa3aaffab, 0x06fcc7e4, 0x2d3f2bfe, 0xa7277db0, 0x6dd607f6, 0xf9488037, 0x50d208b8,
+	0xece34b84, 0x8eba4ae1, 0x8cba47bb, 0xd0a66faa, 0x30cdb878, 0x14fcdfa2, 0xbb7e7106, 0x048dbc8e,
+	0x1f93e194, 0x46b864a0, 0xce84b142, 0xf087255f, 0x799976a8, 0x2defca86, 0x98d95bcb, 0xe31270e8,
+	0xde46a7de, 0xf0d09dec, 0x2ccb46b4, 0xa0e96199, 0xfc663ff7, 0x0833a46e, 0x872bcaab, 0xaf9e04ee,
+	0x59370972, 0x0631f403, 0xe6a4cd6c, 0xa7776b95, 0x9a74ce8e, 0xd59722be, 0xe484959c, 0x720e8f89,
+	0x3c1399af, 0x9352a8b6, 0xaf954172, 0xac0f0f39, 0x0c403ddf, 0x0d83919f, 0xaca86bb6, 0x9074eec4,
+	0x9814004f, 0x9408e461, 0x145b50ab, 0xc33425e9, 0x30f9d130, 0x9a0879da, 0x25d7c489, 0x68bcb9b5,
+	0x09c6d74a, 0xb09f9505, 0xd45e19a1, 0x5b5d27a9, 0xe0af3b02, 0x3276291f, 0x829491dc, 0xa7cf6c04,
+	0x2c6e6b65, 0xc6ee6223, 0x2bce4447, 0x15c71b4d, 0xaf4e86dd, 0xcf976704, 0xf02de7ec, 0xd1d1f4bb,
+	0x190c5bc8, 0x65cebaf2, 0x1143b654, 0x2562d6cc, 0x3d62fc94, 0x56a148d0, 0x8f251e9a, 0xfe1b1a35,
+	0xa3166ea1, 0x4b198a5f, 0x08a7d49a, 0x39f57b9c, 0xf028dd82, 0x673bb86a, 0x30d12422, 0x98488ce2,
+	0x5e2ba40d, 0xc846448c, 0xd16e1886, 0xd66e2065, 0x8ecf7d05, 0xece55d51, 0x20d787d3, 0x88cec80c,
+	0x5a893fd7, 0xaeee4a40, 0x5b1bcef7, 0x9664cd2e, 0x35fced0e, 0x2c90e057, 0x64855a5e, 0x559cf08f,
+	0x24eb537c, 0x2401657a, 0x77c6bcd9, 0x90994850, 0xd37113da, 0x3a749095, 0xb448207e, 0x200899b0,
+	0xf3f1167f, 0x38ab6b3e, 0xc0b80c17, 0xa1c9140f, 0xff6e362b, 0x9d2dbaa8, 0x5e6eb7aa, 0x1be666ab,
+	0x662559f2, 0x5433fe4a, 0xd87a19be, 0x332494f2, 0x6fa57df5, 0xe2aa6cab, 0x5c534eb6, 0xa6bb01fb,
+	0x5ffd6867, 0x61fd6b8d, 0x7d3fcc72, 0xedf78c2c, 0xb88672a7, 0x1f807103, 0xd32a3443, 0x4e56567d,
+	0x898faa1d, 0xf41c6edc, 0xda1393ce, 0x56fd437d, 0xe9e5acb1, 0x760e5e52, 0x269e8614, 0xb844bd05,
+	0x634b10a5, 0x0786f346, 0x49471620, 0x654742f6, 0x43673062, 0xb07ae5e6, 0xad0cb99d, 0x63657f2c,
+	0x4d3c0ddf, 0x3ab86165, 0xda22aa9b, 0x042b54fd, 0xc06b3de0, 0xb49bdc4f, 0xfc043b6f, 0xf4e6ff2e,
+	0x7fb82c6d, 0xb65cb8d2, 0x3b9214dc, 0x0a02478f, 0x0abe2851, 0x01f744ec, 0xd72fb262, 0xac31444b,
+	0x84f30add, 0xb07b98fc, 0xbeb879cf, 0x1e2f9644, 0x0c923940, 0xbfec40dd, 0xf3b8a669, 0x3e284ea4,
+	0x81599f6f, 0xc9b688b6, 0x84a7857c, 0x2e20e246, 0x173ea9c3, 0x7872c0cc, 0x1790a098, 0xabd0f23c,
+	0xa6eb43a4, 0xdfc4fcbe, 0x9778aac4, 0xc81caa1c, 0x866512a1, 0x94af4bad, 0xea25add5, 0x666c20bf,
+	0xa8bd8053, 0xc6ac2307, 0x9c7f110f, 0x1bcda75d, 0x634196bf, 0x1fce86fc, 0x934a943e, 0x4a7723a0,
+	0xf93de9fe, 0xe2930bcd, 0xf5d0e01b, 0x03679db1, 0x2fcf5956, 0x2f8220fc, 0xc23cd554, 0x743455a1,
+	0x6280b7a5, 0xc8ebbe5a, 0xb0eb9892, 0x98eb72ca, 0x55263514, 0x39526542, 0x4b06baf7, 0x59369a53,
+	0x7f707df1, 0x6270504a, 0x89b5a2c5, 0xa5aa3bec, 0x8f686446, 0x789b528c, 0xd65a7dd0, 0xf45a98e6,
+	0x696c2a26, 0x6e8271e4, 0xa3dd7ec8, 0x9315037f, 0x515fe598, 0x50759fd6, 0x33b85b40, 0x24cf37ed,
+	0x5642a745, 0x710c45cf, 0xa91af94e, 0xbe0bae18, 0x9dec7abc, 0xbfda1dfd, 0x19e2fa70, 0x68cc0af1,
+	0x92d14c61, 0x81923dad, 0x3cb776f5, 0x66271997, 0xfd9a69ed, 0x0d9b301f, 0x4d83b4f7, 0xbd453596,
+	0xdd4567f6, 0x420a3034, 0xab37e2f6, 0xb413942a, 0x7712888c, 0xbbc9f49a, 0x2358e2f8, 0x1c58d7f3,
+	0x0a58bb9d, 0xf057e718, 0x745e056f, 0x7d5e139a, 0x5ec862cc, 0xcf083517, 0x44580f1b, 0xc371b086,
+	0x8dd9f3cc, 0x815a757d, 0x8972b107, 0x785a6752, 0xb986e3c5, 0xbe86eba4, 0xef8738c7, 0x5073b9e4,
+	0x3e7d79dc, 0x93e157c0, 0x7e4909d3, 0x865d8837, 0xaaf43cae, 0x2dc10493, 0x178d23df, 0x1e8d2ee4,
+	0x378d563f, 0x6869ba67, 0x25eccb82, 0xc9e3ebe9, 0xf650ef53, 0x4671a9db, 0x50fb44c6, 0x278cd5a9,
+	0x07160c43, 0xba84472f, 0x8c94fe0a, 0xdab8f0be, 0xd3b8e5b9, 0xfddbe2fe, 0x336b5801, 0x10dc00e7,
+	0xa378ae80, 0x9a8bc917, 0x368302ac, 0x9d1ad3a0, 0x8302831e, 0x4800b011, 0xa13ac0e0, 0x66eae784,
+	0x651007a1, 0x203f0d0f, 0x5ce9c821, 0x7416283c, 0xfd32f403, 0x0c1ef6d0, 0x020beb8a, 0x34343d43,
+	0x91c762a0, 0xa0c77a3d, 0x678e0de3, 0xf13b977c, 0x86c3d908, 0xdc183f0b, 0x96e348ab, 0xd49bf710,
+	0xdf9c0861, 0x8baa6a99, 0x18bf6505, 0x38bf9765, 0x0a431205, 0xb9dcce46, 0x140da968, 0x3b20eae6,
+	0xff83d773, 0x8ccc1ab5, 0x56895ccb, 0x64beead4, 0xd83b6c47, 0x2b1ca975, 0xc757ccdf, 0x149447e7,
+	0xc8711cf9, 0x74d1ac2f, 0xcc041e1b, 0x2a72d4df, 0x59ee03c8, 0x466ecbf7, 0x99aa16eb, 0xcdb00cb9,
+	0x0edb45d7, 0x2325a77f, 0x43ff438b, 0x3aff3560, 0xb924992c, 0xe0f7ebed, 0x0b725870, 0x91b30c9f,
+	0x65775274, 0x62c474bd, 0x6fc5fc9e, 0x140254db, 0xaea7b038, 0x265ccc7a, 0x3d0b0c1a, 0x62b1bd47,
+	0xc9e3f4a3, 0x0418d048, 0x051cbb4f, 0x2bf2fca9, 0xd8c74a90, 0x8894f63f, 0xa41af602, 0xe1116edd,
+	0x1368f172, 0x1e824391, 0xc553d788, 0xc502d864, 0xcd647634, 0x74d6e6a3, 0xb35517e1, 0x518c91f8,
+	0x8d43dcca, 0x37a9e2e9, 0x61add0d8, 0x91b2f643, 0x78e56b99, 0x5619daee, 0x7225868b, 0x701c879e,
+	0xf92831a0, 0x3b0ac20a, 0x0f309873, 0xd5f43c75, 0x10d522f5, 0x8ce52897, 0xec1f2f13, 0x3f473d13,
+	0xf9fc36ba, 0x27f9e650, 0xa02f2a11, 0x8f238052, 0x0b7ee07c, 0xbee0c8ec, 0x98a758ee, 0xfa470cb8,
+	0xad6d44e7, 0x277f0c90, 0x320af2bb, 0x7d8008a9, 0x17b713d9, 0x73d7f0fb, 0xa8f10de6, 0x18cf12f5,
+	0xdc05294c, 0x1b79b219, 0x6198454d, 0x1456f871, 0x2d42d136, 0x28e4a2ac, 0x66f8a4c8, 0x8ec59b06,
+	0x190a8560, 0x28a28733, 0xbd3f99b8, 0xd75bfd67, 0x60951f61, 0x369f2f43, 0x1b05e012, 0x1f099e3d,
+	0xa3cf14dd, 0x4c09af46, 0xbb7d893d, 0x77c605ae, 0x6b9d72e6, 0xce7388d7, 0x022cab41, 0x242ce0c7,
+	0xc2ed5423, 0xcb01b601, 0x504699db, 0x018a86af, 0xa2e5f7a4, 0x05e7908e, 0xffbdbac8, 0x3fe62e33,
+	0x087a345c, 0xf00f6804, 0x1b11ea4c, 0xc4db6641, 0x1616f704, 0x94c194dd, 0xc89a9255, 0xfb236052,
+	0x17175c22, 0xfd236378, 0xfe23650b, 0x2a255c15, 0x626d516b, 0x7ad8004f, 0xf55533c2, 0x599b078d,
+	0x0786e359, 0x28b7789d, 0xeaad17f6, 0xd0c74d10, 0x5c948e7c, 0x75fe1c6c, 0x296b1d87, 0x3c389fb5,
+	0xf1235094, 0x5118ecdb, 0xd1563c8e, 0xf0640fc0, 0xc71f3cdf, 0xcf56d3d5, 0x54dd725e, 0x31e73334,
+	0x5e51a7f4, 0x0f010692, 0x5e0987d8, 0x941d8231, 0x3a30048d, 0xbcb468ad, 0xef0c163d, 0x29b48085,
+	0xac7941e7, 0x9af83f79, 0xe89263db, 0xcaa276fc, 0xb2635d43, 0x4d609543, 0x113756ba, 0xf7cc35ca,
+	0x975d477e, 0x6e0659be, 0x1a4d7fb3, 0x1210fd21, 0xa5397040, 0x4c4fcf0a, 0x8b1cdcf7, 0xcfac6dbc,
+	0x81a3ee09, 0x5478b249, 0x949bd8eb, 0x8d7cdd9b, 0xfbd280ec, 0xc208caca, 0x53f5c5c5, 0x6534d479,
+	0x5b0fb63d, 0x1709f099, 0xf21c76ab, 0x8f151f0d, 0x67271994, 0x71705db1, 0x4ff1fd13, 0xbd2b8f81,
+	0xd2a3eb99, 0xb284b83d, 0xa984aa12, 0xc113ebb9, 0xb7d00284, 0xbbb333a9, 0xf6d18d89, 0xdbb36609,
+	0x07eeb0a1, 0xe383c0d8, 0x78e4abc1, 0xbb8144a9, 0x906a2317, 0x6bc3c150, 0xf2cb519a, 0x6742417b,
+	0x1b2d7b1f, 0x1d021b09, 0xf829726f, 0xb91f1e17, 0x92b96905, 0xfa584b1c, 0x754d09c9, 0xc852b537,
+	0x2fd92817, 0xd21af27f, 0x2111744d, 0xbc0a1989, 0xdc28be7a, 0xa3fec29d, 0xb6338687, 0x9b1687bc,
+	0xb033188e, 0x126b681a, 0x1709c67d, 0x4cf16378, 0x6b6f4ad2, 0x6e6f4f8b, 0x61730cab, 0x665e43ae,
+	0x775e5e71, 0x8117c667, 0x165eaa35, 0x98087e57, 0x9a08817d, 0x95f6eeab, 0xbddb452b, 0xf1b1102e,
+	0x9743f141, 0x6fc17503, 0xa8d515ce, 0x45bd0307, 0x4e7071e3, 0xdc17cbd7, 0x01200551, 0x500cd2a3,
+	0x9ca8f58a, 0x52034370, 0x1dcaf533, 0x79403b24, 0x2f615d71, 0xc777ec23, 0xc2a15f7d, 0x7b156997,
+	0xa6c29df7, 0x28fbae93, 0x4a94bc1b, 0x9a1d0b51, 0x912be449, 0x9551af55, 0x97188d03, 0x05799d3a,
+	0x2ee17331, 0xf3a7e721, 0xb1646391, 0x5bdcd6f8, 0x45299ca0, 0xc8c69d76, 0xb357e6c9, 0xc6d23865,
+	0xdeca91af, 0xb89f2d35, 0xdb7ae48e, 0xf03a2671, 0x6571dc07, 0xc7d0608a, 0xded084bf, 0x9f3cad60,
+	0x57220afc, 0xe007a035, 0x2c88e99c, 0x7bee57c4, 0x5ca01d1e, 0x8bf0eae0, 0x93311e1f, 0x68a03002,
+	0x784df687, 0x179ef8ae, 0x1a43fcf6, 0xf38c8537, 0xdfc63452, 0xe8aee4f9, 0x0b29c9a6, 0xbbf00d3d,
+	0x1c29e469, 0x782f9e02, 0x2ce35764, 0x2a22a298, 0x28423729, 0x544552c0, 0xfc28a8dd, 0x8763b8bd,
+	0xcaca7eb9, 0xf1a05de2, 0xad098e95, 0x054818ca, 0x33fa8862, 0x53bf5c1d, 0x5bd24f31, 0xf3c46de3,
+	0xfac478e8, 0x4d21df47, 0x30d77a2a, 0xcd08d89a, 0x96abfaac, 0xd9ba447c, 0x71ee8217, 0x72a6403a,
+	0x6570da3b, 0x7c5e6c2b, 0xd930c9c3, 0x021fd675, 0x1853b729, 0x7fee9821, 0x4bee4645, 0x5cee6108,
+	0x5dee629b, 0x92677434, 0xc33a5b58, 0xe891cba9, 0xb69a6212, 0x835cdfab, 0xa9a26027, 0x659fb684,
+	0x9216d6d3, 0xcd4af1c6, 0xd7386f3f, 0x1e4e35b1, 0x134e2460, 0x6150f8cf, 0xb3af515f, 0x64eb368d,
+	0x36217b13, 0xf9f6cb2a, 0xe30643c6, 0xc662948f, 0xbd628664, 0x9d90d2a5, 0xcd07503c, 0xe675aabd,
+	0xd05188a1, 0x1b5721db, 0x8d9877cc, 0x969885f7, 0xad98aa2c, 0x5eb3a472, 0x8c8123c8, 0xe0d8af34,
+	0x70cc4e3d, 0x41c30dba, 0xe0aafe19, 0x7ba3a355, 0x05aaf66b, 0x1f7ee5e9, 0x31785565, 0x43b5214f,
+	0x11782305, 0x25a1c48f, 0x427c41bf, 0xb638acc4, 0x52918bde, 0x3e9eceaf, 0x029d3d36, 0x51a210fb,
+	0x6ca47a13, 0x407b54ba, 0xb58135ae, 0xbf4d55af, 0xbb2d7fc2, 0x38eb61f4, 0xecdfe852, 0xb7dbc34c,
+	0x4ee58597, 0x01c56eea, 0xb71fbcd0, 0x81fcaa94, 0x48177576, 0x6da9753b, 0x357ea71c, 0xd96623ff,
+	0x23685959, 0x33ce96b3, 0x3b4ed039, 0x08a493fd, 0x3a251573, 0x266b9dbf, 0x2fdb315f, 0x854bceb6,
+	0xf3d9d13e, 0xd97669d5, 0x1d129711, 0x5bfd77df, 0x843d4001, 0x9db57599, 0x26632acb, 0x0f9a8683,
+	0xd935dce3, 0x5746b8e1, 0x3077dc63, 0xe595550d, 0xa04a049f, 0x5274aa96, 0x1c13f056, 0xd4696387,
+	0x9c6634b7, 0xe7b6a500, 0xabfe3391, 0x470ab271, 0x0780721b, 0x50ed780c, 0xbb576584, 0x59909e45,
+	0x5d04b6d7, 0x44756bcf, 0x577589b8, 0x290bec3c, 0x61a1ffc0, 0x11362795, 0x8b222c06, 0x2e094ea1,
+	0xc669ca5d, 0x9afd2ade, 0x11fd8997, 0x451f2f53, 0xc561e563, 0xb99ecd33, 0x9fe54c0d, 0xec520c2b,
+	0x58e16ebf, 0x69be9071, 0x08a96126, 0xd4b9bd13, 0x0cd96196, 0x7e653922, 0x97edc0b1, 0xc99709d3,
+	0x19fc054f, 0x74d7f8cf, 0x99b62639, 0x73b3abd0, 0xbaa6bd5b, 0x2b9f463f, 0xa9989e1e, 0xe86bea0e,
+	0x32e51eda, 0x6ae1cd16, 0xb84ba66a, 0xd120cd42, 0x04dbcde2, 0x89e35b06, 0xdb26ecf9, 0x04ea297b,
+	0xa9350f26, 0xf48ecafd, 0x3020c2f1, 0x10318fd6, 0x4b0d7325, 0x7280152a, 0x35b8f8b1, 0x6ae2c85b,
+	0x35d75c30, 0xd57ecd21, 0xbb73293e, 0x81c01c6f, 0x507edbc1, 0x5ee93aaf, 0x25476c75, 0xea311401,
+	0xd4c0bb4a, 0x405ffabc, 0xb314565c, 0x59aa8e50, 0x5faa97c2, 0x7908a1bf, 0x994d4d8d, 0xd1bafc37,
+	0x23956922, 0x30fe9901, 0x23fe848a, 0xce9f6775, 0x8b3d4b25, 0x086314c8, 0xd5bfa724, 0x47b199ec,
+	0xb8649b36, 0x9ecc4168, 0x6a8eac41, 0x733afbe6, 0x12bb3499, 0x6edd9634, 0x755b0de3, 0x826b96e2,
+	0x58c1f2a5, 0x6d2cadd9, 0x1758c75e, 0x29800d68, 0x6591a254, 0x4911fa74, 0x849cb780, 0xf3a28f02,
+	0xda28af78, 0xea1a22d7, 0x0812e406, 0x3ec97e3f, 0x7cae5ef0, 0x626fb468, 0x8bae768d, 0xc6118a9b,
+	0x87ae7041, 0xd2e4f0cd, 0xa25d0567, 0xb18f8f45, 0x1accfa93, 0x22d4e139, 0x0f95cf5f, 0xbb17f055,
+	0x40c4112e, 0xfa1db75b, 0x7fa8d78a, 0x0cd2206e, 0x81144b47, 0x92914e97, 0x75118176, 0xfb806121,
+	0x82eff0c0, 0xf2b5d477, 0x2cb6fc1d, 0x3e606301, 0xe44f70fc, 0xce105a69, 0x06b72e8b, 0x6f69d0c0,
+	0x77e8e812, 0x43617a85, 0xe5b5c000, 0x2463c78b, 0xededa701, 0xb896b87f, 0x2a299fe8, 0x812b6855,
+	0xd1ae1d35, 0x828c750b, 0x93be7c2d, 0xbbeb0ec6, 0xd3638d40, 0xf72860e0, 0x642a8f17, 0x82007c05,
+	0x97e1e062, 0xd5606b55, 0x105ff428, 0xe7a0914d, 0x966bd2e5, 0xc75b13af, 0xf0c35e45, 0x968367ef,
+	0x137bddf1, 0x64321adc, 0x51c8f7ef, 0x4502982a, 0x7023b9b6, 0xe7033a9c, 0x490d1e49, 0x2dfbb41c,
+	0xf70c5ccc, 0x101bb98c, 0x56718f33, 0x229c3633, 0xf835acd8, 0x354c0958, 0x8b4f02d3, 0x59d1bde0,
+	0x396408e4, 0x5b681001, 0x080ecbf2, 0xf30ad94c, 0x96cc7d08, 0x74aa5e20, 0xbe8a177a, 0x06dfb645,
+	0xb74096b3, 0x17f5bf4a, 0xd03bb1fa, 0x29832b01, 0x32c7d55f, 0x23f0dcb1, 0xd349396d, 0xaf0c6ae8,
+	0x516155d5, 0x2d3e7884, 0x5b0e95b3, 0xbbcaef7f, 0x40adc540, 0xa94dbd28, 0x5a4861e2, 0x4391e9d5,
+	0x5f051dca, 0x1fd734f6, 0x61e1b745, 0x3f789a79, 0x562e400d, 0x73e1d39b, 0x9c590725, 0xaf227d34,
+	0x328b7974, 0xd5eb7ef5, 0xaa03eccd, 0x68356edd, 0x89b824d9, 0xabc23b78, 0x02745a09, 0x38028084,
+	0x576e6797, 0xfcdf64d6, 0x3d5cb223, 0x521a45ec, 0x2e74f175, 0x2f264b6c, 0x8be1f963, 0xd460a6c3,
+	0x30731754, 0x3eddae95, 0x18e3ce90, 0x885fafc0, 0x1999fe35, 0x45ddb99a, 0x7f1dceb8, 0x43ddb674,
+	0x94b9ce0e, 0x6f54d530, 0xad248ed0, 0x1129eb1f, 0xa3318cba, 0x1686360f, 0x73f98169, 0x9af9bece,
+	0x6108f97b, 0x9f44b0b8, 0x28aab7c0, 0x705e4311, 0x4b15e6db, 0x65c3f7d4, 0x8a29afdc, 0x92d7e56e,
+	0x95a3375a, 0x4065c551, 0x5552dd3a, 0x1437da5c, 0xefbe600e, 0xe4378ecc, 0x828d7598, 0x9663842c,
+	0xc87edf1d, 0x97a9a070, 0x550530dc, 0xbb0485e8, 0x4c745f4e, 0xe5f2b7a8, 0xd5a4b1a8, 0xd20046db,
+	0x77e6455d, 0x7c3d8e62, 0xe2673c7d, 0x871db91b, 0x0e4ffa34, 0xf98d0561, 0x7da37ab7, 0x5decd456,
+	0x61598579, 0xfda8d519, 0x9bff1af9, 0x91fb39a4, 0xea7cc005, 0x26922034, 0x20245e55, 0x168d4db1,
+	0xbfdf8fde, 0x536c1b67, 0xcf6ef31f, 0x8a9d4d78, 0xed7bb519, 0x1dc30437, 0x45d8ddad, 0x2867c740,
+	0x3b38c0de, 0x177fee94, 0xf8ac13cc, 0x0e7fe069, 0x107fe38f, 0x1d076787, 0x3ec7be9f, 0xca5cf8eb,
+	0xccf9e529, 0x704548b7, 0xc2dfd0e0, 0xfe780197, 0xe6962567, 0x4cdc043e, 0xf009db3a, 0xdf961a62,
+	0xd22c1205, 0x4650ce75, 0xe2c52a90, 0x3b287903, 0x175f18c7, 0x755b1486, 0xadb4b274, 0x163efe1d,
+	0x7a9dca28, 0x7c9dcd4e, 0x364c8dba, 0x19488e7c, 0x17189edb, 0x2fd30c05, 0x0fdddb5e, 0x10004163,
+	0x9af73a6f, 0xccce4e35, 0xe178888b, 0xc75a1b48, 0x12aa68ef, 0xb4ecab26, 0xa6ff2761, 0x8ac0075c,
+	0xe7e286c2, 0x6ccf8ff3, 0x55e1fe03, 0x3fae1d4f, 0xbca50051, 0x734bd5de, 0x85f63257, 0x29a81331,
+	0xeba3e000, 0xdf786293, 0x668185dd, 0xcf8adba8, 0xf880d31a, 0xe5d5d3d1, 0xc66ebd39, 0x1f260c23,
+	0x472aee0f, 0xad9d1987, 0xd8a6ef14, 0x4e9008f9, 0xe2fdde92, 0xdbbc6135, 0xf62e23f5, 0x73377555,
+	0x3e273ebd, 0x1626ffc5, 0x06db45b7, 0x698229d1, 0x7b6f0d1a, 0xf646d8e4, 0xf6633579, 0x783a3a88,
+	0xd3505382, 0x9973c2a1, 0xfd09edd8, 0x7fb5fe1c, 0x179ebfc2, 0x210ad21b, 0x65d2f63e, 0x1aa5abff,
+	0x43a12d77, 0xc1aa4296, 0x28c558a1, 0xe2c43ed8, 0x99db7136, 0x63100626, 0x20320348, 0xabbf00d2,
+	0x40ca592c, 0xe15e1cdf, 0xca30e8ba, 0x3acf03c8, 0xda2b657d, 0x9bf499df, 0x8977dac5, 0x54168a0b,
+	0xe50bb82f, 0xcb8ea847, 0x25e5f432, 0xb5eb1c39, 0xb4eb1aa6, 0x8d05002a, 0x7fa87040, 0x2ed13539,
+	0xa5d4eb02, 0xe4a498a8, 0x9426199e, 0x85260201, 0x6c25daa6, 0x6fdd0ca3, 0x8735716c, 0x85356e46,
+	0xdd53997e, 0xed444669, 0xf7fdaff2, 0x9b3590e8, 0x41617480, 0x7c0a2a54, 0x0f6f404b, 0x781a575a,
+	0x79fff013, 0x72881a89, 0x57b28d36, 0x97548b98, 0x4b11ed59, 0x9e332616, 0xda73c514, 0xcfed625c,
+	0x394efb1d, 0x19d3f911, 0x1ee8d5b9, 0x7bbfed8e, 0x3fd31769, 0x21c58580, 0x0cff3051, 0xcbb1ba26,
+	0xd8b3340e, 0x624e1cd6, 0x94bb0052, 0xd120999e, 0x19a4f91e, 0x65e3eb44, 0x54d5e147, 0xc41c132e,
+	0xf3ca28d3, 0xb1ba028f, 0x0dfcd891, 0x2ad7e814, 0x92d02e1b, 0x70964e76, 0x7cfeeb06, 0xcb9d5608,
+	0x73d10cee, 0x462ba3fe, 0x61c1fce8, 0xc46d0933, 0xf8d1e2a9, 0xbf86459f, 0x015dd14e, 0x7fea2258,
+	0xb52b1ad2, 0xe7e5908a, 0x49a378f7, 0xeba45674, 0x89ea3216, 0x6165a78e, 0x058f369a, 0x584bb3f0,
+	0xf64aa4f3, 0xe2912d3f, 0xa4e01ecf, 0xcd074ee9, 0x0f4bfc59, 0x23c32e9c, 0x388e9a83, 0x17932487,
+	0x8dba523b, 0x838c38f4, 0xe8e7453c, 0xd6fcb0b4, 0xa2ba755e, 0xc9f9fda8, 0x2a5b45db, 0xdb1d4fcd,
+	0xcc1d3830, 0xd01d3e7c, 0xbe1d2226, 0xf42148bf, 0x58b46d6c, 0x6db48e7b, 0xd1501295, 0x5e6e3e1d,
+	0x61e60ed8, 0xf5c94d67, 0x4e1139a4, 0x80e63fa5, 0x22196250, 0x24c7266a, 0xffc64094, 0xa9434e6f,
+	0x065f6f27, 0x76402487, 0x96887138, 0x147ef412, 0x0aeef2b4, 0x3609175d, 0x3f509c79, 0xdee23ce8,
+	0x4135b234, 0x3f9bc9f0, 0x8f9248c3, 0x8106e2ad, 0x97cc96cd, 0x9907b40c, 0xf6f2925c, 0x19fc1c9f,
+	0x783b6dfb, 0xc2a02602, 0xf6c957f3, 0xd9762931, 0x3e3fc97a, 0x7be7d062, 0x7cf88754, 0xae83b22f,
+	0x32409ed0, 0x32a525e9, 0x4f90f9a5, 0x4b9497c2, 0x81f4e296, 0xa0bbddf6, 0xfcadde26, 0x5fc87550,
+	0x932ed160, 0x12ebe552, 0xa0d43069, 0x9086067a, 0x58e03390, 0x11d55703, 0x272c2506, 0x5481f720,
+	0xa3f4740e, 0x30bfb64e, 0xf5286e17, 0x5f9dc997, 0xb8753924, 0x346b9312, 0xe5526853, 0x1d6b68d2,
+	0xa40145ef, 0x7100f5a6, 0xd0a3db61, 0xd1d1e43a, 0xda70dcab, 0x48a09193, 0x8ae8fe93, 0x1db764d1,
+	0x9b2212ea, 0xdc4f3416, 0x66ed7661, 0x2bc67bab, 0x2146b582, 0x2eb17d6e, 0xd99c5837, 0x7358aa94,
+	0xc3268ff5, 0xbc1d68d5, 0x168cba5f, 0xd1a470ed, 0xa327081f, 0xf583b90b, 0xed86c7f6, 0xde9c9e5e,
+	0x6c9e415e, 0x1692635e, 0x8b4a3910, 0x73d95300, 0x79d95c72, 0xb2aaadb2, 0xc71dd6a2, 0x0deb92d8,
+	0x8abef95f, 0xcd2e66c4, 0xdf97e9af, 0x235d4759, 0x9cd743da, 0x9ed69ddf, 0x8c1ee084, 0x08c68471,
+	0x9c2a16aa, 0x61fd244e, 0x3e3d1192, 0xc1f40149, 0xf5c94e40, 0x04c965dd, 0xfec95c6b, 0x10325d3d,
+	0xab548520, 0x6e78ef71, 0x72be64b7, 0x95580113, 0x1ac9887f, 0x0735d4ba, 0x8ca78fea, 0xea93baf4,
+	0xc8386a5f, 0x6bcf30c7, 0x8d8bd648, 0x2cf147bb, 0x163d08e3, 0x24ac46b7, 0x5238306a, 0x996a84c2,
+	0x1fa6506a, 0x4d8a0419, 0x3a1ad07b, 0x5101bf8a, 0x60ea57a4, 0xcbcb0af1, 0x0fd2bceb, 0x91e6b58f,
+	0x0feca4ae, 0x11eca7d4, 0x1528456c, 0xe5f06252, 0x2ef70bbf, 0x5b11f18a, 0xd6bb0b70, 0xed0680ea,
+	0xf9ba1b37, 0xed485bd3, 0x1b8637a3, 0xc06556b4, 0xe489d9a8, 0x9734bfd9, 0x405c837b, 0x9e921689,
+	0x409a5684, 0x1456906f, 0x7d3374c5, 0x060dd24d, 0xba86335d, 0x5512f19e, 0x8b1ca499, 0xd0fd34dc,
+	0x5962fdab, 0xd2bd82d6, 0xb5fa96c3, 0x54b903ef, 0xbb533e59, 0x0ad23643, 0xbe50deb5, 0x5a1426dd,
+	0xf2238b4f, 0xc24e049b, 0x3f75e28e, 0x30c4179e, 0x085bb7dd, 0x9db0f781, 0x470d9934, 0xc5b64f7f,
+	0x0cae0f47, 0xf0aac9c6, 0xe23db655, 0xb6d72755, 0x8df195a3, 0x0ec54e84, 0xcc4f08e1, 0x824de8cc,
+	0xe65e6734, 0x0f4ead41, 0x7a561177, 0x0f9d0ad2, 0x785baf99, 0x88fca767, 0x71f7ecd1, 0x91a2cfee,
+	0x85088ec9, 0xd1ff7f60, 0xe9c5cd17, 0x6196ab0e, 0x4a9aec37, 0xe5ff632f, 0x73e4f08b, 0x7f8d5c62,
+	0x3a8bb572, 0xf1702730, 0xb3cca4f4, 0x9ad24f01, 0xf2fd37cb, 0xb30da2e7, 0xaf0d9c9b, 0x352a315d,
+	0x0369c115, 0xb6bf5beb, 0xd1abcffb, 0x05ed545f, 0xecda67bd, 0xd5066861, 0x33b18c37, 0x39c37da3,
+	0xfc490740, 0xbfbb082a, 0x4a316299, 0xb3d1333c, 0x6b324223, 0x24860420, 0xb270d7e9, 0x4a5a3a92,
+	0x66a6a3b6, 0xc29ee5c5, 0x9d6e5088, 0x6411385f, 0x8c640957, 0xfa9c8db3, 0x44376993, 0x9381f1ed,
+	0x0f129117, 0xbd01e436, 0x92277e26, 0xf4a33801, 0xf2065b79, 0xedd31977, 0x06c61324, 0x972f6ef8,
+	0xe32125ee, 0xe9d9dd94, 0x9ad15c4e, 0x8fdc0334, 0x3a096a48, 0x69c58d47, 0xb2fdefd0, 0xc93e003d,
+	0x75322f0f, 0x2da59246, 0x172505df, 0xcc89cdee, 0xe4ee60a7, 0x7c5839c0, 0x6d36f44a, 0xd42cfa27,
+	0xc52ce28a, 0x6eb1d44c, 0x51be5075, 0x487f6d68, 0x8e22fd24, 0x2f7c44a4, 0x00051f9c, 0x3ed85ab6,
+	0x9fff970b, 0xc000a1f8, 0x9d2314c1, 0xb361aaa1, 0xc662d82f, 0xa6e57097, 0x424d09c2, 0x3f4d0509,
+	0x66ce47ae, 0x1d79c86d, 0x8646d2c0, 0x8ddf733e, 0x3c297f45, 0x01ee45dd, 0x5559790a, 0xc14e2fe4,
+	0x8ae52dbe, 0xbc170e22, 0x463ef9ca, 0xbe7fa2b5, 0xd67d1843, 0x5b75c0a3, 0x3871b7f3, 0x3771b660,
+	0x4f71dc28, 0x88e8c562, 0xf1976fc0, 0x608c51ef, 0x49fd4b9b, 0x4ebc53f4, 0xb04c2825, 0xadc21246,
+	0xb7b512c5, 0x08caa670, 0x01aa66c1, 0x68b1c4ab, 0x9cc937c4, 0x1f7d40cb, 0xb0b8a7ec, 0x22949296,
+	0xdc941d18, 0x6af3e283, 0xa9534f8c, 0x78f3f88d, 0xbef058e6, 0x5a395431, 0x831b3c43, 0x94f9c058,
+	0xbf6eed9b, 0x126e57b1, 0x80aa62a8, 0xe2a1d321, 0xaac8d7f6, 0xf1ce26a4, 0x9d41db4c, 0x7895ef3d,
+	0x8193ead8, 0xea890fea, 0xa4b334ed, 0x9e3ade72, 0xf4edad79, 0x0fb8f3f5, 0xecc89263, 0xf4c89efb,
+	0x0ee9f3b6, 0xe7326ad2, 0xed98a00a, 0x3dff9460, 0xd7fc2f66, 0x6f48a2c6, 0x31015e75, 0x3783e318,
+	0x47943964, 0x83a9ee85, 0x81a9eb5f, 0x8aa9f98a, 0x43db47af, 0x643a9657, 0x582defa4, 0x463e26fc,
+	0x0993db02, 0xedede01e, 0x71c5071b, 0x42160cdf, 0x9d20495a, 0xbd7fb338, 0x4c1f07b5, 0xa075262b,
+	0x71e15417, 0x27a39a68, 0xd886423c, 0x50c44d75, 0xc80ecdc2, 0x756aa210, 0x9597c9c7, 0x471b872f,
+	0xf0026dd4, 0x154e03d7, 0xe6b4f325, 0x37931cad, 0x1618d2d0, 0x09194b10, 0x720635e3, 0x7f1a1577,
+	0x2d81e37e, 0x8bb04ac4, 0xf2d74d6b, 0x0869ccd5, 0x6ef8bcd3, 0xa0ac8922, 0xa5ac9101, 0x94325672,
+	0xa29f6fb4, 0xb62ee78f, 0xa6afbe26, 0x222860e2, 0x1a28544a, 0xd9535d44, 0x1a58668b, 0x60e6229e,
+	0xf10fd55a, 0x72bf6cdc, 0x2fe6cff5, 0xde9c02e0, 0x25a19b6a, 0xed6b8d83, 0xc92c60e6, 0x61269469,
+	0x5a8df57e, 0x4597d4d5, 0x1cacc026, 0x3f8d6825, 0xd7f169f5, 0xb550e61e, 0xb53b7939, 0xc1cd6b8a,
+	0x2e98fb6d, 0x30115745, 0xfe5b53ae, 0xe819819c, 0xb5991db6, 0xbba9c79c, 0xe0197504, 0x71b85f58,
+	0x4080e69a, 0xd819686c, 0xecd99c6b, 0x05e91ef1, 0x4dd89e40, 0x8a13c4dd, 0xf978aa41, 0x46bb93b3,
+	0x668ae4ba, 0x9f74a228, 0x3c070d48, 0x9e79db4a, 0xc9093448, 0xfffc29bf, 0x7c28a3a2, 0x290281e4,
+	0x47769802, 0xc23de69e, 0x50f049c3, 0xb35cbea9, 0xc626c6fa, 0x5d22088f, 0x35c40b4d, 0x9f5f4af9,
+	0x9f8fb91f, 0x8e964d24, 0x77acd976, 0x1323fa3f, 0xa01eea4d, 0x21a5e4c5, 0xfe7d133d, 0xfa3c29d3,
+	0xbf337e2d, 0xfa1b95d7, 0x151f91ef, 0x07c7a369, 0x27c7d5c9, 0x36c898fd, 0xfa951833, 0x918d7f75,
+	0x110abac7, 0x0a0aafc2, 0x34e3011b, 0x87e5c25b, 0x595d247c, 0x52934c61, 0xfc1c600a, 0x78330dda,
+	0x670010b4, 0xac859018, 0x4f0b3f90, 0x580b4dbb, 0x440b2e3f, 0xf86658bb, 0x9234131c, 0x173713a7,
+	0x762ed9aa, 0xff174457, 0x3029e2e0, 0x46375196, 0xee410a8e, 0x08603478, 0xb9bc7f02, 0x721aa845,
+	0x86277f90, 0x79ca73a0, 0x0b839365, 0x3a1c58d0, 0x6e3ee96a, 0x33f6c0d1, 0x03978f14, 0x7d7e3b24,
+	0x980fe93b, 0x541cf372, 0x88cbbc1f, 0x620fece8, 0xd309e306, 0xd409e499, 0x394698be, 0xd3e12504,
+	0xdd2a72dd, 0x8349fbe6, 0x6755d63a, 0x7ec3329b, 0xd1171470, 0x069e9506, 0x8cea62b8, 0xd0ed0c5b,
+	0xd5107619, 0x931be303, 0x84314ae2, 0xf2c6ad9f, 0xc65bf3ff, 0x2709c503, 0x886b8b1c, 0x0b31d967,
+	0x874111c5, 0x7a44d394, 0xbdcfad4c, 0x0788be9c, 0xa676503b, 0xad9acdcb, 0x2832de82, 0x1cf28112,
+	0xf7898f4b, 0x75d9ffb1, 0x13d4ce10, 0x9b839da2, 0xb2b1d75c, 0xb71ec1f9, 0x88748c09, 0x7b77425e,
+	0x81ca5e55, 0x080c2cef, 0x6e11f646, 0x36a6204b, 0x38a62371, 0x32a619ff, 0x47a63b0e, 0xd13eca7a,
+	0xc975a57f, 0x9453f0e9, 0x7fc9a80a, 0x25c0775d, 0x4bfb5c56, 0xeee0e2f5, 0xe48d989f, 0xfd8ecf9f,
+	0x088ee0f0, 0x41fb820e, 0x2292db75, 0x76cf7a1d, 0x7ecf86b5, 0xa26a1b19, 0xd352ab09, 0xa9adf750,
+	0xbb04eda0, 0x895c45f7, 0x4e8bfa06, 0x718d89e9, 0x7ba53c12, 0xd4b3cc0d, 0x4f6ff371, 0xa573c90f,
+	0x092cfdff, 0x49e4e4b7, 0x51c4ca98, 0x306ee64a, 0xa2e386f6, 0x79d19da8, 0x69bc6238, 0x9b9313aa,
+	0xf3d2f785, 0x1ec2e811, 0xb4c77131, 0x1c8371e1, 0xce5370c8, 0x4ca674d9, 0xaad51ff9, 0x546e0d50,
+	0x1d271edb, 0x9dfa9e17, 0xa2a6f12e, 0x11e3bb5d, 0xda6e2c44, 0xf03503a8, 0x00ee7da2, 0x9549922e,
+	0x180861ef, 0x76c5a2b0, 0xa7b5bfd8, 0xd2256701, 0x8c060b71, 0x7d38043d, 0xcba8b27b, 0xd2d47395,
+	0xc910b870, 0x4695c189, 0x8dab3626, 0x1b957dd8, 0xb5d33649, 0x6dff19f7, 0x5b835053, 0x1a470763,
+	0x285716f3, 0xd8c159cc, 0xc8a9f769, 0x02ddaa3a, 0xd85653f7, 0x27b92a21, 0x1234b331, 0x6e0cf4cf,
+	0xfa5b4120, 0xc1846f28, 0x41607db4, 0x4b635b97, 0xb15e3b0b, 0xc2344d8b, 0xf2bc0989, 0x04fb19d0,
+	0xb1c21716, 0xf61f6076, 0x9a2ccc05, 0x1ae84e76, 0x18cb0dc2, 0xafa590d9, 0x0604c476, 0x1104d5c7,
+	0xe9027447, 0x4c69093d, 0x0304bfbd, 0xbed3da4f, 0xe568b646, 0xbf59e2ed, 0x3e74a47b, 0x1eb36062,
+	0xbff82f60, 0x024998a5, 0x2b7240e8, 0xfe277f23, 0x94d57f9b, 0x74095073, 0x7b095b78, 0x8dfce592,
+	0x5da3ee96, 0xa95b9970, 0x3f89d9e9, 0x0cd9b3b8, 0xf1a5cb25, 0xf627fb04, 0x138da53b, 0x20c52ce5,
+	0x407bc16a, 0xe1e3200b, 0x88f84365, 0x9f38ca9f, 0x524d9e29, 0x5f5f7b26, 0x6b0eb7d7, 0x93160a00,
+	0x7df58431, 0x16c337ea, 0x4f4fafad, 0xad88a4ad, 0x4528fcd1, 0xf0c526f2, 0x998e6ab6, 0x52de2979,
+	0xedc7db05, 0x8a10134f, 0xf69e2ffd, 0xec1659b5, 0xf31664ba, 0x67055d5a, 0x1f21b681, 0x6a3bd927,
+	0x4aaa5bd4, 0x85a56a3d, 0xb22f093b, 0x9a1e36fd, 0xaf4aa366, 0xc313bf51, 0x7aa37a60, 0x8f183650,
+	0x5246915c, 0x7cd621a3, 0x5025c24a, 0x59543db6, 0x84f7806c, 0x4f153a07, 0xf451942b, 0x9ad91f8f,
+	0xd163cb4d, 0x4a7e8369, 0xcf98a1c3, 0xf818c342, 0xdf4f7502, 0x340f34ab, 0xc61b11ac, 0xc23d8808,
+	0x2911d070, 0xa9c48347, 0xdafacd13, 0xd46b8ceb, 0x923254c1, 0x0c652450, 0x647ac537, 0x92be1468,
+	0xf1b2bda8, 0x7a1fbe8a, 0xbfffbf83, 0xfedb5acf, 0x6b9396f0, 0x793774be, 0xf8550fb0, 0xf122cb62,
+	0x9b40756f, 0x81655cfd, 0xd5773946, 0x41684a1e, 0x2b5a7295, 0x0536195c, 0x80c71b1c, 0xbd3c5081,
+	0x7d39ad2a, 0x69b2b311, 0x80bf227d, 0x237a4e26, 0x43685921, 0x52272902, 0xf485126f, 0xdb15e4e2,
+];
@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0x66cf5350, 0x1b3cc0be, 0xb31bf105, 0x778ebbae, 0xf8e3be85, 0x03d5fae8, 0xcbecdefe, 0xd326ef5b,
+	0xa637b535, 0xeceb6166, 0xae537193, 0x392d9f18, 0x6eefd927, 0x8d79d6f7, 0x64393c12, 0xa95d7f96,
+	0xeea1742e, 0x0042ceb9, 0x13fae791, 0xd9544c3e, 0x8a6cb06e, 0x0e58105d, 0x9d55e337, 0xce574d24,
+	0x952773e4, 0xc9e91086, 0xda8fc597, 0xd422dfd8, 0xc4d5269c, 0x01e887ce, 0x01f62dd0, 0x28b3f477,
+	0x47ca1d9a, 0x4d31c7fb, 0x977083df, 0xe3452f04, 0x7ed9997e, 0xa6a72eec, 0x1d31e88e, 0x50ff4155,
+	0x5f4d2ad0, 0xf87b67e5, 0xfb51b311, 0xc7aab74f, 0xfcb4a3de, 0xb70668c9, 0x6b0c2886, 0xb8115776,
+	0xf86a6dd3, 0x056a824a, 0x7d25aab4, 0x9d334e2c, 0x43140ab3, 0xd5e68a6c, 0x0a328feb, 0xafe34ac3,
+	0xa4e33972, 0x809dcfb1, 0x3c6e5735, 0xbc67a0a4, 0xbf76065c, 0xf58d29bd, 0xfec239a8, 0x2ba79aab,
+	0x5828b8df, 0xd9b80ea4, 0x6ff2afe8, 0x0bd9b93a, 0x6f35c0af, 0x5fb26905, 0x173b0066, 0xefe7f696,
+	0xe71204f7, 0x53956cc4, 0xc61f1ea1, 0x900cce8b, 0xba08ccae, 0x1ad1c894, 0xe1a76c8d, 0xb394a98c,
+	0x90f50365, 0x45d476a3, 0x88913c58, 0x14c9716e, 0xe1d02e35, 0xb382103f, 0xb2820eac, 0xc6822e28,
+	0x33ed0654, 0xf5a5b512, 0xb4ad39ef, 0x33efae69, 0xd1c3720a, 0x43fb0cf6, 0xeef121c2, 0x67946e45,
+	0x6f947add, 0x3e8427ee, 0xc354598e, 0x34f581d1, 0xdc7bcfb6, 0x6eca4ec7, 0xe5988783, 0x06fb39bd,
+	0x7705fb59, 0x60896748, 0xfa599360, 0x44048e46, 0x617d93b3, 0xabed6f94, 0x2626cec1, 0x44fd8657,
+	0x536a9d91, 0x2654f3c4, 0x0bf67ff9, 0xb4d02171, 0xdfac0e7d, 0x033ee772, 0x27c0c06a, 0x927e5977,
+	0xf19b204a, 0x90bcf2bd, 0x24eb46be, 0x34bdead6, 0x89e5f4dc, 0xc1047bb2, 0x5720befe, 0xd4662b74,
+	0x6dc84c4e, 0xd3976ceb, 0xef444a89, 0x3f7c576d, 0x5d90569d, 0xe8978dfa, 0x0acf93ae, 0xc4e04264,
+	0x12ba0764, 0x69ab0327, 0x2ba83540, 0x65d80c4e, 0x9a100959, 0x86937a01, 0xd701f24b, 0x286714db,
+	0x01428466, 0x6fd9b42f, 0x628e4e47, 0xb33a44b5, 0x057aef3a, 0x27fb9e87, 0xc69188b5, 0xf2792139,
+	0xe7bd2f3d, 0xb4504e9b, 0x5b7b9066, 0x7b3cc3cb, 0xa2f6de9a, 0xb1609d50, 0xfdbaeaf3, 0xf4491559,
+	0x15e3a021, 0x8363cb28, 0xc270a27b, 0xbaed4c10, 0xcc015e9a, 0x04fa54a4, 0x9f7cf17d, 0xa2c5b8da,
+	0xd1c0c82c, 0x16fb8117, 0x04e9594e, 0x93fc47a1, 0x11e6bcd6, 0x325b718b, 0x7de8ad7c, 0xcc6e37cd,
+	0x2e83f1a4, 0xd55f1a06, 0x57acd896, 0xe56770f2, 0x09ffc7a6, 0x4b43b5f2, 0xa10f1933, 0x90a9f791,
+	0xa62cc033, 0xeda87b07, 0x8b2cfb26, 0xc8413682, 0xb4411706, 0x6312aafe, 0xe0978fc2, 0xfb0eb1b4,
+	0xf994c8cb, 0xf903cd20, 0xd13614ed, 0xbd35f571, 0xeb6ffe2a, 0x0e5ae841, 0x73b86e76, 0xb1e75df5,
+	0x534b46e9, 0x16e4c67a, 0x18d3f034, 0x3314d912, 0x4904267c, 0x945a5cd0, 0x74dbd85a, 0x7e94e655,
+	0x42eeaf11, 0x832a6c69, 0x30462f08, 0x2c21fbba, 0xe3734574, 0x0f5a17e5, 0xb97a70d4, 0x33936155,
+	0xd8f84093, 0xb64af3f9, 0x5baed96b, 0x2e0d4ba0, 0x71a2b939, 0x0035d330, 0x6ebf8e08, 0x700297d3,
+	0xea0c2d72, 0x49f04d5a, 0x90549d1b, 0x86410500, 0x40bb662a, 0x89b2f8b9, 0x91b30551, 0x4cc7e721,
+	0x41c7d5d0, 0xf18ef6da, 0xfac59b3a, 0x72c29ba0, 0xac95ce28, 0x59715db7, 0x10d4ac6f, 0xb0e6ce55,
+	0xb43c5e45, 0x04a0d6e1, 0x820361df, 0xce066c3f, 0x4fd5b27c, 0xcd9f1b34, 0x8648ac1b, 0xf44c3a51,
+	0x65b6b802, 0x96511693, 0x073821a4, 0xe519aeab, 0xcf71b3d2, 0x5302d707, 0xacc27d2c, 0x7dd73b11,
+	0x2b9aa356, 0x67cc1977, 0xfcfa77f6, 0x04477f0d, 0x61f661ed, 0x58e1368f, 0x0c5df2db, 0xbd52ec1b,
+	0xad52d2eb, 0x83a91fc2, 0xc53538c1, 0x339c2dac, 0xe944c53e, 0xbd79810c, 0x36f4a22d, 0x607dcc89,
+	0x3f27fe19, 0x48775476, 0xd96a2e10, 0x7f065468, 0x1fe3766d, 0x47c1165d, 0x2e5c789e, 0x6b660853,
+	0xf3b6d029, 0xff9457b9, 0x809f9612, 0xe5393dc3, 0x0067dbb7, 0xda196e1f, 0xa6ba6ced, 0x35f19830,
+	0x14a41921, 0x261c5834, 0x22dd0e9c, 0x00f0c0d9, 0x048ed4ce, 0x8959caa3, 0xcf679058, 0xd9837b3f,
+	0x4827b834, 0x9cffa8a5, 0x7662c66c, 0x956937d2, 0xca14120c, 0x1c30c49d, 0x1235af2a, 0x7ab76a7d,
+	0xb94411fb, 0x714be8f5, 0x678dce82, 0x76dbff3e, 0x61ca6a33, 0x913629a5, 0xbe26e34a, 0x074d3d76,
+	0x249016b9, 0xf752cba1, 0xd078feb5, 0x054432ec, 0x6f1978b0, 0xa83f2a75, 0x9077053d, 0xe64e0097,
+	0xc5dc3d65, 0xc8fb37cc, 0x1edf74eb, 0x6649ae52, 0x2c05cb0b, 0xac4145fc, 0x4e7154d2, 0xd68575c9,
+	0x8e9170ee, 0xbdd28505, 0xa7ba8365, 0x7d66536a, 0x7238e4be, 0xe61be7ec, 0xf42c964d, 0x4e66126e,
+	0x9852f34d, 0xd038948e, 0xd9468ec3, 0x1f347f75, 0xf8dda44a, 0xb0824ed8, 0xa3367498, 0xe6ac845f,
+	0xf8e34aea, 0x8792de3a, 0xcb778b12, 0x34b30f09, 0xdfb5f7b8, 0x58f9e7e6, 0x441c2eb1, 0x94bc1efa,
+	0x511ed9e2, 0xbdd7be6f, 0x11ea0cce, 0x2c58ad29, 0x20589a45, 0x8632905a, 0xa22c4fdd, 0x9a2c4345,
+	0x4b71be6b, 0x9b5d955f, 0x8536749c, 0x18e42c9b, 0x11da49ce, 0x08e4136b, 0x3b27a0c3, 0xe9320822,
+	0x9fc3da5c, 0x699598d5, 0x3e2d9ff3, 0x548061ae, 0x01fc7e00, 0x82e77137, 0x85170e31, 0xa00a7acc,
+	0xdef39fd4, 0xd5981cec, 0x2fb82815, 0x47258ef6, 0x26002b6d, 0xc4bbe3b1, 0xb7628e7d, 0xc362a161,
+	0xc2457a10, 0x8109cb54, 0xe012c3a9, 0x245a4a1e, 0x71c171af, 0xf51b3fa0, 0xc5e2a389, 0xb2e285a0,
+	0x7dfe594f, 0x495725a9, 0x34312220, 0x47314009, 0x945fc91c, 0x1b698ff6, 0x86dec50f, 0x8d41ca49,
+	0x48858208, 0x00c98b21, 0x12e12df2, 0xe52495e7, 0x1ade1e16, 0x5b2d554d, 0xd08a6e61, 0x1033c7a2,
+	0x69a940cd, 0xd8198128, 0x2f4ce07e, 0xafdf4b02, 0x0ed0c90c, 0xf766bdce, 0xbcac6144, 0xfeb76365,
+	0x4087fe2d, 0x33f396ff, 0xaa0e4266, 0x61ba499c, 0xf978df3f, 0x3f34a5c8, 0xd28353a9, 0x31d85afc,
+	0x447f85f7, 0x3c71ecc5, 0xd6307dad, 0xc3a76991, 0xcb3c5602, 0x746dfd60, 0x8ac7d1bd, 0x6e525d56,
+	0xe2c112f3, 0xebc25341, 0x9c312682, 0x943119ea, 0xc1a443d5, 0xd7ceb4bf, 0x33e3675e, 0x8c5c8597,
+	0xba1f7d8b, 0x2c9b2751, 0x5cb0aa59, 0x638e50b7, 0x10cc46c2, 0x759ced0d, 0x0065e7a3, 0x4cc3aead,
+	0xee8938d1, 0x2077bed2, 0x8673f8c7, 0x8999dffa, 0xbb012f16, 0x5042b900, 0x0aca1039, 0x41c11793,
+	0x68dfbc98, 0x5107350a, 0x771ecb62, 0x88b451f6, 0x84b20ffb, 0xfe899e0a, 0xdc5cee37, 0xbab5e2c6,
+	0xf89f5d7f, 0x5a484263, 0xabe17356, 0x83d57c3b, 0x634cc54d, 0x534cac1d, 0x964b2c80, 0xcbbc98f3,
+	0xbaf454a8, 0x28940241, 0xccc672dc, 0x52047bb1, 0x20975917, 0x9deccfde, 0xbd09c02a, 0xc109c676,
+	0x83966ca2, 0x8697e925, 0x27775d5c, 0xad3fd6ff, 0x3c589375, 0x5dbb3273, 0x0de57e56, 0x13739278,
+	0x7a237fb1, 0xc50003a7, 0xa2065f64, 0xdf6897fa, 0x307a7358, 0x04643110, 0x338e67ef, 0xf9c8905a,
+	0x3d58a7b1, 0xd918e674, 0x829b167c, 0xa99116f5, 0x4f60557d, 0x0b2d8eb9, 0xe1432a06, 0x4f7b2a1f,
+	0x240ce1c4, 0xf19438c9, 0x1d29b204, 0xcb0b4a9e, 0xf4e2c2dd, 0x9e82fa24, 0x2c5c157f, 0x5dc51112,
+	0xdfd6913b, 0x0f808150, 0x5796cf5b, 0x4fd95404, 0x0337956c, 0x60b14f66, 0xbd8fc47e, 0x80b53a3b,
+	0x390586f5, 0x467c995b, 0xd7d1e458, 0xf4e5a906, 0xbc675ac7, 0x8fae249f, 0x503d2ecd, 0xb72759a9,
+	0x95edee1e, 0x256691fa, 0x669d107c, 0x67470936, 0x3054b919, 0x56d6c3de, 0x7318ddb1, 0x31bad94a,
+	0x43993fc5, 0x8e536c83, 0xc3e9458b, 0x0c0c8d03, 0x3774fb51, 0xf40e1cab, 0xb1c31770, 0xa01d8097,
+	0x4599e1a4, 0xd0a38bbc, 0x9869138f, 0x25581a2f, 0xa6b11506, 0xa0d0504c, 0x5c7f3011, 0x4bd257dd,
+	0xfea25e16, 0x75875fea, 0xbb19680e, 0x717d4311, 0xf6bffee6, 0x7066b6ca, 0xe7c64766, 0xea823744,
+	0xdf4decf9, 0x8f31998f, 0x5e520253, 0x0ab3728b, 0xea5ff196, 0xf56002e7, 0xf6a2f4f0, 0x706f3f32,
+	0x6035d56c, 0xbb07f883, 0x1fd26110, 0xf745ee8c, 0x3d4c74af, 0x4868b0fc, 0xdbfe9792, 0x663a5328,
+	0x4f33fbc5, 0x36516cec, 0x56369e75, 0x18489e20, 0x898b7043, 0xc0fccf62, 0x73f49095, 0x75d8ea8b,
+	0x4833422b, 0x5954b9e1, 0x337b9fbc, 0x67b3e5de, 0x28e1d1f3, 0xb6cba74b, 0xb216a3ac, 0x4c273ed5,
+	0x5f1e6c50, 0x1b41c83f, 0x5156117a, 0x02c21b8f, 0xd190ded9, 0x6190aa6c, 0xf5680c30, 0xc5352415,
+	0xb5c7799b, 0xe755cf23, 0x3c5f8f84, 0xa89332e3, 0x59f0d5c3, 0x99a49cbe, 0xc1084818, 0xf15736bc,
+	0xe797ff82, 0xbf15aa93, 0xaa1ad31c, 0xef9c699c, 0x025d7fc9, 0x237a9461, 0xe8503792, 0x440a0451,
+	0x7f058ffd, 0xd91803c9, 0xce1e37a0, 0x9284a626, 0xc0e404c4, 0x8c15fb1b, 0x84860930, 0x87c0617e,
+	0x7f15c42c, 0xaef3178c, 0x763ced56, 0x7473745c, 0x439454f9, 0x0e1a1f6f, 0x206843b5, 0xcc2f645d,
+	0xa8243922, 0xb34b99e8, 0x2418aa06, 0x1db1e569, 0xf0fda7e7, 0x964edaaf, 0x294a1858, 0x90b046d1,
+	0xeff8d2a3, 0xb0c2ec13, 0x89cee56b, 0x68a1d93b, 0x8ca9d735, 0x2d41ac09, 0x46ff0324, 0xd9fa0387,
+	0x3bd8c7dc, 0xc95ec77d, 0x02e87733, 0x4a50c1fc, 0xf3940361, 0xcda00a63, 0x19a8b134, 0x1edc1043,
+	0xa52c3b3e, 0xddffef47, 0xcae3d16a, 0xcf114ba6, 0x6c701cf3, 0x959a1340, 0x193b929c, 0x9891c244,
+	0x6a00127e, 0x9a3444ff, 0xa5e4629d, 0x81fa259b, 0x9283ec2e, 0x2dd1c85a, 0xc711cbc0, 0x837894f3,
+	0x7406fe98, 0x62db8a5f, 0xd64e39fe, 0x18da06ee, 0x8810340a, 0x3e50562b, 0x09fb085a, 0xfe2cae23,
+	0x5e674e89, 0x3b831cf0, 0x0426ca08, 0xf5702ff4, 0x602a1153, 0x2351c4fa, 0x0e51a3eb, 0x6d4fcff4,
+	0x62666a08, 0x3a4e4060, 0x44654ac0, 0x38c6010c, 0xb782a9a4, 0xa7ca6a9c, 0xdb6a5cfb, 0x29238678,
+	0xd422838d, 0x8cd329d3, 0x895421e6, 0x8f090d1a, 0xf8538823, 0x59f183d3, 0x2ef76cf0, 0x3db1faca,
+	0xd65ac1e9, 0x896a64bc, 0x9bd218ca, 0x36395364, 0x76c417ad, 0xacab2185, 0x82f6ab24, 0x81f6a991,
+	0x96242c97, 0x7da6d0d1, 0x07396473, 0x78c99495, 0xaf57ade6, 0xda71f65e, 0x4f00e7d3, 0x93ef9bc1,
+	0x70c9eebd, 0x3f87dca0, 0x51738a78, 0xe36d1990, 0x476ded2e, 0x9f4234b6, 0x668084d4, 0x018f7ffb,
+	0xeead3bdc, 0x829b2500, 0x569854d5, 0xc89cc450, 0xe4dfe492, 0x33df1b7c, 0xc1a4b974, 0xbb48115c,
+	0xb8cb014d, 0x64871fd6, 0x619924e2, 0x54729aa3, 0x91910e30, 0x73a312dc, 0xdac7eef2, 0x5970e393,
+	0x6e7104a2, 0x5dc3bc9c, 0x4e283075, 0xac48b2bd, 0x6b75e719, 0xcd91430f, 0xd691513a, 0xcca5affa,
+	0x166f5a00, 0xd318ca5d, 0x367ba7fc, 0x5e3d74c6, 0xf5194c8a, 0x91a5553f, 0x83b18209, 0xe10f3c0f,
+	0x9e341f88, 0xda4ab0cc, 0xebed0e27, 0x4caf28f1, 0x7858df40, 0x2f402df0, 0x1b1448ba, 0x1902a8d9,
+	0x2ccb8d4d, 0xcf61feac, 0xcc5129b0, 0x4310831c, 0xa1b952b6, 0x38a86b1c, 0x1a237018, 0xc54ed403,
+	0x2862de01, 0x4642d776, 0xab326f9c, 0x1f77d6f6, 0xc750c2ee, 0xe33ce33f, 0xad5d3a12, 0x08c445a8,
+	0x93d375a1, 0xdbdee163, 0x0a9adf19, 0x087c4ea2, 0x424209d5, 0x442d9e7d, 0x4a774447, 0xf25e7b5a,
+	0x211c9c29, 0x2bc8013b, 0xdbb47a8f, 0xe4b488ba, 0x232bd664, 0x8d75cad8, 0x897a4acf, 0x063ee023,
+	0x17e1951d, 0xff938625, 0x9054842c, 0x655bf3a3, 0x49a95733, 0xd2a9b2fb, 0x920ac0d0, 0x4f36f06d,
+	0x51cbc661, 0xe13611f1, 0xc0697d7f, 0x15cc80ab, 0x77bad072, 0x97ac601d, 0xde59e7d5, 0x4386961a,
+	0xa5933240, 0xbe24f15a, 0xcce27576, 0x78bea5b5, 0x69be8e18, 0x8b0539f2, 0x87584dcb, 0x9122daec,
+	0x56bacaa3, 0xc0c34688, 0x0ecc18c5, 0x6813f656, 0xdc3a4035, 0x2aaf8810, 0x93495813, 0x14dbf5f9,
+	0x10a41628, 0x4d076a1a, 0x4c45b8b1, 0xd855f9e8, 0x7aeae70e, 0xbe75caff, 0x2097430d, 0xd7244865,
+	0x6d924739, 0x456b3e2f, 0x4c6fdbfa, 0x6a1a9b59, 0x530b283b, 0x6faef632, 0xa151294b, 0x4316252a,
+	0x44fd8224, 0x92257c36, 0x59310e76, 0x6fed1356, 0x2383313f, 0xc7507bcd, 0x4a4bde55, 0xad485b75,
+	0xb7985549, 0x656f7900, 0xb9195663, 0xa2227836, 0xd65332df, 0xeeab11a1, 0x6804a5b4, 0x2c2c67d7,
+	0xbda17700, 0x20584a36, 0xdfd5b62e, 0x75f79050, 0x38091612, 0x425301a3, 0x01100ea9, 0x851b7457,
+	0x34ae34a2, 0x970e855e, 0x9cec96f4, 0xe6b56043, 0x99715f13, 0x2dd0596c, 0xb274720c, 0xdb8231b4,
+	0xa44b8aa1, 0x07915975, 0x70a0fe86, 0xbb503832, 0xf4c39dcd, 0xd212ed46, 0xcedd8524, 0x1422ab88,
+	0x33d199d2, 0xd173144a, 0xebdd292b, 0xedf3b857, 0x088ee263, 0x45f652b8, 0x1eaeb42d, 0xc9b8cc97,
+	0x75b3b8db, 0x1603dcfa, 0x329056b2, 0xc15ec3c2, 0xd95f68be, 0x2d760963, 0x45c094fe, 0x90e6d7d7,
+	0xaf9ab017, 0x8f8af2ba, 0x638e0542, 0x0e58a9ac, 0xb073bfdb, 0xa573ae8a, 0x5cf3f243, 0x5a041f01,
+	0xeeffa6a9, 0xb737aac3, 0xacc7ee7d, 0x1fa6d403, 0xf2054638, 0xed053e59, 0xb68df053, 0x3b88f7b4,
+	0x3ecf2e16, 0xee437eba, 0xc65cf75d, 0x8177a224, 0x845444e0, 0x338dd29e, 0xc75045b6, 0x30d215ee,
+	0xb8f6b3ff, 0x2aa43c9b, 0x7c92c2f9, 0xfd21e206, 0x0421ed0b, 0x2cb5533d, 0xda625078, 0xfe59fec2,
+	0xf8b4f95b, 0x5f4a60ac, 0x822c4f94, 0x84468c4c, 0xe9ba7277, 0x769cb3d0, 0x01c43292, 0xbbd4a446,
+	0xfebc41a7, 0x1c0d09ed, 0x62441963, 0xcf8cbfc7, 0xf7b2e5bb, 0x4b6aa644, 0x9878b573, 0x3a5c7c92,
+	0x4d1a4fcb, 0xc58b88d3, 0x5490580a, 0xa0f884a9, 0xbe1c05df, 0x2d077029, 0x3a04dd7d, 0x3704d8c4,
+	0x4ace7d00, 0xd0015d1c, 0x8ae0d380, 0x3510fb03, 0xd4fbbc6b, 0x7dc3426b, 0xbb5b55aa, 0x1076f404,
+	0xa95f22b7, 0xa55f1c6b, 0x9d588032, 0x9bf471d8, 0xbe205b73, 0x69138b73, 0x3681d6c5, 0x3281d079,
+	0xed371311, 0x0137328d, 0x78f3d020, 0x90f3f5e8, 0xbcbf3a1a, 0x5ee71012, 0x790c0e85, 0x056c66b3,
+	0xe208dcff, 0xf19dc76a, 0xfa2f27f8, 0x052f3949, 0xff1aa787, 0xa5ecf287, 0xaffcf96c, 0x0059315e,
+	0xf2563ace, 0x2115acdc, 0xe013168f, 0xbb67db33, 0xc1fd16b2, 0x20c846e7, 0xfecfcb6c, 0x19cff5ed,
+	0xad47cc24, 0xb98090c9, 0x71fd614d, 0xd0dbf5af, 0x420cfb9d, 0x3b0cf098, 0xaa951b14, 0xbbebeaf3,
+	0xaf10d537, 0xcc06fe8b, 0xd1381ec3, 0x5d3d99d8, 0x4d1b37a7, 0xf7581c70, 0xbd36e582, 0x57c451af,
+	0x46037ed4, 0xd74d8b78, 0x31035dc5, 0x8dadd055, 0x9869c251, 0x0188d969, 0x4f54b72a, 0x0ae27da7,
+	0x5693b4ce, 0xa8770ab9, 0xcb9b5fe8, 0x64190e32, 0x5810cfa4, 0x57d7f659, 0x87cb95b3, 0x6941ace1,
+	0xec8955ad, 0xf743b4a9, 0x02b07843, 0xdd8cc1b8, 0xfdd6a2a8, 0x6977f277, 0x40bb5284, 0x77c0425b,
+	0x4ab9b893, 0xa823c3f1, 0xd8bab913, 0xb3ae6ee2, 0x92e3afb4, 0x46ae2745, 0x275e3c84, 0x8ec4f1e7,
+	0xef56065b, 0xd2aaf05c, 0xbc20fdd1, 0x1882f85b, 0x3bb1ce7b, 0xec941da1, 0x8657666a, 0xa3c44d17,
+	0x686f5a86, 0x3dd9a4db, 0xff01436b, 0x3c6db025, 0xf70136d3, 0x039a485d, 0x5471bdd7, 0x9b77e62e,
+	0x1c4d5782, 0x392be5e4, 0xbdd15d93, 0x6059a0fd, 0xbd3bc08e, 0x7ddf162f, 0x0219faa0, 0x713e12a1,
+	0x1d56ed39, 0xc6c70062, 0x041c94d0, 0x46f18bb4, 0xe64d234c, 0x76c28c12, 0xa21283cc, 0x720cf069,
+	0x8fa3b8dc, 0x28344177, 0x8cf34b95, 0x915bef21, 0xfd318909, 0xefc461db, 0xc88435ef, 0xefedf355,
+	0x9a18d8eb, 0xa1fd981b, 0x2ce79ec1, 0xdd701ce4, 0x65e81b40, 0x892f6308, 0xa7baaad4, 0x709d40b1,
+	0xebce81cf, 0xe473e3cf, 0xc5ee1d15, 0xef8c6ca0, 0x6298369d, 0x4af5fe54, 0x2bb96802, 0x3b3b6bc5,
+	0xd00653cd, 0xa6bf6fe1, 0x59a2c2c3, 0x62585d11, 0x4347f4fe, 0x0f5007c0, 0x53c79ad4, 0x98f73be4,
+	0x6a50f7c4, 0x2295301e, 0x3862c60e, 0x8411ff3a, 0xd95db9df, 0x7583353d, 0xc736e755, 0xcf14a8bf,
+	0x17cafff4, 0xdd3a06f9, 0x3444819f, 0xd97bcbe3, 0xa989d506, 0x15cebffb, 0x15217196, 0xb02bdbb5,
+	0xff917d8f, 0x4c98a21b, 0x4ffdf745, 0x2fc25a38, 0x35c263aa, 0xad1e4f13, 0x68aecc89, 0xc0e5cce2,
+	0xe7542c88, 0x44b7c76c, 0xa7f1b7fc, 0x84de5508, 0x78de4224, 0xfab50035, 0xae74cf96, 0x5d115e5d,
+	0xfaa4cc93, 0x6211663c, 0x4a14ef7a, 0xe63cb2c3, 0xdb0c906c, 0x38a9f59e, 0x3d5af288, 0x941ed186,
+	0x7111fdd1, 0xeaeb4e5d, 0xdee2c985, 0xa3f6e899, 0xf0985b81, 0xaea56d4b, 0x22955687, 0xb0a18c37,
+	0x1fb970f8, 0x4e89df7f, 0x1ba37256, 0xb2758a8d, 0x0342e2dd, 0x308bbbda, 0x1d5e41c8, 0x64881af7,
+	0x5b96b104, 0x4301ac3d, 0xd606a0e9, 0xb1e68edd, 0xb3d3b199, 0x35170901, 0x619233e0, 0x0db6a33a,
+	0x99b3a65f, 0xde249bc6, 0xfeb54b05, 0xa2b3b48a, 0xcbcf9f77, 0x965f5565, 0xfab380b0, 0x56d89997,
+	0x0f3f8445, 0x71627967, 0x6dcce51c, 0x5cccca59, 0xdb101d08, 0x382cda6b, 0x26557c53, 0xdc066c32,
+	0xc8b08079, 0x000401fb, 0x04040847, 0xf503f0aa, 0x62c3ea2c, 0x65458a06, 0x9ab3b4e6, 0xd86680b0,
+	0xd144f9bc, 0x3f3a3786, 0x13b1c657, 0x50a5d320, 0xb39d8699, 0x62a59088, 0x362225f5, 0x8638d6b5,
+	0x1858b966, 0xce400683, 0x0576cbf4, 0xcdf2eb07, 0xa168a88f, 0x455ba2af, 0xc03e2aab, 0x01d71687,
+	0xad505bdc, 0xa49a6814, 0xa3980220, 0x14df9c48, 0xb3682bd7, 0x5cb00d91, 0x887269f9, 0x239324b9,
+	0x3292e5a6, 0x7f6afa4f, 0x3b17c738, 0x2ef62495, 0x83f8cd13, 0xb2eac80f, 0xdc59e4e0, 0x23daeac2,
+	0x9ecd5c7c, 0x9ef66255, 0xdb496375, 0xe6e39862, 0x729a17fe, 0x02a92cde, 0x62210699, 0x629bf05d,
+	0xd570d860, 0xdc34b396, 0x0f2616f7, 0x18207354, 0x86d05913, 0xd8b0c4e0, 0x75d27ac1, 0x5349aadf,
+	0xb5c9a7ee, 0x9e3e975c, 0xde112d4e, 0x1f551b9a, 0xc9d93151, 0x23428e5c, 0x02281e38, 0xfdcdc59a,
+	0x158acc48, 0xf2fb217f, 0xe11a61a6, 0xa2a86ff2, 0x306fd117, 0xdcbd4603, 0x9b150c94, 0xa3edd1cc,
+	0xf483670b, 0x5794984a, 0x7d958a8d, 0xf037a503, 0xb9c64f60, 0x566d2edc, 0x5ad863ed, 0x316ebd9e,
+	0xe5438f25, 0xfdd7406d, 0x6e7fc41e, 0x8c8290ef, 0x8561bbaf, 0x6efb71b0, 0xa2f0a468, 0x0fc46856,
+	0x83f62fb3, 0x286dc7d2, 0x7c54ef2c, 0x0a4548c1, 0xe2e7cea0, 0xedf00b9b, 0x6503601f, 0x461c1961,
+	0x9e5636df, 0xa60a06bc, 0x1e8f4840, 0x7e979f72, 0xbc4a4088, 0xf4937590, 0x263eebde, 0x769e1d41,
+	0x735b1050, 0x89c60c4f, 0x4af2288a, 0x94cbbeea, 0xa7e682df, 0x466bf564, 0x046b3300, 0x2add53f9,
+	0x65ac1635, 0xeda309a6, 0x483e4b72, 0x2070c088, 0xf0243919, 0x7674c9a1, 0x8274fa35, 0x34b00e8a,
+	0xa493f4a7, 0xd715f2c5, 0xe8160d88, 0x616bf1ec, 0xe1d352d7, 0x1d90f6a0, 0x7cb9f174, 0x06804609,
+	0x7fdabf4d, 0x48395506, 0x29c22cc8, 0x0fd165ff, 0x5c2c0201, 0xa1b40efa, 0x889631d6, 0x18cd63b7,
+	0x848560c6, 0x1a5cef3b, 0x2f5f9754, 0xd1e9cc8e, 0xff834abd, 0x909b5648, 0x437419ce, 0x7a23352c,
+	0xb5e17fe0, 0xbed3b837, 0xbd9bba9b, 0xc9d6c8f6, 0x0035d16b, 0x7e350bf1, 0xd2f4c47c, 0xe1e862ba,
+	0xe16011c2, 0x07be8c50, 0x904b593f, 0x644be3f4, 0x9918853c, 0xe002dcc2, 0x92f5ef65, 0x46f077eb,
+	0x8080a18b, 0x865238a8, 0x8980afb6, 0x52f052a1, 0x38c0c6d2, 0x3fe280ef, 0xf410b117, 0x6c6a89b9,
+	0x3a49e845, 0xde5c5e6d, 0xb84b2bc3, 0x8e26a007, 0x1f2ad9dc, 0x01b353a9, 0x4c53c9e4, 0xc9f16ad8,
+	0x753fbb0b, 0x31070163, 0x493f7d2a, 0x2a0a63af, 0xdfb062cc, 0x417e5ae8, 0x25827663, 0xa3ea9b9b,
+	0x90e7bff4, 0x72652830, 0x37e9e44b, 0xdade262d, 0x5b4fdb36, 0x9969f2fd, 0x94068de7, 0x515cb363,
+	0x77cc6310, 0x758336dc, 0x02ec74b5, 0xefec56cc, 0xf438d4fe, 0x5ba2b611, 0x2d4984c8, 0x2e60a7cd,
+	0x06f795fd, 0x0b65d355, 0x8e36d370, 0x5e0cdb66, 0x9d8e7e79, 0x720cfae2, 0xf7cadc8e, 0xcf14e64c,
+	0x7e8623a2, 0x7076ca63, 0x3826468c, 0xc3d10c90, 0x547909a3, 0x51b0fc9d, 0x30fa0022, 0xc5901de9,
+	0xc2901930, 0x90d24dac, 0xfb80afa4, 0x762b6211, 0x12bfe303, 0x64ea8342, 0xfac0df62, 0x5f0828ac,
+	0xb61b9ebe, 0x1643250e, 0x2d086bac, 0x7195af8e, 0x74b25e22, 0x1501c67b, 0x168cd3a2, 0x6bb5cb7c,
+	0x5aaec15a, 0x6f6ec119, 0x64f9ac28, 0x2ea9c676, 0x32589b7f, 0x4c91c77a, 0x9bec8efb, 0x0cceb9f6,
+	0xa9661cde, 0xa0a8f325, 0xa0c36019, 0xc683f692, 0x6fa39e02, 0x75a3a774, 0x60a38665, 0x8b0f0634,
+	0x301928fe, 0x830ef99c, 0x770ee6b8, 0xdb1635a9, 0x994d512b, 0x914d4493, 0xa357ef1c, 0x42f716b1,
+	0xca168877, 0x47a6462e, 0x2996133c, 0x462a06c5, 0x37d8b555, 0x1f3ad83d, 0x2ecc5f33, 0xc189ed69,
+	0x16588323, 0xc551c6a1, 0xc9a67c0b, 0xdd11afeb, 0xb1301f37, 0xd23c8fe3, 0x5dc46e80, 0x74b58c28,
+	0xdbeb1754, 0x4bde0d06, 0x10249b10, 0xdafc732b, 0x319cf606, 0x0a621699, 0xb5b86d52, 0xad18721f,
+	0xdcede621, 0x726b8748, 0x90415070, 0x7a9624c6, 0x1a4d7e6c, 0x643fb2e2, 0x157e8be0, 0x4f3f91d3,
+	0x073fb9ac, 0x288e883d, 0x88ac38f2, 0x6ca2bd60, 0x54002994, 0x813fef75, 0x27aa3a6f, 0x18aa22d2,
+	0x4b5331f1, 0x0de89c8b, 0xb1a41f37, 0x0ae897d2, 0xaa90743b, 0x5850c604, 0x39ecdf90, 0xf7164263,
+	0xfe164d68, 0x41ec55c9, 0xc54f514e, 0x62c4b728, 0x6077d58b, 0x2aa520e5, 0x0a1d9e19, 0x2ed3c7d3,
+	0x397df242, 0xa0f84cfa, 0xac08647d, 0xff346ffd, 0xf6d58d18, 0xdb0189f7, 0xb2c815e9, 0x89e02972,
+	0x46b82763, 0xf7a4fb4d, 0xf3d7fc9f, 0x1cf7cfcf, 0x35b14bb8, 0xd28bda5a, 0xe1765daf, 0xcf00748d,
+	0x5a6177d1, 0x5b617964, 0x66618ab5, 0x792cf020, 0x7282786c, 0xd3934ea5, 0x5057e001, 0xa0c6584b,
+	0x26abb06e, 0x57eca31c, 0xa4b05928, 0xc701c2dd, 0xb09b4ef7, 0x087df44b, 0x70a31087, 0x2a27b4bd,
+	0x30947ff4, 0x70e60556, 0xfd12fbe5, 0x0f38fab5, 0x4f20b2b5, 0x3499e59a, 0xed7128bd, 0x5c4b6520,
+	0xfdf7c22e, 0x8e1e9cd6, 0x37c9197f, 0x91642225, 0xdbb04a46, 0xa4d0ec32, 0xe5a80e5e, 0x8be40639,
+	0x8a282268, 0x2237f48d, 0x8bd38127, 0x7cd3698a, 0x3969959e, 0xcfc4f0be, 0xc0dd40da, 0x268e12fd,
+	0xe0ff6666, 0xcf6f1966, 0x6b15f74f, 0x6415ec4a, 0x74a8934e, 0xec0a3a31, 0x0631cf1f, 0xe6501665,
+	0x3fb029aa, 0x0f6ba23f, 0xf94f7190, 0xc99574cd, 0x3beeef0e, 0x64984a1c, 0xfb309c7b, 0xcdff1621,
+	0x8b9c7c9e, 0x1e8dfc6c, 0x56da0837, 0xaa20d786, 0xc94541b1, 0xcd4547fd, 0xd2d9e5a1, 0x210bf020,
+	0x309f3312, 0x47bdd703, 0x82b7fd85, 0x1f8cec70, 0xbb32b8b6, 0xd2e0d4e7, 0xaf36b018, 0x406d1085,
+	0xc90c6fdb, 0x768ca3d6, 0x2da0d0bb, 0xa823a2ba, 0xc78400b3, 0x0e434a31, 0xb26281f4, 0x5d501d13,
+	0x9fb1bc37, 0x05b24ed2, 0x6f739ba4, 0x7e6f0f4a, 0xa6fa189d, 0xb0d86039, 0x976f65fc, 0x936f5fb0,
+	0x4559f59e, 0x324d5fc6, 0x35ef6a80, 0x1ae80ea2, 0x0ae7f572, 0xc19bcee4, 0x5099bc16, 0x2ed98b69,
+	0x37d99994, 0x1a1afc86, 0x2de1406e, 0x1f26c35b, 0x93246353, 0xdb924a7c, 0x02d37e25, 0x4b43ba61,
+	0xdfb14166, 0xa4313f80, 0x8fa5b71a, 0x72a58973, 0x1e100117, 0x600f9a76, 0xd1c9ea75, 0x8dabfb5a,
+	0x4126e69d, 0x0b4aea1f, 0x151f4a82, 0xf9c773ad, 0xfb1d49da, 0xf71d438e, 0xdf1d1dc6, 0x5f0ae04a,
+	0x14d1f447, 0x10f84f54, 0x5017f4c0, 0x7eac9f6d, 0x1b06156c, 0x7eb2babf, 0x271caab6, 0xb189056c,
+	0x737e6703, 0x716e32cb, 0xedddfc80, 0x250f5c33, 0xa4201c51, 0xd27172df, 0x0c05e4d3, 0x1bb2e829,
+	0xcd8f30ae, 0x6c71c3a2, 0xe5c6bd26, 0x229b4b39, 0xfe374b5d, 0x5af068aa, 0x5756183d, 0x28699d25,
+	0x54b1ec32, 0x85e99255, 0xe219ceb5, 0x066d95dc, 0x116da72d, 0x2f1414d9, 0x0231a5e2, 0x7d2b791c,
+	0x852b85b4, 0x842b8421, 0xb84a90f1, 0xdf7fd473, 0x7124e8cd, 0x8bd042f0, 0x03fcc138, 0x45615852,
+	0x35274489, 0xb0b18f3d, 0x56a63953, 0x7a3cbb04, 0xabf89128, 0x5dd4233c, 0x18d658ae, 0x2995dc95,
+	0x9b2adfa0, 0x1023a352, 0xb971a350, 0xd81803ef, 0xe96e008a, 0xd6d7a0fb, 0xcd558cc8, 0x6c4b5db0,
+	0xfc3beee2, 0x9d8529ec, 0x88f132e3, 0xe4b238b9, 0x4e82af15, 0xb2a1056d, 0xbaaeb746, 0xbf941443,
+	0xb3f23c3d, 0xb5266a50, 0xae2e1694, 0x594db087, 0x545354d0, 0x47534059, 0xe29731bc, 0xcdac6fa1,
+	0xd744f7a7, 0xf3783eba, 0xd380a510, 0x23dd573d, 0xd9f7461a, 0x34efbbe8, 0x5c848fac, 0x4b4d7619,
+	0x7cb6bf05, 0xd77f7339, 0x41e999b8, 0xe5218ca2, 0x1ea5d5c6, 0x4e0daff3, 0x9d7796c3, 0x196fba64,
+	0xd99ac839, 0x7a5b67a4, 0x7087b5f2, 0x45297bfa, 0x68581cb4, 0xf939b727, 0xac3350b6, 0x1a22e6c6,
+	0x12dbcb12, 0xe98c94ad, 0x87de047c, 0x5d3bfc87, 0x58b70f2d, 0xeab630b1, 0xbf32c7b1, 0xdb91dfb6,
+	0xd5080a23, 0x8e813185, 0xcca6b4a2, 0x1594464b, 0xb6b15ae0, 0x73304839, 0x27ad1115, 0x10148509,
+	0x50eefea8, 0xc1d96d8c, 0xface6c3f, 0x3f0c6d60, 0x311aec74, 0x245e8411, 0xd3c2dee0, 0xa3fce7d4,
+	0xb682e787, 0x452f24c3, 0x95b26afc, 0xc2fb9e45, 0x76d50196, 0x5e95bc08, 0xaae6e478, 0xcff1a404,
+	0x6f0ea3fb, 0xc6e04a6b, 0x814cc12e, 0x2bdd84fc, 0x26eae42e, 0xc36ef49b, 0xbc033bd6, 0x4ee09402,
+	0x49b7a73b, 0x0e96dec9, 0x3475e2e3, 0xe77d51e7, 0xdcbcba86, 0x15700684, 0x891bfe41, 0xa025fcf3,
+	0x6cc800cc, 0x8d1e543c, 0x095d4e44, 0xdc35d076, 0x7cfe3a13, 0x52e9acd9, 0x97583980, 0x025fbf36,
+	0xcd1dafba, 0x460a6bbf, 0x7a6b4e07, 0x1b3794f9, 0xe8e49263, 0xafabe68e, 0x5377e0ac, 0x89e6faa9,
+	0x3b2e53f6, 0x676718a9, 0x10a175fc, 0xe0c18659, 0xa17bfd01, 0xf80e922f, 0x800972d1, 0xceca335d,
+	0x99e913fe, 0xf8adb159, 0x3ca91e9c, 0xaafd4247, 0x419cca4f, 0xe04665e9, 0xde4662c3, 0xdaff4d5b,
+	0x4e8d3229, 0x2009c470, 0xd8bc9117, 0xafb2ee7b, 0x287f295b, 0xc26d25e5, 0xf131ca79, 0x0ede1425,
+	0x2a6ed674, 0x1fd8d6e1, 0x979923f3, 0x87de6c4f, 0x41d1989b, 0x3f0eea17, 0x84a27e73, 0x567c6d8a,
+	0x3b3c0f50, 0xf1b8348d, 0x994ea11a, 0x62729a8f, 0x52e332b6, 0xbfe3dc68, 0xac5dd5d5, 0xd739a768,
+	0x67d3b096, 0x1be03269, 0x0e29e053, 0x19bb91d9, 0x2c2b216e, 0xc9cbe7bf, 0xa9ad8609, 0xea89e2d6,
+	0xcb515861, 0x5d4cbc52, 0xcd9c9e03, 0xdc648272, 0x06625fcc, 0xfecc9f50, 0x0901ae0e, 0x168087ee,
+	0xfe2fd378, 0xfa9666cf, 0x2eb88ae2, 0x3cec5c94, 0xf69d87ce, 0x032720e3, 0x49c531a0, 0x996d0934,
+	0xe7bfcd47, 0x67da9a87, 0x85af11c1, 0x4170025a, 0x0228a290, 0xdea37409, 0xfa6e9f0b, 0xf5ab8bac,
+	0x7bc88b0e, 0x218e4629, 0x4f7762c1, 0xca675d20, 0x1a151906, 0x0821de7f, 0x3903f911, 0xadf3797d,
+	0x3c7329d4, 0xab67f00f, 0x9778daad, 0xa1e181e9, 0xd5608eec, 0x8ef86bbf, 0xf0a7ca1c, 0xcf140a09,
+	0xd2cbd4ce, 0xbb57a7cb, 0xcec37ab9, 0x36844089, 0xd6af9d23, 0x3333858c, 0xdbd1c725, 0xb56db21d,
+	0xc1963420, 0xb914ba57, 0x9378561f, 0x42448761, 0x9ecaa9c6, 0x74c90945, 0x6dc8fe40, 0xbf615f07,
+	0x85c92408, 0x909aa9d0, 0x2b81e874, 0x4d35c56d, 0xd7190a78, 0x73f109cf, 0xce975145, 0xca6632a0,
+	0x45a0634c, 0xcf5ed324, 0x2c2eddc3, 0xf94b95f7, 0x9ae5bda0, 0x268f43bc, 0x70ae018a, 0x85c42b71,
+	0x2ca8b3f3, 0x701a23db, 0x94679bfe, 0x90cd6164, 0x0be53c10, 0x3e4f0a6f, 0x25048768, 0xb3b43b0a,
+	0xa9521c76, 0x2426fd27, 0xe1c08745, 0x9a4d44e1, 0x0d9c0d8d, 0x89d7abf1, 0xc2dcdac9, 0x39794a96,
+	0xd9f46ded, 0x71c06ffe, 0x82a3562b, 0xb45f9766, 0x024d2561, 0xab9a65dd, 0xc45823e2, 0xefb3235c,
+	0x6d228c88, 0xd80c0db1, 0x59ba730e, 0x5dba795a, 0x353ba467, 0x633dcd04, 0x623dcb71, 0xe16c59f7,
+	0x762fcc83, 0xd440ef76, 0xd9b74826, 0x1911811d, 0x9213a6bc, 0x1e83d13c, 0xa67655e9, 0x69b92531,
+	0xb3acb34a, 0x81e6cec7, 0xecec1f90, 0x91b749e2, 0xd2e08fd8, 0x0c38115e, 0xc56a319b, 0x8b0fbb25,
+	0x4ab1cc0e, 0x3f5898de, 0x3b00a347, 0x04da4b41, 0x0894723b, 0x7737c79a, 0x6d7e7590, 0x8976daaa,
+	0x9476ebfb, 0x723e711a, 0xcddd499a, 0xfcaa9ad0, 0x0180cb83, 0x0e80dffa, 0x282d58b4, 0x50563c67,
+	0x0b96d397, 0xd1f1a017, 0x69feb84a, 0xf379d3ce, 0x91027b3b, 0x6013ef70, 0x4a691f7d, 0xc5caac52,
+	0x71830a74, 0x7c54ab59, 0x8373669c, 0x2958b352, 0x3ec9a9a7, 0x53fccc2c, 0x2acf1e76, 0xb1e5fdee,
+	0xee551274, 0xe0345a75, 0x9e5f33a2, 0x56f9421f, 0x1f79268d, 0x091ef610, 0x5507f44c, 0x3bceb0bc,
+	0x2ebdd230, 0xf40e969f, 0xc67c3fe9, 0x99ee1c10, 0x5c162468, 0x529f6db6, 0x3a33d30a, 0x9b2e985e,
+	0x28ad8c4d, 0x8ea41912, 0x4fef5d20, 0x18e40112, 0xb125f0db, 0x120b76d4, 0xc30a0dd5, 0xd08698f7,
+	0xb6d71b95, 0x052e2554, 0x290eb97f, 0x03999ace, 0xb979db10, 0xd6d8b013, 0xd0aa625c, 0x3c296b7f,
+	0xf6efd74b, 0xf0ff6704, 0xdd36db73, 0x0cb11cd0, 0x256ac9ca, 0x2475c269, 0x13b2bf3f, 0xf7135c94,
+	0x3fd00b71, 0x9095abec, 0x4717edd8, 0xc0b9ace5, 0x89497ff7, 0xe38e4171, 0x8164d169, 0x2818efaf,
+];
@@ -0,0 +1,145 @@
+//! Validation of mnemonics without the word lists.
+//!
+//! Devices that only verify mnemonics, and never display them, don't need the
+//! words themselves. This module identifies words by a 32-bit FNV-1a hash
+//! instead, using 8 KiB per language. As long as nothing else in the binary
+//! uses the word lists, like [Language::word_list] or the [Mnemonic] methods,
+//! the linker leaves them out.
+//!
+//! There are no collisions within a word list, but a word that is not in the
+//! list is accepted with a probability of about 2^-21, in which case the
+//! checksum still catches all but one in 16 to 256 such errors. Only
+//! normalized input is accepted.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::{compact, Language};
+//!
+//! let s = "letter advice cage absurd amount doctor acoustic avoid letter advice cage above";
+//! assert_eq!(compact::validate_in(Language::English, s), Ok(()));
+//! let (entropy, len) = compact::to_entropy_in(Language::English, s).unwrap();
+//! assert_eq!(&entropy[..len], &[0x80; 16]);
+//! ```
+//!
+//! [Mnemonic]: crate::Mnemonic
+
+use crate::{bits, is_invalid_word_count, Error, Language, MAX_NB_WORDS};
+
+#[cfg(feature = "chinese-simplified")]
+mod chinese_simplified;
+#[cfg(feature = "chinese-traditional")]
+mod chinese_traditional;
+#[cfg(feature = "czech")]
+mod czech;
+mod english;
+#[cfg(feature = "french")]
+mod french;
+#[cfg(feature = "italian")]
+mod italian;
+#[cfg(feature = "japanese")]
+mod japanese;
+#[cfg(feature = "korean")]
+mod korean;
+#[cfg(feature = "portuguese")]
+mod portuguese;
+#[cfg(feature = "spanish")]
+mod spanish;
+
+/// The hashes of the words of the word list, in the same order.
+fn hashes(language: Language) -> &'static [u32; 2048] {
+	match language {
+		Language::English => &english::HASHES,
+		#[cfg(feature = "chinese-simplified")]
+		Language::SimplifiedChinese => &chinese_simplified::HASHES,
+		#[cfg(feature = "chinese-traditional")]
+		Language::TraditionalChinese => &chinese_traditional::HASHES,
+		#[cfg(feature = "czech")]
+		Language::Czech => &czech::HASHES,
+		#[cfg(feature = "french")]
+		Language::French => &french::HASHES,
+		#[cfg(feature = "italian")]
+		Language::Italian => &italian::HASHES,
+		#[cfg(feature = "japanese")]
+		Language::Japanese => &japanese::HASHES,
+		#[cfg(feature = "korean")]
+		Language::Korean => &korean::HASHES,
+		#[cfg(feature = "portuguese")]
+		Language::Portuguese => &portuguese::HASHES,
+		#[cfg(feature = "spanish")]
+		Language::Spanish => &spanish::HASHES,
+	}
+}
+
+/// The 32-bit FNV-1a hash of the word.
+fn word_hash(word: &str) -> u32 {
+	word.bytes().fold(0x811c_9dc5, |hash, b| (hash ^ b as u32).wrapping_mul(0x0100_0193))
+}
+
+/// Get the index of the normalized word in the word list of the language.
+pub fn find_word(language: Language, word: &str) -> Option<u16> {
+	let hash = word_hash(word);
+	hashes(language).iter().position(|h| *h == hash).map(|i| i as u16)
+}
+
+/// Look up the indices of the words of a mnemonic, without checking the checksum.
+fn find_indices(language: Language, s: &str) -> Result<([u16; MAX_NB_WORDS], usize), Error> {
+	let nb_words = s.split_whitespace().count();
+	if is_invalid_word_count(nb_words) {
+		return Err(Error::BadWordCount(nb_words));
+	}
+
+	let mut indices = [0u16; MAX_NB_WORDS];
+	for (i, (slot, word)) in indices.iter_mut().zip(s.split_whitespace()).enumerate() {
+		*slot = find_word(language, word).ok_or(Error::UnknownWord(i))?;
+	}
+	Ok((indices, nb_words))
+}
+
+/// Validate a mnemonic in normalized UTF8 in the given language.
+pub fn validate_in(language: Language, s: &str) -> Result<(), Error> {
+	to_entropy_in(language, s).map(|_| ())
+}
+
+/// Validate a mnemonic in normalized UTF8 in the given language and get its
+/// entropy. Returns the entropy buffer and its length, like
+/// [Mnemonic::to_entropy_array].
+///
+/// [Mnemonic::to_entropy_array]: crate::Mnemonic::to_entropy_array
+pub fn to_entropy_in(language: Language, s: &str) -> Result<([u8; 33], usize), Error> {
+	let (indices, nb_words) = find_indices(language, s)?;
+	let mut entropy = [0u8; 33];
+	let len = bits::strip_checksum(indices.get(..nb_words).unwrap_or(&[]), &mut entropy)?;
+	Ok((entropy, len))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::Mnemonic;
+
+	#[test]
+	fn test_hashes_match_word_lists() {
+		for lang in Language::ALL {
+			for (word, hash) in lang.word_list().iter().zip(hashes(*lang).iter()) {
+				assert_eq!(word_hash(word), *hash, "{} in {}", word, lang);
+			}
+		}
+	}
+
+	#[test]
+	fn test_validate() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let s = m.to_string();
+		let (entropy, len) = to_entropy_in(Language::English, &s).unwrap();
+		assert_eq!(&entropy[..len], &[0x42; 32][..]);
+
+		assert_eq!(validate_in(Language::English, "abandon zoo"), Err(Error::BadWordCount(2)));
+		let unknown = s.replacen(m.word_at(0).unwrap(), "abandonn", 1);
+		assert_eq!(validate_in(Language::English, &unknown), Err(Error::UnknownWord(0)));
+		let mut words = s.split(' ').collect::<Vec<_>>();
+		words.swap(0, 1);
+		assert_eq!(validate_in(Language::English, &words.join(" ")), Err(Error::InvalidChecksum));
+	}
+}
@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0x91ef5f22, 0x9a6565ad, 0x9bf43100, 0x8026a7b4, 0xc87e0fb2, 0xeb6084f0, 0x008a812c, 0x857070d2,
+	0x76670de9, 0xe8c4ae1f, 0xf2b1eb9d, 0x1ce7b02f, 0xb50002f4, 0xc0074321, 0x17d2787a, 0x5fe23211,
+	0x301b51ea, 0x0ef45158, 0xaa351898, 0xccaa4326, 0xc5273c81, 0x06c292de, 0x6077e79a, 0x78064fd4,
+	0x82375bc1, 0xef53f225, 0x925a3105, 0x2767b4b4, 0x343f3b69, 0x8615e3b7, 0x96301966, 0xcacc9035,
+	0xfbfafffb, 0xa732b246, 0x4ea80c47, 0xd4e8c2fa, 0xf87b3439, 0x52516189, 0x5a777d4b, 0x6766b0b5,
+	0x003c3521, 0xdfd56858, 0x70df4f53, 0x2c8838c6, 0xb370cd94, 0x291d9faa, 0xcd35c74b, 0x435fde1c,
+	0x8b0b04df, 0xcf8b3ab3, 0x3f9e490b, 0x56bde641, 0x52c821f4, 0x07c091d3, 0x8f2d3cda, 0x28cf985e,
+	0xaf426780, 0x9073c2bf, 0x96ac63f6, 0x135ad254, 0x0a9658d9, 0x5e745596, 0xc47fa259, 0xf5914b71,
+	0x9004369b, 0xa805d89c, 0x29856311, 0x7b5b2fb4, 0xcba64f2c, 0x41c422ef, 0x76143a79, 0x12af8fa1,
+	0x017ee3f8, 0x9b12bd26, 0x206dabda, 0xa89a0bdb, 0x6e36db17, 0x85f51972, 0x84a9132f, 0xff613a76,
+	0x8daafe9b, 0x964218d1, 0x479f1fac, 0x7942cc71, 0xb029f19e, 0x8d65b0ad, 0x0fb57859, 0xd81139e4,
+	0x695c56f0, 0x461b0432, 0xdea7b989, 0x62bb9445, 0xd291b5e4, 0xf7270d0c, 0xaa87b04e, 0x7db9cda1,
+	0x71569363, 0xa39b9ce7, 0x42acf23f, 0x7c267fc4, 0xb479c788, 0xfe54e27f, 0x32fbb14a, 0x2b1a4a99,
+	0xe05702ea, 0xab392449, 0x49c07333, 0x0faa6524, 0x527d2af7, 0x2e8be174, 0xb1ab012b, 0x99fbd534,
+	0xe7c19ca4, 0xcecfb617, 0x18c29a61, 0x909791ab, 0xa787663e, 0xb191e4f7, 0x78ca3f8f, 0x8e879f8a,
+	0x29b64e7e, 0x956cd810, 0x604185e3, 0x181197d4, 0x1d568774, 0x1674a8ec, 0xecf7fdcc, 0x51026c29,
+	0xf9e0c486, 0xc80f3d3d, 0x89cc3ec1, 0x3e6c9cd1, 0x6b7138ad, 0xfe5988a8, 0x262d9135, 0xac82ac05,
+	0x2f1f18aa, 0xc6c011cf, 0xa02a5474, 0xf5ec47e9, 0xc438e383, 0xa7d5a751, 0xdcdace19, 0x04455d5d,
+	0x69e62349, 0x48e15ae5, 0x0ae20d64, 0xef717936, 0x229dad37, 0x38104a9c, 0x0bd69ba4, 0x96afd5f9,
+	0xa2943059, 0xa9b848c5, 0x959a0f6b, 0x235560a3, 0x06ee3a9f, 0xbe1e7e12, 0x5a70e2cc, 0x44be67ec,
+	0x0268e7d5, 0x56f1b684, 0xa2bc43a5, 0x42285e28, 0x642e10bc, 0xcafd9429, 0x879214b3, 0x7aff7d6a,
+	0x93a370b8, 0x5134f46d, 0x764e38b6, 0x4dbdbeb7, 0x7a25149a, 0x7a351bf9, 0x2fa00691, 0xfa2a4489,
+	0x495f6087, 0xaafbb2bc, 0x0fe3622c, 0xb74e1ffb, 0xa0bb8abd, 0x045e781e, 0x53ad451c, 0xc44ebe5d,
+	0x4a3d43c1, 0x90988cf1, 0x2955f4af, 0x9070bc4d, 0x15580a33, 0xcf3b456e, 0x8ef58f7b, 0x03688946,
+	0xf5ceb01a, 0x294190b3, 0x516f9b58, 0x09f0c8d6, 0x4a63e4e6, 0x34d67d2d, 0x359aeb48, 0xa81786e7,
+	0x980b7410, 0xef9ac5be, 0x9cc75048, 0x0f836646, 0xd38c5fbb, 0xf48569ab, 0x997cd6d0, 0x3b1cf9fa,
+	0x30045c09, 0x8e71f5dd, 0x31b4280d, 0x8339711a, 0x07aa691e, 0x3bbefb95, 0x2d573c98, 0x7830701e,
+	0x499b6781, 0xb0d3c5ed, 0x6315fb9a, 0xd9ebd6eb, 0x9ecb452d, 0x412efec6, 0xbcac94a7, 0x9c8d6786,
+	0xb1488580, 0xbc284525, 0x082f3631, 0x5cb44af9, 0x78857868, 0x288caf06, 0x93a56b54, 0x4cf7886f,
+	0x064af66d, 0xc1bdc999, 0x404f933f, 0xb8811432, 0xfd4859a1, 0x538b67a7, 0xf27015a7, 0x0136f6e9,
+	0x4797381e, 0x2759cf6e, 0xc705ab50, 0xfbf4cc16, 0x20b712e4, 0x4f00b2d9, 0x035f4346, 0xca4c4698,
+	0xa2c9b1eb, 0xcb0c14a5, 0xa3dafc9f, 0xc0dd68dd, 0x15309e3c, 0x24815760, 0xf06c4736, 0xc3ef9da6,
+	0xa38eeb2b, 0x5128df3a, 0x752ca9c6, 0xe654ae9b, 0x968debc4, 0xd66db3c0, 0x3fd00e9c, 0xfcf924f8,
+	0x7c299d3f, 0x397955b5, 0x5cfbecf1, 0x62387114, 0x54f3454d, 0xec9c20e4, 0x832ffa50, 0x9d14d23e,
+	0xac406228, 0xe41707b2, 0x7d03e868, 0x6e4c5af1, 0xc1c608c7, 0x010e6110, 0x832fe72a, 0x15a29999,
+	0x678bc86e, 0x1b418fc7, 0x74161a5a, 0x9e4c62de, 0x3650e8c9, 0x6d54781a, 0x0401f635, 0xeeeef3f5,
+	0x09735081, 0xdd39cd6c, 0xccbdd648, 0xb585fcd5, 0xbb966177, 0x8b0534b7, 0x00d98d42, 0x496ac038,
+	0xcc04b81e, 0x04cbb2bf, 0xd7d27825, 0xb0d8b2d0, 0x30982362, 0x32e31a16, 0x967be08a, 0xd7c49e7a,
+	0xe70cb716, 0xa8d4731e, 0x08841afc, 0x9701319e, 0x31fbefb7, 0x7fa34591, 0x89b89a2e, 0x71c05bce,
+	0xaf73cce6, 0x7e09a385, 0x86c387e1, 0xe597017b, 0x7a273f98, 0xab0ffddb, 0x4cefc3f1, 0x2935d414,
+	0xe14ac628, 0xc49efcf3, 0x902a29ae, 0xb60d3529, 0xb9eb6588, 0x01c92c35, 0xaf872e40, 0xac19c7f4,
+	0x1b9c5d40, 0x535e30df, 0xeace84f8, 0x3b29f97d, 0x4ce758d0, 0x5b77d55d, 0x9cb9681d, 0xda5e01a6,
+	0x0fc041d3, 0xffd141c5, 0x348b3fb6, 0xca7571dc, 0x7918c311, 0x6939dc23, 0x954a9547, 0x5cf8c29d,
+	0x6ed88b48, 0xd0e3e0e0, 0x8fc22041, 0x3e58ffcd, 0xc8bb1fb3, 0xb1008a7a, 0x3f7fc859, 0x4273a248,
+	0xf8790271, 0x0a4b0288, 0xb9d33dda, 0xe9a7d99e, 0x3c7f6344, 0xb576d55b, 0xca375dc4, 0x8980ce1e,
+	0xd2a182d2, 0xd1f37251, 0xa6ecf304, 0x82963d4a, 0xeac7a982, 0x383be342, 0x1c919fc5, 0xc8ae065e,
+	0x21da0457, 0x2f6a6358, 0x394c356d, 0xc0ae9eff, 0x3ee43728, 0x913685c0, 0xe5bc1e26, 0xe2c12360,
+	0x1ce4ff34, 0x8607c347, 0x6c209f5c, 0xf7432b01, 0x2e59e3c6, 0x11ee5d0d, 0xd5658758, 0xa0e8809c,
+	0x49ac42a9, 0x8fa5b5c6, 0x419bc4f9, 0x5255df1b, 0x31cc89eb, 0xc02ad8ce, 0x3b15c7ed, 0x98373490,
+	0xb062a42a, 0x1652c1d1, 0x96b93543, 0x7749c414, 0x614d6530, 0x7721f254, 0xb2e20c95, 0x1480ae9a,
+	0x94cfcb70, 0x2b39b8e5, 0x125a254f, 0x179cff82, 0xf1b0e9ac, 0x8a0b0218, 0x2d450f48, 0xfa975ccc,
+	0xb022e627, 0xd8d83957, 0x2c6206e5, 0x25c7eb5e, 0x165b1215, 0xd3e3d923, 0x6498b925, 0x13867b61,
+	0x753578ec, 0x8fc1a2dd, 0x3d1dc4a1, 0xa8c38a04, 0xc889f27b, 0xc6eaf3ce, 0x5fa55541, 0xcd9a6be5,
+	0x1797e4b2, 0x133dcbb1, 0x9b0279f9, 0x4881741f, 0x423aa280, 0x569bc218, 0xb061977e, 0x4a172da9,
+	0xccb09e6f, 0x804035ce, 0x579ecf5c, 0xa57c3f30, 0xd5075d72, 0x30937cb3, 0x03bbc6be, 0x8cb15da4,
+	0x157ebf1c, 0x1b2c34ed, 0xf78bd260, 0xa21bd874, 0x319417a7, 0xfa859308, 0x76d2b5bc, 0x2c12fbac,
+	0x20cf1936, 0x6a5aedad, 0x2681f3e4, 0xcc25c2e9, 0x92bdd36c, 0xbdc830d2, 0x4bac20ac, 0x45a79a0c,
+	0x2dcf70c9, 0xfe7c04a1, 0x03862b29, 0xec43f008, 0x7e3d0601, 0x519d5f06, 0xb87c3d8d, 0x2b885b82,
+	0x8a488b38, 0x0bc22bd7, 0xb1317040, 0x9568fb58, 0x946d40fd, 0x59e8d4f0, 0xb3d39418, 0xbfae5f57,
+	0x01f954b7, 0x5704df00, 0x57498380, 0x29cba283, 0x6c548c1f, 0xf533aafc, 0x2d5bb6e6, 0x9b4a4352,
+	0xd7093b38, 0x7948133f, 0xfb60c18d, 0x0512b1a9, 0xd2fc6ca5, 0xba0b9e58, 0xf7c7bea4, 0x54761b84,
+	0xd389f9cb, 0x90645b04, 0x5be90c08, 0xdbe729d4, 0xecb9b803, 0xe000ad2c, 0x8d10be42, 0x69f106c0,
+	0x7eac3702, 0x3e88ba66, 0x4fd32c08, 0x642c1905, 0x43743fad, 0x92b7594d, 0x23e0d728, 0x8816588c,
+	0x170c3458, 0xf363004e, 0xfc879605, 0xd48594c1, 0xad658045, 0x35c11e99, 0x2f5dc645, 0xaa906d71,
+	0x1afba7b6, 0x4760f1e7, 0xce4ff217, 0x8cb79e9c, 0x529766c7, 0x6c96e190, 0x87b3a490, 0x24802019,
+	0x1a7715ff, 0xd8d7c116, 0x1a417137, 0x6cad4675, 0x4158cc30, 0x57440c63, 0xe769d7e8, 0xedb9bba9,
+	0x98497b57, 0x938a7249, 0x4b505d1d, 0x2ec5c968, 0x8cc550ae, 0x8e340317, 0x3f53e732, 0x06e18a81,
+	0x8972062a, 0x04e6a99e, 0x604c5aeb, 0xcd50eda8, 0x7eb5946e, 0x95a59af6, 0x933b139f, 0x9d6d6012,
+	0xd9fe2456, 0xc8eda71d, 0x23abadc5, 0x8ba2dcc3, 0x29805bd7, 0x9939b3e0, 0x3f612ec0, 0xeb1e39c1,
+	0x93337f74, 0x699354cd, 0x79aa73ba, 0x071d70af, 0xf4c0a223, 0x93db1875, 0xfcbb4796, 0x1e277322,
+	0xc9380f89, 0x1bf1c7dc, 0xc26fdaf7, 0x1f088d4c, 0xe2865df1, 0x931e457f, 0xd6c87e11, 0xdd3af99f,
+	0x75623274, 0x24e7165a, 0x271c376b, 0x6b491351, 0x0679ee8b, 0x88de3295, 0x5786ab6e, 0xfc2c2b8f,
+	0x36ae0584, 0x71217a86, 0x4b79c5fc, 0x804f18c3, 0x4171f2f7, 0x7f8f2a0d, 0xaf640f23, 0xaf977394,
+	0xd2fced5f, 0x4823e6f5, 0x8008bbcc, 0xec1a3dbf, 0x8dd1f440, 0x7a72ae26, 0x6ab5ca82, 0x5b65188b,
+	0xcff9d80c, 0xffb0e633, 0xe9aa4289, 0x1baa19e1, 0x88b2bdd8, 0xbb18b937, 0xdeb15367, 0x52223975,
+	0xc7538b87, 0xd36ab835, 0x905436dc, 0x6b308359, 0xaa64c7c1, 0xfc6a711a, 0x5b06aac6, 0xe9303cf4,
+	0x8b7fb616, 0x2e2744e9, 0xe7cf2ccb, 0xfa6e7a7a, 0x0eae50b7, 0x3ee7e46e, 0x90b21425, 0x0edbf727,
+	0xe5b83b6e, 0x69ef4939, 0x984167a5, 0x94518f6b, 0x6fd1b041, 0x5ff6296a, 0x9501ef0a, 0x3bc82085,
+	0xa2fd87e3, 0x7b1f87ae, 0x6b0f350e, 0x872bbf88, 0x0e2a587f, 0x6358080b, 0x8eeafa62, 0x0ee6bd65,
+	0x22dfc18a, 0x9185477e, 0xcb7ae111, 0x88c7215d, 0xe8095bd1, 0x41181e0b, 0xe528cc44, 0x6295300d,
+	0x563f4763, 0xa7261e76, 0xe1ab764d, 0x61115dbc, 0xae144925, 0x16323d0e, 0xe43f72c3, 0x3b370e7b,
+	0x5399d104, 0x80322a0e, 0xe55df467, 0xb774d4dc, 0xcfca0034, 0xb76613ae, 0x54cfbb74, 0x661df8c3,
+	0xf8f2bb93, 0xdfdd71e4, 0xed599cdc, 0xb57c3cb7, 0xd3078691, 0x862c7ed6, 0xbc90ff08, 0x65eafff4,
+	0x1faba356, 0x3c24fe9d, 0xac3fee5b, 0x741b9de8, 0x8ebe9fad, 0x095a752b, 0xc2dcb753, 0xc73a65f9,
+	0xe3d5f947, 0xfdfda266, 0x5c7b58e6, 0x47ca2d96, 0xa0628bca, 0xe8253c67, 0xd647ccf4, 0x97a9f29f,
+	0xf83f806e, 0x6f4601b5, 0xee1c94d6, 0x3c915ecb, 0x41c91acc, 0x8329c1dc, 0xc920824e, 0x3b8e086f,
+	0x115cc4ba, 0x8d7b18b6, 0xf18088d2, 0xf002f091, 0x2480d279, 0x2b8479c6, 0xa94fcaea, 0x5064e0fd,
+	0x48f98811, 0x3c0cb10e, 0x97693ce6, 0x5a9c541d, 0xf845610b, 0x4b3615ef, 0x6334a755, 0x08ef0694,
+	0x8c5b59f2, 0x0f637560, 0x193c58c6, 0x288e55e7, 0x32754bb3, 0xd96ef613, 0x6d3c7bde, 0x7737bc7a,
+	0x06bb5b45, 0x3f1db50a, 0x2c922b2c, 0x26a17a7d, 0x4a53c4d5, 0x08050e9e, 0xdc9115a4, 0x8219b3fa,
+	0x07cbe63f, 0xdc910337, 0x523655f0, 0x372a2597, 0xb4f85340, 0x87349cfe, 0xcefe8c88, 0xca443144,
+	0x46f007cc, 0x6dc09238, 0x9e98ae80, 0x5a48c2c8, 0x275fcf13, 0xdd4fda82, 0xd219fc43, 0x09b1abb9,
+	0x95d9b453, 0xd979d754, 0x746022c8, 0x4fd5603a, 0x3db86c52, 0x3406d13d, 0x7a46dd52, 0x074d2c7f,
+	0x9abbe2e1, 0x6ba61ede, 0x66ccb3e9, 0x045c5be3, 0x07372d46, 0xb878fdef, 0x732fe122, 0x1d9e8f3a,
+	0x5ae6fbeb, 0x90d48046, 0x365e46d4, 0xda3ac544, 0xeb719a50, 0x40ba2835, 0xd63aeadb, 0x7cce2ffe,
+	0x309a45e0, 0x3fd6c88f, 0x05c53098, 0xd4355236, 0x083ed116, 0x88c81569, 0x71c872e2, 0x72501f53,
+	0xc1fa4f4b, 0x9b340827, 0x671a3274, 0x19d8d3da, 0xcf21a6f2, 0x7fa0cd26, 0xf73b6fa6, 0xfe6a025f,
+	0x484bb187, 0x39ede502, 0xf1a46522, 0xda773f57, 0x74b2ca04, 0x4e75936c, 0x5b854799, 0x0c0ee6de,
+	0x9dc9843e, 0x4584452c, 0x15dc8a14, 0x7d2849d7, 0x72f01f80, 0x7cbe0275, 0x5de23b08, 0x15acb706,
+	0x7b732b58, 0x61bedf0f, 0x823a901a, 0xdc698e5b, 0x71d6f885, 0xf56d8a5f, 0xd9d39503, 0xd14c07b0,
+	0x11d984bf, 0x06f549eb, 0x3cd8924e, 0x964590fb, 0x5627d392, 0x686ff3ab, 0xbc1b852c, 0xdc3cb538,
+	0x0c0712ce, 0xaa5db8ca, 0x863401c1, 0x088d9038, 0x39cb7652, 0x40c70429, 0x28a12f81, 0x33bc7cca,
+	0x6fe161f6, 0xa30490f7, 0x9e8824d4, 0x0c0a3077, 0x50920c18, 0x44029a58, 0xd07916cd, 0xb04b3453,
+	0xc619ba29, 0xc94ad299, 0x2e68651e, 0x1af8bf3b, 0x4881123b, 0xb578bc4d, 0xeeace1d0, 0x8e6a2232,
+	0xf1cf7a21, 0x7a6df2be, 0x5abfe702, 0x5b4781a4, 0x5f17cbff, 0x5b034321, 0x64fb041a, 0x34bdc045,
+	0x457d40e9, 0xe244c51a, 0xc9da0a48, 0x9eafad37, 0xad45f26e, 0x3474b7fc, 0xb4b8ca35, 0x75f75b99,
+	0x47f0f09c, 0x4f94a86c, 0x8e845083, 0xb9ef5c75, 0x81a866d6, 0xabe2aa77, 0x313e86ce, 0x8bfdec75,
+	0xc9e7053d, 0x423cf3f0, 0x6feb18fe, 0x6c846f89, 0x06da3ee7, 0x9f1a13c8, 0x882bf65d, 0xd4b473a2,
+	0x93c3f4ef, 0x798324ab, 0xdfe20c7d, 0xf2093843, 0xea302cfb, 0x152be263, 0xdc6ba2d8, 0x6010e593,
+	0x41e3afe0, 0x62ccedd2, 0x168823d9, 0xf1f51c2b, 0xbe827602, 0x3eb06c8a, 0x46419b16, 0xb3f305e7,
+	0x4ac58884, 0xa20eeafb, 0x416929c6, 0xbf2dbfc0, 0xcdd27cb2, 0xf52b471d, 0x58c1a0bc, 0x57bd21fb,
+	0x6da663be, 0xc5ad760c, 0x295edfac, 0x45da64d8, 0x91cff59e, 0x3c388474, 0x0d4f5e3a, 0x79072dd2,
+	0x8fad4634, 0x437879c2, 0x4e658f62, 0x93b33ce3, 0x36a6fe2a, 0x80722194, 0x99849893, 0x23117272,
+	0x5aa8d47c, 0xc54ae31a, 0xe34c2826, 0x0064868d, 0xbde35edd, 0xb66223b7, 0x1260e4c6, 0xdd5b210f,
+	0xf0031e43, 0x94666e67, 0x82d0e01f, 0xe87b72fc, 0x67fe9280, 0xfe0aa8a8, 0x4d96596b, 0x92f9559a,
+	0xacb4e645, 0x1a011a00, 0x0dbef799, 0x53727fa8, 0x806c897e, 0x9045e776, 0x4d197a81, 0xa9406184,
+	0x18ae5de8, 0x1e6a1c83, 0xae21ea5a, 0xd0d1be68, 0xd1eff9e0, 0xd5bf5515, 0x7114e99b, 0x994b92cc,
+	0xaef0af6b, 0x1dff06ae, 0x308af0cd, 0x1233ade9, 0x3c0f44ac, 0x27ea8854, 0xaa19160d, 0x58de8c9d,
+	0xccd02409, 0xab37d84c, 0xc204b0bb, 0x76de38c6, 0xe8b29885, 0x26e129b4, 0x6a547e69, 0xb03b0bd1,
+	0x2a83758e, 0x97ff779f, 0xcbe540fd, 0x9e87d332, 0x29c06f67, 0xa08b66a4, 0x02197121, 0xe006b080,
+	0xfbf303d6, 0x4f9a6322, 0x34170f04, 0x67ab5e24, 0xba88ebf8, 0x3e2de911, 0xc962f62d, 0xfb1bca2d,
+	0x1e7b7b24, 0x41ab3b2a, 0x2d455650, 0x7828b9dd, 0xe1dbe4a8, 0xa0c141f3, 0xcc02d382, 0xa76d1501,
+	0x65a711ba, 0x0dfd9522, 0x3dde7fbc, 0xfec82902, 0xfe3ade10, 0x34f98570, 0x7c4f90ab, 0xe539a869,
+	0xd2e10d4b, 0xcd143536, 0x8eb342b1, 0xfcfa6fe0, 0x8fa9017c, 0xde513212, 0xd10e2b08, 0x56c81b09,
+	0x42598fa6, 0x165bd68d, 0xf72f5258, 0xa2c02839, 0x8948b249, 0xb50acb59, 0x09ac5c27, 0x57f31fae,
+	0x23fce809, 0x24a8b391, 0x1c9850c6, 0x04726f1c, 0xd41b31d9, 0x204c1a9f, 0x4d7529ba, 0x8fe28f79,
+	0x1d33a506, 0xd051d529, 0x4606a983, 0x5a914939, 0x1a830f66, 0x4ce0b07d, 0xdfd75cb5, 0xbc468da1,
+	0x7414aca6, 0x374913df, 0xdf2808f8, 0x7fe10582, 0x2b297067, 0xe4fcbc68, 0x2b265563, 0xc2c6a2cf,
+	0xd86e0028, 0x4ed97522, 0xa3f62589, 0xcb3b2808, 0xe8b9faeb, 0x0c2e1c32, 0x581cadfd, 0x7e48205a,
+	0x73efbe12, 0x5ace8831, 0x6c511d77, 0x94ae7d81, 0x00b1310f, 0x929273d9, 0x8e7553d6, 0xe35e7582,
+	0xd4c6c1c1, 0x20f81cac, 0x6be150db, 0x55ad71cf, 0x6bc9689d, 0x336da285, 0xafa6817a, 0x5aba4203,
+	0xda28ce4d, 0xf594f554, 0x65787549, 0x08f622b2, 0xd71904d0, 0xecf77f31, 0x9e1c40b7, 0x1ea2d581,
+	0x9315fc67, 0x20a7c948, 0xb19ef7eb, 0xfb95fb9a, 0x7cf636e1, 0x455ce0ff, 0x0e2f4ba3, 0x7c06d22f,
+	0x7dd4e88d, 0xb2511c1b, 0x9e0161ae, 0xca040be3, 0x62d82b05, 0xbbbf1c54, 0x73164329, 0xcb842b51,
+	0xd5ad57b2, 0x0d277394, 0x64602a37, 0xc3aac312, 0x325d758d, 0x8d7ce299, 0xebe5d01f, 0x7a13efb4,
+	0xaea0a9ca, 0xcd8cdeec, 0x51a3bc73, 0xbeca05bd, 0x18e46cbd, 0xbc732b68, 0x0bc38854, 0x8893c3dd,
+	0x03349aca, 0x0a1b3b5d, 0x4859aee6, 0xd4e3e8b2, 0x920bd46c, 0x7c2e4dad, 0xfd52618b, 0x1c4c4f5b,
+	0xd222caa4, 0x506d814b, 0x3fa93389, 0x88a6ad36, 0x964c0d3d, 0xaede6ec2, 0x32c22525, 0x9534b25b,
+	0x61d0ddd1, 0x5e3ade89, 0x5721aacc, 0x9f310889, 0xa8238723, 0x96381b9a, 0xfaf8f6e5, 0x28c951bc,
+	0x6c8404fe, 0x141e2745, 0x0077807c, 0xe5dc5e93, 0xd8169c67, 0x7ef34bbb, 0x8c0347ca, 0x8d37118d,
+	0x4e967539, 0x5b62750d, 0x3d4255e2, 0xaf10a300, 0x1f25c75c, 0x1ee8dc1e, 0xc403c0af, 0x4141a419,
+	0x328b08ff, 0xd89a3f3a, 0xb62f4a5c, 0x84029924, 0x38d02990, 0xa97583f5, 0x93219f7a, 0x1653d852,
+	0xd4331bdb, 0xfd644d13, 0x4078f0a1, 0xced8faa7, 0x5ebf7157, 0x555bf004, 0x158783cf, 0x5270c8f3,
+	0x6bc0de67, 0xf92e838b, 0x033e08e0, 0x14fa5e80, 0xc70fc99a, 0x3b39e3da, 0x491d9799, 0x790c9a08,
+	0x0b342f06, 0x9c585e65, 0xb5a30b27, 0xab615e8e, 0x7104bc7e, 0xc89fb886, 0x3d75324a, 0x5d705626,
+	0xe685cb2c, 0x14890382, 0x65ff8987, 0x7162408f, 0xc81d162b, 0xefe1fdaf, 0xd00594c0, 0x7b5e5132,
+	0x8144c856, 0xc1d92377, 0xe79576af, 0x56fff7f6, 0x3ec6050b, 0x1812c321, 0x3a849721, 0x28d0a6ad,
+	0x1f501759, 0x8cbd6045, 0x8aa25ce4, 0xc8ece8c5, 0x10e36202, 0x494dc577, 0x0a34ea4d, 0x77bbe596,
+	0x6552b8b9, 0xa067212a, 0xe0dbc9db, 0xb21a96d0, 0xe0144c69, 0x91ceb0e9, 0xed0a3e4c, 0xfa4b2174,
+	0x11854a78, 0x17f8fc76, 0xd4ef6a3f, 0x83628b55, 0x3e0f4414, 0x6e0b8eae, 0x56659474, 0x0d43ffc6,
+	0xd4572fb1, 0xc6e7ac1e, 0x1291a374, 0xbe16c7cb, 0xd790c41f, 0x5f6c4df9, 0xc84297d2, 0xa27ee3f1,
+	0xa85a9822, 0x901144d8, 0xcbdb3ce6, 0xc5303322, 0xefa38811, 0x080372df, 0xda8d0d39, 0x3497baea,
+	0x1154c628, 0xe8237d8c, 0x9d4ca0af, 0x08a6932f, 0x8c5779ba, 0x4d48c1d5, 0xcb0c9262, 0xadb5a2e1,
+	0x30f015bc, 0x013a3cb5, 0x45522995, 0xd85328fa, 0x85eae58d, 0xcbc6886a, 0x4d667465, 0x2276b5bc,
+	0x746d401c, 0x6ad84809, 0x7d3da1ed, 0xa6ec5e4d, 0xe12ab1f7, 0x29953147, 0x4ef05f63, 0xbd9bd53c,
+	0x58526a9d, 0x6bdf487b, 0x844148f7, 0xe818e8c7, 0x18429e17, 0x3dd4b4e7, 0x7921dc3c, 0xb3d68afd,
+	0xb7408282, 0x2027d195, 0xe735b58a, 0x45cbc8c7, 0x83262573, 0x90d8d295, 0x9407bf2d, 0x22a5507a,
+	0xc3552078, 0x08db1a9c, 0x2e12efac, 0x45d08088, 0x93642cec, 0x27257804, 0xb2f5e29e, 0x5f69ff21,
+	0xa6887bc3, 0x5fad1202, 0x14ed9437, 0xe8bc1bfe, 0x92112853, 0x8404523b, 0x4090f15e, 0x67353909,
+	0x53c9f08c, 0xa870f8e1, 0xf42a8288, 0xf9e8c980, 0x4eb190ff, 0xdc5e399a, 0xf01b6d46, 0xe87925ac,
+	0x9f503735, 0x4c9bad33, 0xbce4d585, 0x0f870d44, 0x1b74395d, 0x5110a0bf, 0x47267519, 0x4aca1f27,
+	0xf56b1ca6, 0xf2446407, 0x2dfda713, 0xe6fe4dfd, 0xc2382293, 0xf6a5a692, 0x4a5d5f34, 0xa40b5285,
+	0x84c218d7, 0x220e530e, 0x2fd4ab34, 0xcde463db, 0xa508e67c, 0xe45227f1, 0x782eb3c2, 0xc414cfc0,
+	0x0fc6757a, 0xc0e2fd2a, 0xc1ebf919, 0x12467974, 0x0c7f137c, 0xb22d7618, 0x839c53bf, 0xc30661e7,
+	0x7f593f48, 0x9abebc28, 0x9bf82a21, 0x69e391fb, 0x800bc66d, 0x6ab76721, 0x183a296f, 0x8da6c0da,
+	0x06824939, 0x71cbe0dc, 0xbba56e70, 0xa1318e9d, 0x08f136e3, 0xaf855d78, 0xefc00a62, 0x062caa59,
+	0x20464cbe, 0x523dffa3, 0x5eb85f9c, 0x1bfc58e5, 0x1111fe2a, 0x8d318e64, 0xe7889aae, 0xfa937fee,
+	0x34b3aed5, 0xdc667be9, 0x967aab5b, 0x69bf9cbb, 0x61a2e106, 0x9c60c9ee, 0x4ba45772, 0x0d780ca1,
+	0xf88934b3, 0xbbb2e6ea, 0x94bc8f0c, 0x83dd36d0, 0x804c4c66, 0x9050e2c4, 0xbe70f1a3, 0xcaecca43,
+	0xffdf5aeb, 0xeabecdec, 0x6c76ad39, 0xaf710a9a, 0x1c900a42, 0x21f48226, 0x64ca6b2c, 0x8793ec51,
+	0x15d75808, 0xa79fe027, 0x47e4e8bd, 0x2bd8a2f9, 0xec881955, 0x59633114, 0xac6c8a01, 0x428776b2,
+	0xd195945d, 0xaeea89a8, 0xf1483c2e, 0x04797b73, 0x0d38fb37, 0xc912dd40, 0x828f7126, 0xc9a49231,
+	0xf0b14673, 0xa9af9819, 0x31324888, 0x95b89bf4, 0xc7dd9c0a, 0x53d47b57, 0x3eae03ea, 0xcc64805d,
+	0xee00b813, 0xe7d8fb3f, 0x5edecfb4, 0x25f4ffc9, 0xb38f42e2, 0x195d6662, 0x6f359364, 0x7869e6d3,
+	0x0214e607, 0x0ef454f5, 0x0d30357a, 0x6638dff2, 0x0ce60842, 0xc9a2208d, 0x8819081f, 0x76a7c4e3,
+	0x4ec6eb2d, 0xf6259d77, 0x2014b4c8, 0x782c1876, 0x445278c2, 0x927256b9, 0xd67c41b1, 0xa06aba0d,
+	0x275d7c59, 0xba74dc26, 0x7a01c621, 0xfb2df41b, 0x34909ec9, 0x851c8712, 0x9eedbc4e, 0x3bbf8b18,
+	0x45daceb9, 0x355aaabd, 0xa6924308, 0xebe2ec9d, 0xf3c1ceef, 0x2f2ca367, 0x171b43dd, 0xd902842e,
+	0x86813f56, 0x64f4c1f8, 0xf6447496, 0xb5203223, 0x75bb72e2, 0x0d699717, 0xbff2c6bb, 0xc3ce4c63,
+	0xaa78cf76, 0xa2fb1510, 0xbf579093, 0xe85557f4, 0xb2efb226, 0xf9749944, 0x71b88b44, 0x2d14ce93,
+	0x0a1c6a1a, 0x1abc9e60, 0x9dd533b3, 0x28680564, 0x3bdf5f43, 0xd851dcac, 0xd3064f78, 0x733b37ad,
+	0x95981562, 0x0dcc5849, 0x4a9878b9, 0xf7c11cee, 0xdd588527, 0xfdd94b85, 0x85e4cf49, 0xd5bed164,
+	0xc646bc68, 0xb8e62437, 0x046f2e06, 0x4d7271cc, 0x832d54d7, 0xed2ab9d6, 0x61bc581a, 0x7b5e086a,
+	0x3baf6f15, 0x080f0749, 0x6a0f559a, 0xfb8f527c, 0x9f95474b, 0x67e8bcbd, 0x9cd4dc6f, 0x3043b4ad,
+	0x1166f01f, 0x54ac9ad6, 0xccff6a09, 0x8ea4ad86, 0x4278fc98, 0x4c8b72b4, 0x011ae653, 0xc5a0b29a,
+	0xed67f139, 0x4654320b, 0xc6dc6d51, 0xa7fd9c19, 0xa43e554d, 0xb4940322, 0x59836c4d, 0xbc22d64d,
+	0x69a3964e, 0x815915b6, 0xab04070f, 0x3af85b53, 0x0b7fa9e2, 0xecee949c, 0x883eeaa8, 0x80a2c75c,
+	0x63f556a2, 0xcede670b, 0x85363f59, 0x9ef15b14, 0x52902927, 0x7ce54e24, 0xe86361f6, 0x48203ab9,
+	0x117ab834, 0x80d2d63e, 0x35f8294f, 0xb2f12f9e, 0x316d1c73, 0xff24427a, 0xf3b5c6fb, 0x05072e80,
+	0x0bd7d3b4, 0x4eb434e4, 0x39827a8d, 0xc9765a83, 0x8f8c1095, 0x8e29ee65, 0x870cd518, 0x9367fd89,
+	0x8553003e, 0xabe848fe, 0xac83ed70, 0x73dd2f15, 0x3c9c4f18, 0xe9a63f15, 0x7cc181f7, 0x61d6abc3,
+	0x70779060, 0xeb4b7b08, 0x0a3d2f28, 0xca6fc69f, 0x3263f92a, 0xb592a310, 0xc09075ca, 0x225eab32,
+	0x29db8cd3, 0x2307e274, 0xcf098f45, 0x1cd5ef1e, 0xa1a7961a, 0x98ea507b, 0x13f67bc2, 0x394c49e1,
+	0x6ab72cd0, 0x8cc53d60, 0x12ae7f52, 0xba91aa67, 0xc49ab481, 0x0fd46d4e, 0xea98a812, 0xb133f5f7,
+	0x90ff295b, 0x408c83e6, 0x458041c6, 0x3d914f75, 0xf27431ee, 0xa3669914, 0xf36e88e7, 0x4e1f426b,
+	0x46eabb60, 0xc53112b9, 0x8ca2acba, 0x1844580f, 0xe03272fe, 0x5b91754e, 0x015bece3, 0xdbecb998,
+	0xf110c109, 0x3ee55c47, 0x85bde970, 0xb2621244, 0x475d8476, 0x6987f753, 0xd00abbc8, 0x57e97115,
+	0xc7a879af, 0xfc41cfcc, 0xa19549d5, 0x86ce71bb, 0xd486e450, 0x52788cce, 0x61e5dd1e, 0x873bc40f,
+	0x8f32959a, 0x989a456d, 0x54475c36, 0x0ababccd, 0xf4b4d7b1, 0x48154872, 0xbd047277, 0x1d8ffaee,
+	0x961dbe69, 0xf5407c5b, 0x6be2311d, 0x69981ec3, 0x2ddbcf28, 0xc5e9d9b8, 0xb42be0ea, 0x2ad6fc6e,
+	0x5bd75628, 0x45360f00, 0x88a3b440, 0x6ef34a8e, 0x346dac52, 0xd8c5a11e, 0x94ffa97e, 0x8867dfe0,
+	0x2ddcf714, 0xcaa0bf11, 0x98550f8d, 0xcbb13c5a, 0xed6a9245, 0x1f90d0f6, 0x717ce205, 0x2c5e5631,
+	0xb0879854, 0xc1a3da7d, 0xd030784f, 0x14a21f70, 0x3305154d, 0xa94cd82b, 0x4941fa9e, 0xdb787655,
+	0xb3c23e4a, 0x3392a584, 0x593edef7, 0x9697190c, 0x5117216a, 0x5d96dd21, 0xc8663061, 0x3dc8b45a,
+	0xd65be2eb, 0xcc70823f, 0xff1abf2b, 0xdc957ac7, 0xf9e6a33b, 0x4b30127d, 0xb6118d76, 0xcade42dd,
+	0xef485db7, 0xb6ffd482, 0xf11d915c, 0xe3776903, 0x948a554e, 0x6e711fc2, 0x4e9df72e, 0x2c16c1da,
+	0xdbe9328c, 0x7de4f5ff, 0x31dc580c, 0x4e3cb087, 0xf282815f, 0xdff94fbf, 0xbc724521, 0x84881b48,
+	0x7513f623, 0x2e13aad4, 0x28e9c5d6, 0xd8c5747e, 0xfe89c59a, 0x7c85fffd, 0xdd2bf57b, 0xd2063a56,
+	0xb93d9d29, 0x5a543b1f, 0x86dbdc08, 0xf2139a27, 0x79b52ec3, 0x896c9d08, 0x3808c3b1, 0xb4144daf,
+	0xd79f301c, 0xee38a06c, 0xe145da5b, 0xfed65090, 0xe35a7ec8, 0xd1363d28, 0xe91f4594, 0xa729ff6b,
+	0x590dca9a, 0x32ffd431, 0x0952c8fc, 0xc55d3ac3, 0x3349d57f, 0x16fc4734, 0x5f39858d, 0x00e29bbb,
+	0x728a9e07, 0x6e7ffd45, 0x6c601f72, 0xf06acdc1, 0x8bff06be, 0xf14375d5, 0x576a28c9, 0x50b6cc16,
+	0x1f8e3642, 0xb501b7e7, 0x658986ce, 0x7cf0c897, 0x5b592cf4, 0x0135bb52, 0x243b7bbd, 0xc6b81d73,
+	0x4700f950, 0x00c67eb0, 0x27eb512b, 0x1e11a546, 0x1630d857, 0x76b95269, 0xcea2611d, 0x15f30fe8,
+	0x5112fc58, 0x2630c188, 0x26bd3529, 0x9350a153, 0x0b317b68, 0xbc5ec59a, 0x1e16ab44, 0x0ce54350,
+	0xd3cb4f9f, 0xcc2636e1, 0xcd25e221, 0x5f47f964, 0xa2eff699, 0x5a256811, 0x75398dde, 0xa1ef3586,
+	0xf4e6fed7, 0xd549c272, 0xcd096397, 0xded03996, 0x91e3f643, 0x1f52c129, 0xdbef1585, 0x614f9f2a,
+	0x979aa4cf, 0xbfa22cd4, 0xc15c6480, 0x65a08be3, 0x38ec4c6a, 0x2e650090, 0x103bc5cb, 0x6fe4ea6f,
+	0x7c779110, 0x4eeeb33e, 0x1c66a929, 0xc3c7b041, 0x79f5cd44, 0x71482f36, 0x6bef59ad, 0x0b26cadc,
+	0xea7c9a5e, 0xe5bf73af, 0x4561bdab, 0xaf5f33e6, 0x834328d4, 0x5a506555, 0x16f6496f, 0xe3848a09,
+	0xcfb3dbf2, 0x5e7c17d8, 0xfab39fe1, 0x51b7958a, 0xa877c67c, 0xac5cdf8f, 0x9f44d496, 0xe373150e,
+	0x222bb51e, 0xe2b77e6c, 0x08ad2ce2, 0xc63e4816, 0x686436c2, 0x2e4e2e78, 0x8a604ad8, 0x79f04479,
+	0x46417abf, 0xb02edd43, 0xb8c3d442, 0xa83217f8, 0x4345ba16, 0x3fb0d040, 0xbd2db132, 0xb3be25e4,
+	0xd8513331, 0x278d57f0, 0x6223af8c, 0x674dfda4, 0xfce93372, 0x231f1cb2, 0xdd84e9cb, 0xe767c552,
+	0xba39cd5a, 0x1cc376bd, 0x06c6d5be, 0x358bb5fa, 0x420e739d, 0x600ed682, 0x59a8ac00, 0xd5893549,
+	0x095d6d7c, 0xba92a03b, 0xd09fb93b, 0x803be5d8, 0x86805400, 0x56e59006, 0xd30416dd, 0xea9ebf53,
+	0x54f65adb, 0x3b762bc9, 0x2ea2d112, 0xb3c95943, 0xa0d941b1, 0xee01bd7a, 0x44db406d, 0x7782da82,
+	0x88070144, 0x29220a17, 0x5111be80, 0x765c1365, 0x2af29102, 0x196916be, 0xb319d7ee, 0x4092cba7,
+	0xf697c473, 0x442b2ef4, 0x6be0277e, 0x23e5c6ee, 0xecfe8708, 0x1b1e6e52, 0x21ba6353, 0x2da56a62,
+	0x9835d54a, 0xdc539e7d, 0x8cfe2904, 0xd53e3fb6, 0xba68dd60, 0xd46b5f8c, 0xf8c18e3c, 0x25ca9ccd,
+	0xe18e670e, 0xcb6aa70c, 0x5bdb2dd5, 0x102cde03, 0x6fdc0047, 0x847bbd1b, 0x9d7fa3b2, 0x64e18fd6,
+	0x807ced25, 0x0975cef1, 0x1293ead1, 0x81a0acae, 0xf5566d14, 0x40548c92, 0x1878d7f9, 0x2cb88f7e,
+	0xc92b955f, 0xd64cb9f7, 0xbd3b6f19, 0x66c3c8dd, 0x24d35a32, 0x21f15ba0, 0xf0734073, 0xc4c3996d,
+	0x486102ac, 0x9710f1e0, 0x937b9fb7, 0x99f197db, 0xf5fa94e4, 0xc2bc8c89, 0x0d9bdb14, 0x15725f24,
+	0x0519f333, 0xae99feaa, 0xe27c67df, 0xa367169e, 0xbed08982, 0x11329b96, 0x7caebe35, 0xc4dd5ee7,
+	0x386462a8, 0x1ce01617, 0x76fdc6a4, 0x5488b299, 0x85525510, 0x27e4936e, 0x3bb01183, 0x3524532d,
+	0x1a998f89, 0x75e53b2e, 0x200c3c60, 0x42cbe116, 0x4c642402, 0xa38d20a8, 0xfde5e7bf, 0x23cb6bdd,
+	0x7fbb3037, 0x735f3f17, 0xff8e1f28, 0xd5c91e08, 0x288f11b9, 0xcfe1e823, 0xc1db30b3, 0x473f6463,
+	0xcb78de8c, 0x8f683fb7, 0x3abcb24c, 0x62897ed3, 0xf7ba83ba, 0x6870fe9e, 0xf0369f6c, 0xd16268ff,
+	0x07103e3e, 0x5e20a15b, 0x5cf1785e, 0x694c5a70, 0xccf29e16, 0x57681497, 0x3d6a9fde, 0x21cf2482,
+	0xe856ecc3, 0xf1fcb5b6, 0x972bfd5e, 0xa2b87274, 0x0bf47039, 0x1711a504, 0xde6fdf01, 0x8e6920de,
+	0x52200fba, 0x9a4eb872, 0xbd7e8f34, 0xc3627827, 0x06b45d8e, 0x70993f54, 0xbc79c75a, 0x1db5fa81,
+	0xb7a21735, 0x6d88534b, 0x7e0a0d43, 0x81e4834e, 0xbd02f0e5, 0x92323c27, 0xaa4cb95e, 0x98505aad,
+	0xcf3f34fb, 0xad5942f2, 0x48873409, 0x190af0c4, 0x3661182f, 0xe86ca770, 0xe496b790, 0xefb2e675,
+	0x0202e755, 0x07230302, 0x549818f5, 0xe8af6700, 0xed633dad, 0xe81a978c, 0xa84cb711, 0x67ce10e1,
+	0xe33fc39c, 0xe9e4d4b9, 0x8a42a160, 0x6453f8b9, 0xde13da88, 0x54c402a7, 0x0a9263ec, 0xbcfb271b,
+	0xd14151cd, 0xb4242360, 0x6ae2b30f, 0x6323b412, 0x29dcb88a, 0xbfbe1bf3, 0x16385f08, 0xba9e5f77,
+	0x67ddcb9b, 0x9e94b015, 0xb1a855b6, 0x7ca02eea, 0x5fee76b3, 0xce7be013, 0x772a865a, 0xd05f7cc5,
+	0x3d0002f7, 0x753cb3f2, 0xd8f1cb7b, 0xb90cd474, 0xaade099e, 0xb8fba9f3, 0x491501ad, 0x7ff1e0f6,
+	0xad393085, 0x9d9d5d15, 0xdb745199, 0x56987d97, 0x6b25283c, 0x55439eae, 0x53592cba, 0x897af7c3,
+	0x931208c1, 0x901a21e9, 0xef85fb22, 0x0730bf94, 0xa1860445, 0x73b82930, 0x6974f487, 0x125d8b73,
+	0x3dc8b0f8, 0xbdaad5ec, 0x084ecd6d, 0xd5017e77, 0x6a566937, 0x7075a6e1, 0x113d6c2c, 0x5a4c29c2,
+	0x302e926f, 0xe020dc69, 0xad1a11a6, 0x5aa345f6, 0xe1684b6f, 0x301799d8, 0xaf4db427, 0x8ac60f17,
+	0x72eb5adc, 0x19a9852e, 0x66a219e1, 0xf92f0492, 0xec0f4baa, 0xa07b89c9, 0x22fe9998, 0xcf55d5af,
+	0x7abed430, 0x4c07d881, 0x69f81abb, 0xdee5f7cf, 0x893a5d36, 0xf4c6929f, 0xfdf29bf2, 0x7ffffbca,
+	0x31405266, 0x54dd49e3, 0xdbf560a5, 0x8596ed61, 0xdaec1ad0, 0x2e16b83e, 0xf6fd4a15, 0x704f305f,
+];
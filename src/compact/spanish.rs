@@ -0,0 +1,258 @@
+pub static HASHES: [u32; 2048] = [
+	0xc5dcbe30, 0x0c8e3cc5, 0xf40f723c, 0xd79f52e7, 0xeadd1bd6, 0x9a364704, 0x99efea22, 0x5f5b98c4,
+	0xb465fa73, 0x09aed449, 0xc9f00b0f, 0xab2883d1, 0xd76ee454, 0x1d26ddcf, 0x50e8905d, 0x41f61c00,
+	0xa602b330, 0x8d171967, 0x2de3c407, 0x666cbd26, 0x84b521cf, 0xf38606db, 0xcd1d82a0, 0xe6f2bd50,
+	0xe375a8ed, 0x2fec05ec, 0x9e6123c4, 0xb199cde9, 0xf233fb6f, 0x08a967fa, 0xab8cb446, 0xaaf52049,
+	0x93616789, 0xfdf98fc6, 0x943e8dda, 0x3d89fd7f, 0x62485e20, 0x73d88546, 0xb59acc85, 0x42801e95,
+	0x201bf456, 0xe1fd138b, 0x91727759, 0xb0e510e7, 0x42166c8b, 0x6d719926, 0x90e89519, 0xb7ca8e5e,
+	0x2a5cb4ed, 0x879ea477, 0x96b118d5, 0x1295e60d, 0x8caaf8e9, 0x33cb0e2f, 0xd2d281a2, 0x0a2b3176,
+	0xc277d64b, 0xe8e7af58, 0x4278842a, 0x279bd265, 0x02909110, 0xba1255f1, 0x8965aa61, 0xcc9e1625,
+	0x36f02d2f, 0xb7e15627, 0x4601263e, 0x776c1c71, 0x384aa4c8, 0xe0847748, 0xbebee6d0, 0x083c7c2c,
+	0xc8a948cc, 0x6d59dafe, 0x1bbd8c73, 0xf16f1185, 0x30f7c55f, 0xecb88c02, 0xf8318e6f, 0x9148f9f6,
+	0x1ad8f02f, 0xe4e55e36, 0x3a6689dc, 0xeec9d849, 0x277b7f6e, 0x2ea72d15, 0xb83fc99d, 0x6aa1560f,
+	0x1f2ac783, 0xe8467410, 0xa6f00aba, 0x06d6f1f8, 0xfbe798bb, 0xdabdd51e, 0x8e06be71, 0x403f14ec,
+	0xc92fee54, 0x4694dc72, 0x11aa3193, 0xebf15267, 0x79818a8e, 0xc82ad10e, 0x5b76cdf8, 0x2081a395,
+	0x7ed1898c, 0x413900ea, 0xa7de6c62, 0x4b9c7c76, 0xff90163e, 0x102a614f, 0x3e785dc2, 0xb55eabcc,
+	0xcdb4cd1a, 0x4952e484, 0x6a92091c, 0xe1faf2b8, 0x36ced1ca, 0x4479a219, 0x061cb951, 0xa51d3e58,
+	0x2881ed2b, 0x5bfba467, 0x10ee4a5d, 0xb7eb0b38, 0x6cb3ab43, 0x02609910, 0x0b670ee6, 0xe28fc4e5,
+	0x523304b4, 0x515d76d8, 0x485bf7dc, 0xf2bccdb4, 0xb17f7ab0, 0x0c21bc53, 0x96061f09, 0x9119bdd1,
+	0x04c8d698, 0x81fa9ef5, 0x9efe0d37, 0xb100395c, 0xf498d6ed, 0xf934a938, 0xf7703936, 0x71470a99,
+	0xfda105a3, 0x346bccb5, 0x28637817, 0x2dca20fd, 0x01eda3e5, 0xbedb7e8d, 0xd46b5d4e, 0x93f4d451,
+	0x91543afd, 0x0bd064a3, 0x9ae5d33d, 0x7e24d0ad, 0x2072d384, 0x1ed1f0dd, 0x7d711a91, 0x6f125773,
+	0x04a997bd, 0x1fb93ff5, 0x339d65f8, 0x2730f03a, 0xb5ad193d, 0x8007c9ec, 0x1fac7402, 0x8149adea,
+	0xd7c9fa58, 0xb00e4125, 0xa48a688c, 0x2a455def, 0x9297f4d0, 0x1bd76ba7, 0x001cb814, 0x21653b4c,
+	0x1a78ea3b, 0x1edd4bf6, 0x258388e8, 0x3abc59fe, 0xb153ad04, 0x5f8e9bd2, 0x1a84878e, 0x29a510eb,
+	0xfd6328ba, 0xf374df3a, 0xe0613999, 0x885fe783, 0x8c5321dc, 0xf689fa70, 0xb3c760b8, 0x1e2effec,
+	0xfda78da3, 0x70ffec21, 0x3abe5a56, 0x20660791, 0x2b480f41, 0x72a634e4, 0xcc8bee93, 0xd542fc41,
+	0xb3d6e397, 0xd8795484, 0xad51566d, 0x7f37761d, 0x65d4498f, 0x3ecfed91, 0x250a2054, 0x4d74cc90,
+	0xc1629a2c, 0x49019a1b, 0x3db1b3d1, 0xd9aee4d7, 0x338f06de, 0x1f0814db, 0xefbef8a4, 0x6b26fbe1,
+	0x90d8ef12, 0x60d983cd, 0xce277c0b, 0x8f54af11, 0xc3c3d5b0, 0xa1bce465, 0xe3215478, 0x8efd10ab,
+	0xacff7e7c, 0x3f8ea74f, 0xacd63075, 0x1a052f7b, 0x1559f377, 0xf49e4cbd, 0x7e12bb1e, 0xca743310,
+	0x03ea2573, 0x107fb598, 0x52e5b4f0, 0x3acf32b1, 0x8b217aca, 0x1da32c4e, 0xaa4d2af5, 0x978f997e,
+	0x857f6412, 0x7c903151, 0xe347ab68, 0xbf3d8c47, 0x293ce71c, 0x86d83132, 0x203a1e29, 0xda6d9d7e,
+	0x89c6a58d, 0xfa55e69e, 0xe5af0661, 0xc3b10f72, 0xa4f3b777, 0xd3aa6cdd, 0x94e14619, 0x71650b20,
+	0xe396fe55, 0x074ccf4a, 0x8e852bba, 0xdefd88d0, 0xd936b579, 0x1f35c456, 0xe590f14d, 0x874545ba,
+	0xc89f033f, 0x727d1d11, 0x2b3df9e0, 0xef81f681, 0xd3068620, 0xe36dd243, 0x8f152f21, 0xa20cec43,
+	0xa203f1e7, 0xef46fa76, 0xe748da35, 0x3b3486d9, 0x346a08a8, 0xe2b0634c, 0x2da01d62, 0x31868bc8,
+	0x7dc311b4, 0x19c05191, 0x2daef7ed, 0x5f4fe5e3, 0x1fc6e6c9, 0x1ea6f391, 0x4895ec8e, 0x17aad894,
+	0x69289302, 0x7d187e86, 0x0f16e5ca, 0xd51c125e, 0x79cef428, 0xf1eaea9d, 0x9a88f446, 0x101a480d,
+	0x023236e9, 0x4a9ade3d, 0xc09f9247, 0x537d458e, 0xa370d085, 0x3440eb29, 0xe8ac4789, 0xba967c48,
+	0x2c737fa6, 0x27b5ae36, 0xf3f2dacb, 0x9c02a802, 0xd6dd9437, 0x7134a9c0, 0xd25f558d, 0xb2ed05e8,
+	0xdfb2f6ad, 0xee74363f, 0x8056574a, 0x337dda38, 0x0b478b6a, 0x940a58f7, 0x11340d24, 0x2931f455,
+	0x0e2bb9a6, 0x0b1a6bcc, 0x9aef1eb7, 0xb9ab6ec2, 0x601a82ac, 0xc6b9e164, 0xbe925ee1, 0x7016c0be,
+	0xc9ce2ac6, 0x55f3efff, 0x662095ea, 0x6ed237ec, 0xe3ff5dc4, 0x626a6d7b, 0x65eca340, 0x89954fe3,
+	0x94f39273, 0xa055563c, 0xd6518470, 0x31adf387, 0xf5bc6bf2, 0xbe5c2828, 0x83275180, 0xcce5e4cd,
+	0x4dbde320, 0x4f60de6b, 0x7bf39569, 0xc429208c, 0x8c0b07e8, 0xaee20649, 0x6613c672, 0x7419ecaa,
+	0x9f253efd, 0x199d02a8, 0x5fd69a22, 0x1fc7ed21, 0xa5bee413, 0x92a01ee6, 0x4bb3d87e, 0x10c2043d,
+	0xccaed86c, 0x9113dfd2, 0xd472b3ad, 0x6925bc00, 0x66418dc4, 0xd75e5044, 0x504b7d84, 0xa5d2d576,
+	0x74157b5a, 0x1995dfd6, 0x60ab26a5, 0xeabb7838, 0x3c5be229, 0x464f25f4, 0xcadbfbfd, 0x45b0d8fe,
+	0x9fb4180a, 0xf4ff8f1b, 0x4334303c, 0xb8c44074, 0x16b94257, 0x0964d824, 0x47cdeec8, 0x88c6ccd5,
+	0x5fa35922, 0xacb2aaa6, 0xfa97f2a7, 0x0ef12485, 0xdab813f2, 0x473347a4, 0x531532fe, 0x9250f425,
+	0x3dadf61c, 0xafec5e8e, 0xbcb4d1fa, 0x9c98c91d, 0xf6997c06, 0x9b26ab9f, 0xfed4bb5c, 0x86097d12,
+	0xf1acfbdf, 0x6be02e6f, 0x1db58fe8, 0x51ac2a1e, 0xf273b5b6, 0x1d9f8503, 0x5fd3aa34, 0x975a02b7,
+	0x05c474aa, 0xb24dd73d, 0x79e20f59, 0x4124fa5d, 0x9f4037b2, 0xb93e2209, 0xb54954b0, 0x7ecc55a1,
+	0x426f74f7, 0xfdb5febf, 0xb6c8a6d6, 0xd86a68bc, 0xd59a4584, 0x41249566, 0xd3336fb9, 0xd8fc1f35,
+	0xf3316382, 0x046b7f82, 0x9abe7417, 0xafc3ca83, 0xff5f6e55, 0xf34afb68, 0xe544d530, 0xefc6d621,
+	0x4aa182f5, 0x92ed9ed1, 0x6d157849, 0x7b385440, 0x4a79f9a1, 0x7cb30432, 0xbe06950c, 0xc30e11e9,
+	0xd93015a6, 0x59bf98c1, 0x0f64fc8b, 0x77389348, 0x681d71bd, 0x4b31923f, 0x5f41e02e, 0xb23d2c98,
+	0xafc27be2, 0xed62f6dc, 0x5fddda0f, 0x144b3dc8, 0x740aaef9, 0xcfde6411, 0x8f7bd4d5, 0xba7d6dd1,
+	0x14d2eb3c, 0x154e0eab, 0xf2edecb5, 0x2224d51b, 0xce6a1a6a, 0x78e62307, 0xe6d7c5b8, 0x46281b23,
+	0x7d9949c0, 0xf1b9c1f5, 0x10853db7, 0xac091a01, 0x877c560a, 0x37c334c0, 0x35f9a8ad, 0xb1411a73,
+	0xebd1f0e9, 0xc377c23a, 0x9072112f, 0x97afdb83, 0xd564141f, 0x47a791d3, 0x7591e0de, 0x81338072,
+	0xba492154, 0xf2cc3675, 0xcc21d98f, 0x7c446695, 0xcb425f52, 0xa1800699, 0x1665f453, 0x909e330d,
+	0x70eed860, 0x7ef97bc6, 0x7943f303, 0x75ebaa87, 0x879af790, 0xabc3845b, 0x197bf34d, 0xedb8f7a3,
+	0xbc9e5f36, 0x8e62d5ca, 0x40dddabb, 0x93404b67, 0x9537ffc8, 0x01703b17, 0xccafa580, 0x00267e55,
+	0xd94f10ac, 0x7ffcfe15, 0xf81db5fe, 0x6d3dff37, 0xfc1c3744, 0xc1c71ee6, 0xd82974f4, 0xaee76ee7,
+	0x7a1a929f, 0x11294484, 0xcc69afeb, 0x6fb5282a, 0x76e6e0c7, 0x64e1e068, 0x54c5d997, 0x8bcf0d6e,
+	0x209933fd, 0x902dc0b8, 0x23144c71, 0xadc81744, 0xc27d70c7, 0xacc8b947, 0x8961fa7d, 0xe234894b,
+	0x9d49dd13, 0xa709776a, 0x944cadb4, 0xec32c68a, 0x0e42e906, 0xa717c76c, 0x2712f234, 0xdb915efc,
+	0x9472ee14, 0x0f61f7b8, 0x59ebe029, 0xda3c8d6c, 0x9e39188e, 0xf1515cda, 0x86849c7a, 0x4e332f31,
+	0x3674cc68, 0x9f64fd50, 0x62b58927, 0x0b548836, 0x851f40c8, 0xebacca89, 0x1eda731b, 0x017277d9,
+	0x2beab7e6, 0x1fd73dc4, 0x4353718b, 0x4b614a58, 0xa49fcd1d, 0x32eb4490, 0x80afa4d5, 0x6722f4b6,
+	0xed68c80e, 0x6c5d37dd, 0xbe15d1e8, 0xd1e9849a, 0xed2abed4, 0xda68aa25, 0x45ae6175, 0x72a1c6ef,
+	0xf4c33058, 0xb90ea5f0, 0xb53ef5f9, 0x550be59c, 0xd2e2cd89, 0x594e785a, 0x4071732a, 0xa8db6453,
+	0x71e87ed3, 0xcd49379a, 0xbf08ebbd, 0x9af7d481, 0x40562a60, 0xaa5ca75c, 0x8172c362, 0xd60f4228,
+	0x5bfb5d49, 0xa059aa6b, 0xc97d8bc7, 0x5c0d8278, 0x24b6cd3b, 0xae821437, 0xe64eb5cd, 0x8b841c41,
+	0x566d22ed, 0xb4217197, 0x11518767, 0x6e94d048, 0xa3ffe358, 0xc04722e5, 0x6819851b, 0x483cfa2a,
+	0x9a74013a, 0x73b60ca2, 0x342eef23, 0xb1125c71, 0xc8ad6e30, 0xcf3f08d7, 0x84c0b464, 0xea48615f,
+	0xcaeee8a8, 0xab55b386, 0xe6dff502, 0xca294199, 0x2fae1864, 0x61de4f01, 0x7fe851b6, 0x7fd8a2aa,
+	0xbbd152af, 0xef5b3faa, 0x5068fc5c, 0x4eb67861, 0xb08d33d1, 0x407ea4f2, 0x8d63bdae, 0x122e3c1e,
+	0xd8a9cd46, 0x2387878b, 0xffb734c3, 0x4634016d, 0x5458e24a, 0xbbb6f8b4, 0xa915a3ee, 0xb9dc2b96,
+	0x1943cf14, 0x80cdb0d2, 0x8619d460, 0x21fdba40, 0xbdb8f871, 0xff6bc91d, 0x026722c0, 0xa5d0aaed,
+	0x4c991aad, 0xacca2e34, 0x1450f814, 0x5664206c, 0xba4ce2e9, 0xba642740, 0x9c10bd66, 0x21635a87,
+	0xc83657a3, 0x769a89fc, 0xb11454b0, 0xa35390f0, 0xb801ac24, 0x0ddc2e28, 0x529f69dd, 0x16d8e9d0,
+	0xbc8894d8, 0x6ba3ce91, 0xb39ebce1, 0x53b4b236, 0x70370fe4, 0x217c24b6, 0xbca5ca47, 0x4e289af9,
+	0xdbb01f67, 0x3cb071cd, 0x4ff091ba, 0x7ad425e7, 0xe97617ca, 0x30216da5, 0x6d22515e, 0xee8c3f45,
+	0x2f04b0cd, 0x1c4fee39, 0x5c8ff3a6, 0xfe59cd4e, 0xf9558bc5, 0x26ccd9db, 0x04b91c9d, 0x05068fe6,
+	0x8d22dbdb, 0x295c931a, 0xab854d48, 0xd6766482, 0xd518b549, 0xe939fd8b, 0x82ec99ed, 0x5e318432,
+	0xf193e7ec, 0x0a3bcf13, 0xae01d1be, 0x31d7a887, 0xb2f80a30, 0x684a43b8, 0x58a64bb2, 0xd643936b,
+	0xca02bbc1, 0x1293ade0, 0x35986227, 0x4556be90, 0x1ae9b4b1, 0x321fe6a1, 0xcb61f5d8, 0xfe9a78e2,
+	0x99cff903, 0x04fcfe97, 0x662433d1, 0x7d5cae56, 0xf45085bc, 0x63cff54e, 0x97d8f039, 0x3ec2c932,
+	0x8f2bbed3, 0x65a6db55, 0x7ec1ce9d, 0x94e5b773, 0xaeea5d8f, 0xfb1c8ad0, 0x215737fa, 0xcb01c0db,
+	0xc8e4a51a, 0x3c94efee, 0xa6c26d48, 0x541d2878, 0x3f683230, 0x905e6cec, 0x4909f10e, 0xcece0674,
+	0xb8c80eb6, 0xc5f3ec87, 0xb8bdee15, 0xabb71dd9, 0x56394584, 0xa970f87e, 0x383256d6, 0x87b32ff1,
+	0x4f1601d3, 0xb7e71ce2, 0x7cd0d774, 0xbebf129d, 0x71c180d2, 0x737e9e0b, 0xeaa5f88a, 0xb883bf8d,
+	0x185ac6e6, 0x2267a297, 0x7388777a, 0xfc12bd1d, 0xee4b6b83, 0xfdca0f94, 0x31ae998c, 0x1a8823ee,
+	0xaa2f7c3b, 0xb428d034, 0xc2172788, 0x5eb2dd81, 0xbe99c86b, 0x8552c8a9, 0x80647364, 0x9792145e,
+	0x046cef28, 0xe6e52a84, 0xa2f6d122, 0x46c3c2fd, 0xdbef2ad3, 0x65b4c370, 0xdd95c048, 0xcebff99e,
+	0x5b564008, 0x6840aace, 0xb50e7261, 0x8887aac3, 0x3c5b16b6, 0x224be33e, 0x1b94f05f, 0x2e6fea5e,
+	0xd035dffd, 0x0d1fa786, 0x8b3a33cf, 0x002e9d99, 0x09fd108b, 0x9856a7c5, 0x4d08ba34, 0x170889b9,
+	0xe964c3d1, 0x1c60f484, 0xe771e042, 0x47d0e5ee, 0x558bd8a7, 0x5e0cb715, 0xbf88571c, 0xa0a09741,
+	0x9a5da7e2, 0x7a5f4d32, 0x9b471e19, 0xcaf200b7, 0x18a60f23, 0x35722546, 0x78f6be46, 0x633e04f2,
+	0xa3553736, 0xaf4bb56e, 0x093752f4, 0xfabad9ad, 0x401b9265, 0x21ea7ee2, 0x0b522708, 0x0c177bde,
+	0x0b64c9c9, 0xab6f8546, 0x8ffd773d, 0xb137e1be, 0xffa31fa4, 0x58c0b195, 0x4f2c7ffc, 0xb84667e8,
+	0x8a014b5b, 0x96553cec, 0xc06fc287, 0x6eef45e9, 0x9068bb32, 0xc52f088d, 0x9d0813a0, 0x35e0f1b8,
+	0xc0873554, 0x2fd65aea, 0xbf43cf53, 0x0c7fb257, 0x2afb265c, 0x343d3507, 0x1470de0a, 0x10a87bc5,
+	0x22ccd615, 0xec2cb76c, 0xcd9c66e3, 0x77958f1a, 0x557efee4, 0xbce40049, 0x9bee59b2, 0x9efaf852,
+	0xaf18a732, 0x7abc26dd, 0x01bff617, 0xb5d64624, 0x30104397, 0x54a0b8b0, 0x43ad69e0, 0xe5ab5889,
+	0x881055c1, 0x8d57501c, 0x4361406a, 0xf4bf1ae8, 0x1a40f56d, 0xf9e6dcca, 0xc83265b1, 0x2e88febe,
+	0xc3d7d4bc, 0x4fabf89e, 0xa461a0b0, 0x1176a177, 0x2b75c485, 0x5dedee89, 0x73a44375, 0xcf09aab4,
+	0x6919f666, 0x5bf8bbd0, 0x03f72484, 0x56bd8dad, 0x489e1e0a, 0xced7ae2a, 0xac1ec41a, 0xfdd94e72,
+	0x5183acea, 0xa6c02e85, 0x65b257be, 0x1f65e7e0, 0x2c79840f, 0x1131f673, 0xb6fc71dd, 0xccce717c,
+	0xa141f7fd, 0x6422073a, 0xa8e69584, 0xebbb0ff3, 0x946977fb, 0x6097445a, 0x0b9432e3, 0x0b9f6bd6,
+	0x2d1e8a0e, 0x6ab13e5d, 0x7242bf0f, 0xbd700e2a, 0x55ced387, 0x6da6b5ba, 0xc3ef75af, 0xccdc728a,
+	0x48169975, 0x15e2094d, 0xff2efd42, 0x8edd2216, 0x9be7a283, 0xa1d662c1, 0x5ae621c5, 0x35c285e9,
+	0xd746374d, 0xa655fcd3, 0xdcd757fc, 0xd5416781, 0x34c24605, 0xc84b54b6, 0x815a3f52, 0x33c27b5b,
+	0x46bb46e2, 0x4127d78d, 0x82bb669c, 0x6ab732a6, 0x2dc6466a, 0xd898c137, 0x7128b160, 0x338aefba,
+	0xaa3240a8, 0x4fc6db11, 0x1bd1d1ee, 0x3714c44a, 0x2b6b3575, 0xefcbd398, 0x82c938e0, 0x2fa3610e,
+	0x22b98c97, 0xdebc2ebf, 0x73075c59, 0x2ea984a6, 0x3e0dd0d4, 0xbd83e9ee, 0x72eb5247, 0x0af2f9c1,
+	0xa12cdcaa, 0xa08f70d7, 0xbe3a73fc, 0x35f6fcd4, 0x7a93add3, 0x71be4d04, 0x715e8e6f, 0x5f82cf1d,
+	0x66a8c859, 0x9c7dfb46, 0xd64ee696, 0xb056a720, 0x87a6aba3, 0xf1fa5a94, 0xd0de0afb, 0x0098b953,
+	0x9d52d407, 0x7b094a5d, 0x88573026, 0x4a51ee34, 0x81cefaac, 0x36db5794, 0xecef05d8, 0x36f4ef7a,
+	0x3bff4631, 0xa1174b77, 0x1910bc8d, 0x88380590, 0x09e1d964, 0x02220cac, 0xc20f40e0, 0x85bb2c19,
+	0xf3b52858, 0x6272ac1f, 0x70840b4a, 0x59021a73, 0xe0448b04, 0xf3dc749d, 0x5c5e1c35, 0xaf804337,
+	0xd4fb4e57, 0x2b284c82, 0xfba4f1b2, 0x5fc7414e, 0x6aea31e3, 0x39424f96, 0xacd386b8, 0x089afead,
+	0x24669c68, 0x45d02442, 0x43f4f1f5, 0xadceac7f, 0x86db536d, 0x7425fa4e, 0x5a3038e5, 0x26a639e8,
+	0x869ad08b, 0x58cc0079, 0x2c48d6c7, 0xca54c08f, 0xa8cf8cd4, 0x4d35af1c, 0x2a51cdfd, 0x10c2d872,
+	0xdb48494c, 0xdac9f2d8, 0x66cdc9d7, 0x645a9b85, 0xa9b9ac48, 0x77ae4c22, 0xfe384397, 0x459f1436,
+	0x70d30297, 0x93a3db7b, 0x237216a3, 0x87308a1d, 0x2302ffd0, 0x43a5df0f, 0x933abca6, 0xf91c3ee8,
+	0xdb9d0152, 0xfc342dd6, 0x07c9b3d6, 0xfc270b4d, 0x03e34570, 0xf00c1598, 0xf3059575, 0x7f6c1e73,
+	0xcbdf866c, 0xf5e20721, 0x72b3f6af, 0x3c5a2b69, 0x11db0cb5, 0xd22e0772, 0x4abff703, 0xf1fb2e00,
+	0x4face8a0, 0x1112e260, 0x182e8446, 0x17eb9e59, 0x7f862b30, 0x836e2c96, 0x4bd8b79b, 0x77ac8271,
+	0x2560df1a, 0x09b48899, 0x82c5f1b1, 0x90ebbcd7, 0xcf36da6f, 0x2a3f8920, 0xcb0b00ff, 0x5436a7bc,
+	0xeb0d71f6, 0x10e7fecd, 0xf446d83f, 0xea4b87c2, 0x98e8bbec, 0xeaeadfb4, 0xf0ff061f, 0xd1230f57,
+	0xef23d958, 0xf821a8ec, 0xa46ee3a0, 0xfafa336d, 0x05033d87, 0xe0100658, 0x08eb3eed, 0x0b854106,
+	0xdaf3f0df, 0x4291fd60, 0x3a0f3dc8, 0x3663237d, 0x1bb36bed, 0x36f32338, 0xbdab0c33, 0x3ff84a46,
+	0x5da8b911, 0xe6eb28b2, 0xeba8e881, 0x8dbed325, 0xbb6629e6, 0x6002d637, 0xf038496e, 0xeb9daf8e,
+	0x55c6b08a, 0x5d439a5d, 0x647bfdf3, 0xee7dc7d1, 0x0e7bbb9a, 0x9273148f, 0x107502fb, 0x15309ee0,
+	0x273de8b8, 0xcb976d00, 0x0ea69ca1, 0xe0a415a0, 0x87a9d0b8, 0xdd7f8470, 0x172c33d8, 0xdda2ec8b,
+	0xf589ebf5, 0xdb98cd91, 0x44522802, 0xd7532767, 0x7410d14b, 0x3dd679b6, 0x5c637457, 0xdd91f2f1,
+	0xd59f7fe4, 0xed774edb, 0x33fc61f8, 0xe6e0bb00, 0x6c1b9612, 0x5263aa4c, 0x740687f2, 0x17c2fc12,
+	0xb6adf2db, 0xf049963b, 0x53787fa6, 0x635f11f7, 0x0037eaf3, 0x0a9af50d, 0xd16fa98c, 0x30d0e0b1,
+	0x3ff45c0e, 0xcb88ac35, 0x12715455, 0x2b1f38cd, 0x9307bd9a, 0x53b483e2, 0x7e4a8278, 0xf19310f9,
+	0xd947a36a, 0x6025951f, 0x1c21684c, 0xd4ad6bbc, 0x2bf73f5c, 0x81fe4f58, 0xc5611fe2, 0xad9393d8,
+	0xba85df6a, 0x7af44613, 0xf1e94497, 0x8578af9a, 0xf8dd87d6, 0x6e740e37, 0xcb8f487d, 0x5dca004a,
+	0xdf1725ab, 0x0d97506f, 0x648c035f, 0x8289f402, 0xec6a7659, 0xd3efbb95, 0x3ee718db, 0xa56e55bb,
+	0xab64057c, 0x76922fe3, 0xac8afe71, 0x57349835, 0x343eee78, 0x536be568, 0x84a38f0e, 0xa2e9e14e,
+	0x146c2e95, 0xe86ed9c6, 0x2071e56f, 0xf28b6b98, 0x8f1f5442, 0xfcc23218, 0x09c007f8, 0xb50ee5e5,
+	0x668533f6, 0x9fc10c16, 0x716f830a, 0xf40c7f3b, 0xe1621270, 0xcf79fb00, 0x9b22bb89, 0xb4fcc4bb,
+	0x4e1d4870, 0xde521e7f, 0x58545a82, 0x6d70520e, 0x436dd159, 0x1bbaeebe, 0xd6f919c6, 0x14ccf793,
+	0x9cad5dc5, 0xa411a092, 0x9a3aceed, 0x12690bb6, 0x61659ce9, 0xe79168de, 0x424a552f, 0x765501fd,
+	0x64b93497, 0xa6b455a8, 0x88f92f39, 0x21c6b27c, 0xa24277a0, 0x4d4fc28b, 0x4bd68c4a, 0x62a43ae6,
+	0x223300a9, 0x019244c2, 0xd00b1310, 0xa17b85b8, 0x73a697ec, 0x93ab477a, 0xec41a968, 0x738f3e9d,
+	0x9109f617, 0xa1eec5f8, 0x129b95cf, 0x3c917e3c, 0x35b508e0, 0x73b364e5, 0xdbc06d8f, 0xc88235ec,
+	0x5ace86e1, 0x1a46434c, 0xdfbaa916, 0xc9fc43c5, 0x0ffce1df, 0xad0004b5, 0x52c8cc7a, 0xf6819d39,
+	0x225fda8f, 0x44bd9956, 0x480771ab, 0x41ecf2d2, 0xb90d47dc, 0xf0426358, 0x67bf9995, 0xee3a90e6,
+	0x22d270ad, 0xe2ea5d38, 0x372e265c, 0xfc3550f1, 0xf393f5c8, 0x1291fe82, 0xa6d2f040, 0xa68866fc,
+	0xed3d2269, 0x2be4fcd3, 0xa09c5d06, 0x1a0d5ec4, 0x1f380056, 0xcffabaa6, 0xe8056dca, 0xcfef81b3,
+	0x8d9a05f1, 0x981a534c, 0xf403bcfa, 0x2bf491e4, 0xabe162c8, 0x8429f25d, 0xa42c6354, 0x53a0ba84,
+	0xd2d63e04, 0x9a1b0a75, 0x24feec2e, 0xf9093446, 0x871d2b23, 0x7a07505d, 0x86b33386, 0xd987fb6c,
+	0x7d3d112e, 0x4cdbba2d, 0x4c069851, 0xbfe1ee3c, 0xbccdf329, 0x9e3718de, 0xff189a33, 0xb115de20,
+	0xe97220f6, 0xa24747d2, 0xa5a09214, 0x6dab2e83, 0x21dc0c49, 0x334bb4d6, 0x134943df, 0x1d5a9cbe,
+	0x5941d77f, 0x2e7b7e0f, 0xfab733d3, 0x8d962041, 0x261e5e61, 0x58d4c3da, 0xcdb085fe, 0x0c2d7aec,
+	0xee639919, 0x2f4661db, 0x9d3eabf0, 0xfffe853c, 0xd1a710c1, 0x5ed2ae8c, 0x81c7acc3, 0x559d82d4,
+	0xc431b709, 0xc22ba3b5, 0x34bb9e4f, 0x5985354e, 0x8d5ce38a, 0x72b98f9d, 0x64bd4b2a, 0x79b75c0b,
+	0x563e5856, 0x6f170c28, 0xb58b2807, 0xb973296d, 0xd1ecdfdb, 0xefbb7ca6, 0x46c5b2cf, 0x62fb424a,
+	0x5055b680, 0xca31a589, 0x8ab1f5f0, 0x71523c30, 0xc58f66ab, 0x95128108, 0xfc25a6e9, 0xbd261f03,
+	0xd32b768c, 0x772c16e3, 0xe915590e, 0x4915f5da, 0x414169ea, 0xa073b469, 0xd9dde743, 0xb974da93,
+	0x850f5fe2, 0xa41dfcf1, 0x197ac375, 0x9a362ed7, 0xde1f46b9, 0x284fe83c, 0xda5ac883, 0xc4206de8,
+	0x531f20f0, 0x196b974b, 0xfdec78b1, 0x24aec1a2, 0xc23cecd5, 0xfd569fcf, 0xd2b75f5d, 0xeccfc58d,
+	0xd72fbc6e, 0xeb575b66, 0x12691597, 0xa83c8fd4, 0xd4b584fc, 0x875e03e8, 0xd9eac97b, 0xf431c413,
+	0x0763a108, 0xa5abc164, 0x7c1fc7d6, 0xe7854927, 0xafce6364, 0x21c22c6d, 0x84673b35, 0x4f55eeec,
+	0xed077901, 0x96ee00de, 0xfcbb9f7a, 0x766d2e86, 0x163a37c6, 0x46acd327, 0x8b4d046c, 0x40db9908,
+	0x610e541b, 0x5880e2e3, 0x671757e9, 0x50fab333, 0x67781505, 0x1846ba8b, 0xf517889d, 0x730b216f,
+	0x623db3e3, 0xd3ef4914, 0xd8f9de4f, 0x6b465416, 0x877a85fc, 0xe2d51d34, 0x8d875b61, 0xda00cf52,
+	0x93b3f206, 0x6c84cd41, 0x6c78014e, 0x8782d4c1, 0x6da7ff5a, 0x22e5f157, 0x3c26b1b7, 0xb3e1d167,
+	0xe83d99f6, 0xa1b490a3, 0x8fa0ec95, 0x31687534, 0x30d0876c, 0x291d8d65, 0xe1362689, 0x1e1481b8,
+	0xc20ce991, 0xcb3ec3aa, 0xd2ff737b, 0x4fa48f7d, 0xc4f94d43, 0xc148568e, 0x7ed46cd1, 0xc7bf8c4e,
+	0xb7fd0a63, 0x21a020ce, 0x0ad6b0c7, 0xacf02d1f, 0x24d30082, 0x297cf437, 0x7f3efade, 0x04b61863,
+	0xb4bee429, 0xb01e463b, 0xe54986e0, 0x8e40a2f7, 0xf8ce9833, 0xd0f2a462, 0x24f40567, 0x28e6b0d1,
+	0xfaf2282e, 0x0be72873, 0x84ced8a6, 0x634b810c, 0xedb5fcbd, 0x77bb6c1c, 0x2b253d75, 0x6a40feb5,
+	0x150ccaa6, 0xeeb98b70, 0x24b4f4f4, 0xd5dfd309, 0xfa1552c9, 0xc819bd8a, 0x99b580f0, 0x1e5dfd21,
+	0x498fd47d, 0x21d57791, 0x62566a90, 0xa83b4d88, 0xfc1d2045, 0xb862cf2c, 0xaa8c0b4c, 0x8654526a,
+	0x1e1b1734, 0x9971ad0c, 0x03b03a9a, 0x8be5c040, 0xc4d62cb0, 0xb7cbc712, 0xcd011a4f, 0xa8e50b26,
+	0xd0e788b5, 0xa0e47c12, 0x653b8673, 0xf746ddf6, 0x9d6c1fbe, 0x28f233cf, 0x2990cbbb, 0x92bf6602,
+	0xf195f69b, 0xfc8b7a90, 0x67636895, 0x6ed93253, 0x5b520c90, 0x66a75967, 0xe1d1d0cb, 0x65b8a0f5,
+	0x65d0a5db, 0x4abdb949, 0x8083d713, 0x92d8fde8, 0xac6dc40c, 0x3be96e7d, 0xc5b7f33a, 0x150c5d8b,
+	0xac65cdef, 0x4b8e2353, 0x627cd2c1, 0x3a2ec386, 0xf4b46942, 0xacc1999f, 0x72f91f33, 0x9ab88777,
+	0x174fd75e, 0x7281c9f3, 0x99ee3345, 0x6644f430, 0xe5fccda0, 0xdb139e1c, 0x013e253e, 0x7b39f2b2,
+	0x8be49bd6, 0x31b81947, 0xea3e2cbb, 0x06e0aa1d, 0x80e92166, 0x33bebc79, 0x30067c52, 0x8511be87,
+	0x816d9d9e, 0x99d3cf3b, 0xdaef7fe2, 0x37b2b357, 0x40f62a44, 0x8e7e83d4, 0x95b30930, 0xec9dd3d0,
+	0x8a491303, 0xb6fd1ce3, 0x9d0eb1f0, 0xcdcd5942, 0x6320f7db, 0x60da74b6, 0x67fd11fd, 0x8d8584d5,
+	0x4b0e2d77, 0x4b0beee0, 0xa22f8308, 0x91ea5989, 0x5cc7dae0, 0x09a50f3e, 0xa6726f41, 0xe286fa5a,
+	0x35714634, 0xfbafa7f9, 0xe09225f3, 0x175bfe7a, 0x45fa257f, 0x0ed0d71c, 0x29be72e8, 0xa203e19b,
+	0x1e8953ec, 0x77c34b85, 0x867cc4e5, 0x1e461378, 0xa8b9acef, 0x966e44ac, 0x9b396add, 0x607d43f6,
+	0x7e991726, 0x9628f918, 0x3a2527fe, 0x00dc1644, 0x8a5df3df, 0x8b3d73b9, 0x9579dbad, 0xbb6a67d3,
+	0xd94a4362, 0xa88392f5, 0xa87b4430, 0x9e7f0609, 0x540e143a, 0x450c3b08, 0x8c077f9d, 0x142e6949,
+	0x9865ee88, 0x4a98fdcf, 0x03e40e63, 0x695f6431, 0x13f246a4, 0x52b2ce99, 0x2d65dab8, 0xa3a3c9b4,
+	0x40f480dc, 0xc0119d34, 0x66ffe5e3, 0x99b63a8e, 0x4dcbef95, 0xc1c15a6e, 0x6525b246, 0xbb54ea2d,
+	0xed1d28b2, 0x0815046e, 0x751937d2, 0xc4a06d8a, 0xbb2b5760, 0xd434064a, 0xe1f587fd, 0x6b34ef4a,
+	0xb66ad680, 0x5a7385cf, 0xc9772cbc, 0xfdc364f1, 0x7271e7a0, 0x30f1c316, 0xd3fdc0b8, 0xcbbaa68a,
+	0xe6db5861, 0xe23d1191, 0x23c1b69e, 0xd27b9a8e, 0x02b8d21f, 0x2472b0d9, 0x2ff46619, 0x51f1ee0a,
+	0xcdae5021, 0x709c27cf, 0x7fe88d68, 0xcbbb9f4c, 0x22e90a68, 0x8cfa5c66, 0x92759140, 0xde036f44,
+	0xc0533627, 0x8ff182a5, 0x35f46f8b, 0x7756984d, 0x0f52d26e, 0x94ca4f39, 0x487fccb8, 0xac11fe0e,
+	0x0d479655, 0x83947dd2, 0x70efed40, 0xdfcfa45b, 0xf27df9b3, 0x9f98fa2c, 0x7ef10320, 0xe92b872c,
+	0x78596428, 0xef1a477d, 0xef3cd9bf, 0xed0da5e7, 0x301b3af6, 0xb9b384e9, 0x08fbb00d, 0xebf0f50a,
+	0xfcf0d33c, 0x1b1509b9, 0xa73eb370, 0x1603b8b9, 0x6740ca27, 0x47283988, 0x2b0df620, 0xcb9b53a2,
+	0xb59f0297, 0x3c449be1, 0xc54664c6, 0x14421e52, 0x0b10fa37, 0x04c96a0a, 0xd8a3e6dd, 0xe3fe49a6,
+	0x6b9efd9b, 0x061ac8d9, 0x911d75c0, 0x82c8a382, 0x70f09c76, 0x7ed71a9a, 0x641581cc, 0x062e5091,
+	0x05b8efe0, 0x18c15c8e, 0xcb64b03e, 0x30421a67, 0x9dd7b856, 0x7b537c26, 0x1317f01a, 0x18228555,
+	0x122db4d6, 0xa8ca4b1d, 0x4da35541, 0x5547e9a6, 0xb50e630a, 0x8a9e5b9a, 0x9fb58511, 0x6d916200,
+	0xa4da19a6, 0x781e3a03, 0x84c9332f, 0x6ac6cbaa, 0x73c3083e, 0x1bc53374, 0x2465d7a1, 0xa05af975,
+	0x5298485c, 0xc5a50203, 0x9fa4390b, 0x5ba2d9e5, 0xc7148f8e, 0x662048d4, 0xa6f1caec, 0xfcd566d6,
+	0xafbd268f, 0x2c548c91, 0xe0b1b773, 0x23320fed, 0xc53a26ed, 0x5d9645b6, 0x07e0e0ee, 0x7b550ae7,
+	0xd3da115a, 0xc4a75e29, 0xb626edd3, 0x21a0d1f6, 0x6763f5f5, 0xf43281de, 0xf26898b2, 0xa9ca73ea,
+	0x9b700a66, 0xddef1c4a, 0x83b6367b, 0x36a64401, 0x288e2911, 0x5a8c9e7b, 0x25944fc9, 0x7fdd6504,
+	0xcc271075, 0xfeeb85e3, 0xca808b70, 0x388e1c26, 0xaf3ec79a, 0x535dae15, 0x51709f46, 0x9d9560f3,
+	0xaf668af7, 0x5b8d3118, 0x765eef72, 0x69916fd2, 0xf079521e, 0x227b7ec7, 0xd7d48dbf, 0xd7d01091,
+	0xf2a8e868, 0xaa79de78, 0xcdf7d515, 0xe4107150, 0x98149973, 0x65d3f5f8, 0xdc51397d, 0xd72f87f7,
+	0x9bb0e397, 0xf18c9c4d, 0xf8e92d26, 0x926dc340, 0x25824a5d, 0xe2363da1, 0x2e6a4963, 0x170149af,
+	0x21b829ed, 0x34d8f5d2, 0x1e2bc3c8, 0x4131b4cc, 0x26458999, 0x75046a35, 0xed50999b, 0xa666f5ff,
+	0x046007aa, 0xe331a50c, 0xcbad9ea4, 0x0138714f, 0x2f15732a, 0x7e7b2e40, 0xb402d949, 0x43ab25aa,
+	0xa05160ac, 0x6743fba1, 0x9072c832, 0x0e03cdf7, 0x02712162, 0x83dc3a4c, 0xab306641, 0x07964f14,
+	0xb530ba29, 0x0587905e, 0x1dfc1831, 0xd8342b7f, 0x06ab655b, 0x1ee87427, 0x3ed4e436, 0x667e106d,
+	0x79d1d7ca, 0xcc4e1fe5, 0xd51c4427, 0x563a24c4, 0x2e1c737d, 0x68b45887, 0x28c2caa6, 0xaccb0149,
+	0xa38a94d8, 0x469c9593, 0x614aa9bc, 0x10201210, 0xe17051fa, 0xf03128d1, 0xffc4e3ed, 0xe2bea618,
+	0xe275af0c, 0x00a491d5, 0x2baa2bce, 0xa49e7b94, 0x13e75fe0, 0x09a39a26, 0x44a1d2ed, 0xe64ab0f9,
+	0x8b54b432, 0x0fa900d0, 0xe9be350a, 0x2f8c373e, 0x287d6e57, 0x487fdf4e, 0x95e2571e, 0x68d8ace6,
+	0x3970bd27, 0x22655fff, 0x44568afb, 0xe5d413f9, 0xb5ce162a, 0x0a59f42f, 0xcf3f0924, 0xb264ce20,
+	0x3efcc23a, 0x49075f54, 0xb09e2453, 0x9a5437d6, 0x2645377f, 0x33bd50ee, 0xcb17ff81, 0x65aa8ae2,
+	0xaf1ba504, 0x1e6be0dc, 0x130f61fa, 0xe097de0f, 0xed6aade7, 0xd08eca83, 0xd7acea9c, 0xcb752abe,
+	0xdded91c0, 0x5d546243, 0x026256c2, 0x0048a6e4, 0x86909524, 0xe3c3d3cb, 0x6b524687, 0xd36b6f27,
+	0xe34df3e2, 0xd65bcb6c, 0x428d4983, 0xa901d4d8, 0x277031f8, 0x2373a07f, 0xcda2ba89, 0x1986b68e,
+	0x99f63e40, 0xda9cf05b, 0xe93cc72a, 0xf93750de, 0xff1023a9, 0x7f968234, 0x980adfce, 0x573c9272,
+	0x15cdef17, 0x2dabd58e, 0xec49d2f4, 0x5731597f, 0x512b3fdf, 0xbc6fef74, 0x833db668, 0x86ab39cf,
+	0xc3776b8f, 0x6117d157, 0x5ad5013a, 0x6d45a90a, 0x494e58fa, 0x61cfd763, 0x3259077d, 0x545ff8c8,
+	0xbb409581, 0x227ceca4, 0x52713ec3, 0x94349203, 0xc3a8330f, 0x588ae01b, 0x2d095e31, 0x29502132,
+	0x3847944e, 0x15dd9fe9, 0x56ba0b34, 0x328f2177, 0x774c26a8, 0xa55957cf, 0x8f5b73c4, 0xcc9ad195,
+	0xaa10e0f5, 0xfc1fdd37, 0xff10d766, 0x822843b2, 0x7a3fbb18, 0x592f7ef8, 0x6c38d771, 0xd59f009a,
+	0x7732d894, 0x4d4e6cf3, 0x774c707a, 0x704b956c, 0x775db99b, 0x32bfa8b7, 0x194cb3c7, 0x009c7eca,
+	0x528d8a5d, 0xafbc555e, 0x728b3cb4, 0x3bb62ab5, 0x578b1233, 0xc46fb613, 0xde69233c, 0xc44d23d1,
+	0x5cf3da34, 0xeacbbdba, 0xa05496c2, 0x6cd16880, 0xe78f2c38, 0xc3a83a47, 0xaf9d8d6f, 0xb9c02f6f,
+	0xf942c414, 0x6c7b521a, 0xe34af037, 0xf0a47f48, 0x7224923d, 0x4f937a14, 0x4cf5e764, 0x4dd5954c,
+	0x250b34fc, 0x3b57f4f4, 0xa7840e08, 0xb4cc3de0, 0xcf5bb70f, 0x49bd309f, 0xb28ece99, 0xadfefa08,
+	0xf2ad7a8a, 0x8e00a412, 0xe6b516e1, 0x7eebd168, 0x51d4a16f, 0xa0c88019, 0xf1a72d56, 0x4930b71c,
+	0xee86226b, 0xf7b8e60f, 0x0a8f48db, 0x686f712e, 0x8043d60f, 0xbb21279c, 0xd6d49904, 0x4799fa89,
+	0x1cd686a0, 0x21c8d758, 0x2d400335, 0xf2137082, 0x2adf9706, 0x1090c702, 0xc7495ba4, 0x50c3fc5b,
+	0x95645411, 0x4e702fcf, 0x4d76ea01, 0xcf019b6f, 0x4bfe262f, 0x58ac040d, 0x826f320a, 0x8713dbce,
+	0x4aae2c9a, 0x83629203, 0x8f89e802, 0x49d178b5, 0x00a79fa8, 0x64962825, 0x01113d5b, 0x45120a60,
+	0xd71eca5d, 0x0d17606d, 0xe33c141c, 0xabc1003d, 0x1f3b6cb6, 0x3f0b6814, 0xaab8afe5, 0xcb98902d,
+	0xa19a8ca6, 0x82306882, 0x8c23d08e, 0xb8960ddd, 0xd2a54155, 0x36577036, 0xdaa9cb1b, 0xb8bf5be4,
+	0x8e535e35, 0x8f41de5a, 0xb397553a, 0xe227ef8f, 0x82a1084d, 0x827c4f89, 0xcda2a057, 0x0b524259,
+	0xc3fa4292, 0x3bcd2b00, 0xca976fab, 0x31fd5244, 0x74123d64, 0x57a170fb, 0x0b03d88f, 0xc3bc287f,
+	0x743adcf7, 0x66a633e1, 0xe2a0d47b, 0xc3919924, 0xcf58b397, 0x32ddb300, 0x37dd45fe, 0x2e1796b2,
+	0xb25c1160, 0x2b849eba, 0x84d8e4b9, 0xac0d360e, 0x7cedf2d9, 0xc3cfb037, 0x581221f1, 0xac46ad33,
+	0xd7e45753, 0x16473acb, 0xac4d6b61, 0x7181d787, 0x82215df8, 0x51a501d6, 0x218f6612, 0x8c799353,
+	0x4e77100c, 0x04934a8e, 0xa99bc751, 0x86429488, 0xc3a2006d, 0x05ca8ab0, 0xfbce4c89, 0x58a50d75,
+	0x6a493496, 0x59b81ca7, 0x6bed91f2, 0x4cd8360b, 0xff90d5fb, 0xddd0d5ff, 0x5b3261b0, 0x603347a3,
+];
@@ -0,0 +1,347 @@
+//! A compatibility layer mirroring the API of the `tiny-bip39` crate.
+//!
+//! This allows migrating code written against `tiny-bip39` step by step. The
+//! types wrap the types of this crate and can be converted into them. Languages
+//! are represented by this crate's [Language].
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::compat::{Mnemonic, MnemonicType, Seed};
+//! use bip39::Language;
+//!
+//! let phrase = "letter advice cage absurd amount doctor acoustic avoid letter advice cage above";
+//! let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+//! assert_eq!(MnemonicType::for_phrase(mnemonic.phrase()).unwrap(), MnemonicType::Words12);
+//!
+//! let seed = Seed::new(&mnemonic, "TREZOR");
+//! assert_eq!(format!("{:x}", seed).len(), 128);
+//!
+//! let mnemonic: bip39::Mnemonic = mnemonic.into();
+//! assert_eq!(seed.as_bytes(), &mnemonic.to_seed("TREZOR")[..]);
+//! ```
+
+use core::convert::TryFrom;
+use core::{fmt, mem};
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{Error, Language, WordCount};
+
+/// The number of words of a mnemonic, named after the variants of `tiny-bip39`.
+///
+/// It converts from and into this crate's [WordCount].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MnemonicType {
+	/// 12 words, 128 bits of entropy.
+	Words12,
+	/// 15 words, 160 bits of entropy.
+	Words15,
+	/// 18 words, 192 bits of entropy.
+	Words18,
+	/// 21 words, 224 bits of entropy.
+	Words21,
+	/// 24 words, 256 bits of entropy.
+	Words24,
+}
+
+impl MnemonicType {
+	/// Get the type for the given number of words.
+	pub fn for_word_count(size: usize) -> Result<MnemonicType, Error> {
+		WordCount::try_from(size).map(MnemonicType::from)
+	}
+
+	/// Get the type for the given number of bits of entropy.
+	pub fn for_key_size(size: usize) -> Result<MnemonicType, Error> {
+		match size {
+			128 | 160 | 192 | 224 | 256 => MnemonicType::for_word_count(size / 32 * 3),
+			_ => Err(Error::BadEntropyBitCount(size)),
+		}
+	}
+
+	/// Get the type for the number of words of the phrase.
+	///
+	/// The words themselves are not validated.
+	pub fn for_phrase(phrase: &str) -> Result<MnemonicType, Error> {
+		MnemonicType::for_word_count(phrase.split_whitespace().count())
+	}
+
+	/// The number of words.
+	pub fn word_count(&self) -> usize {
+		WordCount::from(*self).word_count()
+	}
+
+	/// The number of bits of entropy.
+	pub fn entropy_bits(&self) -> usize {
+		WordCount::from(*self).entropy_bytes() * 8
+	}
+
+	/// The number of checksum bits.
+	pub fn checksum_bits(&self) -> usize {
+		self.word_count() / 3
+	}
+
+	/// The total number of bits encoded by the words.
+	pub fn total_bits(&self) -> usize {
+		self.entropy_bits() + self.checksum_bits()
+	}
+}
+
+impl From<WordCount> for MnemonicType {
+	fn from(word_count: WordCount) -> MnemonicType {
+		match word_count {
+			WordCount::Words12 => MnemonicType::Words12,
+			WordCount::Words15 => MnemonicType::Words15,
+			WordCount::Words18 => MnemonicType::Words18,
+			WordCount::Words21 => MnemonicType::Words21,
+			WordCount::Words24 => MnemonicType::Words24,
+		}
+	}
+}
+
+impl From<MnemonicType> for WordCount {
+	fn from(mtype: MnemonicType) -> WordCount {
+		match mtype {
+			MnemonicType::Words12 => WordCount::Words12,
+			MnemonicType::Words15 => WordCount::Words15,
+			MnemonicType::Words18 => WordCount::Words18,
+			MnemonicType::Words21 => WordCount::Words21,
+			MnemonicType::Words24 => WordCount::Words24,
+		}
+	}
+}
+
+impl Default for MnemonicType {
+	fn default() -> MnemonicType {
+		MnemonicType::Words12
+	}
+}
+
+impl fmt::Display for MnemonicType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} words ({}bits)", self.word_count(), self.entropy_bits())
+	}
+}
+
+/// A mnemonic with the API of `tiny-bip39`, keeping the phrase and the entropy.
+///
+/// Like in `tiny-bip39`, the [fmt::Debug] output doesn't contain the phrase.
+/// With the `zeroize` or `wipe` feature, the phrase and the entropy are erased
+/// from memory on drop.
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct Mnemonic {
+	inner: crate::Mnemonic,
+	phrase: String,
+	entropy: Vec<u8>,
+}
+
+#[cfg(all(feature = "wipe", not(feature = "zeroize")))]
+impl Drop for Mnemonic {
+	fn drop(&mut self) {
+		// SAFETY: zero bytes are valid UTF-8.
+		crate::wipe::wipe(unsafe { self.phrase.as_mut_vec() });
+		crate::wipe::wipe(&mut self.entropy);
+	}
+}
+
+impl Mnemonic {
+	/// Generate a new mnemonic of the given type in the given language.
+	#[cfg(any(feature = "rand", feature = "rand_v09"))]
+	pub fn new(mtype: MnemonicType, lang: Language) -> Mnemonic {
+		crate::Mnemonic::generate_sized_in(lang, mtype.into()).into()
+	}
+
+	/// Create a mnemonic in the given language from the entropy.
+	pub fn from_entropy(entropy: &[u8], lang: Language) -> Result<Mnemonic, Error> {
+		crate::Mnemonic::from_entropy_in(lang, entropy).map(Mnemonic::from)
+	}
+
+	/// Parse a phrase in the given language.
+	pub fn from_phrase(phrase: &str, lang: Language) -> Result<Mnemonic, Error> {
+		crate::Mnemonic::parse_in(lang, phrase).map(Mnemonic::from)
+	}
+
+	/// Check that the phrase is a valid mnemonic in the given language.
+	pub fn validate(phrase: &str, lang: Language) -> Result<(), Error> {
+		crate::Mnemonic::parse_in(lang, phrase).map(|_| ())
+	}
+
+	/// The phrase of the mnemonic.
+	pub fn phrase(&self) -> &str {
+		&self.phrase
+	}
+
+	/// Get the phrase of the mnemonic, consuming it.
+	pub fn into_phrase(mut self) -> String {
+		mem::take(&mut self.phrase)
+	}
+
+	/// The entropy of the mnemonic.
+	pub fn entropy(&self) -> &[u8] {
+		&self.entropy
+	}
+
+	/// The language of the mnemonic.
+	pub fn language(&self) -> Language {
+		self.inner.language()
+	}
+}
+
+impl From<crate::Mnemonic> for Mnemonic {
+	fn from(inner: crate::Mnemonic) -> Mnemonic {
+		Mnemonic {
+			phrase: inner.to_string(),
+			entropy: inner.to_entropy(),
+			inner,
+		}
+	}
+}
+
+impl From<Mnemonic> for crate::Mnemonic {
+	fn from(mnemonic: Mnemonic) -> crate::Mnemonic {
+		mnemonic.inner.clone()
+	}
+}
+
+/// The phrase and the entropy are derived from the inner mnemonic, which is
+/// compared in constant time.
+impl PartialEq for Mnemonic {
+	fn eq(&self, other: &Mnemonic) -> bool {
+		self.inner == other.inner
+	}
+}
+
+impl Eq for Mnemonic {}
+
+impl AsRef<str> for Mnemonic {
+	fn as_ref(&self) -> &str {
+		self.phrase()
+	}
+}
+
+impl fmt::Debug for Mnemonic {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Debug::fmt(&self.inner, f)
+	}
+}
+
+impl fmt::Display for Mnemonic {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.phrase())
+	}
+}
+
+/// The seed of a mnemonic with the API of `tiny-bip39`.
+///
+/// The [fmt::Debug] output doesn't contain the seed, use [fmt::LowerHex] to
+/// print it. With the `zeroize` or `wipe` feature, the seed is erased from
+/// memory on drop.
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct Seed {
+	bytes: [u8; 64],
+}
+
+#[cfg(all(feature = "wipe", not(feature = "zeroize")))]
+impl Drop for Seed {
+	fn drop(&mut self) {
+		crate::wipe::wipe(&mut self.bytes);
+	}
+}
+
+impl Seed {
+	/// Derive the seed of the mnemonic with the given password.
+	pub fn new(mnemonic: &Mnemonic, password: &str) -> Seed {
+		Seed {
+			bytes: mnemonic.inner.to_seed(password),
+		}
+	}
+
+	/// The seed bytes.
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.bytes
+	}
+}
+
+impl AsRef<[u8]> for Seed {
+	fn as_ref(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+impl fmt::Debug for Seed {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("Seed(..)")
+	}
+}
+
+impl fmt::LowerHex for Seed {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if f.alternate() {
+			f.write_str("0x")?;
+		}
+		for b in self.bytes.iter() {
+			write!(f, "{:02x}", b)?;
+		}
+		Ok(())
+	}
+}
+
+impl fmt::UpperHex for Seed {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if f.alternate() {
+			f.write_str("0x")?;
+		}
+		for b in self.bytes.iter() {
+			write!(f, "{:02X}", b)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_mnemonic_type() {
+		for size in &[12, 15, 18, 21, 24] {
+			let mtype = MnemonicType::for_word_count(*size).unwrap();
+			assert_eq!(mtype.word_count(), *size);
+			assert_eq!(MnemonicType::for_key_size(mtype.entropy_bits()), Ok(mtype));
+			assert_eq!(mtype.total_bits(), size * 11);
+			assert_eq!(MnemonicType::from(WordCount::from(mtype)), mtype);
+		}
+		assert_eq!(MnemonicType::for_word_count(13), Err(Error::BadWordCount(13)));
+		assert_eq!(MnemonicType::for_key_size(129), Err(Error::BadEntropyBitCount(129)));
+		assert_eq!(MnemonicType::default().to_string(), "12 words (128bits)");
+	}
+
+	#[test]
+	fn test_mnemonic() {
+		let entropy = [0x7f; 16];
+		let m = Mnemonic::from_entropy(&entropy, Language::English).unwrap();
+		assert_eq!(m.entropy(), &entropy[..]);
+		assert_eq!(m.phrase().split_whitespace().count(), 12);
+		assert_eq!(Mnemonic::validate(m.phrase(), Language::English), Ok(()));
+		assert_eq!(Mnemonic::from_phrase(m.phrase(), Language::English), Ok(m.clone()));
+		assert_eq!(m.to_string(), m.clone().into_phrase());
+
+		let seed = Seed::new(&m, "TREZOR");
+		assert_eq!(format!("{:#x}", seed).len(), 2 + 128);
+		assert!(format!("{:#x}", seed).starts_with("0x"));
+
+		assert_eq!(format!("{:?}", seed), "Seed(..)");
+		assert_eq!(format!("{:?}", m), "Mnemonic { language: English, word_count: 12 }");
+	}
+
+	#[cfg(feature = "rand")]
+	#[test]
+	fn test_new() {
+		let m = Mnemonic::new(MnemonicType::Words24, Language::English);
+		assert_eq!(MnemonicType::for_phrase(m.phrase()), Ok(MnemonicType::Words24));
+		assert_eq!(m.entropy().len(), 32);
+	}
+}
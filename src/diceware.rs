@@ -0,0 +1,124 @@
+//! Diceware-style passphrases made of random words from the word lists.
+//!
+//! **These are not BIP39 mnemonics.** The words are drawn independently and
+//! uniformly, without checksum, so every word adds exactly 11 bits of entropy.
+//! Such passphrases can for example be used as the BIP39 passphrase, or to
+//! encrypt a backup.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::{diceware, Language};
+//!
+//! let mut rng = bip39::rand::thread_rng();
+//! let nb_words = diceware::words_for_entropy(64);
+//! assert_eq!(nb_words, 6);
+//! let passphrase = diceware::generate_with(&mut rng, Language::English, nb_words, "-");
+//! assert_eq!(passphrase.split('-').count(), 6);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::bits::BITS_PER_WORD;
+use crate::Language;
+
+/// The entropy in bits of a passphrase of the given number of words.
+pub fn entropy_bits(nb_words: usize) -> usize {
+	nb_words * BITS_PER_WORD
+}
+
+/// The number of words needed for at least the given entropy in bits.
+pub fn words_for_entropy(bits: usize) -> usize {
+	(bits + BITS_PER_WORD - 1) / BITS_PER_WORD
+}
+
+/// Draw random words from the word list of the language.
+///
+/// The word list has 2048 words, so taking 11 random bits per word gives every
+/// word the same probability.
+pub fn random_words<'a, R>(
+	rng: &'a mut R,
+	language: Language,
+	nb_words: usize,
+) -> impl Iterator<Item = &'static str> + 'a
+where
+	R: RngCore + CryptoRng,
+{
+	let list = language.word_list();
+	(0..nb_words).map(move |_| {
+		let idx = rng.next_u32() & ((1 << BITS_PER_WORD) - 1);
+		list.get(idx as usize).copied().unwrap_or("")
+	})
+}
+
+/// Generate a passphrase of random words from the word list of the language,
+/// separated by `separator`.
+#[cfg(feature = "alloc")]
+pub fn generate_with<R>(rng: &mut R, language: Language, nb_words: usize, separator: &str) -> String
+where
+	R: RngCore + CryptoRng,
+{
+	let mut passphrase = String::new();
+	for (i, word) in random_words(rng, language, nb_words).enumerate() {
+		if i > 0 {
+			passphrase.push_str(separator);
+		}
+		passphrase.push_str(word);
+	}
+	passphrase
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_entropy_accounting() {
+		assert_eq!(entropy_bits(0), 0);
+		assert_eq!(entropy_bits(6), 66);
+		assert_eq!(words_for_entropy(0), 0);
+		assert_eq!(words_for_entropy(1), 1);
+		assert_eq!(words_for_entropy(11), 1);
+		assert_eq!(words_for_entropy(12), 2);
+		assert_eq!(words_for_entropy(128), 12);
+		for bits in 0..300 {
+			assert!(entropy_bits(words_for_entropy(bits)) >= bits);
+		}
+	}
+
+	#[test]
+	fn test_generate() {
+		/// A deterministic generator going through all word indices.
+		struct Counter(u32);
+
+		impl RngCore for Counter {
+			fn next_u32(&mut self) -> u32 {
+				self.0 += 1;
+				// Bits above the 11 used ones must be ignored.
+				self.0 - 1 + 0xfff0_0000
+			}
+			fn next_u64(&mut self) -> u64 {
+				self.next_u32() as u64
+			}
+			fn fill_bytes(&mut self, dest: &mut [u8]) {
+				rand_core::impls::fill_bytes_via_next(self, dest)
+			}
+			fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+				self.fill_bytes(dest);
+				Ok(())
+			}
+		}
+		impl CryptoRng for Counter {}
+
+		let words = random_words(&mut Counter(2046), Language::English, 3).collect::<Vec<_>>();
+		assert_eq!(words, vec!["zone", "zoo", "abandon"]);
+		assert_eq!(
+			generate_with(&mut Counter(0), Language::English, 2, " + "),
+			"abandon + ability"
+		);
+		assert_eq!(generate_with(&mut Counter(0), Language::English, 0, " "), "");
+	}
+}
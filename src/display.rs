@@ -0,0 +1,153 @@
+use core::fmt;
+
+use crate::Mnemonic;
+
+/// The NATO phonetic alphabet code words for the letters `a` to `z`.
+const NATO_ALPHABET: [&str; 26] = [
+	"Alpha", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel", "India", "Juliet",
+	"Kilo", "Lima", "Mike", "November", "Oscar", "Papa", "Quebec", "Romeo", "Sierra", "Tango",
+	"Uniform", "Victor", "Whiskey", "X-ray", "Yankee", "Zulu",
+];
+
+/// Display adapter spelling out every word of a [Mnemonic] in the NATO
+/// phonetic alphabet, one word per line, like `c-a-t: Charlie Alpha Tango`.
+///
+/// Characters outside of `a` to `z` are printed as they are.
+///
+/// Created with [Mnemonic::nato_spelling].
+#[derive(Clone, Copy, Debug)]
+pub struct NatoSpelling<'a>(pub(crate) &'a Mnemonic);
+
+impl<'a> fmt::Display for NatoSpelling<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (i, word) in self.0.words().enumerate() {
+			if i > 0 {
+				f.write_str("\n")?;
+			}
+			for (j, c) in word.chars().enumerate() {
+				if j > 0 {
+					f.write_str("-")?;
+				}
+				write!(f, "{}", c)?;
+			}
+			f.write_str(":")?;
+			for c in word.chars() {
+				let code = match c {
+					'a'..='z' => NATO_ALPHABET.get((c as u8 - b'a') as usize),
+					_ => None,
+				};
+				match code {
+					Some(code) => write!(f, " {}", code)?,
+					None => write!(f, " {}", c)?,
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Adapter revealing the phrase of a [Mnemonic] in both its [fmt::Debug] and
+/// [fmt::Display] output, for when showing it is intended.
+///
+/// Created with [Mnemonic::expose].
+#[derive(Clone, Copy)]
+pub struct Exposed<'a>(pub(crate) &'a Mnemonic);
+
+impl<'a> fmt::Display for Exposed<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self.0, f)
+	}
+}
+
+impl<'a> fmt::Debug for Exposed<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("Mnemonic").field(&format_args!("{}", self.0)).finish()
+	}
+}
+
+/// Display adapter showing only the first and last words of a [Mnemonic],
+/// with the other words masked, like `abandon ability … zoo`.
+///
+/// At least half of the words are always masked, so that the shown words
+/// don't weaken the mnemonic too much: if the first and last words would
+/// cover more than half of the mnemonic, fewer last words, and then fewer
+/// first words, are shown. The [fmt::Debug] output is the same as the
+/// [fmt::Display] output.
+///
+/// Created with [Mnemonic::display_partial].
+#[derive(Clone, Copy)]
+pub struct PartialDisplay<'a> {
+	pub(crate) mnemonic: &'a Mnemonic,
+	pub(crate) nb_first: usize,
+	pub(crate) nb_last: usize,
+}
+
+impl<'a> fmt::Display for PartialDisplay<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let word_count = self.mnemonic.word_count();
+		let max_shown = word_count / 2;
+		let nb_first = self.nb_first.min(max_shown);
+		let nb_last = self.nb_last.min(max_shown - nb_first);
+
+		for word in self.mnemonic.words().take(nb_first) {
+			write!(f, "{} ", word)?;
+		}
+		f.write_str("\u{2026}")?;
+		for word in self.mnemonic.words().skip(word_count - nb_last) {
+			write!(f, " {}", word)?;
+		}
+		Ok(())
+	}
+}
+
+impl<'a> fmt::Debug for PartialDisplay<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_nato_spelling() {
+		let m = Mnemonic::parse_normalized(
+			"cat swing flag economy stadium alone churn speed unique patch report train",
+		)
+		.unwrap();
+		let spelled = m.nato_spelling().to_string();
+		let mut lines = spelled.lines();
+		assert_eq!(lines.next(), Some("c-a-t: Charlie Alpha Tango"));
+		assert_eq!(lines.next(), Some("s-w-i-n-g: Sierra Whiskey India November Golf"));
+		assert_eq!(lines.count(), 10);
+	}
+
+	#[test]
+	fn test_expose() {
+		let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+		let phrase = m.to_string();
+		assert!(!format!("{:?}", m).contains("abandon"));
+		assert_eq!(format!("{:?}", m.expose()), format!("Mnemonic({})", phrase));
+		assert_eq!(m.expose().to_string(), phrase);
+		assert_eq!(format!("{:#}", m.expose()), format!("{:#}", m));
+	}
+
+	#[test]
+	fn test_display_partial() {
+		let m = Mnemonic::parse_normalized(
+			"cat swing flag economy stadium alone churn speed unique patch report train",
+		)
+		.unwrap();
+		assert_eq!(m.display_partial(2, 1).to_string(), "cat swing \u{2026} train");
+		assert_eq!(format!("{:?}", m.display_partial(0, 2)), "\u{2026} report train");
+		assert_eq!(m.display_partial(0, 0).to_string(), "\u{2026}");
+
+		// At least half of the words are always masked.
+		let words = m.words().take(6).collect::<Vec<_>>().join(" ");
+		assert_eq!(m.display_partial(12, 3).to_string(), format!("{} \u{2026}", words));
+		assert_eq!(m.display_partial(6, 6).to_string(), m.display_partial(6, 0).to_string());
+		assert_eq!(m.display_partial(4, 4).to_string(), m.display_partial(4, 2).to_string());
+		assert_eq!(m.display_partial(0, 12).to_string(), m.display_partial(0, 6).to_string());
+	}
+}
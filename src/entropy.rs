@@ -0,0 +1,84 @@
+use core::fmt;
+
+use crate::bytes_eq_ct;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The maximum length of the entropy of a mnemonic in bytes.
+const MAX_ENTROPY_BYTES: usize = 32;
+
+/// The entropy of a [Mnemonic], between 128 and 256 bits.
+///
+/// The comparison with `==` takes a constant time. With the `zeroize` or
+/// `wipe` feature, the entropy is erased from memory on drop.
+///
+/// [Mnemonic]: crate::Mnemonic
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct Entropy {
+	/// The entropy bytes, followed by zeros.
+	bytes: [u8; MAX_ENTROPY_BYTES],
+	/// The length of the entropy in bytes.
+	len: usize,
+}
+
+impl Entropy {
+	/// Copy the entropy from a slice of at most 32 bytes.
+	#[cfg(any(feature = "rand", feature = "rand_v09"))]
+	pub(crate) fn from_slice(entropy: &[u8]) -> Entropy {
+		let mut bytes = [0; MAX_ENTROPY_BYTES];
+		for (b, e) in bytes.iter_mut().zip(entropy) {
+			*b = *e;
+		}
+		Entropy {
+			bytes,
+			len: entropy.len().min(MAX_ENTROPY_BYTES),
+		}
+	}
+
+	/// The entropy bytes.
+	pub fn as_bytes(&self) -> &[u8] {
+		self.bytes.get(..self.len).unwrap_or(&[])
+	}
+
+	/// The length of the entropy in bits.
+	pub fn bit_len(&self) -> usize {
+		self.len * 8
+	}
+}
+
+impl AsRef<[u8]> for Entropy {
+	fn as_ref(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+impl PartialEq for Entropy {
+	fn eq(&self, other: &Entropy) -> bool {
+		// The whole buffers are compared, the bytes after the entropy are zero.
+		self.len == other.len && bytes_eq_ct(&self.bytes, &other.bytes)
+	}
+}
+
+impl Eq for Entropy {}
+
+impl core::hash::Hash for Entropy {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.bytes.hash(state);
+		self.len.hash(state);
+	}
+}
+
+#[cfg(all(feature = "wipe", not(feature = "zeroize")))]
+impl Drop for Entropy {
+	fn drop(&mut self) {
+		crate::wipe::wipe(&mut self.bytes);
+	}
+}
+
+impl fmt::Debug for Entropy {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Entropy").field("bit_len", &self.bit_len()).finish()
+	}
+}
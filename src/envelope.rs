@@ -0,0 +1,193 @@
+//! Versioned mnemonics for application-specific phrase formats.
+//!
+//! An [Envelope] describes a phrase format where the mnemonic itself carries a
+//! version, or purpose, tag: the first bits of the HMAC-SHA512 of the phrase
+//! must match the version. This is the mechanism used by Electrum, whose
+//! standard wallets use the key `Seed version` and the 8-bit version `0x01`.
+//!
+//! Phrases of an envelope are still valid BIP39 mnemonics, including the
+//! checksum, but a wallet will only accept them with the matching envelope.
+//! Use a separate salt prefix to make sure the seeds derived by a plain BIP39
+//! wallet differ from the ones derived with the envelope.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::envelope::Envelope;
+//! use bip39::Language;
+//!
+//! const MY_APP: Envelope = Envelope::new(b"My app", 0x5, 4, "my app");
+//!
+//! # #[cfg(feature = "rand")] {
+//! let mut rng = bip39::rand::thread_rng();
+//! let m = MY_APP.generate_in_with(&mut rng, Language::English, 12).unwrap();
+//! assert!(MY_APP.matches(&m));
+//! assert_eq!(MY_APP.parse_in(Language::English, &m.to_string()), Ok(m.clone()));
+//! assert_ne!(MY_APP.to_seed_normalized(&m, ""), m.to_seed_normalized(""));
+//! # }
+//! ```
+
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
+use bitcoin_hashes::{hmac, sha512, Hash, HashEngine};
+
+use crate::{pbkdf2, Error, Language, Mnemonic};
+
+/// An application-specific phrase format with a version tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Envelope {
+	hmac_key: &'static [u8],
+	version: u32,
+	version_bits: u32,
+	salt_prefix: &'static str,
+}
+
+impl Envelope {
+	/// Create a new envelope.
+	///
+	/// Phrases carry the version in the first `version_bits` bits of their
+	/// HMAC-SHA512 with the given key, at most 32. Seeds are derived with
+	/// PBKDF2 like BIP39, but with `salt_prefix` instead of `mnemonic`.
+	///
+	/// Generating a phrase takes about `2^version_bits` attempts.
+	pub const fn new(
+		hmac_key: &'static [u8],
+		version: u32,
+		version_bits: u32,
+		salt_prefix: &'static str,
+	) -> Envelope {
+		Envelope {
+			hmac_key,
+			version,
+			version_bits,
+			salt_prefix,
+		}
+	}
+
+	/// The version carried by the mnemonic for this envelope's key.
+	pub fn version_of(&self, mnemonic: &Mnemonic) -> u32 {
+		let mut engine = hmac::HmacEngine::<sha512::Hash>::new(self.hmac_key);
+		for (i, word) in mnemonic.words().enumerate() {
+			if i > 0 {
+				engine.input(b" ");
+			}
+			engine.input(word.as_bytes());
+		}
+		let hmac = hmac::Hmac::<sha512::Hash>::from_engine(engine);
+		let mut prefix = [0u8; 4];
+		prefix.copy_from_slice(&hmac[0..4]);
+		let bits = self.version_bits.min(32);
+		u32::from_be_bytes(prefix).checked_shr(32 - bits).unwrap_or(0)
+	}
+
+	/// Whether the mnemonic carries the version of this envelope.
+	pub fn matches(&self, mnemonic: &Mnemonic) -> bool {
+		self.version_of(mnemonic) == self.version
+	}
+
+	/// Parse a mnemonic in the given language and check its version.
+	///
+	/// Returns [Error::InvalidVersion] if it doesn't match the envelope.
+	pub fn parse_in(&self, language: Language, s: &str) -> Result<Mnemonic, Error> {
+		let mnemonic = Mnemonic::parse_in_normalized(language, s)?;
+		if !self.matches(&mnemonic) {
+			return Err(Error::InvalidVersion);
+		}
+		Ok(mnemonic)
+	}
+
+	/// Generate a new mnemonic carrying the version of this envelope.
+	///
+	/// Returns [Error::InvalidVersion] if the version doesn't fit in the
+	/// envelope's version bits, since no mnemonic can carry it.
+	#[cfg(feature = "rand_core")]
+	pub fn generate_in_with<R>(
+		&self,
+		rng: &mut R,
+		language: Language,
+		word_count: usize,
+	) -> Result<Mnemonic, Error>
+	where
+		R: RngCore + CryptoRng,
+	{
+		if u64::from(self.version) >> self.version_bits.min(32) != 0 {
+			return Err(Error::InvalidVersion);
+		}
+		loop {
+			let mnemonic = Mnemonic::generate_in_with(rng, language, word_count)?;
+			if self.matches(&mnemonic) {
+				return Ok(mnemonic);
+			}
+		}
+	}
+
+	/// Convert the mnemonic to seed bytes with the envelope's salt prefix.
+	/// The passphrase must be normalized.
+	pub fn to_seed_normalized(&self, mnemonic: &Mnemonic, normalized_passphrase: &str) -> [u8; 64] {
+		const PBKDF2_ROUNDS: usize = 2048;
+		const PBKDF2_BYTES: usize = 64;
+
+		let mut seed = [0u8; PBKDF2_BYTES];
+		pbkdf2::pbkdf2_with_salt_prefix(
+			mnemonic.words(),
+			self.salt_prefix,
+			normalized_passphrase.as_bytes(),
+			PBKDF2_ROUNDS,
+			&mut seed,
+		);
+		seed
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_envelope() {
+		let envelope = Envelope::new(b"Seed version", 0x01, 8, "electrum");
+		let plain = Envelope::new(b"Seed version", 0, 0, "mnemonic");
+
+		let mut found = None;
+		for i in 0..=u16::max_value() {
+			let mut entropy = [0u8; 16];
+			entropy[..2].copy_from_slice(&i.to_be_bytes());
+			let m = Mnemonic::from_entropy(&entropy).unwrap();
+			assert!(plain.matches(&m));
+			if envelope.matches(&m) {
+				found = Some(m);
+				break;
+			}
+			assert_eq!(
+				envelope.parse_in(Language::English, &m.to_string()),
+				Err(Error::InvalidVersion)
+			);
+		}
+		let m = found.unwrap();
+		assert_eq!(envelope.version_of(&m), 0x01);
+		assert_eq!(envelope.parse_in(Language::English, &m.to_string()), Ok(m.clone()));
+
+		// The salt prefix separates the seeds from plain BIP39 seeds.
+		assert_eq!(plain.to_seed_normalized(&m, "TREZOR"), m.to_seed_normalized("TREZOR"));
+		assert_ne!(envelope.to_seed_normalized(&m, "TREZOR"), m.to_seed_normalized("TREZOR"));
+	}
+
+	#[cfg(feature = "rand")]
+	#[test]
+	fn test_generate_in_with() {
+		let mut rng = rand::thread_rng();
+		let envelope = Envelope::new(b"Seed version", 0x5, 4, "electrum");
+		let m = envelope.generate_in_with(&mut rng, Language::English, 12).unwrap();
+		assert_eq!(envelope.version_of(&m), 0x5);
+
+		// Versions that don't fit in the version bits can't be generated.
+		for (version, bits) in &[(0x10, 4), (1, 0), (0x100, 8)] {
+			let envelope = Envelope::new(b"Seed version", *version, *bits, "electrum");
+			assert_eq!(
+				envelope.generate_in_with(&mut rng, Language::English, 12),
+				Err(Error::InvalidVersion)
+			);
+		}
+	}
+}
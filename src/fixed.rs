@@ -0,0 +1,166 @@
+use core::convert::TryFrom;
+use core::{fmt, str};
+
+use crate::{Error, Language, Mnemonic};
+
+/// Define a wrapper of [Mnemonic] with a fixed number of words.
+macro_rules! fixed_mnemonic {
+	($(#[$doc:meta])* $name:ident, $nb_words:expr, $nb_bytes:expr, $from_entropy_in:ident, $expecting:expr) => {
+		$(#[$doc])*
+		#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+		pub struct $name(Mnemonic);
+
+		impl $name {
+			/// The number of words.
+			pub const WORD_COUNT: usize = $nb_words;
+
+			/// Create a mnemonic in the given language from the entropy.
+			pub fn from_entropy_in(language: Language, entropy: &[u8; $nb_bytes]) -> $name {
+				$name(Mnemonic::$from_entropy_in(language, entropy))
+			}
+
+			/// Create a mnemonic in English from the entropy.
+			pub fn from_entropy(entropy: &[u8; $nb_bytes]) -> $name {
+				$name::from_entropy_in(Language::English, entropy)
+			}
+
+			/// Parse a mnemonic in normalized UTF8 in the given language.
+			///
+			/// Returns [Error::BadWordCount] if it has a different number of
+			/// words.
+			pub fn parse_in_normalized(language: Language, s: &str) -> Result<$name, Error> {
+				$name::try_from(Mnemonic::parse_in_normalized(language, s)?)
+			}
+
+			/// Parse a mnemonic in normalized UTF8 and detect the language from
+			/// the enabled languages.
+			pub fn parse_normalized(s: &str) -> Result<$name, Error> {
+				$name::try_from(Mnemonic::parse_normalized(s)?)
+			}
+
+			/// The mnemonic.
+			pub fn as_mnemonic(&self) -> &Mnemonic {
+				&self.0
+			}
+
+			/// Get the mnemonic.
+			pub fn into_mnemonic(self) -> Mnemonic {
+				self.0
+			}
+
+			/// Convert the mnemonic back to the entropy used to generate it.
+			pub fn to_entropy_array(&self) -> [u8; $nb_bytes] {
+				let (buf, _) = self.0.to_entropy_array();
+				let mut entropy = [0; $nb_bytes];
+				for (e, b) in entropy.iter_mut().zip(buf.iter()) {
+					*e = *b;
+				}
+				entropy
+			}
+		}
+
+		impl TryFrom<Mnemonic> for $name {
+			type Error = Error;
+
+			/// Returns [Error::BadWordCount] if the mnemonic has a different
+			/// number of words.
+			fn try_from(mnemonic: Mnemonic) -> Result<$name, Error> {
+				match mnemonic.word_count() {
+					$nb_words => Ok($name(mnemonic)),
+					n => Err(Error::BadWordCount(n)),
+				}
+			}
+		}
+
+		impl From<$name> for Mnemonic {
+			fn from(mnemonic: $name) -> Mnemonic {
+				mnemonic.0
+			}
+		}
+
+		impl AsRef<Mnemonic> for $name {
+			fn as_ref(&self) -> &Mnemonic {
+				&self.0
+			}
+		}
+
+		impl fmt::Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt::Display::fmt(&self.0, f)
+			}
+		}
+
+		impl str::FromStr for $name {
+			type Err = Error;
+
+			fn from_str(s: &str) -> Result<$name, Error> {
+				$name::try_from(s.parse::<Mnemonic>()?)
+			}
+		}
+
+		serde_string_impl!($name, $expecting);
+	};
+}
+
+fixed_mnemonic!(
+	/// A [Mnemonic] of exactly 12 words, with 128 bits of entropy.
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::Mnemonic12;
+	///
+	/// let m = Mnemonic12::from_entropy(&[0; 16]);
+	/// let entropy: [u8; 16] = m.to_entropy_array();
+	/// assert_eq!(entropy, [0; 16]);
+	/// ```
+	Mnemonic12,
+	12,
+	16,
+	from_entropy_16_in,
+	"a 12-word BIP-39 Mnemonic Code"
+);
+
+fixed_mnemonic!(
+	/// A [Mnemonic] of exactly 24 words, with 256 bits of entropy.
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::{Error, Mnemonic, Mnemonic24};
+	/// use std::convert::TryFrom;
+	///
+	/// let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// assert_eq!(Mnemonic24::try_from(m), Err(Error::BadWordCount(12)));
+	/// ```
+	Mnemonic24,
+	24,
+	32,
+	from_entropy_32_in,
+	"a 24-word BIP-39 Mnemonic Code"
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_fixed_mnemonics() {
+		let m = Mnemonic24::from_entropy(&[0x42; 32]);
+		assert_eq!(m.as_mnemonic().word_count(), Mnemonic24::WORD_COUNT);
+		assert_eq!(m.to_entropy_array(), [0x42; 32]);
+		let s = m.to_string();
+		assert_eq!(s.parse::<Mnemonic24>(), Ok(m.clone()));
+		assert_eq!(Mnemonic24::parse_normalized(&s), Ok(m.clone()));
+		assert_eq!(Mnemonic12::parse_normalized(&s), Err(Error::BadWordCount(24)));
+		assert_eq!(Mnemonic::from(m.clone()), Mnemonic::from_entropy(&[0x42; 32]).unwrap());
+
+		let m = Mnemonic12::from_entropy_in(Language::English, &[0xff; 16]);
+		assert_eq!(m.as_ref().to_entropy_array().1, 16);
+		assert_eq!(Mnemonic12::try_from(m.clone().into_mnemonic()), Ok(m));
+		assert_eq!(
+			Mnemonic12::try_from(Mnemonic::from_entropy(&[0; 20]).unwrap()),
+			Err(Error::BadWordCount(15))
+		);
+	}
+}
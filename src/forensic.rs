@@ -0,0 +1,248 @@
+//! Partial decoding of damaged mnemonics, for data recovery.
+//!
+//! [partial_decode] recovers as much of the entropy of a damaged mnemonic as
+//! possible: every recognized word contributes its 11 bits, while the bits of
+//! unknown or missing words are marked as unknown.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::forensic::{self, ChecksumStatus};
+//!
+//! let s = "letter advice cage absurd amount d0ct0r acoustic avoid letter advice cage above";
+//! let decoded = forensic::partial_decode(s).unwrap();
+//! assert_eq!(decoded.unknown_positions().collect::<Vec<_>>(), vec![5]);
+//! assert_eq!(decoded.known_entropy_bits(), 128 - 11);
+//! assert_eq!(decoded.checksum_status(), ChecksumStatus::Unknown);
+//! ```
+
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::bits::{self, BITS_PER_WORD};
+use crate::{Error, Language, MAX_NB_WORDS, MIN_NB_WORDS};
+
+/// The status of the checksum of a partially decoded mnemonic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChecksumStatus {
+	/// All words are known and the checksum is valid.
+	Valid,
+	/// All words are known and the checksum is invalid.
+	Invalid,
+	/// The checksum can't be verified because of unknown words.
+	Unknown,
+}
+
+/// The result of [partial_decode].
+///
+/// The [fmt::Debug] output doesn't contain the words. With the `zeroize` or
+/// `wipe` feature, the words are erased from memory on drop.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct PartialDecode {
+	language: Language,
+	/// The number of words in the input.
+	nb_input_words: usize,
+	/// The number of words of the mnemonic the input is assumed to be.
+	word_count: usize,
+	/// The word indices, `None` for unknown or missing words.
+	words: [Option<u16>; MAX_NB_WORDS],
+}
+
+#[cfg(all(feature = "wipe", not(feature = "zeroize")))]
+impl Drop for PartialDecode {
+	fn drop(&mut self) {
+		crate::wipe::wipe(&mut self.words);
+	}
+}
+
+impl PartialDecode {
+	/// The language of the recognized words.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// The number of words of the mnemonic.
+	///
+	/// This is the smallest valid word count that is at least the number of
+	/// words in the input, the missing words at the end are unknown.
+	pub fn word_count(&self) -> usize {
+		self.word_count
+	}
+
+	/// Whether words are missing from the end of the input to get a valid word
+	/// count.
+	pub fn is_truncated(&self) -> bool {
+		self.nb_input_words < self.word_count
+	}
+
+	/// The indices of the words, `None` for unknown or missing words.
+	pub fn word_indices(&self) -> impl Iterator<Item = Option<u16>> + Clone + '_ {
+		self.words.iter().take(self.word_count).cloned()
+	}
+
+	/// The positions of the unknown or missing words, starting from 0.
+	pub fn unknown_positions(&self) -> impl Iterator<Item = usize> + Clone + '_ {
+		self.word_indices().enumerate().filter(|(_, w)| w.is_none()).map(|(i, _)| i)
+	}
+
+	/// The length of the entropy in bytes.
+	pub fn entropy_len(&self) -> usize {
+		self.word_count / 3 * 4
+	}
+
+	/// The entropy, with the unknown bits set to zero. Returns the buffer and
+	/// the length of the entropy, see [PartialDecode::entropy_mask].
+	pub fn entropy(&self) -> ([u8; 32], usize) {
+		let mut indices = [0u16; MAX_NB_WORDS];
+		for (idx, word) in indices.iter_mut().zip(self.word_indices()) {
+			*idx = word.unwrap_or(0);
+		}
+		self.pack(&indices)
+	}
+
+	/// The mask of the known bits of the entropy. Returns the buffer and the
+	/// length of the entropy, see [PartialDecode::entropy].
+	pub fn entropy_mask(&self) -> ([u8; 32], usize) {
+		let mut masks = [0u16; MAX_NB_WORDS];
+		for (mask, word) in masks.iter_mut().zip(self.word_indices()) {
+			*mask = if word.is_some() {
+				(1 << BITS_PER_WORD) - 1
+			} else {
+				0
+			};
+		}
+		self.pack(&masks)
+	}
+
+	/// The number of known bits of the entropy.
+	pub fn known_entropy_bits(&self) -> usize {
+		let (mask, len) = self.entropy_mask();
+		mask.iter().take(len).map(|b| b.count_ones() as usize).sum()
+	}
+
+	/// The status of the checksum.
+	pub fn checksum_status(&self) -> ChecksumStatus {
+		let mut indices = [0u16; MAX_NB_WORDS];
+		for (idx, word) in indices.iter_mut().zip(self.word_indices()) {
+			match word {
+				Some(w) => *idx = w,
+				None => return ChecksumStatus::Unknown,
+			}
+		}
+		match bits::strip_checksum(indices.get(..self.word_count).unwrap_or(&[]), &mut []) {
+			Ok(_) => ChecksumStatus::Valid,
+			Err(_) => ChecksumStatus::Invalid,
+		}
+	}
+
+	/// Pack the bits of the entropy part of the words.
+	fn pack(&self, words: &[u16]) -> ([u8; 32], usize) {
+		let mut buf = [0u8; 33];
+		bits::pack(words.get(..self.word_count).unwrap_or(&[]), &mut buf);
+		let mut entropy = [0u8; 32];
+		entropy.copy_from_slice(&buf[..32]);
+		(entropy, self.entropy_len())
+	}
+}
+
+impl fmt::Debug for PartialDecode {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("PartialDecode")
+			.field("language", &self.language)
+			.field("word_count", &self.word_count)
+			.field("unknown_words", &self.unknown_positions().count())
+			.finish()
+	}
+}
+
+/// Partially decode a damaged mnemonic in normalized UTF8 in the given
+/// language. Words that are not in the word list are marked as unknown.
+///
+/// Returns [Error::BadWordCount] if there are no words or more than 24.
+pub fn partial_decode_in(language: Language, s: &str) -> Result<PartialDecode, Error> {
+	let nb_input_words = s.split_whitespace().count();
+	if nb_input_words == 0 || nb_input_words > MAX_NB_WORDS {
+		return Err(Error::BadWordCount(nb_input_words));
+	}
+
+	let mut words = [None; MAX_NB_WORDS];
+	for (slot, word) in words.iter_mut().zip(s.split_whitespace()) {
+		*slot = language.find_word(word);
+	}
+	Ok(PartialDecode {
+		language,
+		nb_input_words,
+		word_count: ((nb_input_words + 2) / 3 * 3).max(MIN_NB_WORDS),
+		words,
+	})
+}
+
+/// Partially decode a damaged mnemonic in normalized UTF8, in the enabled
+/// language that recognizes the most words, preferring the earlier languages
+/// of [Language::ALL] in case of a tie. See [partial_decode_in].
+pub fn partial_decode(s: &str) -> Result<PartialDecode, Error> {
+	let mut best = partial_decode_in(Language::English, s)?;
+	for language in Language::ALL.iter().skip(1) {
+		let decoded = partial_decode_in(*language, s)?;
+		if decoded.unknown_positions().count() < best.unknown_positions().count() {
+			best = decoded;
+		}
+	}
+	Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::Mnemonic;
+
+	#[test]
+	fn test_partial_decode() {
+		let m = Mnemonic::from_entropy(&[0xff; 32]).unwrap();
+		let s = m.to_string();
+		let decoded = partial_decode(&s).unwrap();
+		assert_eq!(decoded.word_count(), 24);
+		assert_eq!(decoded.unknown_positions().count(), 0);
+		assert_eq!(decoded.entropy(), ([0xff; 32], 32));
+		assert_eq!(decoded.entropy_mask(), ([0xff; 32], 32));
+		assert_eq!(decoded.checksum_status(), ChecksumStatus::Valid);
+
+		// Damage the second word, covering bits 11 to 21.
+		let damaged = s.replacen(" zoo ", " z00 ", 1);
+		let decoded = partial_decode(&damaged).unwrap();
+		assert_eq!(decoded.unknown_positions().collect::<Vec<_>>(), vec![1]);
+		assert_eq!(decoded.known_entropy_bits(), 256 - 11);
+		let (entropy, len) = decoded.entropy();
+		assert_eq!(&entropy[..4], &[0xff, 0xe0, 0x03, 0xff]);
+		assert_eq!(len, 32);
+		assert_eq!(decoded.entropy_mask().0, entropy);
+		assert_eq!(decoded.checksum_status(), ChecksumStatus::Unknown);
+		assert_eq!(
+			format!("{:?}", decoded),
+			"PartialDecode { language: English, word_count: 24, unknown_words: 1 }"
+		);
+
+		let swapped = s.replacen("zoo", "abandon", 1);
+		let decoded = partial_decode(&swapped).unwrap();
+		assert_eq!(decoded.checksum_status(), ChecksumStatus::Invalid);
+	}
+
+	#[test]
+	fn test_truncated() {
+		let decoded = partial_decode_in(Language::English, "abandon abandon zoo").unwrap();
+		assert!(decoded.is_truncated());
+		assert_eq!(decoded.word_count(), 12);
+		assert_eq!(decoded.entropy_len(), 16);
+		assert_eq!(decoded.known_entropy_bits(), 33);
+		assert_eq!(decoded.unknown_positions().collect::<Vec<_>>(), (3..12).collect::<Vec<_>>());
+
+		let decoded = partial_decode_in(Language::English, &"zoo ".repeat(13)).unwrap();
+		assert_eq!(decoded.word_count(), 15);
+		assert_eq!(partial_decode(""), Err(Error::BadWordCount(0)));
+		assert_eq!(partial_decode(&"zoo ".repeat(25)), Err(Error::BadWordCount(25)));
+	}
+}
@@ -1,5 +1,8 @@
 /// Implement serde serialization based on the
 /// fmt::Display and std::FromStr traits.
+///
+/// This doesn't need the `alloc` feature of serde, so that it can be used
+/// with no_std codecs.
 macro_rules! serde_string_impl {
 	($name:ident, $expecting:expr) => {
 		#[cfg(feature = "serde")]
@@ -10,7 +13,6 @@ macro_rules! serde_string_impl {
 			{
 				use core::fmt::{self, Formatter};
 				use core::str::FromStr;
-				use alloc::string::String;
 
 				struct Visitor;
 				impl<'de> $crate::serde::de::Visitor<'de> for Visitor {
@@ -33,13 +35,6 @@ macro_rules! serde_string_impl {
 					{
 						self.visit_str(v)
 					}
-
-					fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-					where
-						E: $crate::serde::de::Error,
-					{
-						self.visit_str(&v)
-					}
 				}
 
 				deserializer.deserialize_str(Visitor)
@@ -57,3 +52,20 @@ macro_rules! serde_string_impl {
 		}
 	};
 }
+
+/// Implement infallible [Mnemonic] constructors from fixed-size entropy arrays.
+macro_rules! fixed_entropy_constructors {
+	($($len:literal => $name_in:ident, $name:ident, $doc_in:literal, $doc:literal;)*) => {
+		$(
+			#[doc = $doc_in]
+			pub fn $name_in(language: Language, entropy: &[u8; $len]) -> Mnemonic {
+				Mnemonic::from_valid_entropy_in(language, entropy)
+			}
+
+			#[doc = $doc]
+			pub fn $name(entropy: &[u8; $len]) -> Mnemonic {
+				Mnemonic::from_valid_entropy_in(Language::English, entropy)
+			}
+		)*
+	};
+}
@@ -1,24 +1,79 @@
+use core::convert::TryFrom;
 use core::fmt;
+use core::iter::FromIterator;
 
-#[cfg(feature = "chinese-simplified")]
-mod chinese_simplified;
-#[cfg(feature = "chinese-traditional")]
-mod chinese_traditional;
-#[cfg(feature = "czech")]
-mod czech;
-mod english;
-#[cfg(feature = "french")]
-mod french;
-#[cfg(feature = "italian")]
-mod italian;
-#[cfg(feature = "japanese")]
-mod japanese;
-#[cfg(feature = "korean")]
-mod korean;
-#[cfg(feature = "portuguese")]
-mod portuguese;
-#[cfg(feature = "spanish")]
-mod spanish;
+use bitcoin_hashes::{sha256, Hash, HashEngine};
+
+use crate::{Error, Suggestions};
+
+/// The minimum number of characters of an abbreviated word.
+const MIN_ABBREVIATION_CHARS: usize = 4;
+
+/// The BCP 47 tags of the languages, indexed by their codes, see [Language].
+pub(crate) const LANGUAGE_TAGS: [&str; MAX_NB_LANGUAGES] =
+	["en", "zh-Hans", "zh-Hant", "cs", "fr", "it", "ja", "ko", "pt", "es"];
+
+/// Declare the word list modules.
+///
+/// With the `external-wordlists` feature, the modules declare the word list as
+/// an external static with the given symbol name instead of defining it.
+macro_rules! wordlist_modules {
+	($($(#[$cfg:meta])* $module:ident => $symbol:literal;)*) => {
+		$(
+			$(#[$cfg])*
+			#[cfg(not(feature = "external-wordlists"))]
+			mod $module;
+			$(#[$cfg])*
+			#[cfg(feature = "external-wordlists")]
+			mod $module {
+				extern "Rust" {
+					#[link_name = $symbol]
+					pub static WORDS: [&'static str; 2048];
+				}
+			}
+		)*
+	};
+}
+
+wordlist_modules! {
+	#[cfg(feature = "chinese-simplified")]
+	chinese_simplified => "BIP39_WORDLIST_CHINESE_SIMPLIFIED";
+	#[cfg(feature = "chinese-traditional")]
+	chinese_traditional => "BIP39_WORDLIST_CHINESE_TRADITIONAL";
+	#[cfg(feature = "czech")]
+	czech => "BIP39_WORDLIST_CZECH";
+	english => "BIP39_WORDLIST_ENGLISH";
+	#[cfg(feature = "french")]
+	french => "BIP39_WORDLIST_FRENCH";
+	#[cfg(feature = "italian")]
+	italian => "BIP39_WORDLIST_ITALIAN";
+	#[cfg(feature = "japanese")]
+	japanese => "BIP39_WORDLIST_JAPANESE";
+	#[cfg(feature = "korean")]
+	korean => "BIP39_WORDLIST_KOREAN";
+	#[cfg(feature = "portuguese")]
+	portuguese => "BIP39_WORDLIST_PORTUGUESE";
+	#[cfg(feature = "spanish")]
+	spanish => "BIP39_WORDLIST_SPANISH";
+}
+
+/// Get a reference to the word list of a word list module.
+#[cfg(not(feature = "external-wordlists"))]
+macro_rules! words {
+	($module:ident) => {
+		&$module::WORDS
+	};
+}
+
+/// Get a reference to the word list of a word list module.
+#[cfg(feature = "external-wordlists")]
+macro_rules! words {
+	($module:ident) => {
+		// SAFETY: the firmware providing the symbol guarantees that it is an
+		// immutable word list, see the `external-wordlists` feature.
+		unsafe { &$module::WORDS }
+	};
+}
 
 /// The maximum number of languages enabled.
 pub(crate) const MAX_NB_LANGUAGES: usize = 10;
@@ -27,37 +82,43 @@ pub(crate) const MAX_NB_LANGUAGES: usize = 10;
 ///
 /// The English language is always available, other languages are enabled using
 /// the compilation features.
+///
+/// Every language has a stable code, independent of the enabled features, that
+/// is also its discriminant: English 0, Simplified Chinese 1, Traditional
+/// Chinese 2, Czech 3, French 4, Italian 5, Japanese 6, Korean 7, Portuguese 8
+/// and Spanish 9. See [Language::to_u8] and [Language::from_u8].
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
 pub enum Language {
 	/// The English language.
-	English,
+	English = 0,
 	#[cfg(feature = "chinese-simplified")]
 	/// The Simplified Chinese language.
-	SimplifiedChinese,
+	SimplifiedChinese = 1,
 	#[cfg(feature = "chinese-traditional")]
 	/// The Traditional Chinese language.
-	TraditionalChinese,
+	TraditionalChinese = 2,
 	#[cfg(feature = "czech")]
 	/// The Czech language.
-	Czech,
+	Czech = 3,
 	#[cfg(feature = "french")]
 	/// The French language.
-	French,
+	French = 4,
 	#[cfg(feature = "italian")]
 	/// The Italian language.
-	Italian,
+	Italian = 5,
 	#[cfg(feature = "japanese")]
 	/// The Japanese language.
-	Japanese,
+	Japanese = 6,
 	#[cfg(feature = "korean")]
 	/// The Korean language.
-	Korean,
+	Korean = 7,
 	#[cfg(feature = "portuguese")]
 	/// The Portuguese language.
-	Portuguese,
+	Portuguese = 8,
 	#[cfg(feature = "spanish")]
 	/// The Spanish language.
-	Spanish,
+	Spanish = 9,
 }
 
 impl Default for Language {
@@ -98,32 +159,176 @@ impl Language {
 		Language::ALL
 	}
 
+	/// The stable code of the language, see [Language].
+	pub fn to_u8(self) -> u8 {
+		self as u8
+	}
+
+	/// Get the language with the given stable code, see [Language].
+	///
+	/// Returns `None` if the code is unknown or the language is not enabled.
+	pub fn from_u8(code: u8) -> Option<Language> {
+		Language::ALL.iter().cloned().find(|l| l.to_u8() == code)
+	}
+
+	/// The BCP 47 tag of the language, like `en` or `zh-Hant`.
+	pub fn tag(self) -> &'static str {
+		LANGUAGE_TAGS.get(self.to_u8() as usize).copied().unwrap_or("")
+	}
+
+	/// Suggest the language of the word list for a BCP 47 locale like `pt-BR`,
+	/// falling back to English if there is no matching enabled language.
+	///
+	/// POSIX locales like `zh_TW.UTF-8` are accepted as well. Chinese locales
+	/// use the Traditional Chinese word list for the Hant script and for Taiwan,
+	/// Hong Kong and Macau, and the Simplified Chinese word list otherwise.
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::Language;
+	///
+	/// assert_eq!(Language::suggest_for_locale("en-US"), Language::English);
+	/// assert_eq!(Language::suggest_for_locale("tlh"), Language::English);
+	/// ```
+	pub fn suggest_for_locale(locale: &str) -> Language {
+		let locale = locale.split('.').next().unwrap_or("");
+		let mut subtags = locale.split(|c| c == '-' || c == '_');
+		let primary = subtags.next().unwrap_or("");
+		let is = |subtag: &str, code: &str| subtag.eq_ignore_ascii_case(code);
+
+		let tag = if is(primary, "zh") {
+			let traditional =
+				subtags.any(|t| ["hant", "tw", "hk", "mo"].iter().any(|code| is(t, code)));
+			if traditional {
+				"zh-Hant"
+			} else {
+				"zh-Hans"
+			}
+		} else {
+			primary
+		};
+		Language::ALL.iter().cloned().find(|l| is(l.tag(), tag)).unwrap_or(Language::English)
+	}
+
 	/// The word list for this language.
+	///
+	/// With the `external-wordlists` feature, the word lists are not part of this
+	/// crate but have to be defined by the final binary, for example to place them
+	/// in a specific linker section on memory-mapped external flash. Each enabled
+	/// language needs an immutable static with the exact content of the BIP39 word
+	/// list, named like `BIP39_WORDLIST_ENGLISH`:
+	///
+	/// ```ignore
+	/// #[no_mangle]
+	/// #[link_section = ".extflash.rodata"]
+	/// pub static BIP39_WORDLIST_ENGLISH: [&str; 2048] = ["abandon", "ability", /* ... */];
+	/// ```
 	#[inline]
 	pub fn word_list(self) -> &'static [&'static str; 2048] {
 		match self {
-			Language::English => &english::WORDS,
+			Language::English => words!(english),
+			#[cfg(feature = "chinese-simplified")]
+			Language::SimplifiedChinese => words!(chinese_simplified),
+			#[cfg(feature = "chinese-traditional")]
+			Language::TraditionalChinese => words!(chinese_traditional),
+			#[cfg(feature = "czech")]
+			Language::Czech => words!(czech),
+			#[cfg(feature = "french")]
+			Language::French => words!(french),
+			#[cfg(feature = "italian")]
+			Language::Italian => words!(italian),
+			#[cfg(feature = "japanese")]
+			Language::Japanese => words!(japanese),
+			#[cfg(feature = "korean")]
+			Language::Korean => words!(korean),
+			#[cfg(feature = "portuguese")]
+			Language::Portuguese => words!(portuguese),
+			#[cfg(feature = "spanish")]
+			Language::Spanish => words!(spanish),
+		}
+	}
+
+	/// The SHA-256 of the official word list file of this language in the
+	/// bips repository, with one word per line.
+	fn official_checksum(self) -> [u8; 32] {
+		match self {
+			Language::English => [
+				0x2f, 0x5e, 0xed, 0x53, 0xa4, 0x72, 0x7b, 0x4b, 0xf8, 0x88, 0x0d, 0x8f, 0x3f, 0x19,
+				0x9e, 0xfc, 0x90, 0xe5, 0x85, 0x03, 0x64, 0x6d, 0x9f, 0xf8, 0xef, 0xf3, 0xa2, 0xed,
+				0x3b, 0x24, 0xdb, 0xda,
+			],
 			#[cfg(feature = "chinese-simplified")]
-			Language::SimplifiedChinese => &chinese_simplified::WORDS,
+			Language::SimplifiedChinese => [
+				0x5c, 0x59, 0x42, 0x79, 0x2b, 0xd8, 0x34, 0x0c, 0xb8, 0xb2, 0x7c, 0xd5, 0x92, 0xf1,
+				0x01, 0x5e, 0xdf, 0x56, 0xa8, 0xc5, 0xb2, 0x62, 0x76, 0xee, 0x18, 0xa4, 0x82, 0x42,
+				0x8e, 0x7c, 0x57, 0x26,
+			],
 			#[cfg(feature = "chinese-traditional")]
-			Language::TraditionalChinese => &chinese_traditional::WORDS,
+			Language::TraditionalChinese => [
+				0x41, 0x7b, 0x26, 0xb3, 0xd8, 0x50, 0x0a, 0x4a, 0xe3, 0xd5, 0x97, 0x17, 0xd7, 0x01,
+				0x19, 0x52, 0xdb, 0x6f, 0xc2, 0xfb, 0x84, 0xb8, 0x07, 0xf3, 0xf9, 0x4a, 0xc7, 0x34,
+				0xe8, 0x9c, 0x1b, 0x5f,
+			],
 			#[cfg(feature = "czech")]
-			Language::Czech => &czech::WORDS,
+			Language::Czech => [
+				0x7e, 0x80, 0xe1, 0x61, 0xc3, 0xe9, 0x3d, 0x95, 0x54, 0xc2, 0xef, 0xb7, 0x8d, 0x4e,
+				0x3c, 0xeb, 0xf8, 0xfc, 0x72, 0x7e, 0x9c, 0x52, 0xe0, 0x3b, 0x83, 0xb9, 0x44, 0x06,
+				0xbd, 0xcc, 0x95, 0xfc,
+			],
 			#[cfg(feature = "french")]
-			Language::French => &french::WORDS,
+			Language::French => [
+				0xeb, 0xc3, 0x95, 0x9a, 0xb7, 0x80, 0x1a, 0x1d, 0xf6, 0xba, 0xc4, 0xfa, 0x7d, 0x97,
+				0x06, 0x52, 0xf1, 0xdf, 0x76, 0xb6, 0x83, 0xcd, 0x2f, 0x40, 0x03, 0xc9, 0x41, 0xc6,
+				0x3d, 0x51, 0x7e, 0x59,
+			],
 			#[cfg(feature = "italian")]
-			Language::Italian => &italian::WORDS,
+			Language::Italian => [
+				0xd3, 0x92, 0xc4, 0x9f, 0xdb, 0x70, 0x0a, 0x24, 0xcd, 0x1f, 0xce, 0xb2, 0x37, 0xc1,
+				0xf6, 0x5d, 0xcc, 0x12, 0x8f, 0x6b, 0x34, 0xa8, 0xaa, 0xcb, 0x58, 0xb5, 0x93, 0x84,
+				0xb5, 0xc6, 0x48, 0xc2,
+			],
 			#[cfg(feature = "japanese")]
-			Language::Japanese => &japanese::WORDS,
+			Language::Japanese => [
+				0x2e, 0xed, 0x0a, 0xef, 0x49, 0x22, 0x91, 0xe0, 0x61, 0x63, 0x3d, 0x7a, 0xd8, 0x11,
+				0x7f, 0x1a, 0x2b, 0x03, 0xeb, 0x80, 0xa2, 0x9d, 0x0e, 0x4e, 0x31, 0x17, 0xac, 0x25,
+				0x28, 0xd0, 0x5f, 0xfd,
+			],
 			#[cfg(feature = "korean")]
-			Language::Korean => &korean::WORDS,
+			Language::Korean => [
+				0x9e, 0x95, 0xf8, 0x6c, 0x16, 0x7d, 0xe8, 0x8f, 0x45, 0x0f, 0x0a, 0xaf, 0x89, 0xe8,
+				0x7f, 0x66, 0x24, 0xa5, 0x7f, 0x97, 0x3c, 0x67, 0xb5, 0x16, 0xe3, 0x38, 0xe8, 0xe8,
+				0xb8, 0x89, 0x7f, 0x60,
+			],
 			#[cfg(feature = "portuguese")]
-			Language::Portuguese => &portuguese::WORDS,
+			Language::Portuguese => [
+				0x26, 0x85, 0xe9, 0xc1, 0x94, 0xc8, 0x2a, 0xe6, 0x7e, 0x10, 0xba, 0x59, 0xd9, 0xea,
+				0x53, 0x45, 0xa2, 0x3d, 0xc0, 0x93, 0xe9, 0x22, 0x76, 0xfc, 0x53, 0x61, 0xf6, 0x66,
+				0x7d, 0x79, 0xcd, 0x3f,
+			],
 			#[cfg(feature = "spanish")]
-			Language::Spanish => &spanish::WORDS,
+			Language::Spanish => [
+				0x46, 0x84, 0x6a, 0x5a, 0x01, 0x39, 0xd1, 0xe3, 0xcb, 0x77, 0x29, 0x3e, 0x52, 0x1c,
+				0x28, 0x65, 0xf7, 0xbc, 0xdb, 0x82, 0xc4, 0x4e, 0x8d, 0x0a, 0x06, 0xa2, 0xcd, 0x0e,
+				0xcb, 0xa4, 0x8c, 0x0b,
+			],
 		}
 	}
 
+	/// Verify that the compiled-in word list is identical to the official
+	/// BIP39 word list, by comparing its SHA-256 hash.
+	///
+	/// This allows checking at runtime that the word lists weren't tampered
+	/// with at build time.
+	pub fn verify_integrity(self) -> bool {
+		let mut engine = sha256::Hash::engine();
+		for word in self.word_list().iter() {
+			engine.input(word.as_bytes());
+			engine.input(b"\n");
+		}
+		sha256::Hash::from_engine(engine).to_byte_array() == self.official_checksum()
+	}
+
 	/// Returns true if all words in the list are guaranteed to
 	/// only be in this list and not in any other.
 	#[inline]
@@ -152,6 +357,7 @@ impl Language {
 	}
 
 	/// Get words from the word list that start with the given prefix.
+	#[allow(clippy::indexing_slicing)]
 	pub fn words_by_prefix(self, prefix: &str) -> &[&'static str] {
 		// The words in the word list are ordered lexicographically. This means
 		// that we cannot use `binary_search` to find words more efficiently,
@@ -166,6 +372,35 @@ impl Language {
 		&self.word_list()[first..first + count]
 	}
 
+	/// Get words from the word list that match the given glob pattern.
+	///
+	/// In the pattern, `*` matches any sequence of characters (including none)
+	/// and `?` matches exactly one character. All other characters match themselves.
+	/// This allows searching for words that contain (`*ship*`) or end with (`*ight`)
+	/// a given fragment.
+	pub fn words_matching<'a>(
+		self,
+		pattern: &'a str,
+	) -> impl Iterator<Item = &'static str> + Clone + 'a {
+		self.word_list().iter().cloned().filter(move |w| glob_matches(pattern, w))
+	}
+
+	/// Get the closest words of the word list to a word that is not in it,
+	/// at most 2 edits away, closest first.
+	///
+	/// Use it for "did you mean" messages after an [Error::UnknownWord], or
+	/// for callers checking words one by one as they are typed.
+	///
+	/// ```
+	/// use bip39::Language;
+	///
+	/// let suggestions = Language::English.suggestions("acoustik");
+	/// assert_eq!(suggestions.iter().collect::<Vec<_>>(), vec!["acoustic"]);
+	/// ```
+	pub fn suggestions(self, word: &str) -> Suggestions {
+		Suggestions::new(self, word)
+	}
+
 	/// Get the index of the word in the word list.
 	#[inline]
 	pub fn find_word(self, word: &str) -> Option<u16> {
@@ -193,6 +428,152 @@ impl Language {
 			_ => self.word_list().iter().position(|w| *w == word).map(|i| i as u16),
 		}
 	}
+
+	/// Get the index of the word in the word list, or of the only word that
+	/// starts with it if it has at least 4 characters.
+	///
+	/// The words of the English word list are identified by their first 4
+	/// letters, which is all that some metal backups keep. The prefix must
+	/// match a single word, which is not the case for all the prefixes of
+	/// some other languages.
+	///
+	/// ```
+	/// use bip39::Language;
+	///
+	/// let about = Language::English.find_word("about");
+	/// assert_eq!(Language::English.find_abbreviated_word("abou"), about);
+	/// assert_eq!(Language::English.find_abbreviated_word("abo"), None);
+	/// ```
+	pub fn find_abbreviated_word(self, word: &str) -> Option<u16> {
+		if let Some(idx) = self.find_word(word) {
+			return Some(idx);
+		}
+		if word.chars().count() < MIN_ABBREVIATION_CHARS {
+			return None;
+		}
+		// Not all word lists are sorted, so all the words are checked.
+		let mut matches = self.word_list().iter().enumerate().filter(|(_, w)| w.starts_with(word));
+		match (matches.next(), matches.next()) {
+			(Some((idx, _)), None) => Some(idx as u16),
+			_ => None,
+		}
+	}
+}
+
+/// Match the word against a glob pattern supporting `*` and `?` wildcards.
+fn glob_matches(pattern: &str, word: &str) -> bool {
+	let (mut p, mut w) = (pattern, word);
+	// The pattern and word positions right after the last `*` we encountered,
+	// used to let the `*` consume another character when matching fails.
+	let mut backtrack: Option<(&str, &str)> = None;
+	loop {
+		let mut pchars = p.chars();
+		let mut wchars = w.chars();
+		match (pchars.next(), wchars.next()) {
+			(Some('*'), _) => {
+				p = pchars.as_str();
+				backtrack = Some((p, w));
+			}
+			(Some(pc), Some(wc)) if pc == '?' || pc == wc => {
+				p = pchars.as_str();
+				w = wchars.as_str();
+			}
+			(None, None) => return true,
+			_ => match backtrack {
+				Some((bp, bw)) => {
+					let mut bchars = bw.chars();
+					if bchars.next().is_none() {
+						return false;
+					}
+					p = bp;
+					w = bchars.as_str();
+					backtrack = Some((p, w));
+				}
+				None => return false,
+			},
+		}
+	}
+}
+
+impl TryFrom<u8> for Language {
+	type Error = Error;
+
+	/// Get the language with the given stable code, see [Language::from_u8].
+	fn try_from(code: u8) -> Result<Language, Error> {
+		Language::from_u8(code).ok_or(Error::UnknownLanguage(code))
+	}
+}
+
+/// A set of languages, to restrict language detection at runtime.
+///
+/// Detecting the language among fewer languages is faster and avoids
+/// ambiguities with languages the caller doesn't expect.
+///
+/// Example:
+///
+/// ```
+/// use bip39::{Language, LanguageSet};
+///
+/// let set = LanguageSet::new().with(Language::English);
+/// assert!(set.contains(Language::English));
+/// assert_eq!(set.len(), 1);
+/// assert_eq!(LanguageSet::all().len(), Language::ALL.len());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LanguageSet(u16);
+
+impl LanguageSet {
+	/// The empty set.
+	pub fn new() -> LanguageSet {
+		LanguageSet(0)
+	}
+
+	/// The set of all enabled languages.
+	pub fn all() -> LanguageSet {
+		Language::ALL.iter().cloned().collect()
+	}
+
+	/// The set with the language added.
+	pub fn with(self, language: Language) -> LanguageSet {
+		LanguageSet(self.0 | 1 << language.to_u8())
+	}
+
+	/// The set with the language removed.
+	pub fn without(self, language: Language) -> LanguageSet {
+		LanguageSet(self.0 & !(1 << language.to_u8()))
+	}
+
+	/// Whether the set contains the language.
+	pub fn contains(self, language: Language) -> bool {
+		self.0 & 1 << language.to_u8() != 0
+	}
+
+	/// The number of languages in the set.
+	pub fn len(self) -> usize {
+		self.0.count_ones() as usize
+	}
+
+	/// Whether the set is empty.
+	pub fn is_empty(self) -> bool {
+		self.0 == 0
+	}
+
+	/// An iterator over the languages of the set, in the order of [Language::ALL].
+	pub fn iter(self) -> impl Iterator<Item = Language> + Clone {
+		Language::ALL.iter().cloned().filter(move |l| self.contains(*l))
+	}
+}
+
+impl From<Language> for LanguageSet {
+	fn from(language: Language) -> LanguageSet {
+		LanguageSet::new().with(language)
+	}
+}
+
+impl FromIterator<Language> for LanguageSet {
+	fn from_iter<I: IntoIterator<Item = Language>>(iter: I) -> LanguageSet {
+		iter.into_iter().fold(LanguageSet::new(), LanguageSet::with)
+	}
 }
 
 impl fmt::Display for Language {
@@ -202,9 +583,25 @@ impl fmt::Display for Language {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::unused_enumerate_index, clippy::unwrap_or_default)]
 mod tests {
 	use super::*;
 
+	#[test]
+	fn test_language_set() {
+		let set = LanguageSet::new();
+		assert!(set.is_empty());
+		let set = set.with(Language::English).with(Language::English);
+		assert_eq!(set.len(), 1);
+		assert_eq!(set, LanguageSet::from(Language::English));
+		assert_eq!(set.iter().collect::<Vec<_>>(), vec![Language::English]);
+		assert!(set.without(Language::English).is_empty());
+
+		let all = LanguageSet::all();
+		assert!(Language::ALL.iter().all(|l| all.contains(*l)));
+		assert_eq!(all.iter().collect::<Vec<_>>(), Language::ALL.to_vec());
+	}
+
 	#[cfg(all(
 		feature = "chinese-simplified",
 		feature = "chinese-traditional",
@@ -232,8 +629,6 @@ mod tests {
 		//! 2685e9c194c82ae67e10ba59d9ea5345a23dc093e92276fc5361f6667d79cd3f  portuguese.txt
 		//! 46846a5a0139d1e3cb77293e521c2865f7bcdb82c44e8d0a06a2cd0ecba48c0b  spanish.txt
 
-		use bitcoin_hashes::{sha256, Hash, HashEngine};
-
 		let checksums = [
 			(
 				"5c5942792bd8340cb8b27cd592f1015edf56a8c5b26276ee18a482428e7c5726",
@@ -278,6 +673,13 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn verify_integrity() {
+		for lang in Language::ALL {
+			assert!(lang.verify_integrity(), "word list for language {} was altered", lang);
+		}
+	}
+
 	#[test]
 	fn words_by_prefix() {
 		let lang = Language::English;
@@ -292,6 +694,34 @@ mod tests {
 		assert!(res.is_empty());
 	}
 
+	#[test]
+	fn words_matching() {
+		let lang = Language::English;
+
+		let res: Vec<_> = lang.words_matching("*ship*").collect();
+		assert_eq!(res, ["ship"]);
+
+		let res: Vec<_> = lang.words_matching("*ight").collect();
+		assert_eq!(
+			res,
+			[
+				"bright", "eight", "flight", "height", "light", "midnight", "night", "right",
+				"sight", "slight", "tonight"
+			]
+		);
+
+		let res: Vec<_> = lang.words_matching("c?t").collect();
+		assert_eq!(res, ["cat"]);
+
+		let res: Vec<_> = lang.words_matching("wo*").collect();
+		assert_eq!(res, lang.words_by_prefix("wo"));
+
+		assert_eq!(lang.words_matching("*").count(), 2048);
+		assert_eq!(lang.words_matching("zoo").collect::<Vec<_>>(), ["zoo"]);
+		assert_eq!(lang.words_matching("zoo?").count(), 0);
+		assert_eq!(lang.words_matching("").count(), 0);
+	}
+
 	#[cfg(all(
 		feature = "chinese-simplified",
 		feature = "chinese-traditional",
@@ -349,6 +779,56 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_language_codes() {
+		for lang in Language::ALL {
+			assert_eq!(Language::from_u8(lang.to_u8()), Some(*lang));
+			assert_eq!(Language::try_from(lang.to_u8()), Ok(*lang));
+		}
+		assert_eq!(Language::English.to_u8(), 0);
+		assert_eq!(Language::try_from(10), Err(Error::UnknownLanguage(10)));
+
+		#[cfg(feature = "all-languages")]
+		{
+			let codes = Language::ALL.iter().map(|l| l.to_u8()).collect::<Vec<_>>();
+			assert_eq!(codes, (0..10).collect::<Vec<_>>());
+		}
+	}
+
+	#[test]
+	fn test_suggest_for_locale() {
+		assert_eq!(Language::English.tag(), "en");
+		for lang in Language::ALL {
+			assert_eq!(Language::suggest_for_locale(lang.tag()), *lang);
+		}
+		assert_eq!(Language::suggest_for_locale("en"), Language::English);
+		assert_eq!(Language::suggest_for_locale(""), Language::English);
+		assert_eq!(Language::suggest_for_locale("de-DE"), Language::English);
+
+		#[cfg(feature = "all-languages")]
+		{
+			let cases = [
+				("fr-CA", Language::French),
+				("FR", Language::French),
+				("pt_BR.UTF-8", Language::Portuguese),
+				("es-419", Language::Spanish),
+				("cs-CZ", Language::Czech),
+				("it", Language::Italian),
+				("ja-JP", Language::Japanese),
+				("ko-KR", Language::Korean),
+				("zh", Language::SimplifiedChinese),
+				("zh-Hans-CN", Language::SimplifiedChinese),
+				("zh-CN", Language::SimplifiedChinese),
+				("zh-Hant", Language::TraditionalChinese),
+				("zh_TW.UTF-8", Language::TraditionalChinese),
+				("zh-HK", Language::TraditionalChinese),
+			];
+			for (locale, lang) in cases.iter() {
+				assert_eq!(Language::suggest_for_locale(locale), *lang, "{}", locale);
+			}
+		}
+	}
+
 	/// Test the full round trip from index -> word-string -> index for all langauges
 	mod round_trip {
 		use super::*;
@@ -23,6 +23,11 @@
 #![deny(dead_code)]
 #![deny(unused_imports)]
 #![deny(missing_docs)]
+// Library code must not panic on any input.
+#![cfg_attr(
+	not(test),
+	deny(clippy::indexing_slicing, clippy::unwrap_used, clippy::expect_used, clippy::panic)
+)]
 #![cfg_attr(all(not(test), not(feature = "std")), no_std)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
@@ -39,15 +44,22 @@ extern crate unicode_normalization;
 
 #[cfg(feature = "rand")]
 pub extern crate crate_rand as rand;
+#[cfg(feature = "rand_v09")]
+pub extern crate rand_0_9;
 #[cfg(feature = "rand_core")]
 pub extern crate rand_core;
+#[cfg(feature = "rand_core_v09")]
+pub extern crate rand_core_0_9;
 #[cfg(feature = "serde")]
 pub extern crate serde;
 
 #[cfg(feature = "alloc")]
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use core::convert::TryFrom;
 use core::{fmt, str};
 
+use bitcoin_hashes::{hmac, sha256, Hash, HashEngine};
+
 /// We support a wide range of dependency versions for `rand` and `rand_core` and not
 /// all versions play nicely together. These re-exports fix that.
 #[cfg(all(feature = "rand", feature = "rand_core"))]
@@ -58,8 +70,6 @@ use rand_core::{CryptoRng, RngCore};
 #[cfg(feature = "std")]
 use std::error;
 
-use bitcoin_hashes::{sha256, Hash};
-
 #[cfg(feature = "unicode-normalization")]
 use unicode_normalization::UnicodeNormalization;
 
@@ -70,10 +80,62 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 
 #[macro_use]
 mod internal_macros;
+pub mod base2048;
+pub mod batch;
+pub mod bits;
+#[cfg(feature = "alloc")]
+mod bundle;
+pub mod cards;
+#[cfg(feature = "cbor")]
+pub mod cbor;
+pub mod ceremony;
+#[cfg(feature = "alloc")]
+pub mod codex32;
+#[cfg(feature = "compact-wordlists")]
+pub mod compact;
+#[cfg(feature = "compat-tiny-bip39")]
+pub mod compat;
+#[cfg(feature = "diceware")]
+pub mod diceware;
+mod display;
+mod entropy;
+pub mod envelope;
+mod fixed;
+pub mod forensic;
 mod language;
+#[cfg(feature = "non-standard")]
+pub mod nonstandard;
+pub mod parity;
+pub mod passphrase;
 mod pbkdf2;
+#[cfg(feature = "alloc")]
+pub mod recovery;
+mod report;
+mod seed;
+#[cfg(feature = "serde")]
+pub mod serde_compact;
+mod suggest;
+mod summary;
+#[cfg(feature = "alloc")]
+pub mod uri;
+#[cfg(any(feature = "wipe", feature = "zeroize"))]
+mod wipe;
+mod word;
+mod word_count;
 
-pub use language::Language;
+#[cfg(feature = "alloc")]
+pub use bundle::MnemonicWithPassphrase;
+pub use display::{Exposed, NatoSpelling, PartialDisplay};
+pub use entropy::Entropy;
+pub use fixed::{Mnemonic12, Mnemonic24};
+pub use language::{Language, LanguageSet};
+pub use pbkdf2::Pbkdf2Prf;
+pub use report::{PartialParse, ValidationReport, WordStatus};
+pub use seed::Seed;
+pub use suggest::Suggestions;
+pub use summary::Summary;
+pub use word::{Word, WordIndex};
+pub use word_count::WordCount;
 
 /// The minimum number of words in a mnemonic.
 #[allow(unused)]
@@ -99,7 +161,7 @@ impl AmbiguousLanguages {
 
 	/// An iterator over the possible languages.
 	pub fn iter(&self) -> impl Iterator<Item = Language> + '_ {
-		Language::ALL.iter().enumerate().filter(move |(i, _)| self.0[*i]).map(|(_, l)| *l)
+		Language::ALL.iter().zip(self.0.iter()).filter(|(_, p)| **p).map(|(l, _)| *l)
 	}
 
 	/// Returns a vector of the possible languages.
@@ -111,21 +173,54 @@ impl AmbiguousLanguages {
 
 /// A BIP39 error.
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
+#[non_exhaustive]
 pub enum Error {
 	/// Mnemonic has a word count that is not a multiple of 6.
 	BadWordCount(usize),
 	/// Mnemonic contains an unknown word.
 	/// Error contains the index of the word.
 	/// Use `mnemonic.split_whitespace().get(i)` to get the word.
+	/// Use [Language::suggestions] to get the closest words of the word list.
 	UnknownWord(usize),
 	/// Entropy was not a multiple of 32 bits or between 128-256n bits in length.
 	BadEntropyBitCount(usize),
 	/// The mnemonic has an invalid checksum.
+	/// Use [Mnemonic::checksum_mismatch_in] to get the details.
 	InvalidChecksum,
 	/// The mnemonic can be interpreted as multiple languages.
 	/// Use the helper methods of the inner struct to inspect
 	/// which languages are possible.
 	AmbiguousLanguages(AmbiguousLanguages),
+	/// An entropy share doesn't match its commitment.
+	/// Error contains the index of the share.
+	InvalidCommitment(usize),
+	/// The passphrase contains a character not allowed by the passphrase policy.
+	/// Error contains the byte position of the character.
+	InvalidPassphraseCharacter(usize),
+	/// The binary or text encoding of a mnemonic is invalid.
+	InvalidEncoding,
+	/// Mnemonic doesn't carry the expected version.
+	InvalidVersion,
+	/// A backup card is malformed or doesn't match the other card.
+	InvalidCard,
+	/// Mnemonic contains a word with non-ASCII characters.
+	/// Error contains the index of the word.
+	NonAsciiWord(usize),
+	/// The language code is unknown or the language is not enabled.
+	UnknownLanguage(u8),
+	/// The output buffer is too small.
+	/// Error contains the number of bytes needed.
+	BufferTooSmall(usize),
+	/// Mnemonic has a missing, repeated or non-canonical word separator.
+	/// Error contains the byte position of the offending character.
+	InvalidSeparator(usize),
+	/// Mnemonic contains a word that is in the word list only in another form,
+	/// in uppercase or not normalized.
+	/// Error contains the index of the word.
+	NonCanonicalWord(usize),
+	/// A word position is past the end of the mnemonic.
+	/// Error contains the position.
+	InvalidPosition(usize),
 }
 
 impl fmt::Display for Error {
@@ -157,6 +252,25 @@ impl fmt::Display for Error {
 				}
 				Ok(())
 			}
+			Error::InvalidCommitment(i) => {
+				write!(f, "entropy share doesn't match its commitment (share {})", i)
+			}
+			Error::InvalidPassphraseCharacter(i) => {
+				write!(f, "passphrase contains a character that is not allowed (byte {})", i)
+			}
+			Error::InvalidEncoding => write!(f, "the encoding of the mnemonic is invalid"),
+			Error::InvalidVersion => write!(f, "mnemonic doesn't carry the expected version"),
+			Error::InvalidCard => write!(f, "backup card is malformed or doesn't match"),
+			Error::NonAsciiWord(i) => write!(f, "mnemonic contains a non-ASCII word (word {})", i),
+			Error::UnknownLanguage(c) => write!(f, "unknown or disabled language code: {}", c),
+			Error::BufferTooSmall(n) => write!(f, "output buffer is too small: {} bytes needed", n),
+			Error::InvalidSeparator(i) => {
+				write!(f, "mnemonic contains an invalid word separator (byte {})", i)
+			}
+			Error::NonCanonicalWord(i) => {
+				write!(f, "mnemonic contains a word in a non-canonical form (word {})", i)
+			}
+			Error::InvalidPosition(i) => write!(f, "word position is out of range: {}", i),
 		}
 	}
 }
@@ -171,7 +285,26 @@ impl error::Error for Error {}
 /// the Cargo features.)
 ///
 /// Supported number of words are 12, 15, 18, 21, and 24.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Mnemonics are compared in constant time with `==`, but not with the
+/// ordering methods.
+///
+/// The [fmt::Debug] output only shows the language and the number of words,
+/// so that the mnemonic doesn't end up in logs or panic messages by accident.
+/// Use [Mnemonic::expose] to show the words on purpose.
+/// The [fmt::Display] output is the phrase, with the words separated by
+/// spaces. The alternate form, with `{:#}`, is a numbered list of the words,
+/// one per line, for the user to write down:
+///
+/// ```
+/// use bip39::Mnemonic;
+///
+/// let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+/// let table = format!("{:#}", m);
+/// assert_eq!(table.lines().next(), Some(" 1. abandon"));
+/// assert_eq!(table.lines().last(), Some("12. about"));
+/// ```
+#[derive(Clone, PartialOrd, Ord)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 pub struct Mnemonic {
 	/// The language the mnemonic.
@@ -184,17 +317,26 @@ pub struct Mnemonic {
 #[cfg(feature = "zeroize")]
 impl zeroize::DefaultIsZeroes for Language {}
 
+#[cfg(all(feature = "wipe", not(feature = "zeroize")))]
+impl Drop for Mnemonic {
+	fn drop(&mut self) {
+		wipe::wipe(&mut self.words);
+	}
+}
+
 serde_string_impl!(Mnemonic, "a BIP-39 Mnemonic Code");
 
 impl Mnemonic {
 	/// Ensure the content of the [Cow] is normalized UTF8.
 	/// Performing this on a [Cow] means that all allocations for normalization
 	/// can be avoided for languages without special UTF8 characters.
+	///
+	/// The content is only replaced if it is not normalized yet, so an owned
+	/// [String] that is already normalized is kept as is, without copying.
 	#[inline]
 	#[cfg(feature = "unicode-normalization")]
 	pub fn normalize_utf8_cow<'a>(cow: &mut Cow<'a, str>) {
-		let is_nfkd = unicode_normalization::is_nfkd_quick(cow.as_ref().chars());
-		if is_nfkd != unicode_normalization::IsNormalized::Yes {
+		if !unicode_normalization::is_nfkd(cow.as_ref()) {
 			*cow = Cow::Owned(cow.as_ref().nfkd().to_string());
 		}
 	}
@@ -204,45 +346,25 @@ impl Mnemonic {
 	pub fn from_entropy_in(language: Language, entropy: &[u8]) -> Result<Mnemonic, Error> {
 		const MAX_ENTROPY_BITS: usize = 256;
 		const MIN_ENTROPY_BITS: usize = 128;
-		const MAX_CHECKSUM_BITS: usize = 8;
 
-		let nb_bytes = entropy.len();
-		let nb_bits = nb_bytes * 8;
-
-		if nb_bits % 32 != 0 {
-			return Err(Error::BadEntropyBitCount(nb_bits));
-		}
-		if nb_bits < MIN_ENTROPY_BITS || nb_bits > MAX_ENTROPY_BITS {
+		let nb_bits = entropy.len() * 8;
+		if nb_bits % 32 != 0 || !(MIN_ENTROPY_BITS..=MAX_ENTROPY_BITS).contains(&nb_bits) {
 			return Err(Error::BadEntropyBitCount(nb_bits));
 		}
 
-		let check = sha256::Hash::hash(&entropy);
-		let mut bits = [false; MAX_ENTROPY_BITS + MAX_CHECKSUM_BITS];
-		for i in 0..nb_bytes {
-			for j in 0..8 {
-				bits[i * 8 + j] = (entropy[i] & (1 << (7 - j))) > 0;
-			}
-		}
-		for i in 0..nb_bytes / 4 {
-			bits[8 * nb_bytes + i] = (check[i / 8] & (1 << (7 - (i % 8)))) > 0;
-		}
+		Ok(Mnemonic::from_valid_entropy_in(language, entropy))
+	}
 
+	/// Create a [Mnemonic] from entropy that is known to have a valid length.
+	fn from_valid_entropy_in(language: Language, entropy: &[u8]) -> Mnemonic {
 		let mut words = [EOF; MAX_NB_WORDS];
-		let nb_words = nb_bytes * 3 / 4;
-		for i in 0..nb_words {
-			let mut idx = 0;
-			for j in 0..11 {
-				if bits[i * 11 + j] {
-					idx += 1 << (10 - j);
-				}
-			}
-			words[i] = idx;
-		}
+		// This can't fail because the length of the entropy was checked before.
+		let _ = bits::append_checksum(entropy, &mut words);
 
-		Ok(Mnemonic {
+		Mnemonic {
 			lang: language,
-			words: words,
-		})
+			words,
+		}
 	}
 
 	/// Create a new English [Mnemonic] from the given entropy.
@@ -251,6 +373,54 @@ impl Mnemonic {
 		Mnemonic::from_entropy_in(Language::English, entropy)
 	}
 
+	/// Create a new [Mnemonic] in the specified language from hex-encoded
+	/// entropy, in lower or upper case.
+	///
+	/// Returns [Error::InvalidEncoding] if the string is not hex, and
+	/// [Error::BadEntropyBitCount] if the entropy has an invalid length.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::{Language, Mnemonic};
+	///
+	/// let hex = "9e885d952ad362caeb4efe34a8e91bd2";
+	/// let mnemonic = Mnemonic::from_entropy_hex_in(Language::English, hex).unwrap();
+	/// assert_eq!(mnemonic.words().next(), Some("ozone"));
+	/// ```
+	pub fn from_entropy_hex_in(language: Language, hex: &str) -> Result<Mnemonic, Error> {
+		let mut entropy = [0u8; MAX_NB_WORDS / 3 * 4];
+		let res = decode_hex(hex, &mut entropy)
+			.and_then(|len| Mnemonic::from_entropy_in(language, entropy.get(..len).unwrap_or(&[])));
+		#[cfg(any(feature = "wipe", feature = "zeroize"))]
+		wipe::wipe(&mut entropy);
+		res
+	}
+
+	/// Create a new English [Mnemonic] from hex-encoded entropy.
+	/// See [Mnemonic::from_entropy_hex_in].
+	pub fn from_entropy_hex(hex: &str) -> Result<Mnemonic, Error> {
+		Mnemonic::from_entropy_hex_in(Language::English, hex)
+	}
+
+	fixed_entropy_constructors! {
+		16 => from_entropy_16_in, from_entropy_16,
+			"Create a new 12-word [Mnemonic] in the specified language from 128 bits of entropy.",
+			"Create a new 12-word English [Mnemonic] from 128 bits of entropy.";
+		20 => from_entropy_20_in, from_entropy_20,
+			"Create a new 15-word [Mnemonic] in the specified language from 160 bits of entropy.",
+			"Create a new 15-word English [Mnemonic] from 160 bits of entropy.";
+		24 => from_entropy_24_in, from_entropy_24,
+			"Create a new 18-word [Mnemonic] in the specified language from 192 bits of entropy.",
+			"Create a new 18-word English [Mnemonic] from 192 bits of entropy.";
+		28 => from_entropy_28_in, from_entropy_28,
+			"Create a new 21-word [Mnemonic] in the specified language from 224 bits of entropy.",
+			"Create a new 21-word English [Mnemonic] from 224 bits of entropy.";
+		32 => from_entropy_32_in, from_entropy_32,
+			"Create a new 24-word [Mnemonic] in the specified language from 256 bits of entropy.",
+			"Create a new 24-word English [Mnemonic] from 256 bits of entropy.";
+	}
+
 	/// Generate a new [Mnemonic] in the given language
 	/// with the given randomness source.
 	/// For the different supported word counts, see documentation on [Mnemonic].
@@ -271,6 +441,116 @@ impl Mnemonic {
 	) -> Result<Mnemonic, Error>
 	where
 		R: RngCore + CryptoRng,
+	{
+		Mnemonic::generate_in_with_fill(language, word_count, |e| RngCore::fill_bytes(rng, e))
+	}
+
+	/// Generate a new [Mnemonic] in the given language
+	/// with the given randomness source, passing the raw entropy to `audit`
+	/// before it is converted into words.
+	///
+	/// This allows an independent observer, like a key ceremony log, to record
+	/// the entropy, or its hash, that actually produced the mnemonic.
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::{Mnemonic, Language};
+	/// use bitcoin_hashes::{sha256, Hash};
+	///
+	/// let mut rng = bip39::rand::thread_rng();
+	/// let mut hash = None;
+	/// let m = Mnemonic::generate_in_with_audit(&mut rng, Language::English, 24, |entropy| {
+	///     hash = Some(sha256::Hash::hash(entropy));
+	/// }).unwrap();
+	/// assert_eq!(hash, Some(sha256::Hash::hash(&m.to_entropy())));
+	/// ```
+	#[cfg(feature = "rand_core")]
+	pub fn generate_in_with_audit<R, F>(
+		rng: &mut R,
+		language: Language,
+		word_count: usize,
+		audit: F,
+	) -> Result<Mnemonic, Error>
+	where
+		R: RngCore + CryptoRng,
+		F: FnOnce(&[u8]),
+	{
+		Mnemonic::generate_in_with_fill(language, word_count, |e| {
+			RngCore::fill_bytes(rng, e);
+			audit(e);
+		})
+	}
+
+	/// Generate a new [Mnemonic] in the given language
+	/// with the given randomness source, without repeated words.
+	///
+	/// This is for backup procedures like voice readback that require all
+	/// words to be distinct. Mnemonics with repeated words are discarded and
+	/// generated again, which costs a fraction of a bit of entropy: about 0.05
+	/// bits for 12 words and 0.2 bits for 24 words.
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::{Mnemonic, Language};
+	///
+	/// let mut rng = bip39::rand::thread_rng();
+	/// let m = Mnemonic::generate_in_with_distinct_words(&mut rng, Language::English, 24).unwrap();
+	/// assert!(!m.has_repeated_words());
+	/// ```
+	#[cfg(feature = "rand_core")]
+	pub fn generate_in_with_distinct_words<R>(
+		rng: &mut R,
+		language: Language,
+		word_count: usize,
+	) -> Result<Mnemonic, Error>
+	where
+		R: RngCore + CryptoRng,
+	{
+		loop {
+			let mnemonic = Mnemonic::generate_in_with(rng, language, word_count)?;
+			if !mnemonic.has_repeated_words() {
+				return Ok(mnemonic);
+			}
+		}
+	}
+
+	/// Generate a new [Mnemonic] in the given language
+	/// with the given `rand_core` v0.9 randomness source.
+	/// For the different supported word counts, see documentation on [Mnemonic].
+	///
+	/// Example:
+	///
+	/// ```
+	/// # #[cfg(feature = "rand_v09")] {
+	/// use bip39::{Mnemonic, Language};
+	///
+	/// let mut rng = bip39::rand_0_9::rng();
+	/// let m = Mnemonic::generate_in_with_v09(&mut rng, Language::English, 24).unwrap();
+	/// # }
+	/// ```
+	#[cfg(feature = "rand_core_v09")]
+	pub fn generate_in_with_v09<R>(
+		rng: &mut R,
+		language: Language,
+		word_count: usize,
+	) -> Result<Mnemonic, Error>
+	where
+		R: rand_core_0_9::CryptoRng + ?Sized,
+	{
+		Mnemonic::generate_in_with_fill(language, word_count, |e| rng.fill_bytes(e))
+	}
+
+	/// Generate a new [Mnemonic] from entropy written by the `fill` function.
+	#[cfg(any(feature = "rand_core", feature = "rand_core_v09"))]
+	fn generate_in_with_fill<F>(
+		language: Language,
+		word_count: usize,
+		fill: F,
+	) -> Result<Mnemonic, Error>
+	where
+		F: FnOnce(&mut [u8]),
 	{
 		if is_invalid_word_count(word_count) {
 			return Err(Error::BadWordCount(word_count));
@@ -278,8 +558,9 @@ impl Mnemonic {
 
 		let entropy_bytes = (word_count / 3) * 4;
 		let mut entropy = [0u8; (MAX_NB_WORDS / 3) * 4];
-		RngCore::fill_bytes(rng, &mut entropy[0..entropy_bytes]);
-		Mnemonic::from_entropy_in(language, &entropy[0..entropy_bytes])
+		let entropy = entropy.get_mut(..entropy_bytes).ok_or(Error::BadWordCount(word_count))?;
+		fill(entropy);
+		Mnemonic::from_entropy_in(language, entropy)
 	}
 
 	/// Generate a new [Mnemonic] in the given language.
@@ -292,9 +573,41 @@ impl Mnemonic {
 	///
 	/// let m = Mnemonic::generate_in(Language::English, 24).unwrap();
 	/// ```
-	#[cfg(feature = "rand")]
+	#[cfg(any(feature = "rand", feature = "rand_v09"))]
 	pub fn generate_in(language: Language, word_count: usize) -> Result<Mnemonic, Error> {
-		Mnemonic::generate_in_with(&mut rand::thread_rng(), language, word_count)
+		Mnemonic::generate_in_with_fill(language, word_count, fill_from_thread_rng)
+	}
+
+	/// Generate a new [Mnemonic] in the given language, also returning the
+	/// entropy it was generated from.
+	/// For the different supported word counts, see documentation on [Mnemonic].
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::{Mnemonic, Language};
+	///
+	/// let (m, entropy) = Mnemonic::generate_in_returning_entropy(Language::English, 24).unwrap();
+	/// assert_eq!(m.to_entropy(), entropy.as_bytes());
+	/// ```
+	#[cfg(any(feature = "rand", feature = "rand_v09"))]
+	pub fn generate_in_returning_entropy(
+		language: Language,
+		word_count: usize,
+	) -> Result<(Mnemonic, Entropy), Error> {
+		let mut entropy = Entropy::from_slice(&[]);
+		let mnemonic = Mnemonic::generate_in_with_fill(language, word_count, |e| {
+			fill_from_thread_rng(e);
+			entropy = Entropy::from_slice(e);
+		})?;
+		Ok((mnemonic, entropy))
+	}
+
+	/// Generate a new English [Mnemonic], also returning the entropy it was
+	/// generated from. See [Mnemonic::generate_in_returning_entropy].
+	#[cfg(any(feature = "rand", feature = "rand_v09"))]
+	pub fn generate_returning_entropy(word_count: usize) -> Result<(Mnemonic, Entropy), Error> {
+		Mnemonic::generate_in_returning_entropy(Language::English, word_count)
 	}
 
 	/// Generate a new [Mnemonic] in English.
@@ -307,16 +620,76 @@ impl Mnemonic {
 	///
 	/// let m = Mnemonic::generate(24).unwrap();
 	/// ```
-	#[cfg(feature = "rand")]
+	#[cfg(any(feature = "rand", feature = "rand_v09"))]
 	pub fn generate(word_count: usize) -> Result<Mnemonic, Error> {
 		Mnemonic::generate_in(Language::English, word_count)
 	}
 
+	/// Generate a new [Mnemonic] in the given language with the given
+	/// [WordCount].
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::{Language, Mnemonic, WordCount};
+	///
+	/// let m = Mnemonic::generate_sized_in(Language::English, WordCount::Words24);
+	/// assert_eq!(m.word_count(), 24);
+	/// ```
+	#[cfg(any(feature = "rand", feature = "rand_v09"))]
+	pub fn generate_sized_in(language: Language, word_count: WordCount) -> Mnemonic {
+		match word_count {
+			WordCount::Words12 => generate_array(|e| Mnemonic::from_entropy_16_in(language, e)),
+			WordCount::Words15 => generate_array(|e| Mnemonic::from_entropy_20_in(language, e)),
+			WordCount::Words18 => generate_array(|e| Mnemonic::from_entropy_24_in(language, e)),
+			WordCount::Words21 => generate_array(|e| Mnemonic::from_entropy_28_in(language, e)),
+			WordCount::Words24 => generate_array(|e| Mnemonic::from_entropy_32_in(language, e)),
+		}
+	}
+
+	/// Generate a new English [Mnemonic] with the given [WordCount].
+	#[cfg(any(feature = "rand", feature = "rand_v09"))]
+	pub fn generate_sized(word_count: WordCount) -> Mnemonic {
+		Mnemonic::generate_sized_in(Language::English, word_count)
+	}
+
 	/// Get the language of the [Mnemonic].
 	pub fn language(&self) -> Language {
 		self.lang
 	}
 
+	/// Get a [Summary] of the [Mnemonic] that is safe to log.
+	pub fn summary(&self) -> Summary {
+		Summary::new(self)
+	}
+
+	/// Check whether the user input is this mnemonic, for example when asking
+	/// the user to enter the phrase again to confirm their backup.
+	///
+	/// The input is normalized and the words can be separated by any whitespace.
+	/// The comparison takes the same time wherever the phrases differ.
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// assert!(m.confirm(" abandon abandon abandon abandon abandon abandon\n\
+	///     abandon abandon abandon abandon abandon about "));
+	/// assert!(!m.confirm("abandon abandon abandon"));
+	/// ```
+	pub fn confirm(&self, user_input: &str) -> bool {
+		#[cfg(feature = "unicode-normalization")]
+		let user_input = {
+			let mut cow = Cow::Borrowed(user_input);
+			Mnemonic::normalize_utf8_cow(&mut cow);
+			cow
+		};
+
+		words_eq_ct(self.words(), user_input.split_whitespace())
+	}
+
 	/// Returns an iterator over the words of the [Mnemonic].
 	///
 	/// # Examples
@@ -331,14 +704,31 @@ impl Mnemonic {
 	///     println!("{}. {}", i, word);
 	/// }
 	/// ```
-	pub fn words(&self) -> impl Iterator<Item = &'static str> + Clone + '_ {
+	///
+	/// The iterator knows its length and can be walked from both ends, for
+	/// example to show the words a page at a time:
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// let words = mnemonic.words();
+	/// assert_eq!(words.len(), 12);
+	/// assert_eq!(words.rev().next(), Some("about"));
+	/// ```
+	pub fn words(
+		&self,
+	) -> impl ExactSizeIterator<Item = &'static str> + DoubleEndedIterator + Clone + '_ {
 		let list = self.lang.word_list();
-		self.word_indices().map(move |i| list[i])
+		// Indices always come from the word list, but stay panic-free anyway.
+		self.word_indices().map(move |i| list.get(i).copied().unwrap_or(""))
 	}
 
 	/// Returns an iterator over the words of the [Mnemonic].
 	#[deprecated(since = "2.1.0", note = "Use Mnemonic::words instead")]
-	pub fn word_iter(&self) -> impl Iterator<Item = &'static str> + Clone + '_ {
+	pub fn word_iter(
+		&self,
+	) -> impl ExactSizeIterator<Item = &'static str> + DoubleEndedIterator + Clone + '_ {
 		self.words()
 	}
 
@@ -354,28 +744,152 @@ impl Mnemonic {
 	/// let list = Language::English.word_list();
 	/// let mnemonic = Mnemonic::from_entropy(&[0; 32]).unwrap();
 	/// for i in mnemonic.word_indices() {
-	/// 	println!("{} ({})", list[i], i);
+	///     println!("{} ({})", list[i], i);
 	/// }
 	/// ```
-	pub fn word_indices(&self) -> impl Iterator<Item = usize> + Clone + '_ {
-		self.words.iter().take_while(|&&w| w != EOF).map(|w| *w as usize)
+	pub fn word_indices(
+		&self,
+	) -> impl ExactSizeIterator<Item = usize> + DoubleEndedIterator + Clone + '_ {
+		self.indices().iter().map(|w| *w as usize)
+	}
+
+	/// Get the word at the given zero-based position in the [Mnemonic].
+	///
+	/// Returns `None` if the position is not smaller than the word count.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// assert_eq!(mnemonic.word_at(0), Some("abandon"));
+	/// assert_eq!(mnemonic.word_at(11), Some("about"));
+	/// assert_eq!(mnemonic.word_at(12), None);
+	/// ```
+	pub fn word_at(&self, position: usize) -> Option<&'static str> {
+		let idx = *self.indices().get(position)?;
+		self.lang.word_list().get(idx as usize).cloned()
+	}
+
+	/// Get the [Word] at the given zero-based position in the [Mnemonic].
+	///
+	/// Returns `None` if the position is not smaller than the word count.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// let word = mnemonic.word(11).unwrap();
+	/// assert_eq!(word.as_str(), "about");
+	/// assert_eq!(word.index().to_one_based(), 4);
+	/// ```
+	pub fn word(&self, position: usize) -> Option<Word> {
+		let idx = WordIndex::new(*self.indices().get(position)?)?;
+		Some(Word::new(self.lang, idx))
+	}
+
+	/// Reveal the phrase of the [Mnemonic] in the [fmt::Debug] output, which
+	/// is redacted otherwise.
+	///
+	/// This makes it explicit at the call site that the phrase is shown on
+	/// purpose, for example in a debug log of a test wallet.
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// assert_eq!(format!("{:?}", mnemonic), "Mnemonic { language: English, word_count: 12 }");
+	/// assert!(format!("{:?}", mnemonic.expose()).starts_with("Mnemonic(abandon abandon "));
+	/// ```
+	pub fn expose(&self) -> Exposed<'_> {
+		Exposed(self)
+	}
+
+	/// Spell out the words of the [Mnemonic] in the NATO phonetic alphabet.
+	///
+	/// This is intended for reading a mnemonic aloud over a voice channel.
+	/// See [NatoSpelling] for the format.
+	pub fn nato_spelling(&self) -> NatoSpelling<'_> {
+		NatoSpelling(self)
+	}
+
+	/// Display only the first `nb_first` and last `nb_last` words of the
+	/// [Mnemonic], with the other words masked.
+	///
+	/// This is intended for confirmation previews and support screenshots,
+	/// where showing the whole mnemonic is not acceptable. At most half of the
+	/// words are shown, see [PartialDisplay] for the format.
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// assert_eq!(mnemonic.display_partial(2, 1).to_string(), "abandon abandon \u{2026} about");
+	/// ```
+	pub fn display_partial(&self, nb_first: usize, nb_last: usize) -> PartialDisplay<'_> {
+		PartialDisplay {
+			mnemonic: self,
+			nb_first,
+			nb_last,
+		}
+	}
+
+	/// Returns an iterator over the 11-bit patterns of the words of the [Mnemonic],
+	/// most significant bit first.
+	///
+	/// This is intended for backups that store bits rather than letters, like
+	/// punched metal plates. Use [Mnemonic::from_word_bits_in] to convert back.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// let last = mnemonic.word_bits().last().unwrap();
+	/// let punched: String = last.iter().map(|b| if *b { 'o' } else { '.' }).collect();
+	/// assert_eq!(punched, ".........oo"); // "about" has index 3
+	/// ```
+	pub fn word_bits(&self) -> impl Iterator<Item = [bool; 11]> + Clone + '_ {
+		self.indices().iter().map(|idx| {
+			let mut pattern = [false; 11];
+			for (i, bit) in pattern.iter_mut().enumerate() {
+				*bit = idx >> (10 - i) & 1 == 1;
+			}
+			pattern
+		})
+	}
+
+	/// The word indices without the EOF padding.
+	fn indices(&self) -> &[u16] {
+		self.words.get(..self.word_count()).unwrap_or(&[])
 	}
 
 	/// Determine the language of the mnemonic as a word iterator.
 	/// See documentation on [Mnemonic::language_of] for more info.
-	fn language_of_iter<'a, W: Iterator<Item = &'a str>>(words: W) -> Result<Language, Error> {
+	fn language_of_iter<'a, W: Iterator<Item = &'a str>>(
+		languages: LanguageSet,
+		words: W,
+	) -> Result<Language, Error> {
 		let mut words = words.peekable();
 		let langs = Language::ALL;
 		{
 			// Start scope to drop first_word so that words can be reborrowed later.
 			let first_word = words.peek().ok_or(Error::BadWordCount(0))?;
-			if first_word.len() == 0 {
+			if first_word.is_empty() {
 				return Err(Error::BadWordCount(0));
 			}
 
 			// We first try find the first word in wordlists that
 			// have guaranteed unique words.
-			for language in langs.iter().filter(|l| l.unique_words()) {
+			for language in langs.iter().filter(|l| l.unique_words() && languages.contains(**l)) {
 				if language.find_word(first_word).is_some() {
 					return Ok(*language);
 				}
@@ -386,15 +900,15 @@ impl Mnemonic {
 		// (those without unique words), and eliminate until there is
 		// just one left.
 		let mut possible = [false; language::MAX_NB_LANGUAGES];
-		for (i, lang) in langs.iter().enumerate() {
+		for (p, lang) in possible.iter_mut().zip(langs) {
 			// To start, only consider lists that don't have unique words.
 			// Those were considered above.
-			possible[i] = !lang.unique_words();
+			*p = !lang.unique_words() && languages.contains(*lang);
 		}
 		for (idx, word) in words.enumerate() {
 			// Scrap languages that don't have this word.
-			for (i, lang) in langs.iter().enumerate() {
-				possible[i] &= lang.find_word(word).is_some();
+			for (p, lang) in possible.iter_mut().zip(langs) {
+				*p &= lang.find_word(word).is_some();
 			}
 
 			// Get an iterator over remaining possible languages.
@@ -413,7 +927,7 @@ impl Mnemonic {
 			}
 		}
 
-		return Err(Error::AmbiguousLanguages(AmbiguousLanguages(possible)));
+		Err(Error::AmbiguousLanguages(AmbiguousLanguages(possible)))
 	}
 
 	/// Determine the language of the mnemonic.
@@ -427,60 +941,272 @@ impl Mnemonic {
 	/// interpreted in multiple languages, an [Error::AmbiguousLanguages] is
 	/// returned, containing the possible languages.
 	pub fn language_of<S: AsRef<str>>(mnemonic: S) -> Result<Language, Error> {
-		Mnemonic::language_of_iter(mnemonic.as_ref().split_whitespace())
+		Mnemonic::language_of_in_set(LanguageSet::all(), mnemonic)
 	}
 
-	/// Parse a mnemonic in normalized UTF8 in the given language.
-	pub fn parse_in_normalized(language: Language, s: &str) -> Result<Mnemonic, Error> {
-		let nb_words = s.split_whitespace().count();
-		if is_invalid_word_count(nb_words) {
-			return Err(Error::BadWordCount(nb_words));
-		}
-
-		// Here we will store the eventual words.
-		let mut words = [EOF; MAX_NB_WORDS];
+	/// Determine the language of the mnemonic among the given languages.
+	///
+	/// This is faster than [Mnemonic::language_of] for fewer languages and
+	/// avoids ambiguities with the other enabled languages.
+	pub fn language_of_in_set<S: AsRef<str>>(
+		languages: LanguageSet,
+		mnemonic: S,
+	) -> Result<Language, Error> {
+		Mnemonic::language_of_iter(languages, mnemonic.as_ref().split_whitespace())
+	}
 
-		// And here we keep track of the bits to calculate and validate the checksum.
-		// We only use `nb_words * 11` elements in this array.
-		let mut bits = [false; MAX_NB_WORDS * 11];
+	/// Determine the language of the mnemonic, preferring the given default
+	/// language when it can be interpreted in multiple languages.
+	fn language_of_with_default(default: Language, mnemonic: &str) -> Result<Language, Error> {
+		match Mnemonic::language_of(mnemonic) {
+			Err(Error::AmbiguousLanguages(a)) if a.iter().any(|l| l == default) => Ok(default),
+			res => res,
+		}
+	}
 
-		for (i, word) in s.split_whitespace().enumerate() {
-			let idx = language.find_word(word).ok_or(Error::UnknownWord(i))?;
+	/// Parse a mnemonic from an iterator over its normalized words in the given language.
+	fn parse_in_words<'a, W>(language: Language, words: W) -> Result<Mnemonic, Error>
+	where
+		W: Iterator<Item = &'a str> + Clone,
+	{
+		let (indices, nb_words) = find_indices_in(language, words)?;
+		let used = indices.get(..nb_words).ok_or(Error::BadWordCount(nb_words))?;
+		Mnemonic::from_indices_in(language, used)
+	}
 
-			words[i] = idx;
+	/// Parse a mnemonic from an iterator over its normalized words, detecting
+	/// the language from the enabled languages.
+	///
+	/// The parsing functions that detect the language among all the enabled
+	/// languages go through this, so that they resolve it the same way. Those
+	/// taking a [LanguageSet], a default language or a priority order resolve
+	/// it with their own rules.
+	fn parse_words<'a, W>(words: W) -> Result<Mnemonic, Error>
+	where
+		W: Iterator<Item = &'a str> + Clone,
+	{
+		let language = match Language::ALL {
+			[only] => *only,
+			_ => Mnemonic::language_of_iter(LanguageSet::all(), words.clone())?,
+		};
+		Mnemonic::parse_in_words(language, words)
+	}
 
-			for j in 0..11 {
-				bits[i * 11 + j] = idx >> (10 - j) & 1 == 1;
-			}
+	/// Create a [Mnemonic] from word indices, validating the word count and checksum.
+	pub(crate) fn from_indices_in(language: Language, indices: &[u16]) -> Result<Mnemonic, Error> {
+		if is_invalid_word_count(indices.len()) {
+			return Err(Error::BadWordCount(indices.len()));
 		}
 
 		// Verify the checksum.
-		// We only use `nb_words / 3 * 4` elements in this array.
 		let mut entropy = [0u8; MAX_NB_WORDS / 3 * 4];
-		let nb_bytes_entropy = nb_words / 3 * 4;
-		for i in 0..nb_bytes_entropy {
-			for j in 0..8 {
-				if bits[i * 8 + j] {
-					entropy[i] += 1 << (7 - j);
-				}
-			}
-		}
-		let check = sha256::Hash::hash(&entropy[0..nb_bytes_entropy]);
-		for i in 0..nb_bytes_entropy / 4 {
-			if bits[8 * nb_bytes_entropy + i] != ((check[i / 8] & (1 << (7 - (i % 8)))) > 0) {
-				return Err(Error::InvalidChecksum);
-			}
-		}
+		let res = bits::strip_checksum(indices, &mut entropy);
+		#[cfg(any(feature = "wipe", feature = "zeroize"))]
+		wipe::wipe(&mut entropy);
+		res?;
 
+		let mut words = [EOF; MAX_NB_WORDS];
+		for (slot, idx) in words.iter_mut().zip(indices) {
+			*slot = *idx;
+		}
 		Ok(Mnemonic {
 			lang: language,
-			words: words,
+			words,
 		})
 	}
 
-	/// Parse a mnemonic in normalized UTF8 in the given language without checksum check.
+	/// Create a [Mnemonic] in the given language from its words, given one by
+	/// one.
 	///
-	/// It is advised to use this method together with the utility methods
+	/// This accepts any collection or iterator of words, like the words typed
+	/// in the separate fields of a recovery form, without joining them into a
+	/// phrase first. The words are normalized if the `unicode-normalization`
+	/// feature is enabled, otherwise they must already be normalized.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::{Language, Mnemonic};
+	///
+	/// let mut words = vec!["abandon"; 11];
+	/// words.push("about");
+	/// let mnemonic = Mnemonic::from_words_in(Language::English, &words).unwrap();
+	/// assert_eq!(mnemonic, Mnemonic::from_entropy(&[0; 16]).unwrap());
+	/// ```
+	pub fn from_words_in<I>(language: Language, words: I) -> Result<Mnemonic, Error>
+	where
+		I: IntoIterator,
+		I::Item: AsRef<str>,
+	{
+		let mut indices = [EOF; MAX_NB_WORDS];
+		let mut nb_words = 0;
+		let mut unknown = None;
+		for word in words {
+			if let (Some(slot), None) = (indices.get_mut(nb_words), &unknown) {
+				#[cfg(feature = "unicode-normalization")]
+				let word = {
+					let mut cow = Cow::Borrowed(word.as_ref());
+					Mnemonic::normalize_utf8_cow(&mut cow);
+					cow
+				};
+				match language.find_word(word.as_ref()) {
+					Some(idx) => *slot = idx,
+					None => unknown = Some(Error::UnknownWord(nb_words)),
+				}
+			}
+			nb_words += 1;
+		}
+
+		if is_invalid_word_count(nb_words) {
+			return Err(Error::BadWordCount(nb_words));
+		}
+		if let Some(e) = unknown {
+			return Err(e);
+		}
+		Mnemonic::from_indices_in(language, indices.get(..nb_words).unwrap_or(&[]))
+	}
+
+	/// Create a [Mnemonic] in the given language from the word list indices
+	/// of its words.
+	///
+	/// This is the reverse of [Mnemonic::word_indices], for devices that store
+	/// the indices rather than the words. Returns [Error::UnknownWord] if an
+	/// index is not below 2048.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::{Language, Mnemonic};
+	///
+	/// let indices = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3];
+	/// let mnemonic = Mnemonic::from_word_indices_in(Language::English, &indices).unwrap();
+	/// assert_eq!(mnemonic.words().last(), Some("about"));
+	/// ```
+	pub fn from_word_indices_in(language: Language, indices: &[u16]) -> Result<Mnemonic, Error> {
+		Mnemonic::from_indices_in(language, indices)
+	}
+
+	/// Create a [Mnemonic] in the given language from the 11-bit patterns of its words.
+	///
+	/// This is the reverse of [Mnemonic::word_bits]. Each pattern has the most
+	/// significant bit first.
+	pub fn from_word_bits_in(
+		language: Language,
+		patterns: &[[bool; 11]],
+	) -> Result<Mnemonic, Error> {
+		if is_invalid_word_count(patterns.len()) {
+			return Err(Error::BadWordCount(patterns.len()));
+		}
+
+		let mut indices = [0u16; MAX_NB_WORDS];
+		for (idx, pattern) in indices.iter_mut().zip(patterns) {
+			*idx = pattern.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16);
+		}
+		let used = indices.get(..patterns.len()).ok_or(Error::BadWordCount(patterns.len()))?;
+		Mnemonic::from_indices_in(language, used)
+	}
+
+	/// Parse a mnemonic in normalized UTF8 in the given language.
+	pub fn parse_in_normalized(language: Language, s: &str) -> Result<Mnemonic, Error> {
+		Mnemonic::parse_in_words(language, s.split_whitespace())
+	}
+
+	/// Parse a mnemonic in the given language, accepting only its canonical
+	/// form.
+	///
+	/// The words must be exactly the normalized words of the word list, so in
+	/// lowercase, separated by a single space, or a single ideographic space
+	/// (U+3000) for Japanese, without leading or trailing whitespace. This is
+	/// for tools that need to detect malformed backups rather than fixing
+	/// them silently. Returns [Error::InvalidSeparator] for any other
+	/// whitespace, [Error::NonCanonicalWord] for words in uppercase or not
+	/// normalized, and [Error::UnknownWord] for any other word.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::{Error, Language, Mnemonic};
+	///
+	/// let s = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+	/// assert!(Mnemonic::parse_in_strict(Language::English, s).is_ok());
+	///
+	/// let s = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon  about";
+	/// assert_eq!(Mnemonic::parse_in_strict(Language::English, s), Err(Error::InvalidSeparator(88)));
+	/// ```
+	pub fn parse_in_strict(language: Language, s: &str) -> Result<Mnemonic, Error> {
+		let separator = canonical_separator(language);
+		// Leading separators are caught by starting as if after a separator.
+		let mut after_separator = true;
+		for (pos, c) in s.char_indices() {
+			if c == separator {
+				if after_separator {
+					return Err(Error::InvalidSeparator(pos));
+				}
+				after_separator = true;
+			} else if c.is_whitespace() {
+				return Err(Error::InvalidSeparator(pos));
+			} else {
+				after_separator = false;
+			}
+		}
+		if after_separator && !s.is_empty() {
+			return Err(Error::InvalidSeparator(s.len() - separator.len_utf8()));
+		}
+		let words = s.split(separator).filter(|w| !w.is_empty());
+		match Mnemonic::parse_in_words(language, words.clone()) {
+			Err(Error::UnknownWord(i)) => match words.clone().nth(i) {
+				Some(w) if !is_canonical_word(w) => Err(Error::NonCanonicalWord(i)),
+				_ => Err(Error::UnknownWord(i)),
+			},
+			res => res,
+		}
+	}
+
+	/// Get the details of the checksum mismatch of a mnemonic in normalized
+	/// UTF8 in the given language, or `None` if the checksum is valid.
+	///
+	/// This helps telling users whether the last word, or an earlier one, is
+	/// wrong. See [bits::checksum_mismatch].
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::{Language, Mnemonic};
+	///
+	/// let s = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+	/// let mismatch = Mnemonic::checksum_mismatch_in(Language::English, s).unwrap().unwrap();
+	/// assert_eq!(Language::English.word_list()[mismatch.correct_last_index as usize], "about");
+	/// ```
+	pub fn checksum_mismatch_in(
+		language: Language,
+		s: &str,
+	) -> Result<Option<bits::ChecksumMismatch>, Error> {
+		let (indices, nb_words) = find_indices_in(language, s.split_whitespace())?;
+		bits::checksum_mismatch(indices.get(..nb_words).unwrap_or(&[]))
+	}
+
+	/// Check every word of a mnemonic in normalized UTF8 in the given
+	/// language, and its checksum if possible.
+	///
+	/// Unlike parsing, this doesn't stop at the first problem, so that a
+	/// recovery screen can mark all the unknown words and suggest corrections.
+	/// See [ValidationReport].
+	pub fn check_in(language: Language, s: &str) -> ValidationReport {
+		ValidationReport::new(language, s)
+	}
+
+	/// Check a mnemonic in normalized UTF8 in the given language while it is
+	/// being typed.
+	///
+	/// This tells whether the words so far are valid and can still be
+	/// completed into a valid mnemonic. See [PartialParse].
+	pub fn parse_partial_in(language: Language, s: &str) -> PartialParse {
+		PartialParse::new(language, s)
+	}
+
+	/// Parse a mnemonic in normalized UTF8 in the given language without checksum check.
+	///
+	/// It is advised to use this method together with the utility methods
 	/// - [Mnemonic::normalize_utf8_cow]
 	/// - [Mnemonic::language_of]
 	pub fn parse_in_normalized_without_checksum_check(
@@ -495,24 +1221,136 @@ impl Mnemonic {
 		// Here we will store the eventual words.
 		let mut words = [EOF; MAX_NB_WORDS];
 
-		for (i, word) in s.split_whitespace().enumerate() {
-			let idx = language.find_word(word).ok_or(Error::UnknownWord(i))?;
-
-			words[i] = idx;
+		for (i, (slot, word)) in words.iter_mut().zip(s.split_whitespace()).enumerate() {
+			*slot = language.find_word(word).ok_or(Error::UnknownWord(i))?;
 		}
 
 		Ok(Mnemonic {
 			lang: language,
-			words: words,
+			words,
 		})
 	}
 
 	/// Parse a mnemonic in normalized UTF8.
 	pub fn parse_normalized(s: &str) -> Result<Mnemonic, Error> {
-		let lang = Mnemonic::language_of(s)?;
+		Mnemonic::parse_words(s.split_whitespace())
+	}
+
+	/// Parse a mnemonic in normalized UTF8 and detect the language among the
+	/// given languages.
+	pub fn parse_normalized_in_set(languages: LanguageSet, s: &str) -> Result<Mnemonic, Error> {
+		let lang = Mnemonic::language_of_in_set(languages, s)?;
 		Mnemonic::parse_in_normalized(lang, s)
 	}
 
+	/// Parse a mnemonic in normalized UTF8, using the given default language if
+	/// it can be interpreted in multiple languages, including the default one.
+	///
+	/// Applications that aren't English-first can pass their language here
+	/// instead of resolving [Error::AmbiguousLanguages] themselves.
+	pub fn parse_normalized_with_default(default: Language, s: &str) -> Result<Mnemonic, Error> {
+		let lang = Mnemonic::language_of_with_default(default, s)?;
+		Mnemonic::parse_in_normalized(lang, s)
+	}
+
+	/// Parse a mnemonic, using the given default language if it can be
+	/// interpreted in multiple languages. See
+	/// [Mnemonic::parse_normalized_with_default].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::{Language, Mnemonic};
+	///
+	/// let s = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+	/// let m = Mnemonic::parse_with_default(Language::English, s).unwrap();
+	/// assert_eq!(m.language(), Language::English);
+	/// ```
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse_with_default<'a, S: Into<Cow<'a, str>>>(
+		default: Language,
+		s: S,
+	) -> Result<Mnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_normalized_with_default(default, cow.as_ref())
+	}
+
+	/// Parse a mnemonic and detect the language among the given languages. See
+	/// [Mnemonic::parse_normalized_in_set].
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse_in_set<'a, S: Into<Cow<'a, str>>>(
+		languages: LanguageSet,
+		s: S,
+	) -> Result<Mnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_normalized_in_set(languages, cow.as_ref())
+	}
+
+	/// Parse a mnemonic in normalized UTF8, resolving ambiguous languages with
+	/// the given priority order.
+	///
+	/// If the mnemonic can be interpreted in multiple languages, the first
+	/// language of `priority` in which it is valid is used. The possible
+	/// languages are returned along with the mnemonic, so that the ambiguity can
+	/// be recorded or shown to the user. If none of the languages in `priority`
+	/// is possible, [Error::AmbiguousLanguages] is returned.
+	pub fn parse_normalized_with_priority(
+		s: &str,
+		priority: &[Language],
+	) -> Result<(Mnemonic, Option<AmbiguousLanguages>), Error> {
+		let ambiguous = match Mnemonic::language_of(s) {
+			Ok(lang) => return Ok((Mnemonic::parse_in_normalized(lang, s)?, None)),
+			Err(Error::AmbiguousLanguages(a)) => a,
+			Err(e) => return Err(e),
+		};
+
+		let mut res = Err(Error::AmbiguousLanguages(ambiguous));
+		for lang in priority.iter().filter(|l| ambiguous.iter().any(|a| a == **l)) {
+			res = Mnemonic::parse_in_normalized(*lang, s);
+			if res.is_ok() {
+				break;
+			}
+		}
+		res.map(|m| (m, Some(ambiguous)))
+	}
+
+	/// Parse a mnemonic, resolving ambiguous languages with the given priority
+	/// order. See [Mnemonic::parse_normalized_with_priority].
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse_with_priority<'a, S: Into<Cow<'a, str>>>(
+		s: S,
+		priority: &[Language],
+	) -> Result<(Mnemonic, Option<AmbiguousLanguages>), Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_normalized_with_priority(cow.as_ref(), priority)
+	}
+
+	/// Parse a mnemonic in the given language, only accepting ASCII input.
+	///
+	/// ASCII input doesn't need Unicode normalization, so this is available
+	/// without the `unicode-normalization` dependency. Words with non-ASCII
+	/// characters are rejected with [Error::NonAsciiWord].
+	#[cfg(feature = "ascii-only")]
+	pub fn parse_ascii_in(language: Language, s: &str) -> Result<Mnemonic, Error> {
+		if let Some(i) = s.split_whitespace().position(|w| !w.is_ascii()) {
+			return Err(Error::NonAsciiWord(i));
+		}
+		Mnemonic::parse_in_normalized(language, s)
+	}
+
+	/// Parse a mnemonic, only accepting ASCII input, and detect the language
+	/// from the enabled languages. See [Mnemonic::parse_ascii_in].
+	#[cfg(feature = "ascii-only")]
+	pub fn parse_ascii(s: &str) -> Result<Mnemonic, Error> {
+		if let Some(i) = s.split_whitespace().position(|w| !w.is_ascii()) {
+			return Err(Error::NonAsciiWord(i));
+		}
+		Mnemonic::parse_normalized(s)
+	}
+
 	/// Parse a mnemonic in the given language.
 	#[cfg(feature = "unicode-normalization")]
 	pub fn parse_in<'a, S: Into<Cow<'a, str>>>(
@@ -521,37 +1359,264 @@ impl Mnemonic {
 	) -> Result<Mnemonic, Error> {
 		let mut cow = s.into();
 		Mnemonic::normalize_utf8_cow(&mut cow);
-		Ok(Mnemonic::parse_in_normalized(language, cow.as_ref())?)
+		Mnemonic::parse_in_normalized(language, cow.as_ref())
 	}
 
 	/// Parse a mnemonic and detect the language from the enabled languages.
+	///
+	/// The input is only copied if it needs to be normalized, so passing an
+	/// owned [String] that is already normalized doesn't allocate.
 	#[cfg(feature = "unicode-normalization")]
 	pub fn parse<'a, S: Into<Cow<'a, str>>>(s: S) -> Result<Mnemonic, Error> {
 		let mut cow = s.into();
 		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_words(cow.as_ref().split_whitespace())
+	}
 
-		let language = if Language::ALL.len() == 1 {
-			Language::ALL[0]
-		} else {
-			Mnemonic::language_of(cow.as_ref())?
-		};
+	/// Parse a mnemonic in the given language, ignoring the case of the words
+	/// and any whitespace around them.
+	///
+	/// This accepts phrases pasted from documents and password managers, with
+	/// capitalized words, tabs, line breaks or runs of spaces. The returned
+	/// mnemonic is displayed in the canonical form, to show the user what was
+	/// understood. See [Mnemonic::parse_in_strict] to reject such phrases.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::{Language, Mnemonic};
+	///
+	/// let s = "  Abandon abandon ABANDON abandon\tabandon abandon\r\nabandon abandon abandon
+	///     abandon abandon About\n";
+	/// let m = Mnemonic::parse_in_lenient(Language::English, s).unwrap();
+	/// assert_eq!(m.to_string(), "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn parse_in_lenient(language: Language, s: &str) -> Result<Mnemonic, Error> {
+		Mnemonic::parse_in(language, s.to_lowercase())
+	}
+
+	/// Parse a mnemonic like [Mnemonic::parse_in_lenient], and detect the
+	/// language from the enabled languages.
+	#[cfg(feature = "alloc")]
+	pub fn parse_lenient(s: &str) -> Result<Mnemonic, Error> {
+		Mnemonic::parse(s.to_lowercase())
+	}
+
+	/// Parse a mnemonic in normalized UTF8 in the given language, with the
+	/// words separated by whitespace or any of the given separators.
+	///
+	/// This accepts phrases exported by password managers or as CSV, like
+	/// `abandon,ability,able` with `&[',']`. Consecutive separators are
+	/// treated as one.
+	pub fn parse_in_normalized_with_separators(
+		language: Language,
+		s: &str,
+		separators: &[char],
+	) -> Result<Mnemonic, Error> {
+		Mnemonic::parse_in_words(language, separated_words(s, separators))
+	}
+
+	/// Parse a mnemonic in the given language, with the words separated by
+	/// whitespace or any of the given separators.
+	///
+	/// The separators are matched after normalization, so a fullwidth comma is
+	/// matched by `','`. See [Mnemonic::parse_in_normalized_with_separators].
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse_in_with_separators<'a, S: Into<Cow<'a, str>>>(
+		language: Language,
+		s: S,
+		separators: &[char],
+	) -> Result<Mnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_in_normalized_with_separators(language, cow.as_ref(), separators)
+	}
+
+	/// Parse a mnemonic with the words separated by whitespace or any of the
+	/// given separators, and detect the language from the enabled languages.
+	///
+	/// See [Mnemonic::parse_in_with_separators] for more info.
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse_with_separators<'a, S: Into<Cow<'a, str>>>(
+		s: S,
+		separators: &[char],
+	) -> Result<Mnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_words(separated_words(cow.as_ref(), separators))
+	}
+
+	/// Parse a mnemonic in normalized UTF8 in the given language, accepting
+	/// words abbreviated to their first 4 or more characters.
+	///
+	/// This is for backups that only keep the first letters of each word, like
+	/// stamped metal plates. An abbreviation must match a single word, see
+	/// [Language::find_abbreviated_word].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::{Language, Mnemonic};
+	///
+	/// let s = "aban aban aban aban aban aban aban aban aban aban aban abou";
+	/// let m = Mnemonic::parse_in_normalized_abbreviated(Language::English, s).unwrap();
+	/// assert_eq!(m, Mnemonic::from_entropy(&[0; 16]).unwrap());
+	/// ```
+	pub fn parse_in_normalized_abbreviated(language: Language, s: &str) -> Result<Mnemonic, Error> {
+		let (indices, nb_words) =
+			find_indices_with(s.split_whitespace(), |w| language.find_abbreviated_word(w))?;
+		Mnemonic::from_indices_in(language, indices.get(..nb_words).unwrap_or(&[]))
+	}
+
+	/// Parse a mnemonic in the given language, accepting abbreviated words.
+	/// See [Mnemonic::parse_in_normalized_abbreviated].
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse_in_abbreviated<'a, S: Into<Cow<'a, str>>>(
+		language: Language,
+		s: S,
+	) -> Result<Mnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_in_normalized_abbreviated(language, cow.as_ref())
+	}
+
+	/// Parse a mnemonic in normalized UTF8 in the given language, written as a
+	/// numbered or punctuated list.
+	///
+	/// This accepts backups like `1. abandon 2. ability …`, `1) abandon`,
+	/// `#1 abandon` or `abandon, ability; able`, one word per line or not. The
+	/// words are separated by whitespace or ASCII punctuation, and the numbers
+	/// are skipped. The numbers are not checked to be in order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::{Language, Mnemonic};
+	///
+	/// let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// let table = format!("{:#}", m); // " 1. abandon\n 2. abandon\n…"
+	/// assert_eq!(Mnemonic::parse_in_normalized_list(Language::English, &table), Ok(m));
+	/// ```
+	pub fn parse_in_normalized_list(language: Language, s: &str) -> Result<Mnemonic, Error> {
+		Mnemonic::parse_in_words(language, list_words(s))
+	}
+
+	/// Parse a mnemonic in the given language, written as a numbered or
+	/// punctuated list. See [Mnemonic::parse_in_normalized_list].
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse_in_list<'a, S: Into<Cow<'a, str>>>(
+		language: Language,
+		s: S,
+	) -> Result<Mnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_in_normalized_list(language, cow.as_ref())
+	}
+
+	/// Parse a mnemonic written as a numbered or punctuated list, and detect
+	/// the language from the enabled languages.
+	///
+	/// See [Mnemonic::parse_in_normalized_list] for more info.
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse_list<'a, S: Into<Cow<'a, str>>>(s: S) -> Result<Mnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_words(list_words(cow.as_ref()))
+	}
+
+	/// Parse a Chinese mnemonic written without separators between the words.
+	///
+	/// Every word in the Simplified and Traditional Chinese word lists is a single
+	/// character, so the phrase can be segmented character by character.
+	/// Any whitespace in the input is ignored.
+	#[cfg(all(
+		feature = "unicode-normalization",
+		any(feature = "chinese-simplified", feature = "chinese-traditional")
+	))]
+	pub fn parse_in_unseparated<'a, S: Into<Cow<'a, str>>>(
+		language: Language,
+		s: S,
+	) -> Result<Mnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_in_words(language, char_words(cow.as_ref()))
+	}
 
-		Ok(Mnemonic::parse_in_normalized(language, cow.as_ref())?)
+	/// Parse a Chinese mnemonic written without separators between the words
+	/// and detect the language from the enabled languages.
+	///
+	/// See [Mnemonic::parse_in_unseparated] for more info.
+	#[cfg(all(
+		feature = "unicode-normalization",
+		any(feature = "chinese-simplified", feature = "chinese-traditional")
+	))]
+	pub fn parse_unseparated<'a, S: Into<Cow<'a, str>>>(s: S) -> Result<Mnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Mnemonic::parse_words(char_words(cow.as_ref()))
 	}
 
 	/// Get the number of words in the mnemonic.
 	pub fn word_count(&self) -> usize {
-		self.word_indices().count()
+		// The word count is always valid, so only the possible ends need checking.
+		(MIN_NB_WORDS..MAX_NB_WORDS)
+			.step_by(3)
+			.find(|n| self.words.get(*n) == Some(&EOF))
+			.unwrap_or(MAX_NB_WORDS)
+	}
+
+	/// Whether a word occurs more than once in the mnemonic.
+	pub fn has_repeated_words(&self) -> bool {
+		let indices = self.indices();
+		indices
+			.iter()
+			.enumerate()
+			.any(|(i, idx)| indices.iter().skip(i + 1).any(|other| other == idx))
 	}
 
 	/// Convert to seed bytes with a passphrase in normalized UTF8.
 	pub fn to_seed_normalized(&self, normalized_passphrase: &str) -> [u8; 64] {
+		let mut seed = [0u8; 64];
+		self.to_seed_normalized_into(normalized_passphrase, &mut seed);
+		seed
+	}
+
+	/// Convert to seed bytes with a passphrase in normalized UTF8, written
+	/// directly in the given buffer.
+	///
+	/// This lets the seed be derived in memory owned by the caller, like a
+	/// locked page, without any intermediate copy.
+	pub fn to_seed_normalized_into(&self, normalized_passphrase: &str, seed: &mut [u8; 64]) {
 		const PBKDF2_ROUNDS: usize = 2048;
-		const PBKDF2_BYTES: usize = 64;
 
-		let mut seed = [0u8; PBKDF2_BYTES];
-		pbkdf2::pbkdf2(self.words(), normalized_passphrase.as_bytes(), PBKDF2_ROUNDS, &mut seed);
-		seed
+		pbkdf2::pbkdf2(self.words(), normalized_passphrase.as_bytes(), PBKDF2_ROUNDS, seed);
+	}
+
+	/// Estimate how long deriving a seed takes on the current device.
+	///
+	/// This times a few runs of a small number of PBKDF2 rounds and
+	/// extrapolates to the 2048 rounds of [Mnemonic::to_seed], which takes long
+	/// enough on slow devices to need a progress bar or a worker thread. The
+	/// estimate itself takes about a tenth of a seed derivation.
+	#[cfg(feature = "std")]
+	pub fn estimate_seed_duration() -> std::time::Duration {
+		const PBKDF2_ROUNDS: u32 = 2048;
+		const SAMPLE_ROUNDS: u32 = 64;
+		const NB_SAMPLES: usize = 3;
+
+		let mnemonic = Mnemonic::from_entropy_32(&[0; 32]);
+		let mut seed = [0u8; 64];
+		// The fastest sample is the least disturbed by other work.
+		let fastest = (0..NB_SAMPLES)
+			.map(|_| {
+				let start = std::time::Instant::now();
+				pbkdf2::pbkdf2(mnemonic.words(), b"", SAMPLE_ROUNDS as usize, &mut seed);
+				start.elapsed()
+			})
+			.min()
+			.unwrap_or_default();
+		fastest * (PBKDF2_ROUNDS / SAMPLE_ROUNDS)
 	}
 
 	/// Convert to seed bytes.
@@ -565,49 +1630,276 @@ impl Mnemonic {
 		self.to_seed_normalized(normalized_passphrase.as_ref())
 	}
 
+	/// Convert to seed bytes, written directly in the given buffer.
+	/// See [Mnemonic::to_seed_normalized_into].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// let mut seed = [0; 64];
+	/// mnemonic.to_seed_into("TREZOR", &mut seed);
+	/// assert_eq!(seed, mnemonic.to_seed("TREZOR"));
+	/// ```
+	#[cfg(feature = "unicode-normalization")]
+	pub fn to_seed_into<'a, P: Into<Cow<'a, str>>>(&self, passphrase: P, seed: &mut [u8; 64]) {
+		let mut cow = passphrase.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		self.to_seed_normalized_into(cow.as_ref(), seed)
+	}
+
+	/// Convert to seed bytes, encoded in lowercase hex.
+	///
+	/// Use [Seed] to parse it back. Unlike the [Seed], the returned string is
+	/// not erased from memory on drop.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::{Mnemonic, Seed};
+	///
+	/// let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// let hex = mnemonic.to_seed_hex("TREZOR");
+	/// assert!(hex.starts_with("c55257c360c07c72"));
+	/// assert_eq!(hex.parse::<Seed>().unwrap().as_bytes(), &mnemonic.to_seed("TREZOR"));
+	/// ```
+	#[cfg(feature = "unicode-normalization")]
+	pub fn to_seed_hex<'a, P: Into<Cow<'a, str>>>(&self, passphrase: P) -> alloc::string::String {
+		Seed::from_mnemonic(self, passphrase).to_string()
+	}
+
+	/// Convert to seed bytes with a passphrase in normalized UTF8, in a buffer
+	/// that is zeroized on drop.
+	#[cfg(feature = "zeroize")]
+	pub fn to_seed_normalized_zeroizing(
+		&self,
+		normalized_passphrase: &str,
+	) -> zeroize::Zeroizing<[u8; 64]> {
+		// The seed is derived in place so that no copy of it is left behind.
+		let mut seed = zeroize::Zeroizing::new([0u8; 64]);
+		self.to_seed_normalized_into(normalized_passphrase, &mut seed);
+		seed
+	}
+
+	/// Convert to seed bytes, in a buffer that is zeroized on drop.
+	#[cfg(all(feature = "zeroize", feature = "unicode-normalization"))]
+	pub fn to_seed_zeroizing<'a, P: Into<Cow<'a, str>>>(
+		&self,
+		passphrase: P,
+	) -> zeroize::Zeroizing<[u8; 64]> {
+		let mut cow = passphrase.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		self.to_seed_normalized_zeroizing(cow.as_ref())
+	}
+
+	/// Check that the seed of the mnemonic with the passphrase is the expected
+	/// seed, in constant time.
+	///
+	/// This lets migration and audit tools confirm a stored seed without
+	/// handling the derived seed. The passphrase is normalized if the
+	/// `unicode-normalization` feature is enabled, otherwise it must already
+	/// be normalized.
+	///
+	/// Example:
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// let seed = m.to_seed_normalized("TREZOR");
+	/// assert!(m.verify_seed("TREZOR", &seed));
+	/// assert!(!m.verify_seed("", &seed));
+	/// ```
+	pub fn verify_seed(&self, passphrase: &str, expected: &[u8]) -> bool {
+		#[cfg(feature = "unicode-normalization")]
+		let passphrase = {
+			let mut cow = Cow::Borrowed(passphrase);
+			Mnemonic::normalize_utf8_cow(&mut cow);
+			cow
+		};
+
+		#[cfg_attr(not(any(feature = "wipe", feature = "zeroize")), allow(unused_mut))]
+		let mut seed = self.to_seed_normalized(&passphrase);
+		let eq = bytes_eq_ct(&seed, expected);
+		#[cfg(any(feature = "wipe", feature = "zeroize"))]
+		wipe::wipe(&mut seed);
+		eq
+	}
+
+	/// Convert to seed bytes, only accepting ASCII passphrases.
+	///
+	/// ASCII passphrases don't need Unicode normalization, so this is available
+	/// without the `unicode-normalization` dependency. Passphrases with non-ASCII
+	/// characters are rejected with [Error::InvalidPassphraseCharacter].
+	#[cfg(feature = "ascii-only")]
+	pub fn to_seed_ascii(&self, passphrase: &str) -> Result<[u8; 64], Error> {
+		if let Some(i) = passphrase.bytes().position(|b| !b.is_ascii()) {
+			return Err(Error::InvalidPassphraseCharacter(i));
+		}
+		Ok(self.to_seed_normalized(passphrase))
+	}
+
+	/// Convert to seed bytes, only accepting passphrases of printable ASCII
+	/// characters. See [passphrase::check_strict_ascii] for details.
+	pub fn to_seed_strict(&self, passphrase: &str) -> Result<[u8; 64], Error> {
+		passphrase::check_strict_ascii(passphrase)?;
+		Ok(self.to_seed_normalized(passphrase))
+	}
+
+	/// Convert to a 32-byte seed with a passphrase in normalized UTF8, for
+	/// protocols that need a 32-byte secret instead of the 64-byte BIP39 seed.
+	///
+	/// The result is the HKDF-SHA256 extract step with `domain` as salt applied to
+	/// the BIP39 seed, i.e. `HMAC-SHA256(domain, seed)`. The domain should be a
+	/// unique string identifying the protocol, so that different protocols get
+	/// independent secrets from the same mnemonic.
+	///
+	/// This is not part of BIP39: the result is not compatible with simply
+	/// truncating the 64-byte seed.
+	pub fn to_seed_32_normalized(&self, normalized_passphrase: &str, domain: &[u8]) -> [u8; 32] {
+		#[cfg_attr(not(any(feature = "wipe", feature = "zeroize")), allow(unused_mut))]
+		let mut seed = self.to_seed_normalized(normalized_passphrase);
+		let mut engine = hmac::HmacEngine::<sha256::Hash>::new(domain);
+		engine.input(&seed);
+		#[cfg(any(feature = "wipe", feature = "zeroize"))]
+		wipe::wipe(&mut seed);
+		hmac::Hmac::from_engine(engine).to_byte_array()
+	}
+
+	/// Convert to a 32-byte seed for protocols other than Bitcoin.
+	/// See [Mnemonic::to_seed_32_normalized] for details.
+	#[cfg(feature = "unicode-normalization")]
+	pub fn to_seed_32<'a, P: Into<Cow<'a, str>>>(&self, passphrase: P, domain: &[u8]) -> [u8; 32] {
+		let normalized_passphrase = {
+			let mut cow = passphrase.into();
+			Mnemonic::normalize_utf8_cow(&mut cow);
+			cow
+		};
+		self.to_seed_32_normalized(normalized_passphrase.as_ref(), domain)
+	}
+
+	/// Convert to seed bytes with a passphrase in normalized UTF8, computing
+	/// the HMACs with the given [Pbkdf2Prf].
+	pub fn to_seed_normalized_with<P>(
+		&self,
+		normalized_passphrase: &str,
+		prf: &mut P,
+	) -> Result<[u8; 64], P::Error>
+	where
+		P: Pbkdf2Prf + ?Sized,
+	{
+		const PBKDF2_ROUNDS: usize = 2048;
+		const PBKDF2_BYTES: usize = 64;
+
+		let mut seed = [0u8; PBKDF2_BYTES];
+		pbkdf2::pbkdf2_with(
+			prf,
+			self.words(),
+			normalized_passphrase.as_bytes(),
+			PBKDF2_ROUNDS,
+			&mut seed,
+		)?;
+		Ok(seed)
+	}
+
+	/// Convert to seed bytes, computing the HMACs with the given [Pbkdf2Prf].
+	#[cfg(feature = "unicode-normalization")]
+	pub fn to_seed_with<'a, P, Q>(&self, passphrase: Q, prf: &mut P) -> Result<[u8; 64], P::Error>
+	where
+		P: Pbkdf2Prf + ?Sized,
+		Q: Into<Cow<'a, str>>,
+	{
+		let normalized_passphrase = {
+			let mut cow = passphrase.into();
+			Mnemonic::normalize_utf8_cow(&mut cow);
+			cow
+		};
+		self.to_seed_normalized_with(normalized_passphrase.as_ref(), prf)
+	}
+
 	/// Convert the mnemonic back to the entropy used to generate it.
 	/// The return value is a byte array and the size.
-	/// Use [Mnemonic::to_entropy] (needs `std`) to get a [`Vec<u8>`].
+	/// This works directly on the word indices and doesn't allocate.
+	/// Use [Mnemonic::to_entropy] (needs `alloc`) to get a [`Vec<u8>`].
 	pub fn to_entropy_array(&self) -> ([u8; 33], usize) {
-		// We unwrap errors here because this method can only be called on
-		// values that were already previously validated.
-
-		let language = Mnemonic::language_of_iter(self.words()).unwrap();
-
 		// Preallocate enough space for the longest possible word list
 		let mut entropy = [0; 33];
-		let mut cursor = 0;
-		let mut offset = 0;
-		let mut remainder = 0;
-
-		let nb_words = self.word_count();
-		for word in self.words() {
-			let idx = language.find_word(word).expect("invalid mnemonic");
-
-			remainder |= ((idx as u32) << (32 - 11)) >> offset;
-			offset += 11;
-
-			while offset >= 8 {
-				entropy[cursor] = (remainder >> 24) as u8;
-				cursor += 1;
-				remainder <<= 8;
-				offset -= 8;
-			}
-		}
-
-		if offset != 0 {
-			entropy[cursor] = (remainder >> 24) as u8;
-		}
+		bits::pack(self.indices(), &mut entropy);
 
-		let entropy_bytes = (nb_words / 3) * 4;
+		let entropy_bytes = (self.word_count() / 3) * 4;
 		(entropy, entropy_bytes)
 	}
 
+	/// Convert the mnemonic back to the entropy used to generate it, writing
+	/// it at the start of the given buffer.
+	/// Returns the size of the entropy in bytes, or [Error::BufferTooSmall] if
+	/// it doesn't fit. 32 bytes are enough for any mnemonic.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use bip39::Mnemonic;
+	///
+	/// let mnemonic = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+	/// let mut buf = [0; 32];
+	/// let len = mnemonic.to_entropy_into(&mut buf).unwrap();
+	/// assert_eq!(&buf[..len], &[0x42; 16]);
+	/// ```
+	pub fn to_entropy_into(&self, out: &mut [u8]) -> Result<usize, Error> {
+		let entropy_bytes = (self.word_count() / 3) * 4;
+		let out = out.get_mut(..entropy_bytes).ok_or(Error::BufferTooSmall(entropy_bytes))?;
+		bits::pack(self.indices(), out);
+		Ok(entropy_bytes)
+	}
+
 	/// Convert the mnemonic back to the entropy used to generate it.
+	/// The returned vector is the only allocation, see [Mnemonic::to_entropy_array].
 	#[cfg(feature = "alloc")]
 	pub fn to_entropy(&self) -> Vec<u8> {
 		let (arr, len) = self.to_entropy_array();
-		arr[0..len].to_vec()
+		arr.iter().take(len).cloned().collect()
+	}
+
+	/// Consume the mnemonic and return its phrase.
+	///
+	/// The string is allocated with its exact length, so that no partial
+	/// copy of the phrase is left behind in memory by reallocations, and the
+	/// mnemonic itself is erased on drop with the `zeroize` or `wipe` feature.
+	/// Use [Mnemonic::into_phrase_zeroizing] to have the phrase erased too.
+	#[cfg(feature = "alloc")]
+	pub fn into_phrase(self) -> alloc::string::String {
+		let len = self.words().map(|w| w.len() + 1).sum::<usize>() - 1;
+		let mut phrase = alloc::string::String::with_capacity(len);
+		for (i, word) in self.words().enumerate() {
+			if i > 0 {
+				phrase.push(' ');
+			}
+			phrase.push_str(word);
+		}
+		phrase
+	}
+
+	/// Consume the mnemonic and return its phrase, in a string that is
+	/// zeroized on drop. See [Mnemonic::into_phrase].
+	#[cfg(all(feature = "alloc", feature = "zeroize"))]
+	pub fn into_phrase_zeroizing(self) -> zeroize::Zeroizing<alloc::string::String> {
+		zeroize::Zeroizing::new(self.into_phrase())
+	}
+
+	/// Encode the mnemonic as a `bip39:` URI, see [uri].
+	#[cfg(feature = "alloc")]
+	pub fn to_uri(&self) -> alloc::string::String {
+		use alloc::string::ToString;
+
+		uri::Uri::new(self.clone()).to_string()
+	}
+
+	/// Parse a mnemonic from a `bip39:` URI, ignoring the metadata. See [uri].
+	#[cfg(feature = "alloc")]
+	pub fn from_uri(s: &str) -> Result<Mnemonic, Error> {
+		s.parse::<uri::Uri>().map(uri::Uri::into_mnemonic)
 	}
 
 	/// Return checksum value for the Mnemonic.
@@ -634,19 +1926,71 @@ impl Mnemonic {
 	/// condition should hold.
 	pub fn checksum(&self) -> u8 {
 		let word_count = self.word_count();
-		let last_word = self.words[word_count - 1];
+		let last_word = self.indices().last().cloned().unwrap_or(0);
 		let mask = 0xFF >> (8 - word_count / 3);
 		last_word as u8 & mask
 	}
+
+	/// Check the checksum encoded by the last word against a fresh SHA256
+	/// digest of the entropy.
+	///
+	/// Like for [Mnemonic::checksum], this should hold for all `Mnemonic`
+	/// instances. It is intended for auditing tools that want to show the
+	/// checksum being recomputed, rather than trusting the validation done
+	/// at construction.
+	///
+	/// ```rust
+	/// # use bip39::Mnemonic;
+	/// let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+	/// assert_eq!(m.checksum(), 3); // the last word is "about"
+	/// assert!(m.verify_checksum());
+	/// ```
+	pub fn verify_checksum(&self) -> bool {
+		bits::strip_checksum(self.indices(), &mut []).is_ok()
+	}
+}
+
+impl PartialEq for Mnemonic {
+	fn eq(&self, other: &Mnemonic) -> bool {
+		// All the word slots are compared, the EOF padding included, so that
+		// the time doesn't depend on the words.
+		let diff = self.words.iter().zip(other.words.iter()).fold(0u16, |d, (a, b)| d | (a ^ b));
+		self.lang == other.lang && diff == 0
+	}
+}
+
+impl Eq for Mnemonic {}
+
+impl core::hash::Hash for Mnemonic {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.lang.hash(state);
+		self.words.hash(state);
+	}
+}
+
+impl fmt::Debug for Mnemonic {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Mnemonic")
+			.field("language", &self.lang)
+			.field("word_count", &self.word_count())
+			.finish()
+	}
 }
 
 impl fmt::Display for Mnemonic {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		for (i, word) in self.words().enumerate() {
-			if i > 0 {
-				f.write_str(" ")?;
+			if f.alternate() {
+				if i > 0 {
+					f.write_str("\n")?;
+				}
+				write!(f, "{:>2}. {}", i + 1, word)?;
+			} else {
+				if i > 0 {
+					f.write_str(" ")?;
+				}
+				f.write_str(word)?;
 			}
-			f.write_str(word)?;
 		}
 		Ok(())
 	}
@@ -667,29 +2011,422 @@ impl str::FromStr for Mnemonic {
 	}
 }
 
+impl<'a> TryFrom<&'a str> for Mnemonic {
+	type Error = Error;
+
+	/// Parse the mnemonic like [str::FromStr].
+	fn try_from(s: &'a str) -> Result<Mnemonic, Error> {
+		s.parse()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<alloc::string::String> for Mnemonic {
+	type Error = Error;
+
+	/// Parse the mnemonic like [str::FromStr].
+	fn try_from(s: alloc::string::String) -> Result<Mnemonic, Error> {
+		#[cfg(feature = "unicode-normalization")]
+		{
+			Mnemonic::parse(s)
+		}
+		#[cfg(not(feature = "unicode-normalization"))]
+		{
+			Mnemonic::parse_normalized(&s)
+		}
+	}
+}
+
+impl<'a> TryFrom<&'a [u8]> for Mnemonic {
+	type Error = Error;
+
+	/// Create an English mnemonic from the entropy, like [Mnemonic::from_entropy].
+	fn try_from(entropy: &'a [u8]) -> Result<Mnemonic, Error> {
+		Mnemonic::from_entropy(entropy)
+	}
+}
+
+/// Split a string into its individual non-whitespace characters.
+#[cfg(all(
+	feature = "unicode-normalization",
+	any(feature = "chinese-simplified", feature = "chinese-traditional")
+))]
+fn char_words(s: &str) -> impl Iterator<Item = &str> + Clone {
+	s.char_indices().filter(|(_, c)| !c.is_whitespace()).map(move |(i, c)| &s[i..i + c.len_utf8()])
+}
+
+/// Split a numbered or punctuated list into its words, skipping the numbers.
+fn list_words(s: &str) -> impl Iterator<Item = &str> + Clone {
+	s.split(|c: char| c.is_whitespace() || c.is_ascii_punctuation())
+		.filter(|w| !w.is_empty() && !w.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// The separator of the words of a mnemonic in its canonical form.
+fn canonical_separator(language: Language) -> char {
+	match language {
+		#[cfg(feature = "japanese")]
+		Language::Japanese => '\u{3000}',
+		_ => ' ',
+	}
+}
+
+/// Whether the word is in the canonical form of the word lists: in lowercase
+/// and, when it can be checked, normalized.
+fn is_canonical_word(word: &str) -> bool {
+	#[cfg(feature = "unicode-normalization")]
+	{
+		if !unicode_normalization::is_nfkd(word) {
+			return false;
+		}
+	}
+	!word.chars().any(char::is_uppercase)
+}
+
+/// Split the string into words separated by whitespace or any of the
+/// separators, skipping empty words.
+fn separated_words<'a>(
+	s: &'a str,
+	separators: &'a [char],
+) -> impl Iterator<Item = &'a str> + Clone + 'a {
+	s.split(move |c: char| c.is_whitespace() || separators.contains(&c)).filter(|w| !w.is_empty())
+}
+
+/// Look up the indices of the normalized words in the given language, without
+/// verifying the checksum. Returns the indices followed by [EOF] and the number
+/// of words.
+fn find_indices_in<'a, W>(
+	language: Language,
+	words: W,
+) -> Result<([u16; MAX_NB_WORDS], usize), Error>
+where
+	W: Iterator<Item = &'a str> + Clone,
+{
+	find_indices_with(words, |w| language.find_word(w))
+}
+
+/// Like [find_indices_in], with the given function to look up the words.
+fn find_indices_with<'a, W, F>(words: W, find: F) -> Result<([u16; MAX_NB_WORDS], usize), Error>
+where
+	W: Iterator<Item = &'a str> + Clone,
+	F: Fn(&str) -> Option<u16>,
+{
+	let nb_words = words.clone().count();
+	if is_invalid_word_count(nb_words) {
+		return Err(Error::BadWordCount(nb_words));
+	}
+
+	// Here we will store the eventual words.
+	let mut indices = [EOF; MAX_NB_WORDS];
+
+	for (i, (slot, word)) in indices.iter_mut().zip(words).enumerate() {
+		*slot = find(word).ok_or(Error::UnknownWord(i))?;
+	}
+	Ok((indices, nb_words))
+}
+
+/// Build a mnemonic from random entropy of the size of the array `E`, which
+/// is erased afterwards.
+#[cfg(any(feature = "rand", feature = "rand_v09"))]
+fn generate_array<E, F>(build: F) -> Mnemonic
+where
+	E: AsMut<[u8]> + Default,
+	F: FnOnce(&E) -> Mnemonic,
+{
+	let mut entropy = E::default();
+	fill_from_thread_rng(entropy.as_mut());
+	let mnemonic = build(&entropy);
+	#[cfg(any(feature = "wipe", feature = "zeroize"))]
+	wipe::wipe(entropy.as_mut());
+	mnemonic
+}
+
+/// Fill the buffer with randomness from the thread-local random number generator.
+#[cfg(any(feature = "rand", feature = "rand_v09"))]
+fn fill_from_thread_rng(entropy: &mut [u8]) {
+	#[cfg(feature = "rand")]
+	RngCore::fill_bytes(&mut rand::thread_rng(), entropy);
+	#[cfg(not(feature = "rand"))]
+	rand_core_0_9::RngCore::fill_bytes(&mut rand_0_9::rng(), entropy);
+}
+
+/// Compare two phrases word by word in a time that only depends on their
+/// lengths, as if the words were separated by single spaces.
+fn words_eq_ct<'a, 'b, A, B>(a: A, b: B) -> bool
+where
+	A: Iterator<Item = &'a str>,
+	B: Iterator<Item = &'b str>,
+{
+	let mut a = a.enumerate().flat_map(|(i, word)| {
+		let sep = if i > 0 {
+			Some(b' ')
+		} else {
+			None
+		};
+		sep.into_iter().chain(word.bytes())
+	});
+	let mut b = b.enumerate().flat_map(|(i, word)| {
+		let sep = if i > 0 {
+			Some(b' ')
+		} else {
+			None
+		};
+		sep.into_iter().chain(word.bytes())
+	});
+
+	let mut diff = 0u8;
+	loop {
+		match (a.next(), b.next()) {
+			(None, None) => break,
+			(x, y) => {
+				diff |= x.unwrap_or(0) ^ y.unwrap_or(0);
+				diff |= (x.is_some() != y.is_some()) as u8;
+			}
+		}
+	}
+	diff == 0
+}
+
+/// Decode a hex string, in lower or upper case, at the start of the buffer.
+///
+/// Returns the number of bytes, [Error::InvalidEncoding] if the string is not
+/// hex, or [Error::BadEntropyBitCount] with the number of bits of the string
+/// if it doesn't fit in the buffer.
+fn decode_hex(hex: &str, out: &mut [u8]) -> Result<usize, Error> {
+	fn digit(c: u8) -> Result<u8, Error> {
+		match c {
+			b'0'..=b'9' => Ok(c - b'0'),
+			b'a'..=b'f' => Ok(c - b'a' + 10),
+			b'A'..=b'F' => Ok(c - b'A' + 10),
+			_ => Err(Error::InvalidEncoding),
+		}
+	}
+
+	if hex.len() % 2 != 0 {
+		return Err(Error::InvalidEncoding);
+	}
+	let len = hex.len() / 2;
+	let out = out.get_mut(..len).ok_or(Error::BadEntropyBitCount(len * 8))?;
+	for (b, digits) in out.iter_mut().zip(hex.as_bytes().chunks(2)) {
+		let mut value = 0;
+		for c in digits {
+			value = (value << 4) | digit(*c)?;
+		}
+		*b = value;
+	}
+	Ok(len)
+}
+
+/// Compare two byte strings in a time that only depends on their lengths.
+fn bytes_eq_ct(a: &[u8], b: &[u8]) -> bool {
+	if a.len() != b.len() {
+		return false;
+	}
+	a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
 fn is_invalid_word_count(word_count: usize) -> bool {
 	word_count < MIN_NB_WORDS || word_count % 3 != 0 || word_count > MAX_NB_WORDS
 }
 
 #[cfg(test)]
+#[allow(
+	clippy::needless_borrow,
+	clippy::needless_borrows_for_generic_args,
+	clippy::needless_range_loop,
+	clippy::useless_vec,
+	clippy::expect_fun_call
+)]
 mod tests {
 	use super::*;
 
 	use bitcoin_hashes::hex::FromHex;
 
-	#[cfg(feature = "rand")]
+	#[cfg(feature = "rand")]
+	#[test]
+	fn test_language_of() {
+		for lang in Language::ALL {
+			let m = Mnemonic::generate_in(*lang, 24).unwrap();
+			assert_eq!(*lang, Mnemonic::language_of_iter(LanguageSet::all(), m.words()).unwrap());
+			assert_eq!(
+				*lang,
+				Mnemonic::language_of_iter(LanguageSet::all(), m.to_string().split_whitespace())
+					.unwrap()
+			);
+			assert_eq!(*lang, Mnemonic::language_of(m.to_string()).unwrap());
+			assert_eq!(*lang, Mnemonic::language_of(&m.to_string()).unwrap());
+		}
+	}
+
+	#[cfg(feature = "french")]
+	#[test]
+	fn test_language_of_with_default() {
+		// Words in both the English and French word lists.
+		let s = "abandon animal correct";
+		let ambiguous = match Mnemonic::language_of(s) {
+			Err(Error::AmbiguousLanguages(a)) => a.iter().collect::<Vec<_>>(),
+			res => panic!("unexpected result: {:?}", res),
+		};
+		assert_eq!(ambiguous, vec![Language::English, Language::French]);
+
+		assert_eq!(Mnemonic::language_of_with_default(Language::French, s), Ok(Language::French));
+		assert_eq!(Mnemonic::language_of_with_default(Language::English, s), Ok(Language::English));
+		assert_eq!(
+			Mnemonic::language_of_with_default(Language::English, "abaisser"),
+			Ok(Language::French)
+		);
+		#[cfg(feature = "spanish")]
+		assert!(Mnemonic::language_of_with_default(Language::Spanish, s).is_err());
+	}
+
+	#[cfg(feature = "french")]
+	#[test]
+	fn test_language_set() {
+		use Language::{English, French};
+
+		// Valid in both English and French.
+		let both = "volume innocent science phrase figure lecture intact concert guide fatigue sentence wagon";
+		let english = LanguageSet::from(English);
+		assert_eq!(Mnemonic::language_of_in_set(english, both), Ok(English));
+		let french = LanguageSet::from(French);
+		assert_eq!(Mnemonic::parse_normalized_in_set(french, both).unwrap().language(), French);
+		let both_set = english.with(French);
+		assert!(Mnemonic::language_of_in_set(both_set, both).is_err());
+
+		assert_eq!(Mnemonic::language_of_in_set(french, "zoo"), Err(Error::UnknownWord(0)));
+		assert_eq!(
+			Mnemonic::language_of_in_set(LanguageSet::new(), "abandon"),
+			Err(Error::UnknownWord(0))
+		);
+	}
+
+	#[cfg(feature = "french")]
+	#[test]
+	fn test_parse_with_priority() {
+		use Language::{English, French};
+
+		// Valid in both English and French.
+		let both = "volume innocent science phrase figure lecture intact concert guide fatigue sentence wagon";
+		// Only valid in French.
+		let french = "service surprise amateur caution miracle concert badge dragon fatigue salon mobile danger";
+
+		let (m, ambiguous) =
+			Mnemonic::parse_normalized_with_priority(both, &[French, English]).unwrap();
+		assert_eq!(m.language(), French);
+		assert_eq!(ambiguous.unwrap().iter().collect::<Vec<_>>(), vec![English, French]);
+		let (m, _) = Mnemonic::parse_normalized_with_priority(both, &[English, French]).unwrap();
+		assert_eq!(m.language(), English);
+
+		let (m, ambiguous) =
+			Mnemonic::parse_normalized_with_priority(french, &[English, French]).unwrap();
+		assert_eq!(m.language(), French);
+		assert!(ambiguous.is_some());
+		match Mnemonic::parse_normalized_with_priority(french, &[English]) {
+			Err(Error::InvalidChecksum) => {}
+			res => panic!("unexpected result: {:?}", res),
+		}
+		match Mnemonic::parse_normalized_with_priority(french, &[]) {
+			Err(Error::AmbiguousLanguages(_)) => {}
+			res => panic!("unexpected result: {:?}", res),
+		}
+
+		let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+		assert_eq!(
+			Mnemonic::parse_normalized_with_priority(&m.to_string(), &[French]),
+			Ok((m, None))
+		);
+	}
+
+	#[cfg(feature = "ascii-only")]
+	#[test]
+	fn test_ascii_only() {
+		let s = "letter advice cage absurd amount doctor acoustic avoid letter advice cage above";
+		let m = Mnemonic::parse_ascii(s).unwrap();
+		assert_eq!(Mnemonic::parse_ascii_in(Language::English, s), Ok(m.clone()));
+		assert_eq!(
+			Mnemonic::parse_ascii("letter advice cage absürd amount doctor"),
+			Err(Error::NonAsciiWord(3))
+		);
+		assert_eq!(
+			Mnemonic::parse_ascii_in(Language::English, "lettér"),
+			Err(Error::NonAsciiWord(0))
+		);
+
+		assert_eq!(m.to_seed_ascii("TREZOR"), Ok(m.to_seed_normalized("TREZOR")));
+		assert_eq!(m.to_seed_ascii("TRÉZOR"), Err(Error::InvalidPassphraseCharacter(2)));
+	}
+
+	#[test]
+	fn test_has_repeated_words() {
+		let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+		assert!(m.has_repeated_words());
+		let m = Mnemonic::parse_normalized(
+			"cat swing flag economy stadium alone churn speed unique patch report train",
+		)
+		.unwrap();
+		assert!(!m.has_repeated_words());
+	}
+
+	#[cfg(feature = "rand")]
+	#[test]
+	fn test_generate_distinct_words() {
+		let mut rng = rand::thread_rng();
+		for _ in 0..20 {
+			let m =
+				Mnemonic::generate_in_with_distinct_words(&mut rng, Language::English, 24).unwrap();
+			assert!(!m.has_repeated_words());
+		}
+	}
+
+	#[cfg(feature = "unicode-normalization")]
 	#[test]
-	fn test_language_of() {
-		for lang in Language::ALL {
-			let m = Mnemonic::generate_in(*lang, 24).unwrap();
-			assert_eq!(*lang, Mnemonic::language_of_iter(m.words()).unwrap());
-			assert_eq!(
-				*lang,
-				Mnemonic::language_of_iter(m.to_string().split_whitespace()).unwrap()
-			);
-			assert_eq!(*lang, Mnemonic::language_of(m.to_string()).unwrap());
-			assert_eq!(*lang, Mnemonic::language_of(&m.to_string()).unwrap());
+	fn test_normalize_utf8_cow_keeps_buffer() {
+		let s = "letter advice cage absurd amount doctor acoustic avoid letter advice cage above";
+		let owned = s.to_owned();
+		let ptr = owned.as_ptr();
+		let mut cow = Cow::Owned(owned);
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		match cow {
+			Cow::Owned(ref o) => assert_eq!(o.as_ptr(), ptr),
+			Cow::Borrowed(_) => panic!("owned string was replaced"),
+		}
+		let mut cow = Cow::Borrowed(s);
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		if let Cow::Owned(_) = cow {
+			panic!("borrowed string was copied");
 		}
+
+		let mut cow = Cow::Borrowed("lett\u{00e9}r");
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		assert_eq!(cow, "lette\u{0301}r");
+		assert!(Mnemonic::parse(s.to_owned()).is_ok());
+	}
+
+	#[test]
+	fn test_confirm() {
+		let m = Mnemonic::parse_normalized(
+			"cat swing flag economy stadium alone churn speed unique patch report train",
+		)
+		.unwrap();
+		assert!(m.confirm(&m.to_string()));
+		assert!(m.confirm(
+			"  cat swing flag economy stadium alone\n\tchurn speed unique patch report train\n"
+		));
+		assert!(!m.confirm("cat swing flag economy stadium alone churn speed unique patch report"));
+		assert!(!m.confirm(
+			"cat swing flag economy stadium alone churn speed unique patch report trains"
+		));
+		assert!(!m
+			.confirm("bat swing flag economy stadium alone churn speed unique patch report train"));
+		assert!(!m.confirm(""));
+
+		assert!(words_eq_ct(["a", "bc"].iter().cloned(), "a bc".split(' ')));
+		assert!(!words_eq_ct(["a", "bc"].iter().cloned(), "a bc\0".split(' ')));
+		assert!(!words_eq_ct(["a", "bc"].iter().cloned(), "abc".split(' ')));
+		assert!(!words_eq_ct(None.into_iter(), "\0".split(' ')));
+
+		assert!(bytes_eq_ct(b"abc", b"abc"));
+		assert!(!bytes_eq_ct(b"abc", b"abd"));
+		assert!(!bytes_eq_ct(b"abc", b"ab"));
 	}
 
 	#[cfg(feature = "std")]
@@ -716,6 +2453,25 @@ mod tests {
 		let _ = Mnemonic::generate(24).unwrap();
 		let _ = Mnemonic::generate_in(Language::English, 24).unwrap();
 		let _ = Mnemonic::generate_in_with(&mut rand::thread_rng(), Language::English, 24).unwrap();
+		assert_eq!(Mnemonic::generate_sized(WordCount::Words15).word_count(), 15);
+	}
+
+	#[cfg(feature = "rand_v09")]
+	#[test]
+	fn test_generate_v09() {
+		let m =
+			Mnemonic::generate_in_with_v09(&mut rand_0_9::rng(), Language::English, 24).unwrap();
+		assert_eq!(m.word_count(), 24);
+	}
+
+	#[cfg(feature = "rand")]
+	#[test]
+	fn test_generate_returning_entropy() {
+		for word_count in [12, 15, 18, 21, 24].iter() {
+			let (m, entropy) = Mnemonic::generate_returning_entropy(*word_count).unwrap();
+			assert_eq!(entropy.bit_len(), word_count / 3 * 32);
+			assert_eq!(m, Mnemonic::from_entropy_in(m.language(), entropy.as_bytes()).unwrap());
+		}
 	}
 
 	#[cfg(feature = "rand")]
@@ -953,9 +2709,350 @@ mod tests {
 			let digest = sha256::Hash::hash(&ent);
 			dbg!(digest);
 			assert_eq!(digest[0] >> (8 - word_count / 3), cs);
+			assert!(m.verify_checksum());
+		}
+	}
+
+	#[test]
+	fn test_word_at() {
+		let m = Mnemonic::parse_normalized(
+			"letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+		)
+		.unwrap();
+		for (i, word) in m.words().enumerate() {
+			assert_eq!(m.word_at(i), Some(word));
+		}
+		assert_eq!(m.word_at(m.word_count()), None);
+		assert_eq!(m.word_at(usize::max_value()), None);
+	}
+
+	#[test]
+	fn test_words_double_ended() {
+		let m = Mnemonic::from_entropy(&[0x42; 24]).unwrap();
+		let mut words = m.words();
+		assert_eq!(words.len(), 18);
+		assert_eq!(words.next_back(), m.word_at(17));
+		assert_eq!(words.next(), m.word_at(0));
+		assert_eq!(words.len(), 16);
+		let reversed = m.words().rev().collect::<Vec<_>>();
+		assert_eq!(reversed.len(), 18);
+		assert!(reversed.iter().rev().eq(m.words().collect::<Vec<_>>().iter()));
+		assert_eq!(m.word_indices().len(), 18);
+	}
+
+	#[test]
+	fn test_from_word_indices() {
+		let m = Mnemonic::from_entropy(&[0x42; 20]).unwrap();
+		let mut indices = m.word_indices().map(|i| i as u16).collect::<Vec<_>>();
+		assert_eq!(Mnemonic::from_word_indices_in(Language::English, &indices), Ok(m));
+
+		assert_eq!(
+			Mnemonic::from_word_indices_in(Language::English, &indices[1..]),
+			Err(Error::BadWordCount(14))
+		);
+		let original = indices[3];
+		indices[3] = 2048;
+		assert_eq!(
+			Mnemonic::from_word_indices_in(Language::English, &indices),
+			Err(Error::UnknownWord(3))
+		);
+		indices[3] = original ^ 1;
+		assert_eq!(
+			Mnemonic::from_word_indices_in(Language::English, &indices),
+			Err(Error::InvalidChecksum)
+		);
+	}
+
+	#[test]
+	fn test_to_entropy_into() {
+		let m = Mnemonic::from_entropy(&[0xff; 20]).unwrap();
+		let mut buf = [0; 21];
+		assert_eq!(m.to_entropy_into(&mut buf), Ok(20));
+		// The checksum is not written after the entropy.
+		assert_eq!(buf[..20], [0xff; 20]);
+		assert_eq!(buf[20], 0);
+		assert_eq!(m.to_entropy_into(&mut buf[..20]), Ok(20));
+		assert_eq!(m.to_entropy_into(&mut buf[..19]), Err(Error::BufferTooSmall(20)));
+	}
+
+	#[test]
+	fn test_try_from() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let s = m.to_string();
+		assert_eq!(Mnemonic::try_from(s.as_str()), Ok(m.clone()));
+		assert_eq!(Mnemonic::try_from(s.clone()), Ok(m.clone()));
+		assert_eq!(Mnemonic::try_from(&[0x42; 16][..]), Ok(m));
+		assert_eq!(Mnemonic::try_from(&s[8..]), Err(Error::BadWordCount(11)));
+		assert_eq!(Mnemonic::try_from(&[0x42; 15][..]), Err(Error::BadEntropyBitCount(120)));
+	}
+
+	#[test]
+	fn test_from_words_in() {
+		let m = Mnemonic::from_entropy(&[0x42; 28]).unwrap();
+		let words = m.words().collect::<Vec<_>>();
+		assert_eq!(Mnemonic::from_words_in(Language::English, &words), Ok(m.clone()));
+		assert_eq!(Mnemonic::from_words_in(Language::English, m.words()), Ok(m.clone()));
+		let owned = words.iter().map(|w| w.to_string()).collect::<Vec<_>>();
+		assert_eq!(Mnemonic::from_words_in(Language::English, owned), Ok(m));
+
+		assert_eq!(
+			Mnemonic::from_words_in(Language::English, &words[1..]),
+			Err(Error::BadWordCount(20))
+		);
+		assert_eq!(
+			Mnemonic::from_words_in(Language::English, words.iter().chain(&words)),
+			Err(Error::BadWordCount(42))
+		);
+		let mut typo = words.clone();
+		typo[5] = "bitcoin";
+		assert_eq!(Mnemonic::from_words_in(Language::English, &typo), Err(Error::UnknownWord(5)));
+		typo[5] = words[6];
+		assert_eq!(Mnemonic::from_words_in(Language::English, &typo), Err(Error::InvalidChecksum));
+	}
+
+	#[test]
+	fn test_display_alternate() {
+		let m = Mnemonic::parse_normalized(
+			"letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+		)
+		.unwrap();
+		let table = format!("{:#}", m);
+		assert_eq!(table.lines().count(), 12);
+		assert!(table.starts_with(" 1. letter\n 2. advice\n"));
+		assert!(table.ends_with("\n10. advice\n11. cage\n12. above"));
+		assert_eq!(format!("{}", m).split(' ').count(), 12);
+	}
+
+	#[test]
+	fn test_debug_redacted() {
+		let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+		assert_eq!(format!("{:?}", m), "Mnemonic { language: English, word_count: 12 }");
+		let m = Mnemonic24::from_entropy(&[0; 32]);
+		assert!(!format!("{:?}", m).contains("abandon"));
+		assert!(!format!("{:#?}", m).contains("abandon"));
+	}
+
+	#[test]
+	fn test_eq() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		assert_eq!(m, Mnemonic::from_entropy(&[0x42; 16]).unwrap());
+		assert_ne!(m, Mnemonic::from_entropy(&[0x43; 16]).unwrap());
+		assert_ne!(m, Mnemonic::from_entropy(&[0x42; 20]).unwrap());
+		#[cfg(feature = "french")]
+		assert_ne!(m, Mnemonic::from_entropy_in(Language::French, &[0x42; 16]).unwrap());
+
+		#[cfg(any(feature = "rand", feature = "rand_v09"))]
+		{
+			let (m, entropy) = Mnemonic::generate_returning_entropy(12).unwrap();
+			let (_, other) = Mnemonic::generate_returning_entropy(12).unwrap();
+			assert_eq!(entropy, Entropy::from_slice(&m.to_entropy()));
+			assert_ne!(entropy, other);
+		}
+	}
+
+	#[test]
+	fn test_from_entropy_hex() {
+		let hex = "9e885d952ad362caeb4efe34a8e91bd2";
+		let m = Mnemonic::from_entropy(&Vec::<u8>::from_hex(hex).unwrap()).unwrap();
+		assert_eq!(Mnemonic::from_entropy_hex(hex), Ok(m.clone()));
+		assert_eq!(Mnemonic::from_entropy_hex(&hex.to_uppercase()), Ok(m));
+		assert_eq!(Mnemonic::from_entropy_hex(&hex[1..]), Err(Error::InvalidEncoding));
+		assert_eq!(Mnemonic::from_entropy_hex(&hex[2..]), Err(Error::BadEntropyBitCount(120)));
+		assert_eq!(Mnemonic::from_entropy_hex(&hex.replace('e', "g")), Err(Error::InvalidEncoding));
+		assert_eq!(Mnemonic::from_entropy_hex(&hex.repeat(3)), Err(Error::BadEntropyBitCount(384)));
+		assert_eq!(Mnemonic::from_entropy_hex("+1"), Err(Error::InvalidEncoding));
+	}
+
+	#[test]
+	fn test_into_phrase() {
+		for len in &[16, 20, 24, 28, 32] {
+			let m = Mnemonic::from_entropy(&[0x42; 32][..*len]).unwrap();
+			let s = m.to_string();
+			let phrase = m.into_phrase();
+			assert_eq!(phrase, s);
+			assert_eq!(phrase.capacity(), phrase.len());
+		}
+		#[cfg(feature = "zeroize")]
+		{
+			let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+			assert_eq!(*m.clone().into_phrase_zeroizing(), m.to_string());
+		}
+	}
+
+	#[test]
+	fn test_parse_strict() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let s = m.to_string();
+		assert_eq!(Mnemonic::parse_in_strict(Language::English, &s), Ok(m));
+
+		for (bad, pos) in &[
+			(format!(" {}", s), 0),
+			(format!("{} ", s), s.len()),
+			(s.replacen(' ', "  ", 1), s.find(' ').unwrap() + 1),
+			(s.replacen(' ', "\t", 1), s.find(' ').unwrap()),
+			(s.replacen(' ', "\n", 1), s.find(' ').unwrap()),
+			(s.replacen(' ', "\u{3000}", 1), s.find(' ').unwrap()),
+		] {
+			assert_eq!(
+				Mnemonic::parse_in_strict(Language::English, bad),
+				Err(Error::InvalidSeparator(*pos))
+			);
+			assert!(Mnemonic::parse_in_normalized(Language::English, bad).is_ok());
+		}
+		let upper = s.to_uppercase();
+		assert_eq!(
+			Mnemonic::parse_in_strict(Language::English, &upper),
+			Err(Error::NonCanonicalWord(0))
+		);
+		let capitalized = s.replacen("category", "Category", 1);
+		assert_ne!(capitalized, s);
+		let index = s.split(' ').position(|w| w == "category").unwrap();
+		assert_eq!(
+			Mnemonic::parse_in_strict(Language::English, &capitalized),
+			Err(Error::NonCanonicalWord(index))
+		);
+		let unknown = s.replacen("category", "categry", 1);
+		assert_eq!(
+			Mnemonic::parse_in_strict(Language::English, &unknown),
+			Err(Error::UnknownWord(index))
+		);
+		assert_eq!(Mnemonic::parse_in_strict(Language::English, ""), Err(Error::BadWordCount(0)));
+
+		#[cfg(feature = "japanese")]
+		{
+			let m = Mnemonic::from_entropy_in(Language::Japanese, &[0x42; 16]).unwrap();
+			let canonical = m.words().collect::<Vec<_>>().join("\u{3000}");
+			assert_eq!(Mnemonic::parse_in_strict(Language::Japanese, &canonical), Ok(m.clone()));
+			assert!(Mnemonic::parse_in_strict(Language::Japanese, &m.to_string()).is_err());
 		}
 	}
 
+	#[test]
+	fn test_parse_lenient() {
+		let m = Mnemonic::from_entropy(&[0x42; 24]).unwrap();
+		let messy = m
+			.words()
+			.enumerate()
+			.map(|(i, w)| match i % 3 {
+				0 => w.to_uppercase(),
+				1 => format!("\t{}\n", w),
+				_ => format!("  {}", w[..1].to_uppercase() + &w[1..]),
+			})
+			.collect::<Vec<_>>()
+			.join(" ");
+		assert!(Mnemonic::parse_normalized(&messy).is_err());
+		assert_eq!(Mnemonic::parse_in_lenient(Language::English, &messy), Ok(m.clone()));
+		assert_eq!(Mnemonic::parse_lenient(&messy), Ok(m));
+		assert_eq!(Mnemonic::parse_lenient("Abandon Zoo"), Err(Error::BadWordCount(2)));
+	}
+
+	#[test]
+	fn test_parse_list() {
+		let m = Mnemonic::from_entropy(&[0x42; 20]).unwrap();
+		let words = m.words().collect::<Vec<_>>();
+		let formats = [
+			format!("{:#}", m),
+			words
+				.iter()
+				.enumerate()
+				.map(|(i, w)| format!("{}) {}\n", i + 1, w))
+				.collect::<String>(),
+			words.iter().enumerate().map(|(i, w)| format!("#{}:{}\r\n", i + 1, w)).collect(),
+			words.join(", "),
+			words.join(";"),
+		];
+		for list in formats.iter() {
+			assert_eq!(Mnemonic::parse_in_normalized_list(Language::English, list), Ok(m.clone()));
+			#[cfg(feature = "unicode-normalization")]
+			assert_eq!(Mnemonic::parse_list(list.as_str()), Ok(m.clone()));
+		}
+
+		// The language is resolved like with Mnemonic::parse.
+		#[cfg(feature = "unicode-normalization")]
+		assert_eq!(
+			Mnemonic::parse_list("1. abandon 2. animal 3. correct"),
+			Mnemonic::parse("abandon animal correct")
+		);
+
+		let list = format!("{:#}", m).replacen(words[2], "bitcoin", 1);
+		assert_eq!(
+			Mnemonic::parse_in_normalized_list(Language::English, &list),
+			Err(Error::UnknownWord(2))
+		);
+		let list = format!("{:#}", m).replacen(" 3.", " 3. 4", 1);
+		assert_eq!(Mnemonic::parse_in_normalized_list(Language::English, &list), Ok(m));
+	}
+
+	#[test]
+	fn test_parse_abbreviated() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let abbreviated =
+			m.words().map(|w| w.chars().take(4).collect::<String>()).collect::<Vec<_>>().join(" ");
+		assert_eq!(
+			Mnemonic::parse_in_normalized_abbreviated(Language::English, &abbreviated),
+			Ok(m.clone())
+		);
+		assert_eq!(
+			Mnemonic::parse_in_normalized_abbreviated(Language::English, &m.to_string()),
+			Ok(m.clone())
+		);
+		let mixed = m.words().enumerate().map(|(i, w)| {
+			if i % 2 == 0 {
+				w
+			} else {
+				&w[..w.len().min(5)]
+			}
+		});
+		assert_eq!(
+			Mnemonic::parse_in_normalized_abbreviated(
+				Language::English,
+				&mixed.collect::<Vec<_>>().join(" ")
+			),
+			Ok(m)
+		);
+
+		// All English words are identified by their first 4 letters.
+		for (i, word) in Language::English.word_list().iter().enumerate() {
+			let prefix = word.chars().take(4).collect::<String>();
+			assert_eq!(Language::English.find_abbreviated_word(&prefix), Some(i as u16));
+		}
+
+		let s = "aban aban aban aban aban aban aban aban aban aban aban abo";
+		assert_eq!(
+			Mnemonic::parse_in_normalized_abbreviated(Language::English, s),
+			Err(Error::UnknownWord(11))
+		);
+		assert!(
+			Mnemonic::parse_in_normalized(Language::English, &s.replace("abo", "abou")).is_err()
+		);
+	}
+
+	#[test]
+	fn test_word_bits() {
+		let m = Mnemonic::parse_normalized(
+			"letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+		)
+		.unwrap();
+		let patterns = m.word_bits().collect::<Vec<_>>();
+		// "letter" has index 1028.
+		assert_eq!(
+			patterns[0],
+			[true, false, false, false, false, false, false, false, true, false, false]
+		);
+		assert_eq!(Mnemonic::from_word_bits_in(Language::English, &patterns), Ok(m));
+
+		let mut damaged = patterns.clone();
+		damaged[11][10] = !damaged[11][10];
+		assert_eq!(
+			Mnemonic::from_word_bits_in(Language::English, &damaged),
+			Err(Error::InvalidChecksum)
+		);
+		assert_eq!(
+			Mnemonic::from_word_bits_in(Language::English, &patterns[..11]),
+			Err(Error::BadWordCount(11))
+		);
+	}
+
 	#[test]
 	fn test_invalid_engish() {
 		// correct phrase:
@@ -990,6 +3087,237 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_no_panic_on_garbage() {
+		// A simple xorshift generator keeps this test deterministic.
+		let mut state = 0x2545f4914f6cdd1du64;
+		let mut next = move || {
+			state ^= state << 13;
+			state ^= state >> 7;
+			state ^= state << 17;
+			state
+		};
+
+		let list = Language::English.word_list();
+		for _ in 0..1000 {
+			let mut phrase = String::new();
+			for _ in 0..next() % 30 {
+				let r = next();
+				match r % 8 {
+					0 => phrase.push_str("notaword"),
+					1 => phrase.push(char::from_u32((r >> 32) as u32 % 0x30000).unwrap_or('\0')),
+					_ => phrase.push_str(list[(r >> 8) as usize % list.len()]),
+				}
+				phrase.push(if r % 7 == 0 {
+					'\u{3000}'
+				} else {
+					' '
+				});
+			}
+
+			let _ = Mnemonic::parse_normalized(&phrase);
+			#[cfg(feature = "unicode-normalization")]
+			let _ = Mnemonic::parse(phrase.as_str());
+			if let Ok(m) = Mnemonic::parse_in_normalized(Language::English, &phrase) {
+				let _ = m.to_entropy_array();
+				let _ = m.to_seed_normalized(&phrase);
+				let _ = m.checksum();
+			}
+		}
+
+		for len in 0..64 {
+			let entropy = (0..len).map(|_| next() as u8).collect::<Vec<_>>();
+			if let Ok(m) = Mnemonic::from_entropy(&entropy) {
+				assert_eq!(m.to_entropy(), entropy);
+				let _ = m.to_seed_normalized("");
+			}
+		}
+	}
+
+	#[cfg(feature = "zeroize")]
+	#[test]
+	fn test_to_seed_zeroizing() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		assert_eq!(*m.to_seed_normalized_zeroizing("TREZOR"), m.to_seed_normalized("TREZOR"));
+		#[cfg(feature = "unicode-normalization")]
+		assert_eq!(*m.to_seed_zeroizing("caf\u{e9}"), m.to_seed("caf\u{e9}"));
+
+		#[cfg(any(feature = "rand", feature = "rand_v09"))]
+		{
+			let (_, mut entropy) = Mnemonic::generate_returning_entropy(12).unwrap();
+			zeroize::Zeroize::zeroize(&mut entropy);
+			assert_eq!(entropy.bit_len(), 0);
+		}
+	}
+
+	#[test]
+	fn test_to_seed_hex() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let hex = m.to_seed_hex("caf\u{e9}");
+		assert_eq!(Vec::<u8>::from_hex(&hex).unwrap(), &m.to_seed("caf\u{e9}")[..]);
+		assert_eq!(hex.parse::<Seed>(), Ok(Seed::from(m.to_seed("caf\u{e9}"))));
+	}
+
+	#[test]
+	fn test_to_seed_into() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let mut seed = [0xff; 64];
+		m.to_seed_normalized_into("TREZOR", &mut seed);
+		assert_eq!(seed, m.to_seed_normalized("TREZOR"));
+		#[cfg(feature = "unicode-normalization")]
+		{
+			m.to_seed_into("caf\u{e9}", &mut seed);
+			assert_eq!(seed, m.to_seed_normalized("caf\u{65}\u{301}"));
+		}
+	}
+
+	#[test]
+	fn test_verify_seed() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let seed = m.to_seed_normalized("caf\u{65}\u{301}");
+		assert!(m.verify_seed("caf\u{65}\u{301}", &seed));
+		#[cfg(feature = "unicode-normalization")]
+		assert!(m.verify_seed("caf\u{e9}", &seed));
+		assert!(!m.verify_seed("", &seed));
+		assert!(!m.verify_seed("caf\u{65}\u{301}", &seed[..32]));
+		let other = Mnemonic::from_entropy(&[0x43; 32]).unwrap();
+		assert!(!other.verify_seed("caf\u{65}\u{301}", &seed));
+	}
+
+	#[test]
+	fn test_to_seed_strict() {
+		let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+		assert_eq!(m.to_seed_strict("TREZOR"), Ok(m.to_seed_normalized("TREZOR")));
+		assert_eq!(m.to_seed_strict("TR\u{c9}ZOR"), Err(Error::InvalidPassphraseCharacter(2)));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_estimate_seed_duration() {
+		let estimate = Mnemonic::estimate_seed_duration();
+		assert!(estimate > std::time::Duration::from_secs(0));
+		assert!(estimate < std::time::Duration::from_secs(60));
+	}
+
+	#[test]
+	fn test_to_seed_32() {
+		let m = Mnemonic::parse(
+			"letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+		)
+		.unwrap();
+		let seed = m.to_seed_normalized("TREZOR");
+		let seed_32 = m.to_seed_32_normalized("TREZOR", b"example");
+		assert_ne!(&seed_32[..], &seed[..32]);
+		assert_eq!(
+			&seed_32[..],
+			&Vec::<u8>::from_hex(
+				"c39d53f1a79d4cad9fd334dc48197aaebd7f973d484fa839339679877f99bee4"
+			)
+			.unwrap()[..]
+		);
+		assert_ne!(seed_32, m.to_seed_32_normalized("TREZOR", b"other"));
+		assert_ne!(seed_32, m.to_seed_32_normalized("", b"example"));
+		assert_eq!(seed_32, m.to_seed_32("TREZOR", b"example"));
+	}
+
+	#[test]
+	fn test_to_seed_with() {
+		/// A PRF that computes whole blocks itself, counting the HMACs
+		/// requested from outside.
+		struct BlockPrf {
+			key: Vec<u8>,
+			nb_computes: usize,
+		}
+
+		impl BlockPrf {
+			fn hmac(&self, message: &[&[u8]]) -> [u8; 64] {
+				let mut engine = hmac::HmacEngine::<bitcoin_hashes::sha512::Hash>::new(&self.key);
+				for part in message {
+					engine.input(part);
+				}
+				hmac::Hmac::from_engine(engine).to_byte_array()
+			}
+		}
+
+		impl Pbkdf2Prf for BlockPrf {
+			type Error = ();
+
+			fn set_key(&mut self, key: &[&[u8]]) -> Result<(), ()> {
+				self.key = key.concat();
+				Ok(())
+			}
+
+			fn compute(&mut self, message: &[&[u8]]) -> Result<[u8; 64], ()> {
+				self.nb_computes += 1;
+				Ok(self.hmac(message))
+			}
+
+			fn compute_block(
+				&mut self,
+				message: &[&[u8]],
+				iterations: usize,
+			) -> Result<[u8; 64], ()> {
+				let mut u = self.hmac(message);
+				let mut block = u;
+				for _ in 1..iterations {
+					u = self.hmac(&[&u]);
+					block.iter_mut().zip(u.iter()).for_each(|(b, u)| *b ^= u);
+				}
+				Ok(block)
+			}
+		}
+
+		let m = Mnemonic::parse(
+			"letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+		)
+		.unwrap();
+		let mut prf = BlockPrf {
+			key: Vec::new(),
+			nb_computes: 0,
+		};
+		assert_eq!(
+			m.to_seed_normalized_with("TREZOR", &mut prf),
+			Ok(m.to_seed_normalized("TREZOR"))
+		);
+		assert_eq!(prf.nb_computes, 0);
+	}
+
+	#[test]
+	fn test_word_count() {
+		for nb_words in (MIN_NB_WORDS..=MAX_NB_WORDS).step_by(3) {
+			let m = Mnemonic::from_entropy(&[0xff; 32][..nb_words / 3 * 4]).unwrap();
+			assert_eq!(m.word_count(), nb_words);
+			assert_eq!(m.word_indices().count(), nb_words);
+			assert_eq!(m.words().count(), nb_words);
+		}
+	}
+
+	#[test]
+	fn test_fixed_entropy_constructors() {
+		let entropy = [0x7f; 32];
+		assert_eq!(
+			Mnemonic::from_entropy_16(&[0x7f; 16]),
+			Mnemonic::from_entropy(&entropy[..16]).unwrap()
+		);
+		assert_eq!(
+			Mnemonic::from_entropy_20(&[0x7f; 20]),
+			Mnemonic::from_entropy(&entropy[..20]).unwrap()
+		);
+		assert_eq!(
+			Mnemonic::from_entropy_24(&[0x7f; 24]),
+			Mnemonic::from_entropy(&entropy[..24]).unwrap()
+		);
+		assert_eq!(
+			Mnemonic::from_entropy_28(&[0x7f; 28]),
+			Mnemonic::from_entropy(&entropy[..28]).unwrap()
+		);
+		assert_eq!(Mnemonic::from_entropy_32(&entropy), Mnemonic::from_entropy(&entropy).unwrap());
+		assert_eq!(
+			Mnemonic::from_entropy_32_in(Language::English, &entropy),
+			Mnemonic::from_entropy(&entropy).unwrap()
+		);
+	}
+
 	#[test]
 	fn test_invalid_entropy() {
 		//between 128 and 256 bits, but not divisible by 32
@@ -1002,6 +3330,73 @@ mod tests {
 		assert_eq!(Mnemonic::from_entropy(&vec![b'x'; 36]), Err(Error::BadEntropyBitCount(288)));
 	}
 
+	#[cfg(all(feature = "chinese-simplified", feature = "chinese-traditional", feature = "std"))]
+	#[test]
+	fn test_parse_unseparated_chinese() {
+		let entropy =
+			Vec::<u8>::from_hex("68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787").unwrap();
+		for &lang in &[Language::SimplifiedChinese, Language::TraditionalChinese] {
+			let mnemonic = Mnemonic::from_entropy_in(lang, &entropy).unwrap();
+			let joined = mnemonic.to_string().replace(' ', "");
+			assert_eq!(joined.chars().count(), 18);
+
+			assert_eq!(mnemonic, Mnemonic::parse_in_unseparated(lang, joined.as_str()).unwrap());
+			assert_eq!(mnemonic, Mnemonic::parse_unseparated(joined.as_str()).unwrap());
+			// Stray whitespace is ignored.
+			let spaced = format!(" {}\n{} ", &joined[..9], &joined[9..]);
+			assert_eq!(mnemonic, Mnemonic::parse_in_unseparated(lang, spaced).unwrap());
+		}
+
+		let short: String = Language::SimplifiedChinese.word_list()[..11].concat();
+		assert_eq!(
+			Mnemonic::parse_in_unseparated(Language::SimplifiedChinese, short),
+			Err(Error::BadWordCount(11))
+		);
+	}
+
+	#[test]
+	fn test_parse_with_separators() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let words = m.words().collect::<Vec<_>>();
+
+		let csv = words.join(",");
+		assert_eq!(
+			Mnemonic::parse_in_normalized_with_separators(Language::English, &csv, &[',']),
+			Ok(m.clone())
+		);
+		let messy = format!("{}, {}\n", words[..6].join("-"), words[6..].join("/-"));
+		assert_eq!(
+			Mnemonic::parse_in_normalized_with_separators(
+				Language::English,
+				&messy,
+				&['-', '/', ',']
+			),
+			Ok(m.clone())
+		);
+		assert_eq!(
+			Mnemonic::parse_in_normalized_with_separators(Language::English, &csv, &['-']),
+			Err(Error::BadWordCount(1))
+		);
+
+		#[cfg(feature = "unicode-normalization")]
+		{
+			// The fullwidth comma is normalized to a comma.
+			let fullwidth = words.join("\u{ff0c}");
+			assert_eq!(
+				Mnemonic::parse_in_with_separators(Language::English, fullwidth.as_str(), &[',']),
+				Ok(m.clone())
+			);
+			assert_eq!(Mnemonic::parse_with_separators(fullwidth, &[',']), Ok(m));
+
+			// The language is resolved like with Mnemonic::parse.
+			let ambiguous = "abandon,animal,correct";
+			assert_eq!(
+				Mnemonic::parse_with_separators(ambiguous, &[',']),
+				Mnemonic::parse(ambiguous.replace(',', " "))
+			);
+		}
+	}
+
 	#[cfg(all(feature = "japanese", feature = "std"))]
 	#[test]
 	fn test_vectors_japanese() {
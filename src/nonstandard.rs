@@ -0,0 +1,248 @@
+//! Mnemonics with more than 256 bits of entropy, which are **not** standard
+//! BIP39 mnemonics.
+//!
+//! Some legacy and experimental wallets created phrases of 27 words or more,
+//! extending the BIP39 rules: the checksum is one bit for every 32 bits of
+//! entropy, and the seed is derived from the phrase like for any mnemonic.
+//! [ExtendedMnemonic] decodes and re-derives such backups, for entropy that is
+//! a multiple of 32 bits between 128 and 8192 bits. [crate::Mnemonic] keeps
+//! rejecting them, and so does the [crate::recovery] module, which only
+//! searches standard mnemonics.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::nonstandard::ExtendedMnemonic;
+//!
+//! let m = ExtendedMnemonic::from_entropy(&[0; 40]).unwrap();
+//! assert_eq!(m.word_count(), 30);
+//! let parsed = ExtendedMnemonic::parse_normalized(&m.to_string()).unwrap();
+//! assert_eq!(parsed.to_entropy(), vec![0; 40]);
+//! assert!(parsed.to_standard().is_none());
+//! ```
+
+#[cfg(feature = "unicode-normalization")]
+use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::bits::{self, BITS_PER_WORD};
+use crate::{pbkdf2, Error, Language, Mnemonic, MIN_NB_WORDS};
+
+/// The maximum length of the entropy in bytes, with a checksum of a whole
+/// SHA-256 hash.
+const MAX_ENTROPY_LEN: usize = 1024;
+
+/// A mnemonic of any length allowed by the extended BIP39 rules.
+///
+/// Like for [Mnemonic], the [fmt::Debug] output doesn't show the words.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ExtendedMnemonic {
+	language: Language,
+	/// The indices of the words.
+	words: Vec<u16>,
+}
+
+impl ExtendedMnemonic {
+	/// Create a mnemonic in the given language from the entropy.
+	///
+	/// Returns [Error::BadEntropyBitCount] if the entropy is not a multiple of
+	/// 32 bits between 128 and 8192 bits.
+	pub fn from_entropy_in(language: Language, entropy: &[u8]) -> Result<ExtendedMnemonic, Error> {
+		if entropy.len() < MIN_NB_WORDS / 3 * 4 || entropy.len() > MAX_ENTROPY_LEN {
+			return Err(Error::BadEntropyBitCount(entropy.len() * 8));
+		}
+
+		let mut words =
+			vec![0; (entropy.len() * 8 + bits::checksum_bits(entropy.len())) / BITS_PER_WORD];
+		bits::append_checksum(entropy, &mut words)?;
+		Ok(ExtendedMnemonic {
+			language,
+			words,
+		})
+	}
+
+	/// Create a mnemonic in English from the entropy.
+	pub fn from_entropy(entropy: &[u8]) -> Result<ExtendedMnemonic, Error> {
+		ExtendedMnemonic::from_entropy_in(Language::English, entropy)
+	}
+
+	/// Parse a mnemonic in normalized UTF8 in the given language.
+	///
+	/// Returns [Error::BadWordCount] if the number of words is not a multiple
+	/// of 3 between 12 and 768.
+	pub fn parse_in_normalized(language: Language, s: &str) -> Result<ExtendedMnemonic, Error> {
+		let nb_words = s.split_whitespace().count();
+		if nb_words < MIN_NB_WORDS || nb_words % 3 != 0 || nb_words > max_nb_words() {
+			return Err(Error::BadWordCount(nb_words));
+		}
+
+		let mut words = Vec::with_capacity(nb_words);
+		for (i, word) in s.split_whitespace().enumerate() {
+			let idx = language.find_word(word).ok_or(Error::UnknownWord(i))?;
+			words.push(idx);
+		}
+		bits::strip_checksum(&words, &mut [])?;
+		Ok(ExtendedMnemonic {
+			language,
+			words,
+		})
+	}
+
+	/// Parse a mnemonic in normalized UTF8 and detect the language from the
+	/// enabled languages.
+	pub fn parse_normalized(s: &str) -> Result<ExtendedMnemonic, Error> {
+		let language = Mnemonic::language_of(s)?;
+		ExtendedMnemonic::parse_in_normalized(language, s)
+	}
+
+	/// Parse a mnemonic in the given language.
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse_in<'a, S: Into<Cow<'a, str>>>(
+		language: Language,
+		s: S,
+	) -> Result<ExtendedMnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		ExtendedMnemonic::parse_in_normalized(language, cow.as_ref())
+	}
+
+	/// Parse a mnemonic and detect the language from the enabled languages.
+	#[cfg(feature = "unicode-normalization")]
+	pub fn parse<'a, S: Into<Cow<'a, str>>>(s: S) -> Result<ExtendedMnemonic, Error> {
+		let mut cow = s.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		ExtendedMnemonic::parse_normalized(cow.as_ref())
+	}
+
+	/// The language of the mnemonic.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// The number of words of the mnemonic.
+	pub fn word_count(&self) -> usize {
+		self.words.len()
+	}
+
+	/// Returns an iterator over the words of the mnemonic.
+	pub fn words(&self) -> impl Iterator<Item = &'static str> + Clone + '_ {
+		let list = self.language.word_list();
+		self.words.iter().map(move |i| list.get(*i as usize).copied().unwrap_or(""))
+	}
+
+	/// Convert the mnemonic back to the entropy used to generate it.
+	pub fn to_entropy(&self) -> Vec<u8> {
+		// The checksum was verified on construction, the entropy is the
+		// leading bits of the words.
+		let mut entropy = vec![0; self.words.len() * BITS_PER_WORD / 33 * 32 / 8];
+		bits::pack(&self.words, &mut entropy);
+		entropy
+	}
+
+	/// Convert to seed bytes with a passphrase in normalized UTF8.
+	pub fn to_seed_normalized(&self, normalized_passphrase: &str) -> [u8; 64] {
+		const PBKDF2_ROUNDS: usize = 2048;
+
+		let mut seed = [0u8; 64];
+		pbkdf2::pbkdf2(self.words(), normalized_passphrase.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+		seed
+	}
+
+	/// Convert to seed bytes.
+	#[cfg(feature = "unicode-normalization")]
+	pub fn to_seed<'a, P: Into<Cow<'a, str>>>(&self, passphrase: P) -> [u8; 64] {
+		let mut cow = passphrase.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		self.to_seed_normalized(cow.as_ref())
+	}
+
+	/// Convert to a standard [Mnemonic], or `None` if it has more than 24
+	/// words.
+	pub fn to_standard(&self) -> Option<Mnemonic> {
+		Mnemonic::from_indices_in(self.language, &self.words).ok()
+	}
+}
+
+impl From<Mnemonic> for ExtendedMnemonic {
+	fn from(mnemonic: Mnemonic) -> ExtendedMnemonic {
+		ExtendedMnemonic {
+			language: mnemonic.language(),
+			words: mnemonic.indices().to_vec(),
+		}
+	}
+}
+
+impl fmt::Debug for ExtendedMnemonic {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("ExtendedMnemonic")
+			.field("language", &self.language)
+			.field("word_count", &self.word_count())
+			.finish()
+	}
+}
+
+impl fmt::Display for ExtendedMnemonic {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (i, word) in self.words().enumerate() {
+			if i > 0 {
+				f.write_str(" ")?;
+			}
+			f.write_str(word)?;
+		}
+		Ok(())
+	}
+}
+
+/// The number of words of a mnemonic with the longest entropy.
+fn max_nb_words() -> usize {
+	(MAX_ENTROPY_LEN * 8 + bits::checksum_bits(MAX_ENTROPY_LEN)) / BITS_PER_WORD
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_roundtrip() {
+		for len in (16..=MAX_ENTROPY_LEN).step_by(4) {
+			let entropy = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+			let m = ExtendedMnemonic::from_entropy(&entropy).unwrap();
+			assert_eq!(m.word_count(), len * 3 / 4);
+			assert_eq!(m.to_entropy(), entropy);
+			let parsed = ExtendedMnemonic::parse_in_normalized(Language::English, &m.to_string());
+			assert_eq!(parsed, Ok(m.clone()));
+
+			if len <= 32 {
+				let standard = Mnemonic::from_entropy(&entropy).unwrap();
+				assert_eq!(m.to_standard(), Some(standard.clone()));
+				assert_eq!(m.to_seed_normalized("x"), standard.to_seed_normalized("x"));
+				assert_eq!(ExtendedMnemonic::from(standard), m);
+			} else {
+				assert_eq!(m.to_standard(), None);
+			}
+		}
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_eq!(ExtendedMnemonic::from_entropy(&[0; 12]), Err(Error::BadEntropyBitCount(96)));
+		assert_eq!(ExtendedMnemonic::from_entropy(&[0; 34]), Err(Error::BadEntropyBitCount(272)));
+		assert_eq!(
+			ExtendedMnemonic::from_entropy(&[0; MAX_ENTROPY_LEN + 4]),
+			Err(Error::BadEntropyBitCount(8224))
+		);
+
+		let m = ExtendedMnemonic::from_entropy(&[0xff; 64]).unwrap();
+		let s = m.to_string();
+		assert_eq!(ExtendedMnemonic::parse_normalized(&s[4..]), Err(Error::BadWordCount(47)));
+		let wrong = s.replacen("zoo", "abandon", 1);
+		assert_eq!(ExtendedMnemonic::parse_normalized(&wrong), Err(Error::InvalidChecksum));
+		let too_long = "zoo ".repeat(max_nb_words() + 3);
+		assert_eq!(
+			ExtendedMnemonic::parse_normalized(&too_long),
+			Err(Error::BadWordCount(max_nb_words() + 3))
+		);
+	}
+}
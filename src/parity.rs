@@ -0,0 +1,248 @@
+//! Error-correcting parity words for mnemonics.
+//!
+//! **This is not part of BIP39.** An extended phrase can't be used where a
+//! BIP39 mnemonic is expected, only the words before the separator can.
+//!
+//! An extended phrase consists of the mnemonic, the [SEPARATOR] and a number
+//! of parity words, like `... cage above + forum unit`. The parity words are a
+//! systematic Reed-Solomon code of the word indices over GF(2^11): with `k`
+//! parity words, any `k` words of the extended phrase can be reconstructed
+//! when they have been damaged or lost, see [recover_in].
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::{parity, Mnemonic};
+//!
+//! let m = Mnemonic::from_entropy(&[0x7f; 16]).unwrap();
+//! let extended = parity::extended_phrase(&m, 2);
+//!
+//! // Lose two words.
+//! let mut words = extended.split(' ').collect::<Vec<_>>();
+//! words[3] = "?";
+//! words[7] = "?";
+//! assert_eq!(parity::recover(&words.join(" ")), Ok(m));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{
+	borrow::Cow,
+	string::{String, ToString},
+};
+
+use crate::{is_invalid_word_count, Error, Language, Mnemonic, MAX_NB_WORDS};
+
+/// The separator between the mnemonic and the parity words.
+pub const SEPARATOR: &str = "+";
+
+/// The marker for an illegible word when recovering an extended phrase.
+pub const ILLEGIBLE_WORD: &str = "?";
+
+/// The maximum number of parity words.
+pub const MAX_PARITY_WORDS: usize = 8;
+
+/// The primitive polynomial x^11 + x^2 + 1 defining GF(2^11).
+const MODULUS: u32 = 0x805;
+
+/// Multiply two elements of GF(2^11).
+fn gf_mul(a: u16, b: u16) -> u16 {
+	let mut a = a as u32;
+	let mut b = b;
+	let mut res = 0;
+	while b != 0 {
+		if b & 1 != 0 {
+			res ^= a;
+		}
+		b >>= 1;
+		a <<= 1;
+		if a & 0x800 != 0 {
+			a ^= MODULUS;
+		}
+	}
+	res as u16
+}
+
+/// Invert a non-zero element of GF(2^11).
+fn gf_inv(a: u16) -> u16 {
+	// a^(2^11 - 2) = a^-1
+	let mut res = 1;
+	let mut base = a;
+	let mut exp = 2046u16;
+	while exp != 0 {
+		if exp & 1 != 0 {
+			res = gf_mul(res, base);
+		}
+		base = gf_mul(base, base);
+		exp >>= 1;
+	}
+	res
+}
+
+/// Evaluate the polynomial going through the given points at `x`.
+///
+/// The word at position `p` of an extended phrase is the value at `x = p`.
+fn interpolate<P>(points: P, x: u16) -> u16
+where
+	P: Iterator<Item = (u16, u16)> + Clone,
+{
+	let mut res = 0;
+	for (xj, yj) in points.clone() {
+		let mut num = 1;
+		let mut den = 1;
+		for (xm, _) in points.clone().filter(|(xm, _)| *xm != xj) {
+			// Subtraction is XOR in GF(2^11).
+			num = gf_mul(num, x ^ xm);
+			den = gf_mul(den, xj ^ xm);
+		}
+		res ^= gf_mul(yj, gf_mul(num, gf_inv(den)));
+	}
+	res
+}
+
+/// Compute the parity words of the mnemonic, at most [MAX_PARITY_WORDS].
+pub fn parity_words(
+	mnemonic: &Mnemonic,
+	nb_parity: usize,
+) -> impl Iterator<Item = &'static str> + Clone + '_ {
+	let list = mnemonic.language().word_list();
+	let points = mnemonic.word_indices().enumerate().map(|(p, i)| (p as u16, i as u16));
+	let nb_words = mnemonic.word_count();
+	(nb_words..nb_words + nb_parity.min(MAX_PARITY_WORDS)).map(move |p| {
+		let i = interpolate(points.clone(), p as u16) as usize;
+		list.get(i).copied().unwrap_or("")
+	})
+}
+
+/// Create the extended phrase of the mnemonic with `nb_parity` parity words,
+/// at most [MAX_PARITY_WORDS].
+#[cfg(feature = "alloc")]
+pub fn extended_phrase(mnemonic: &Mnemonic, nb_parity: usize) -> String {
+	let mut phrase = mnemonic.to_string();
+	phrase.push(' ');
+	phrase.push_str(SEPARATOR);
+	for word in parity_words(mnemonic, nb_parity) {
+		phrase.push(' ');
+		phrase.push_str(word);
+	}
+	phrase
+}
+
+/// Recover a mnemonic in the given language from its extended phrase.
+///
+/// Words that are not in the word list, like the [ILLEGIBLE_WORD] marker `?`,
+/// are reconstructed from the other words, as long as there are no more of
+/// them than parity words.
+///
+/// Returns [Error::BadWordCount] if the number of words is invalid,
+/// [Error::UnknownWord] with the position of the first illegible word if
+/// there are too many of them and [Error::InvalidChecksum] if the words are
+/// not consistent with the parity words or the mnemonic checksum.
+pub fn recover_in(language: Language, s: &str) -> Result<Mnemonic, Error> {
+	#[cfg(feature = "alloc")]
+	let s = {
+		let mut cow = Cow::Borrowed(s);
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		cow
+	};
+
+	let nb_words = s.split_whitespace().take_while(|w| *w != SEPARATOR).count();
+	let nb_parity = s.split_whitespace().skip(nb_words + 1).count();
+	if is_invalid_word_count(nb_words) || nb_parity > MAX_PARITY_WORDS {
+		return Err(Error::BadWordCount(nb_words + nb_parity));
+	}
+
+	// The indices of the words of the extended phrase, None if illegible.
+	let mut words = [None; MAX_NB_WORDS + MAX_PARITY_WORDS];
+	let tokens = s.split_whitespace().filter(|w| *w != SEPARATOR);
+	for (slot, word) in words.iter_mut().zip(tokens) {
+		*slot = language.find_word(word);
+	}
+	let words = words.get(..nb_words + nb_parity).unwrap_or(&[]);
+
+	let nb_illegible = words.iter().filter(|w| w.is_none()).count();
+	if nb_illegible > nb_parity {
+		let first = words.iter().position(|w| w.is_none()).unwrap_or(0);
+		return Err(Error::UnknownWord(first));
+	}
+
+	// Any `nb_words` legible words determine all the others.
+	let known =
+		words.iter().enumerate().filter_map(|(p, w)| w.map(|i| (p as u16, i))).take(nb_words);
+	let mut indices = [0u16; MAX_NB_WORDS];
+	for (p, (idx, word)) in indices.iter_mut().zip(words).enumerate() {
+		*idx = word.unwrap_or_else(|| interpolate(known.clone(), p as u16));
+	}
+	let consistent = words
+		.iter()
+		.enumerate()
+		.skip(nb_words)
+		.all(|(p, w)| w.map(|i| i == interpolate(known.clone(), p as u16)).unwrap_or(true));
+	if !consistent {
+		return Err(Error::InvalidChecksum);
+	}
+
+	Mnemonic::from_indices_in(language, indices.get(..nb_words).unwrap_or(&[]))
+}
+
+/// Recover an English mnemonic from its extended phrase. See [recover_in].
+pub fn recover(s: &str) -> Result<Mnemonic, Error> {
+	recover_in(Language::English, s)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_field() {
+		// The modulus is primitive: x generates all 2047 non-zero elements.
+		let mut x = 1;
+		for i in 1..=2047 {
+			x = gf_mul(x, 2);
+			assert_eq!(x == 1, i == 2047);
+		}
+		for a in 1..2048 {
+			assert_eq!(gf_mul(a, gf_inv(a)), 1);
+		}
+	}
+
+	#[test]
+	fn test_recover() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let extended = extended_phrase(&m, 3);
+		assert_eq!(recover(&extended), Ok(m.clone()));
+		assert_eq!(extended.split(' ').nth(24), Some(SEPARATOR));
+		assert_eq!(extended.split(' ').count(), 24 + 1 + 3);
+
+		// Any three words can be lost, including parity words.
+		let words = extended.split(' ').collect::<Vec<_>>();
+		for &(a, b, c) in &[(0, 1, 2), (5, 23, 25), (25, 26, 27), (0, 12, 27)] {
+			let mut damaged = words.clone();
+			damaged[a] = ILLEGIBLE_WORD;
+			damaged[b] = "zooo";
+			damaged[c] = ILLEGIBLE_WORD;
+			assert_eq!(recover(&damaged.join(" ")), Ok(m.clone()));
+		}
+
+		// But not four.
+		let mut damaged = words.clone();
+		for p in &[1, 2, 3, 4] {
+			damaged[*p] = ILLEGIBLE_WORD;
+		}
+		assert_eq!(recover(&damaged.join(" ")), Err(Error::UnknownWord(1)));
+
+		// Wrong words are detected with the remaining parity words.
+		let mut damaged = words.clone();
+		damaged[3] = if words[3] == "zoo" {
+			"abandon"
+		} else {
+			"zoo"
+		};
+		assert_eq!(recover(&damaged.join(" ")), Err(Error::InvalidChecksum));
+
+		// Without parity words, this is a plain mnemonic.
+		assert_eq!(recover(&m.to_string()), Ok(m.clone()));
+		assert_eq!(recover(&format!("{} +", m)), Ok(m));
+		assert_eq!(recover("abandon + zoo"), Err(Error::BadWordCount(2)));
+	}
+}
@@ -0,0 +1,428 @@
+//! Utilities for dealing with BIP39 passphrases.
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+#[cfg(feature = "alloc")]
+use core::fmt;
+#[cfg(feature = "unicode-normalization")]
+use core::iter;
+
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::UnicodeNormalization;
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::Error;
+#[cfg(feature = "alloc")]
+use crate::{bytes_eq_ct, Mnemonic};
+
+/// Passphrases that are guessed first by anyone trying to brute-force a seed.
+const COMMON_PASSPHRASES: &[&str] = &[
+	"123",
+	"1234",
+	"12345",
+	"123456",
+	"1234567",
+	"12345678",
+	"123456789",
+	"abc123",
+	"admin",
+	"bitcoin",
+	"dragon",
+	"iloveyou",
+	"ledger",
+	"letmein",
+	"monkey",
+	"passphrase",
+	"password",
+	"qwerty",
+	"satoshi",
+	"secret",
+	"trezor",
+	"welcome",
+];
+
+/// The estimated entropy assigned to a passphrase found in the list of common passphrases.
+const COMMON_PASSPHRASE_BITS: u32 = 8;
+
+/// A coarse rating of the strength of a passphrase.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strength {
+	/// Less than 28 bits of entropy, guessable almost instantly.
+	VeryWeak,
+	/// Between 28 and 36 bits of entropy.
+	Weak,
+	/// Between 36 and 60 bits of entropy.
+	Fair,
+	/// Between 60 and 128 bits of entropy.
+	Strong,
+	/// At least 128 bits of entropy.
+	VeryStrong,
+}
+
+impl Strength {
+	fn from_entropy_bits(bits: u32) -> Strength {
+		match bits {
+			0..=27 => Strength::VeryWeak,
+			28..=35 => Strength::Weak,
+			36..=59 => Strength::Fair,
+			60..=127 => Strength::Strong,
+			_ => Strength::VeryStrong,
+		}
+	}
+}
+
+/// The result of [estimate_strength].
+///
+/// The estimate is a heuristic: it is based on the character classes used and
+/// penalizes obvious patterns, but it can't know how the passphrase was chosen.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct StrengthEstimate {
+	/// The estimated entropy of the passphrase in bits.
+	pub entropy_bits: u32,
+	/// The rating corresponding to [StrengthEstimate::entropy_bits].
+	pub strength: Strength,
+	/// The passphrase is one of a list of very common passwords.
+	pub common: bool,
+	/// The passphrase contains runs of a repeated character, like `aaa`.
+	pub repeats: bool,
+	/// The passphrase contains ascending or descending sequences, like `abc` or `321`.
+	pub sequences: bool,
+}
+
+/// Estimate the strength of a BIP39 passphrase.
+///
+/// Every character contributes `log2` of the size of the character pool used by
+/// the passphrase (lowercase, uppercase, digits, ASCII symbols and non-ASCII
+/// characters). Characters repeating or continuing a sequence from the previous
+/// character contribute only a single bit.
+///
+/// Note that the empty passphrase, the BIP39 default, has no entropy at all.
+pub fn estimate_strength(passphrase: &str) -> StrengthEstimate {
+	let pool = char_pool_size(passphrase);
+
+	let mut sixteenths = 0;
+	let mut repeats = false;
+	let mut sequences = false;
+	let mut prev: Option<char> = None;
+	let mut run = 0;
+	for c in passphrase.chars() {
+		let repeat = prev == Some(c);
+		let step = prev.map(|p| (c as i64 - p as i64).abs() == 1).unwrap_or(false);
+		if repeat || step {
+			run += 1;
+			// Two related characters in a row can be accidental, three can't.
+			repeats |= repeat && run >= 2;
+			sequences |= step && run >= 2;
+			sixteenths += 16;
+		} else {
+			run = 0;
+			sixteenths += log2_sixteenths(pool);
+		}
+		prev = Some(c);
+	}
+	let mut entropy_bits = sixteenths / 16;
+
+	let common = COMMON_PASSPHRASES.iter().any(|p| p.eq_ignore_ascii_case(passphrase));
+	if common {
+		entropy_bits = entropy_bits.min(COMMON_PASSPHRASE_BITS);
+	}
+
+	StrengthEstimate {
+		entropy_bits,
+		strength: Strength::from_entropy_bits(entropy_bits),
+		common,
+		repeats,
+		sequences,
+	}
+}
+
+/// Check that the passphrase only contains printable ASCII characters.
+///
+/// This is a stricter policy than BIP39 requires, for wallets that want to
+/// avoid any issues with normalization or invisible characters, like zero-width
+/// spaces, when the passphrase is typed on another device. Printable ASCII
+/// characters are never changed by normalization.
+///
+/// Returns [Error::InvalidPassphraseCharacter] with the byte position of the
+/// first other character.
+pub fn check_strict_ascii(passphrase: &str) -> Result<(), Error> {
+	match passphrase.bytes().position(|b| !(b' '..=b'~').contains(&b)) {
+		Some(position) => Err(Error::InvalidPassphraseCharacter(position)),
+		None => Ok(()),
+	}
+}
+
+/// The kind of change made to a character by NFKD normalization.
+#[cfg(feature = "unicode-normalization")]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum NormalizationKind {
+	/// The character is decomposed into an equivalent sequence that looks the
+	/// same, like a composed `é` into `e` and a combining accent.
+	Canonical,
+	/// The character is replaced by a compatible sequence that can look
+	/// different, like the ligature `ﬁ` into `fi` or full-width `Ａ` into `A`.
+	Compatibility,
+}
+
+/// A character of a passphrase that is changed by NFKD normalization.
+#[cfg(feature = "unicode-normalization")]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct NormalizationChange {
+	/// The byte position of the character in the passphrase.
+	pub position: usize,
+	/// The character as typed.
+	pub original: char,
+	/// The kind of change.
+	pub kind: NormalizationKind,
+}
+
+/// Whether the passphrase is already in NFKD normal form.
+///
+/// BIP39 normalizes passphrases before deriving the seed. A passphrase that is
+/// not normalized may be typed differently on another device, for example with
+/// composed accents, while resulting in the same seed, or the other way around.
+#[cfg(feature = "unicode-normalization")]
+pub fn is_normalized(passphrase: &str) -> bool {
+	unicode_normalization::is_nfkd(passphrase)
+}
+
+/// Report the characters of the passphrase that NFKD normalization changes.
+///
+/// Wallets can use this to warn users before deriving a seed from a
+/// passphrase that will be altered. Besides the reported characters,
+/// normalization can also reorder combining marks, see [is_normalized].
+#[cfg(feature = "unicode-normalization")]
+pub fn normalization_changes(
+	passphrase: &str,
+) -> impl Iterator<Item = NormalizationChange> + Clone + '_ {
+	passphrase.char_indices().filter_map(|(position, c)| {
+		if iter::once(c).nfkd().eq(iter::once(c)) {
+			return None;
+		}
+		let kind = if iter::once(c).nfd().eq(iter::once(c).nfkd()) {
+			NormalizationKind::Canonical
+		} else {
+			NormalizationKind::Compatibility
+		};
+		Some(NormalizationChange {
+			position,
+			original: c,
+			kind,
+		})
+	})
+}
+
+/// A passphrase, normalized once when it is created.
+///
+/// It can be passed by reference to [Mnemonic::to_seed] and the other methods
+/// taking a passphrase, which then don't allocate to normalize it again. The
+/// [fmt::Debug] output doesn't contain the passphrase, the comparison takes a
+/// constant time, and with the `zeroize` or `wipe` feature the passphrase is
+/// erased from memory on drop.
+///
+/// Example:
+///
+/// ```
+/// use bip39::passphrase::Passphrase;
+/// use bip39::Mnemonic;
+///
+/// let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+/// let passphrase = Passphrase::new("caf\u{e9}");
+/// assert_eq!(passphrase.as_str(), "cafe\u{301}");
+/// assert_eq!(m.to_seed(&passphrase), m.to_seed("caf\u{e9}"));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct Passphrase(String);
+
+#[cfg(feature = "alloc")]
+impl Passphrase {
+	/// Normalize the passphrase.
+	pub fn new(passphrase: &str) -> Passphrase {
+		let mut cow = Cow::Borrowed(passphrase);
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Passphrase(cow.into_owned())
+	}
+
+	/// Wrap an already normalized passphrase.
+	pub(crate) fn from_normalized(normalized_passphrase: String) -> Passphrase {
+		Passphrase(normalized_passphrase)
+	}
+
+	/// The normalized passphrase.
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	/// Whether the passphrase is empty.
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a Passphrase> for Cow<'a, str> {
+	fn from(passphrase: &'a Passphrase) -> Cow<'a, str> {
+		Cow::Borrowed(passphrase.as_str())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for Passphrase {
+	fn eq(&self, other: &Passphrase) -> bool {
+		bytes_eq_ct(self.0.as_bytes(), other.0.as_bytes())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for Passphrase {}
+
+#[cfg(all(feature = "alloc", feature = "wipe", not(feature = "zeroize")))]
+impl Drop for Passphrase {
+	fn drop(&mut self) {
+		// SAFETY: zero bytes are valid UTF-8.
+		crate::wipe::wipe(unsafe { self.0.as_mut_vec() });
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for Passphrase {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("Passphrase(..)")
+	}
+}
+
+/// The number of distinct characters of all character classes used in the passphrase.
+fn char_pool_size(passphrase: &str) -> u32 {
+	let mut pool = 0;
+	if passphrase.chars().any(|c| c.is_ascii_lowercase()) {
+		pool += 26;
+	}
+	if passphrase.chars().any(|c| c.is_ascii_uppercase()) {
+		pool += 26;
+	}
+	if passphrase.chars().any(|c| c.is_ascii_digit()) {
+		pool += 10;
+	}
+	if passphrase.chars().any(|c| c.is_ascii() && !c.is_ascii_alphanumeric()) {
+		pool += 33;
+	}
+	if !passphrase.is_ascii() {
+		pool += 100;
+	}
+	pool
+}
+
+/// Approximate `log2(n)` in units of 1/16 bit, for `n > 0`.
+fn log2_sixteenths(n: u32) -> u32 {
+	let int = 31 - n.leading_zeros();
+	// The mantissa in [1, 2) as 16.16 fixed point. The fractional bits of the
+	// logarithm are extracted one at a time by repeatedly squaring it.
+	let mut m = ((n as u64) << 16) >> int;
+	let mut frac = 0;
+	for _ in 0..4 {
+		m = (m * m) >> 16;
+		frac <<= 1;
+		if m >= 2 << 16 {
+			m >>= 1;
+			frac |= 1;
+		}
+	}
+	int * 16 + frac
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_log2_sixteenths() {
+		assert_eq!(log2_sixteenths(1), 0);
+		assert_eq!(log2_sixteenths(2), 16);
+		assert_eq!(log2_sixteenths(26), 75); // 4.70
+		assert_eq!(log2_sixteenths(95), 105); // 6.57
+		assert_eq!(log2_sixteenths(1 << 20), 320);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn test_passphrase() {
+		let passphrase = Passphrase::new("\u{fb01}ne caf\u{e9}");
+		assert_eq!(passphrase.as_str(), "fine cafe\u{301}");
+		assert_eq!(passphrase, Passphrase::new("fine cafe\u{301}"));
+		assert_ne!(passphrase, Passphrase::new("fine cafe"));
+		assert_eq!(format!("{:?}", passphrase), "Passphrase(..)");
+		assert!(Passphrase::new("").is_empty());
+
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		assert_eq!(m.to_seed(&passphrase), m.to_seed_normalized("fine cafe\u{301}"));
+	}
+
+	#[test]
+	fn test_check_strict_ascii() {
+		assert_eq!(check_strict_ascii(""), Ok(()));
+		assert_eq!(check_strict_ascii("Tr0ub4dor&3 ~!"), Ok(()));
+		assert_eq!(check_strict_ascii("caf\u{e9}"), Err(Error::InvalidPassphraseCharacter(3)));
+		assert_eq!(check_strict_ascii("a\u{200b}b"), Err(Error::InvalidPassphraseCharacter(1)));
+		assert_eq!(check_strict_ascii("tab\there"), Err(Error::InvalidPassphraseCharacter(3)));
+	}
+
+	#[cfg(feature = "unicode-normalization")]
+	#[test]
+	fn test_normalization_changes() {
+		assert!(is_normalized("correct horse"));
+		assert_eq!(normalization_changes("correct horse").count(), 0);
+
+		let passphrase = "caf\u{e9} \u{fb01}sh";
+		assert!(!is_normalized(passphrase));
+		let changes = normalization_changes(passphrase).collect::<Vec<_>>();
+		assert_eq!(
+			changes,
+			[
+				NormalizationChange {
+					position: 3,
+					original: '\u{e9}',
+					kind: NormalizationKind::Canonical,
+				},
+				NormalizationChange {
+					position: 6,
+					original: '\u{fb01}',
+					kind: NormalizationKind::Compatibility,
+				},
+			]
+		);
+
+		// Combining marks in non-canonical order are only reordered.
+		assert!(!is_normalized("a\u{301}\u{316}"));
+		assert_eq!(normalization_changes("a\u{301}\u{316}").count(), 0);
+	}
+
+	#[test]
+	fn test_estimate_strength() {
+		let est = estimate_strength("");
+		assert_eq!(est.entropy_bits, 0);
+		assert_eq!(est.strength, Strength::VeryWeak);
+
+		let est = estimate_strength("1234");
+		assert!(est.common);
+		assert!(est.sequences);
+		assert_eq!(est.strength, Strength::VeryWeak);
+
+		let est = estimate_strength("Password");
+		assert!(est.common);
+		assert_eq!(est.strength, Strength::VeryWeak);
+
+		let est = estimate_strength("aaaaaaaaaaaaaaaa");
+		assert!(est.repeats);
+		assert_eq!(est.strength, Strength::VeryWeak);
+
+		let est = estimate_strength("correct horse");
+		assert!(!est.common && !est.repeats && !est.sequences);
+		assert_eq!(est.strength, Strength::Strong);
+
+		let est = estimate_strength("Tr0ub4dor&3-xK!9qZ#7vL@2mP$8wN^5");
+		assert_eq!(est.strength, Strength::VeryStrong);
+	}
+}
@@ -1,6 +1,86 @@
 use bitcoin_hashes::{hmac, sha512, Hash, HashEngine};
 
-const SALT_PREFIX: &'static str = "mnemonic";
+use crate::MAX_NB_WORDS;
+
+const SALT_PREFIX: &str = "mnemonic";
+
+/// The pseudorandom function used by PBKDF2 to derive the seed: HMAC-SHA512
+/// keyed with the mnemonic.
+///
+/// Implement this trait to compute the HMACs outside of this crate, for example
+/// on a secure element or an HSM, and use it with [Mnemonic::to_seed_with].
+///
+/// By default, every iteration is a call to [Pbkdf2Prf::compute] and the
+/// results are combined into the seed by this crate, in ordinary memory. To
+/// keep the intermediate HMACs on the device, override
+/// [Pbkdf2Prf::compute_block] as well so that only the final block leaves it.
+///
+/// Messages and keys are passed as a list of parts that have to be concatenated.
+///
+/// Example:
+///
+/// ```
+/// use bip39::{Mnemonic, Pbkdf2Prf};
+/// use bitcoin_hashes::{hmac, sha512, Hash, HashEngine};
+///
+/// /// A software implementation, the same as used by [Mnemonic::to_seed].
+/// struct SoftwarePrf(Vec<u8>);
+///
+/// impl Pbkdf2Prf for SoftwarePrf {
+///     type Error = core::convert::Infallible;
+///
+///     fn set_key(&mut self, key: &[&[u8]]) -> Result<(), Self::Error> {
+///         self.0 = key.concat();
+///         Ok(())
+///     }
+///
+///     fn compute(&mut self, message: &[&[u8]]) -> Result<[u8; 64], Self::Error> {
+///         let mut engine = hmac::HmacEngine::<sha512::Hash>::new(&self.0);
+///         for part in message {
+///             engine.input(part);
+///         }
+///         Ok(hmac::Hmac::from_engine(engine).to_byte_array())
+///     }
+/// }
+///
+/// let m = Mnemonic::parse("letter advice cage absurd amount doctor acoustic avoid letter advice cage above").unwrap();
+/// let seed = m.to_seed_normalized_with("TREZOR", &mut SoftwarePrf(Vec::new())).unwrap();
+/// assert_eq!(seed, m.to_seed_normalized("TREZOR"));
+/// ```
+///
+/// [Mnemonic::to_seed_with]: crate::Mnemonic::to_seed_with
+/// [Mnemonic::to_seed]: crate::Mnemonic::to_seed
+pub trait Pbkdf2Prf {
+	/// The error returned when the computation fails.
+	type Error;
+
+	/// Set the HMAC key for all following calls to [Pbkdf2Prf::compute].
+	fn set_key(&mut self, key: &[&[u8]]) -> Result<(), Self::Error>;
+
+	/// Compute the HMAC-SHA512 of the message using the key set before.
+	fn compute(&mut self, message: &[&[u8]]) -> Result<[u8; 64], Self::Error>;
+
+	/// Compute a PBKDF2 block using the key set before: the XOR of the
+	/// `iterations` chained HMACs, starting with the HMAC of the message.
+	///
+	/// The default implementation calls [Pbkdf2Prf::compute] for every
+	/// iteration.
+	fn compute_block(
+		&mut self,
+		message: &[&[u8]],
+		iterations: usize,
+	) -> Result<[u8; 64], Self::Error> {
+		let mut salt = self.compute(message)?;
+		let mut block = salt;
+		for _ in 1..iterations {
+			salt = self.compute(&[&salt])?;
+			xor(&mut block, &salt);
+		}
+		#[cfg(any(feature = "wipe", feature = "zeroize"))]
+		crate::wipe::wipe(&mut salt);
+		Ok(block)
+	}
+}
 
 /// Calculate the binary size of the mnemonic.
 fn mnemonic_byte_len<M>(mnemonic: M) -> usize
@@ -28,6 +108,16 @@ fn mnemonic_write_into<M>(mnemonic: M, engine: &mut sha512::HashEngine)
 	}
 }
 
+/// The mnemonic in binary form: the words separated by spaces.
+fn mnemonic_bytes<M>(mnemonic: M) -> impl Iterator<Item = u8> + Clone
+	where M: Iterator<Item = &'static str> + Clone,
+{
+	mnemonic.enumerate().flat_map(|(i, word)| {
+		let sep = if i > 0 { Some(b' ') } else { None };
+		sep.into_iter().chain(word.bytes())
+	})
+}
+
 /// Create an HMAC engine from the passphrase.
 /// We need a special method because we can't allocate a new byte
 /// vector for the entire serialized mnemonic.
@@ -47,34 +137,26 @@ fn create_hmac_engine<M>(mnemonic: M) -> hmac::HmacEngine<sha512::Hash>
 			sha512::Hash::from_engine(engine)
 		};
 
-		for (b_i, b_h) in ipad.iter_mut().zip(&hash[..]) {
+		for (b_i, b_h) in ipad.iter_mut().zip(hash.as_byte_array()) {
 			*b_i ^= *b_h;
 		}
-		for (b_o, b_h) in opad.iter_mut().zip(&hash[..]) {
+		for (b_o, b_h) in opad.iter_mut().zip(hash.as_byte_array()) {
 			*b_o ^= *b_h;
 		}
 	} else {
-		// First modify the first elements from the prefix.
-		let mut cursor = 0;
-		for (i, word) in mnemonic.enumerate() {
-			if i > 0 {
-				ipad[cursor] ^= ' ' as u8;
-				opad[cursor] ^= ' ' as u8;
-				cursor += 1;
-			}
-			for (b_i, b_h) in ipad.iter_mut().skip(cursor).zip(word.as_bytes()) {
-				*b_i ^= *b_h;
-			}
-			for (b_o, b_h) in opad.iter_mut().skip(cursor).zip(word.as_bytes()) {
-				*b_o ^= *b_h;
-			}
-			cursor += word.len();
-			assert!(cursor <= sha512::HashEngine::BLOCK_SIZE, "mnemonic_byte_len is broken");
+		for ((b_i, b_o), b_m) in ipad.iter_mut().zip(opad.iter_mut()).zip(mnemonic_bytes(mnemonic)) {
+			*b_i ^= b_m;
+			*b_o ^= b_m;
 		}
 	};
 
-	iengine.input(&ipad[..sha512::HashEngine::BLOCK_SIZE]);
-	oengine.input(&opad[..sha512::HashEngine::BLOCK_SIZE]);
+	iengine.input(&ipad);
+	oengine.input(&opad);
+	#[cfg(any(feature = "wipe", feature = "zeroize"))]
+	{
+		crate::wipe::wipe(&mut ipad);
+		crate::wipe::wipe(&mut opad);
+	}
 	hmac::HmacEngine::from_inner_engines(iengine, oengine)
 }
 
@@ -82,8 +164,8 @@ fn create_hmac_engine<M>(mnemonic: M) -> hmac::HmacEngine<sha512::Hash>
 #[inline]
 fn u32_to_array_be(val: u32) -> [u8; 4] {
 	let mut res = [0; 4];
-	for i in 0..4 {
-		res[i] = ((val >> (4 - i - 1) * 8) & 0xff) as u8;
+	for (i, r) in res.iter_mut().enumerate() {
+		*r = ((val >> ((4 - i - 1) * 8)) & 0xff) as u8;
 	}
 	res
 }
@@ -98,6 +180,19 @@ fn xor(res: &mut [u8], salt: &[u8]) {
 /// PBKDF2-HMAC-SHA512 implementation using bitcoin_hashes.
 pub(crate) fn pbkdf2<M>(mnemonic: M, unprefixed_salt: &[u8], c: usize, res: &mut [u8])
 	where M: Iterator<Item = &'static str> + Clone,
+{
+	pbkdf2_with_salt_prefix(mnemonic, SALT_PREFIX, unprefixed_salt, c, res)
+}
+
+/// PBKDF2-HMAC-SHA512 implementation with a salt prefix other than "mnemonic".
+pub(crate) fn pbkdf2_with_salt_prefix<M>(
+	mnemonic: M,
+	salt_prefix: &str,
+	unprefixed_salt: &[u8],
+	c: usize,
+	res: &mut [u8],
+)
+	where M: Iterator<Item = &'static str> + Clone,
 {
 	let prf = create_hmac_engine(mnemonic);
 
@@ -108,7 +203,7 @@ pub(crate) fn pbkdf2<M>(mnemonic: M, unprefixed_salt: &[u8], c: usize, res: &mut
 
 		let mut salt = {
 			let mut prfc = prf.clone();
-			prfc.input(SALT_PREFIX.as_bytes());
+			prfc.input(salt_prefix.as_bytes());
 			prfc.input(unprefixed_salt);
 			prfc.input(&u32_to_array_be((i + 1) as u32));
 
@@ -124,5 +219,44 @@ pub(crate) fn pbkdf2<M>(mnemonic: M, unprefixed_salt: &[u8], c: usize, res: &mut
 
 			xor(chunk, &salt);
 		}
+		#[cfg(any(feature = "wipe", feature = "zeroize"))]
+		crate::wipe::wipe(&mut salt);
+	}
+}
+
+/// PBKDF2 implementation using an external pseudorandom function.
+pub(crate) fn pbkdf2_with<P, M>(
+	prf: &mut P,
+	mnemonic: M,
+	unprefixed_salt: &[u8],
+	c: usize,
+	res: &mut [u8],
+) -> Result<(), P::Error>
+	where P: Pbkdf2Prf + ?Sized,
+	      M: Iterator<Item = &'static str>,
+{
+	// The words interleaved with separators, unused parts are left empty.
+	let mut key: [&[u8]; MAX_NB_WORDS * 2 - 1] = [&[]; MAX_NB_WORDS * 2 - 1];
+	let parts = mnemonic.enumerate().flat_map(|(i, word)| {
+		let sep = if i > 0 { Some(&b" "[..]) } else { None };
+		sep.into_iter().chain(Some(word.as_bytes()))
+	});
+	for (slot, part) in key.iter_mut().zip(parts) {
+		*slot = part;
+	}
+	prf.set_key(&key)?;
+
+	for (i, chunk) in res.chunks_mut(sha512::Hash::LEN).enumerate() {
+		for v in chunk.iter_mut() {
+			*v = 0;
+		}
+
+		let index = u32_to_array_be((i + 1) as u32);
+		#[cfg_attr(not(any(feature = "wipe", feature = "zeroize")), allow(unused_mut))]
+		let mut block = prf.compute_block(&[SALT_PREFIX.as_bytes(), unprefixed_salt, &index], c)?;
+		xor(chunk, &block);
+		#[cfg(any(feature = "wipe", feature = "zeroize"))]
+		crate::wipe::wipe(&mut block);
 	}
+	Ok(())
 }
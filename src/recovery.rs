@@ -0,0 +1,933 @@
+//! Recovery of mnemonics with transcription errors.
+//!
+//! These functions help to recover mnemonics that fail to parse because of
+//! mistakes made when writing down or copying the words. Note that a random
+//! change to a mnemonic results in a valid checksum with a probability of 1/16
+//! to 1/256, depending on the word count, so the recovered candidates might
+//! have to be checked against other information, like a known address.
+//!
+//! For this, [Search] accepts an oracle that checks the candidates.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::recovery::Search;
+//!
+//! // The last word is missing and the third one is either "cage" or "case".
+//! let search = Search::from_phrase(
+//!     "letter advice cage|case absurd amount doctor acoustic avoid letter advice cage ?",
+//! ).unwrap();
+//! let seed = hex_seed("d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30\
+//!     fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8");
+//! let m = search.find(|m| m.to_seed("TREZOR") == seed).unwrap();
+//! assert_eq!(m.words().last(), Some("above"));
+//! # fn hex_seed(s: &str) -> [u8; 64] {
+//! #     use bitcoin_hashes::hex::FromHex;
+//! #     <[u8; 64]>::from_hex(s).unwrap()
+//! # }
+//! ```
+
+use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{fmt, str};
+#[cfg(feature = "std")]
+use std::error;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(feature = "std")]
+use std::thread;
+
+use crate::{find_indices_in, is_invalid_word_count, Error, Language, Mnemonic, MAX_NB_WORDS};
+
+/// The marker for an unknown word in the phrase of a [Search].
+pub const UNKNOWN_WORD: &str = "?";
+
+/// The separator of alternative words in the phrase of a [Search].
+pub const ALTERNATIVE_SEPARATOR: char = '|';
+
+/// A mnemonic recovered by swapping two adjacent words.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transposition {
+	/// The position of the first of the two swapped words.
+	pub position: usize,
+	/// The mnemonic with the words swapped.
+	pub mnemonic: Mnemonic,
+}
+
+/// A mnemonic recovered by substituting words.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Candidate {
+	/// The number of words that were substituted.
+	pub substitutions: usize,
+	/// The recovered mnemonic.
+	pub mnemonic: Mnemonic,
+}
+
+/// Parse the words of a phrase into their indices, without verifying the checksum.
+fn phrase_indices(language: Language, s: &str) -> Result<Vec<u16>, Error> {
+	let mut cow = Cow::Borrowed(s);
+	Mnemonic::normalize_utf8_cow(&mut cow);
+	let (indices, nb_words) = find_indices_in(language, cow.split_whitespace())?;
+	Ok(indices.iter().take(nb_words).cloned().collect())
+}
+
+/// Find all mnemonics in the given language that are obtained by swapping a
+/// single pair of adjacent words of the phrase.
+///
+/// This is meant for phrases that failed to parse with [Error::InvalidChecksum],
+/// since swapping adjacent words is a common mistake when copying a phrase.
+/// Returns [Error::BadWordCount] or [Error::UnknownWord] if the phrase has
+/// other problems.
+pub fn transpositions_in(language: Language, s: &str) -> Result<Vec<Transposition>, Error> {
+	let mut indices = phrase_indices(language, s)?;
+
+	let mut found = Vec::new();
+	for position in 0..indices.len().saturating_sub(1) {
+		if indices.get(position) == indices.get(position + 1) {
+			continue;
+		}
+		indices.swap(position, position + 1);
+		if let Ok(mnemonic) = Mnemonic::from_indices_in(language, &indices) {
+			found.push(Transposition {
+				position,
+				mnemonic,
+			});
+		}
+		indices.swap(position, position + 1);
+	}
+	Ok(found)
+}
+
+/// Find all English mnemonics that are obtained by swapping a single pair of
+/// adjacent words of the phrase. See [transpositions_in] for details.
+pub fn transpositions(s: &str) -> Result<Vec<Transposition>, Error> {
+	transpositions_in(Language::English, s)
+}
+
+/// Find all mnemonics in the given language that are obtained by replacing at
+/// most `max_substitutions` words of the phrase with their neighbors in the
+/// word list, i.e. the word before or after it.
+///
+/// This recovers the common mistake of copying the word above or below the
+/// intended one from a printed word list. The candidates are sorted by the
+/// number of substitutions, so the phrase itself comes first if it is valid.
+///
+/// The number of checked combinations grows quickly: for 24 words and two
+/// substitutions, it is about a thousand, for three it is about 17 thousand.
+pub fn neighbors_in(
+	language: Language,
+	s: &str,
+	max_substitutions: usize,
+) -> Result<Vec<Candidate>, Error> {
+	let mut indices = phrase_indices(language, s)?;
+	let mut found = Vec::new();
+	substitute_neighbors(language, &mut indices, 0, max_substitutions, 0, &mut found);
+	found.sort_by_key(|c| c.substitutions);
+	Ok(found)
+}
+
+/// Find all English mnemonics that are obtained by replacing words of the
+/// phrase with their neighbors in the word list. See [neighbors_in] for details.
+pub fn neighbors(s: &str, max_substitutions: usize) -> Result<Vec<Candidate>, Error> {
+	neighbors_in(Language::English, s, max_substitutions)
+}
+
+/// Estimate the size of the search of [neighbors_in] for a phrase with
+/// `nb_words` words, when trying `nb_passphrases` passphrases for every candidate.
+pub fn estimate_neighbors(
+	nb_words: usize,
+	max_substitutions: usize,
+	nb_passphrases: usize,
+) -> Estimate {
+	// Choosing the positions to substitute and one of two neighbors for each.
+	let mut combinations = 0u128;
+	let mut choices = 1u128;
+	for k in 0..=max_substitutions.min(nb_words) {
+		combinations = combinations.saturating_add(choices);
+		choices = choices.saturating_mul(2 * (nb_words - k) as u128) / (k as u128 + 1);
+	}
+	Estimate::new(combinations, nb_words, nb_passphrases)
+}
+
+/// Check the current indices and recursively substitute the words from
+/// position `start` on with their neighbors, within the remaining budget.
+fn substitute_neighbors(
+	language: Language,
+	indices: &mut [u16],
+	start: usize,
+	budget: usize,
+	substitutions: usize,
+	found: &mut Vec<Candidate>,
+) {
+	if let Ok(mnemonic) = Mnemonic::from_indices_in(language, indices) {
+		found.push(Candidate {
+			substitutions,
+			mnemonic,
+		});
+	}
+	if budget == 0 {
+		return;
+	}
+
+	for position in start..indices.len() {
+		let original = match indices.get(position) {
+			Some(idx) => *idx,
+			None => return,
+		};
+		let neighbors = [original.checked_sub(1), Some(original + 1).filter(|i| *i < 2048)];
+		for neighbor in neighbors.iter().filter_map(|n| *n) {
+			if let Some(idx) = indices.get_mut(position) {
+				*idx = neighbor;
+			}
+			substitute_neighbors(
+				language,
+				indices,
+				position + 1,
+				budget - 1,
+				substitutions + 1,
+				found,
+			);
+		}
+		if let Some(idx) = indices.get_mut(position) {
+			*idx = original;
+		}
+	}
+}
+
+/// A search for a mnemonic of which some words are unknown or uncertain.
+///
+/// The [fmt::Debug] output doesn't contain the words.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Search {
+	language: Language,
+	/// The candidate word indices for every position.
+	slots: Vec<Vec<u16>>,
+}
+
+impl Search {
+	/// Create a search from a phrase in the given language.
+	///
+	/// Unknown words are marked with [UNKNOWN_WORD], `?`, and alternatives for
+	/// uncertain words are separated by [ALTERNATIVE_SEPARATOR], like `cage|case`.
+	pub fn from_phrase_in(language: Language, s: &str) -> Result<Search, Error> {
+		let mut cow = Cow::Borrowed(s);
+		Mnemonic::normalize_utf8_cow(&mut cow);
+
+		let nb_words = cow.split_whitespace().count();
+		if is_invalid_word_count(nb_words) {
+			return Err(Error::BadWordCount(nb_words));
+		}
+
+		let mut slots = Vec::with_capacity(nb_words);
+		for (i, word) in cow.split_whitespace().enumerate() {
+			let slot = if word == UNKNOWN_WORD {
+				(0..2048).collect()
+			} else {
+				let mut slot = Vec::new();
+				for alternative in word.split(ALTERNATIVE_SEPARATOR) {
+					slot.push(language.find_word(alternative).ok_or(Error::UnknownWord(i))?);
+				}
+				slot
+			};
+			slots.push(slot);
+		}
+		Ok(Search {
+			language,
+			slots,
+		})
+	}
+
+	/// Create a search from an English phrase. See [Search::from_phrase_in] for details.
+	pub fn from_phrase(s: &str) -> Result<Search, Error> {
+		Search::from_phrase_in(Language::English, s)
+	}
+
+	/// The language of the searched mnemonic.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// The number of combinations of words to check, saturating at [u128::MAX].
+	fn nb_combinations(&self) -> u128 {
+		self.slots.iter().fold(1u128, |acc, slot| acc.saturating_mul(slot.len() as u128))
+	}
+
+	/// Iterate over all candidate mnemonics with a valid checksum.
+	pub fn candidates(&self) -> Candidates<'_> {
+		Candidates {
+			search: self,
+			next: 0,
+			end: self.nb_combinations(),
+		}
+	}
+
+	/// Estimate the size of the search, when trying `nb_passphrases` passphrases
+	/// for every candidate.
+	///
+	/// Use this to warn about searches that are computationally infeasible.
+	pub fn estimate_candidates(&self, nb_passphrases: usize) -> Estimate {
+		Estimate::new(self.nb_combinations(), self.slots.len(), nb_passphrases)
+	}
+
+	/// Resume the search from a checkpoint taken with [Candidates::checkpoint].
+	///
+	/// Returns [None] if the checkpoint doesn't fit this search.
+	pub fn resume(&self, checkpoint: Checkpoint) -> Option<Candidates<'_>> {
+		if checkpoint.next > checkpoint.end || checkpoint.end > self.nb_combinations() {
+			return None;
+		}
+		Some(Candidates {
+			search: self,
+			next: checkpoint.next,
+			end: checkpoint.end,
+		})
+	}
+
+	/// Get the checkpoint starting the part `index` of the search split into
+	/// `count` parts of about the same size, to distribute it across machines.
+	///
+	/// Use [Search::resume] to iterate over the candidates of the part.
+	pub fn shard(&self, index: usize, count: usize) -> Checkpoint {
+		Checkpoint::shard(self.nb_combinations(), index, count)
+	}
+
+	/// Find the first candidate mnemonic accepted by the oracle.
+	///
+	/// The oracle checks a candidate against information known about the
+	/// wallet, like an address, an xpub or a fingerprint, to distinguish it
+	/// from the many other candidates with a valid checksum.
+	pub fn find<F>(&self, mut oracle: F) -> Option<Mnemonic>
+	where
+		F: FnMut(&Mnemonic) -> bool,
+	{
+		self.candidates().find(|m| oracle(m))
+	}
+}
+
+impl fmt::Debug for Search {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Search")
+			.field("language", &self.language)
+			.field("word_count", &self.slots.len())
+			.finish()
+	}
+}
+
+/// An iterator over the candidates of a [Search] with a valid checksum.
+///
+/// Created with [Search::candidates].
+#[derive(Clone, Debug)]
+pub struct Candidates<'a> {
+	search: &'a Search,
+	/// The rank of the next combination to check.
+	next: u128,
+	/// The rank after the last combination to check.
+	end: u128,
+}
+
+impl<'a> Candidates<'a> {
+	/// Get a checkpoint to resume the search later with [Search::resume].
+	///
+	/// The search resumes after the last candidate returned by the iterator.
+	pub fn checkpoint(&self) -> Checkpoint {
+		Checkpoint {
+			next: self.next,
+			end: self.end,
+		}
+	}
+
+	/// Get the word indices of the combination with the given rank, the last
+	/// word changing the fastest.
+	fn combination(&self, mut rank: u128) -> [u16; MAX_NB_WORDS] {
+		let mut indices = [0; MAX_NB_WORDS];
+		for (idx, slot) in indices.iter_mut().zip(&self.search.slots).rev() {
+			let len = slot.len() as u128;
+			*idx = slot.get((rank % len) as usize).cloned().unwrap_or(0);
+			rank /= len;
+		}
+		indices
+	}
+}
+
+impl<'a> Iterator for Candidates<'a> {
+	type Item = Mnemonic;
+
+	fn next(&mut self) -> Option<Mnemonic> {
+		let nb_words = self.search.slots.len();
+		while self.next < self.end {
+			let indices = self.combination(self.next);
+			self.next += 1;
+			let indices = indices.get(..nb_words).unwrap_or(&[]);
+			if let Ok(m) = Mnemonic::from_indices_in(self.search.language, indices) {
+				return Some(m);
+			}
+		}
+		None
+	}
+}
+
+/// A search for the order of the words of a mnemonic, for example when the
+/// words were written on separate cards and the order was lost.
+///
+/// Words can be fixed at their position when it is known, which makes the
+/// search much smaller: every word that is not fixed multiplies the number of
+/// permutations to check by the number of words that are not fixed. An order
+/// is checked only once when a word appears several times.
+///
+/// Example:
+///
+/// ```
+/// use bip39::recovery::PermutationSearch;
+///
+/// // Only the order of the last three words is unknown.
+/// let search = PermutationSearch::from_phrase(
+///     "letter advice cage absurd amount doctor acoustic avoid letter cage above advice",
+/// ).unwrap();
+/// let search = (0..9).try_fold(search, |s, position| s.with_fixed_position(position)).unwrap();
+/// let candidates = search.candidates().collect::<Vec<_>>();
+/// assert!(candidates.iter().any(|m| m.to_string().ends_with("advice cage above")));
+/// ```
+///
+/// The [fmt::Debug] output doesn't contain the words.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PermutationSearch {
+	language: Language,
+	/// The word indices, in the given order.
+	words: Vec<u16>,
+	/// Whether the word at every position is known to be in its place.
+	fixed: Vec<bool>,
+	/// The maximum number of permutations to check.
+	budget: u128,
+}
+
+impl PermutationSearch {
+	/// Create a search for the order of the words of a phrase in the given
+	/// language. No word is fixed and the budget is unlimited.
+	pub fn from_phrase_in(language: Language, s: &str) -> Result<PermutationSearch, Error> {
+		let words = phrase_indices(language, s)?;
+		Ok(PermutationSearch {
+			language,
+			fixed: vec![false; words.len()],
+			words,
+			budget: u128::max_value(),
+		})
+	}
+
+	/// Create a search for the order of the words of an English phrase. See
+	/// [PermutationSearch::from_phrase_in] for details.
+	pub fn from_phrase(s: &str) -> Result<PermutationSearch, Error> {
+		PermutationSearch::from_phrase_in(Language::English, s)
+	}
+
+	/// Fix the word at the given position, starting from 0, because its
+	/// position is known.
+	///
+	/// Returns [Error::InvalidPosition] if the position is past the end of the
+	/// phrase.
+	pub fn with_fixed_position(mut self, position: usize) -> Result<PermutationSearch, Error> {
+		*self.fixed.get_mut(position).ok_or(Error::InvalidPosition(position))? = true;
+		Ok(self)
+	}
+
+	/// Limit the search to the first `budget` permutations.
+	pub fn with_budget(mut self, budget: u128) -> PermutationSearch {
+		self.budget = budget;
+		self
+	}
+
+	/// The language of the searched mnemonic.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// The positions of the words that are not fixed.
+	fn free_positions(&self) -> impl Iterator<Item = usize> + '_ {
+		self.fixed.iter().enumerate().filter(|(_, f)| !**f).map(|(i, _)| i)
+	}
+
+	/// The number of permutations to check, within the budget.
+	fn nb_permutations(&self) -> u128 {
+		let nb_free = self.free_positions().count() as u128;
+		let total = (1..=nb_free).fold(1u128, |acc, k| acc.saturating_mul(k));
+		total.min(self.budget)
+	}
+
+	/// Iterate over all orders of the words with a valid checksum.
+	pub fn candidates(&self) -> Permutations<'_> {
+		Permutations {
+			search: self,
+			next: 0,
+			end: self.nb_permutations(),
+		}
+	}
+
+	/// Estimate the size of the search, when trying `nb_passphrases` passphrases
+	/// for every candidate.
+	pub fn estimate_candidates(&self, nb_passphrases: usize) -> Estimate {
+		Estimate::new(self.nb_permutations(), self.words.len(), nb_passphrases)
+	}
+
+	/// Resume the search from a checkpoint taken with [Permutations::checkpoint].
+	///
+	/// Returns [None] if the checkpoint doesn't fit this search.
+	pub fn resume(&self, checkpoint: Checkpoint) -> Option<Permutations<'_>> {
+		if checkpoint.next > checkpoint.end || checkpoint.end > self.nb_permutations() {
+			return None;
+		}
+		Some(Permutations {
+			search: self,
+			next: checkpoint.next,
+			end: checkpoint.end,
+		})
+	}
+
+	/// Get the checkpoint starting the part `index` of the search split into
+	/// `count` parts of about the same size. See [Search::shard].
+	pub fn shard(&self, index: usize, count: usize) -> Checkpoint {
+		Checkpoint::shard(self.nb_permutations(), index, count)
+	}
+
+	/// Find the first order of the words accepted by the oracle. See
+	/// [Search::find].
+	pub fn find<F>(&self, mut oracle: F) -> Option<Mnemonic>
+	where
+		F: FnMut(&Mnemonic) -> bool,
+	{
+		self.candidates().find(|m| oracle(m))
+	}
+
+	/// Find an order of the words accepted by the oracle, splitting the search
+	/// over `nb_threads` threads.
+	///
+	/// All threads stop as soon as one of them finds a candidate. If several
+	/// candidates are accepted, any of them can be returned.
+	#[cfg(feature = "std")]
+	pub fn find_parallel<F>(&self, nb_threads: usize, oracle: F) -> Option<Mnemonic>
+	where
+		F: Fn(&Mnemonic) -> bool + Send + Sync + 'static,
+	{
+		let nb_threads = nb_threads.max(1);
+		let oracle = Arc::new(oracle);
+		let done = Arc::new(AtomicBool::new(false));
+		let handles = (0..nb_threads)
+			.map(|i| {
+				let search = self.clone();
+				let checkpoint = self.shard(i, nb_threads);
+				let oracle = oracle.clone();
+				let done = done.clone();
+				thread::spawn(move || {
+					let found = search
+						.resume(checkpoint)?
+						.take_while(|_| !done.load(Ordering::Relaxed))
+						.find(|m| oracle(m));
+					if found.is_some() {
+						done.store(true, Ordering::Relaxed);
+					}
+					found
+				})
+			})
+			.collect::<Vec<_>>();
+		// A thread can only fail if the oracle panics.
+		handles.into_iter().filter_map(|h| h.join().ok().flatten()).next()
+	}
+}
+
+impl fmt::Debug for PermutationSearch {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("PermutationSearch")
+			.field("language", &self.language)
+			.field("word_count", &self.words.len())
+			.field("fixed_words", &self.fixed.iter().filter(|f| **f).count())
+			.field("budget", &self.budget)
+			.finish()
+	}
+}
+
+/// An iterator over the orders of the words of a [PermutationSearch] with a
+/// valid checksum.
+///
+/// Created with [PermutationSearch::candidates].
+#[derive(Clone, Debug)]
+pub struct Permutations<'a> {
+	search: &'a PermutationSearch,
+	/// The rank of the next permutation to check.
+	next: u128,
+	/// The rank after the last permutation to check.
+	end: u128,
+}
+
+impl<'a> Permutations<'a> {
+	/// Get a checkpoint to resume the search later with
+	/// [PermutationSearch::resume].
+	///
+	/// The search resumes after the last candidate returned by the iterator.
+	pub fn checkpoint(&self) -> Checkpoint {
+		Checkpoint {
+			next: self.next,
+			end: self.end,
+		}
+	}
+
+	/// Get the word indices of the permutation with the given rank, the last
+	/// free word changing the fastest.
+	///
+	/// Returns [None] if the permutation puts repeated words in another order
+	/// than the given one, since it was already checked under another rank.
+	fn permutation(&self, mut rank: u128) -> Option<[u16; MAX_NB_WORDS]> {
+		let search = self.search;
+		let mut indices = [0; MAX_NB_WORDS];
+		for (idx, word) in indices.iter_mut().zip(&search.words) {
+			*idx = *word;
+		}
+
+		// The free words in the given order, taken out as they are placed.
+		let mut remaining = search.free_positions().collect::<Vec<_>>();
+		let mut nb_left = remaining.len() as u128;
+		let mut factorial = (1..nb_left).fold(1u128, |acc, k| acc.saturating_mul(k));
+		for position in search.free_positions() {
+			let choice = (rank / factorial) as usize;
+			rank %= factorial;
+			let source = remaining.remove(choice.min(remaining.len() - 1));
+			let word = search.words.get(source).cloned().unwrap_or(0);
+			// A remaining copy of the same word that was given earlier should
+			// have been placed first.
+			if remaining.iter().any(|r| *r < source && search.words.get(*r) == Some(&word)) {
+				return None;
+			}
+			if let Some(idx) = indices.get_mut(position) {
+				*idx = word;
+			}
+			nb_left -= 1;
+			factorial /= nb_left.max(1);
+		}
+		Some(indices)
+	}
+}
+
+impl<'a> Iterator for Permutations<'a> {
+	type Item = Mnemonic;
+
+	fn next(&mut self) -> Option<Mnemonic> {
+		let nb_words = self.search.words.len();
+		while self.next < self.end {
+			let indices = self.permutation(self.next);
+			self.next += 1;
+			let indices = match indices {
+				Some(ref indices) => indices.get(..nb_words).unwrap_or(&[]),
+				None => continue,
+			};
+			if let Ok(m) = Mnemonic::from_indices_in(self.search.language, indices) {
+				return Some(m);
+			}
+		}
+		None
+	}
+}
+
+/// An estimate of the size of a recovery search.
+///
+/// All numbers saturate at [u128::MAX].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Estimate {
+	/// The number of combinations of words to check.
+	pub combinations: u128,
+	/// The expected number of candidates with a valid checksum.
+	pub candidates: u128,
+	/// The expected number of seed derivations to check all candidates with
+	/// all passphrases. With an oracle, this is the expensive part of a search.
+	pub derivations: u128,
+}
+
+impl Estimate {
+	fn new(combinations: u128, nb_words: usize, nb_passphrases: usize) -> Estimate {
+		// Every word count has a checksum of one bit per three words.
+		let candidates = combinations >> (nb_words / 3);
+		Estimate {
+			combinations,
+			candidates,
+			derivations: candidates.saturating_mul(nb_passphrases as u128),
+		}
+	}
+}
+
+/// The progress of a [Search], to pause it and resume it later.
+///
+/// A checkpoint is the range of combinations of words that remain to be
+/// checked. It is displayed and parsed as `next/end` and serialized as such a
+/// string with serde.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Checkpoint {
+	/// The rank of the next combination to check.
+	next: u128,
+	/// The rank after the last combination to check.
+	end: u128,
+}
+
+impl Checkpoint {
+	/// Get the checkpoint of the part `index` of `total` ranks split into
+	/// `count` parts of about the same size.
+	fn shard(total: u128, index: usize, count: usize) -> Checkpoint {
+		let count = count.max(1) as u128;
+		let bound = |i: u128| -> u128 {
+			if i >= count {
+				total
+			} else {
+				// Avoid overflowing for huge searches.
+				total / count * i + total % count * i / count
+			}
+		};
+		Checkpoint {
+			next: bound(index as u128),
+			end: bound(index as u128 + 1),
+		}
+	}
+
+	/// The number of combinations that remain to be checked.
+	pub fn remaining(&self) -> u128 {
+		self.end.saturating_sub(self.next)
+	}
+
+	/// Whether all combinations have been checked.
+	pub fn is_done(&self) -> bool {
+		self.remaining() == 0
+	}
+}
+
+impl fmt::Display for Checkpoint {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}/{}", self.next, self.end)
+	}
+}
+
+/// The error returned when parsing an invalid [Checkpoint].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseCheckpointError;
+
+impl fmt::Display for ParseCheckpointError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("invalid checkpoint, expected `next/end`")
+	}
+}
+
+#[cfg(feature = "std")]
+impl error::Error for ParseCheckpointError {}
+
+impl str::FromStr for Checkpoint {
+	type Err = ParseCheckpointError;
+
+	fn from_str(s: &str) -> Result<Checkpoint, ParseCheckpointError> {
+		let mut parts = s.splitn(2, '/');
+		let mut rank = || -> Result<u128, ParseCheckpointError> {
+			parts.next().and_then(|p| p.parse().ok()).ok_or(ParseCheckpointError)
+		};
+		let next = rank()?;
+		let end = rank()?;
+		if next > end {
+			return Err(ParseCheckpointError);
+		}
+		Ok(Checkpoint {
+			next,
+			end,
+		})
+	}
+}
+
+serde_string_impl!(Checkpoint, "a recovery search checkpoint");
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_transpositions() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let mut words = m.words().collect::<Vec<_>>();
+		for position in 0..words.len() - 1 {
+			if words[position] == words[position + 1] {
+				continue;
+			}
+			words.swap(position, position + 1);
+			let phrase = words.join(" ");
+			words.swap(position, position + 1);
+
+			let found = transpositions(&phrase).unwrap();
+			assert!(found.contains(&Transposition {
+				position,
+				mnemonic: m.clone(),
+			}));
+			for t in found {
+				assert_eq!(Mnemonic::parse(t.mnemonic.to_string()), Ok(t.mnemonic));
+			}
+		}
+
+		assert_eq!(transpositions("abandon abandon"), Err(Error::BadWordCount(2)));
+	}
+
+	#[test]
+	fn test_search() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let mut words = m.words().collect::<Vec<_>>();
+
+		// For 12 words, 7 bits of the last word are entropy and 4 are checksum.
+		words[11] = UNKNOWN_WORD;
+		let search = Search::from_phrase(&words.join(" ")).unwrap();
+		let candidates = search.candidates().collect::<Vec<_>>();
+		assert_eq!(candidates.len(), 128);
+		assert!(candidates.contains(&m));
+		assert_eq!(search.find(|c| c.to_entropy() == m.to_entropy()), Some(m.clone()));
+		assert_eq!(search.find(|_| false), None);
+		assert_eq!(format!("{:?}", search), "Search { language: English, word_count: 12 }");
+
+		words[11] = m.words().last().unwrap();
+		let alternatives = format!("{}|zoo", words[3]);
+		words[3] = &alternatives;
+		let search = Search::from_phrase(&words.join(" ")).unwrap();
+		assert_eq!(search.candidates().next(), Some(m));
+
+		assert_eq!(Search::from_phrase("? ? ?"), Err(Error::BadWordCount(3)));
+		words[3] = "zoo|zooo";
+		assert_eq!(Search::from_phrase(&words.join(" ")), Err(Error::UnknownWord(3)));
+	}
+
+	#[test]
+	fn test_checkpoints() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let mut words = m.words().collect::<Vec<_>>();
+		words[11] = UNKNOWN_WORD;
+		let search = Search::from_phrase(&words.join(" ")).unwrap();
+		let all = search.candidates().collect::<Vec<_>>();
+
+		// Pause after a few candidates and resume from the serialized checkpoint.
+		let mut candidates = search.candidates();
+		let first = candidates.by_ref().take(5).collect::<Vec<_>>();
+		let checkpoint = candidates.checkpoint().to_string();
+		let checkpoint = checkpoint.parse::<Checkpoint>().unwrap();
+		let rest = search.resume(checkpoint).unwrap().collect::<Vec<_>>();
+		assert_eq!([first, rest].concat(), all);
+
+		let mut candidates = search.candidates();
+		assert_eq!(candidates.by_ref().count(), all.len());
+		assert!(candidates.checkpoint().is_done());
+		assert!(search.resume("0/4096".parse().unwrap()).is_none());
+
+		// The shards cover the whole search.
+		let mut sharded = Vec::new();
+		for i in 0..3 {
+			sharded.extend(search.resume(search.shard(i, 3)).unwrap());
+		}
+		assert_eq!(sharded, all);
+		assert_eq!(search.shard(2, 3).end, 2048);
+
+		assert_eq!("1/".parse::<Checkpoint>(), Err(ParseCheckpointError));
+		assert_eq!("2/1".parse::<Checkpoint>(), Err(ParseCheckpointError));
+	}
+
+	#[test]
+	fn test_estimates() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let mut words = m.words().collect::<Vec<_>>();
+		words[11] = UNKNOWN_WORD;
+		let search = Search::from_phrase(&words.join(" ")).unwrap();
+		let estimate = search.estimate_candidates(10);
+		assert_eq!(estimate.combinations, 2048);
+		assert_eq!(estimate.candidates, search.candidates().count() as u128);
+		assert_eq!(estimate.derivations, 1280);
+
+		let search = Search::from_phrase(&["?"; 24].join(" ")).unwrap();
+		assert_eq!(search.estimate_candidates(1).combinations, u128::max_value());
+
+		assert_eq!(estimate_neighbors(24, 0, 1).combinations, 1);
+		assert_eq!(estimate_neighbors(24, 2, 1).combinations, 1 + 48 + 276 * 4);
+		assert_eq!(estimate_neighbors(12, 12, 1).combinations, 3u128.pow(12));
+	}
+
+	#[test]
+	fn test_neighbors() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let list = Language::English.word_list();
+		let mut indices = m.word_indices().map(|i| i as u16).collect::<Vec<_>>();
+		// Copy the word below in the list at one position and above at another.
+		indices[2] += 1;
+		indices[7] -= 1;
+		let phrase = indices.iter().map(|i| list[*i as usize]).collect::<Vec<_>>().join(" ");
+
+		let found = neighbors(&phrase, 1).unwrap();
+		assert!(found.iter().all(|c| c.mnemonic != m));
+		let found = neighbors(&phrase, 2).unwrap();
+		assert!(found.contains(&Candidate {
+			substitutions: 2,
+			mnemonic: m.clone(),
+		}));
+		assert!(found.windows(2).all(|w| w[0].substitutions <= w[1].substitutions));
+
+		let found = neighbors(&m.to_string(), 0).unwrap();
+		assert_eq!(
+			found,
+			vec![Candidate {
+				substitutions: 0,
+				mnemonic: m,
+			}]
+		);
+	}
+
+	#[test]
+	fn test_permutations() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let mut words = m.words().collect::<Vec<_>>();
+		words.swap(1, 4);
+		words.swap(2, 9);
+		let shuffled = words.join(" ");
+
+		// Fixing all but four words leaves 24 permutations.
+		let mut search = PermutationSearch::from_phrase(&shuffled).unwrap();
+		for position in [0, 3, 5, 6, 7, 8, 10, 11].iter() {
+			search = search.with_fixed_position(*position).unwrap();
+		}
+		assert_eq!(search.estimate_candidates(1).combinations, 24);
+		assert_eq!(search.clone().with_fixed_position(12), Err(Error::InvalidPosition(12)));
+		let debug = format!("{:?}", search);
+		assert!(debug
+			.starts_with("PermutationSearch { language: English, word_count: 12, fixed_words: 8,"));
+		let all = search.candidates().collect::<Vec<_>>();
+		assert!(all.contains(&m));
+		assert_eq!(search.find(|c| c == &m), Some(m.clone()));
+		#[cfg(feature = "std")]
+		{
+			let target = m.clone();
+			assert_eq!(search.find_parallel(3, move |c| c == &target), Some(m.clone()));
+			assert_eq!(search.find_parallel(3, |_| false), None);
+		}
+
+		// The budget stops the search early.
+		let limited = search.clone().with_budget(10);
+		let first_ten = Checkpoint {
+			next: 0,
+			end: 10,
+		};
+		let expected = search.resume(first_ten).unwrap().collect::<Vec<_>>();
+		assert_eq!(limited.candidates().collect::<Vec<_>>(), expected);
+		assert!(limited.resume(search.shard(0, 1)).is_none());
+	}
+
+	#[test]
+	fn test_permutations_repeated_words() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let mut words = m.words().collect::<Vec<_>>();
+		words[1] = words[0];
+		let search = PermutationSearch::from_phrase(&words.join(" ")).unwrap();
+		let search = (3..12).try_fold(search, |s, p| s.with_fixed_position(p)).unwrap();
+
+		// Swapping the two copies of the first word gives the same order, so
+		// only the 3 distinct orders of the first three words are checked.
+		let (a, b) = (m.words[0], m.words[2]);
+		let candidates = search.candidates();
+		let orders = (0..6)
+			.filter_map(|r| candidates.permutation(r))
+			.map(|o| [o[0], o[1], o[2]])
+			.collect::<Vec<_>>();
+		assert_eq!(orders, vec![[a, a, b], [a, b, a], [b, a, a]]);
+	}
+}
@@ -0,0 +1,438 @@
+use core::fmt;
+
+use crate::forensic::ChecksumStatus;
+use crate::{
+	bits, is_invalid_word_count, Language, Mnemonic, Suggestions, WordIndex, MAX_NB_WORDS,
+};
+
+/// The status of the checksum of the first `word_count` indices, which is only
+/// known if their number is valid and they are all known.
+fn checksum_status(
+	indices: &[u16; MAX_NB_WORDS],
+	word_count: usize,
+	all_known: bool,
+) -> ChecksumStatus {
+	let indices = match indices.get(..word_count) {
+		Some(indices) if all_known && !is_invalid_word_count(word_count) => indices,
+		_ => return ChecksumStatus::Unknown,
+	};
+	if bits::strip_checksum(indices, &mut []).is_ok() {
+		ChecksumStatus::Valid
+	} else {
+		ChecksumStatus::Invalid
+	}
+}
+
+/// The status of a word of a [ValidationReport].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordStatus {
+	/// The word is in the word list, at the given index.
+	Known(WordIndex),
+	/// The word is not in the word list, with the closest words.
+	Unknown(Suggestions),
+}
+
+impl WordStatus {
+	/// Whether the word is in the word list.
+	pub fn is_known(&self) -> bool {
+		match *self {
+			WordStatus::Known(_) => true,
+			WordStatus::Unknown(_) => false,
+		}
+	}
+}
+
+impl fmt::Debug for WordStatus {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			WordStatus::Known(_) => f.write_str("Known(..)"),
+			WordStatus::Unknown(s) => f.debug_tuple("Unknown").field(&s).finish(),
+		}
+	}
+}
+
+/// The result of checking every word of a mnemonic, for recovery screens
+/// that point out all the problems at once.
+///
+/// Created with [Mnemonic::check_in]. Only the first 24 words are checked if
+/// there are more. The [fmt::Debug] output doesn't contain the words.
+///
+/// Example:
+///
+/// ```
+/// use bip39::forensic::ChecksumStatus;
+/// use bip39::{Language, Mnemonic, WordStatus};
+///
+/// let s = "letter advice cage absurd amount d0ct0r acoustic avoid letter advice cage abuve";
+/// let report = Mnemonic::check_in(Language::English, s);
+/// assert!(!report.is_valid());
+/// assert_eq!(report.unknown_positions().collect::<Vec<_>>(), vec![5, 11]);
+/// assert_eq!(report.checksum_status(), ChecksumStatus::Unknown);
+/// match report.word(11) {
+///     Some(WordStatus::Unknown(suggestions)) => {
+///         assert_eq!(suggestions.iter().next(), Some("above"))
+///     }
+///     _ => panic!("the last word is unknown"),
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ValidationReport {
+	language: Language,
+	/// The number of words in the input.
+	word_count: usize,
+	/// The status of the first words, `None` past the end of the input.
+	words: [Option<WordStatus>; MAX_NB_WORDS],
+	checksum_status: ChecksumStatus,
+}
+
+impl ValidationReport {
+	pub(crate) fn new(language: Language, s: &str) -> ValidationReport {
+		let mut words = [None; MAX_NB_WORDS];
+		let mut indices = [0u16; MAX_NB_WORDS];
+		let mut all_known = true;
+		for ((slot, idx), word) in
+			words.iter_mut().zip(indices.iter_mut()).zip(s.split_whitespace())
+		{
+			*slot = Some(match language.find_word(word).and_then(WordIndex::new) {
+				Some(i) => {
+					*idx = i.to_u16();
+					WordStatus::Known(i)
+				}
+				None => {
+					all_known = false;
+					WordStatus::Unknown(Suggestions::new(language, word))
+				}
+			});
+		}
+
+		let word_count = s.split_whitespace().count();
+		let checksum_status = checksum_status(&indices, word_count, all_known);
+
+		ValidationReport {
+			language,
+			word_count,
+			words,
+			checksum_status,
+		}
+	}
+
+	/// The language the words were checked against.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// The number of words in the input.
+	pub fn word_count(&self) -> usize {
+		self.word_count
+	}
+
+	/// Whether the number of words is 12, 15, 18, 21 or 24.
+	pub fn is_valid_word_count(&self) -> bool {
+		!is_invalid_word_count(self.word_count)
+	}
+
+	/// The status of the word at the given zero-based position, or `None`
+	/// past the 24 first words of the input.
+	pub fn word(&self, position: usize) -> Option<WordStatus> {
+		*self.words.get(position)?
+	}
+
+	/// The status of the words, in order.
+	pub fn words(&self) -> impl Iterator<Item = WordStatus> + Clone + '_ {
+		self.words.iter().filter_map(|w| *w)
+	}
+
+	/// The positions of the unknown words, starting from 0.
+	pub fn unknown_positions(&self) -> impl Iterator<Item = usize> + Clone + '_ {
+		self.words().enumerate().filter(|(_, w)| !w.is_known()).map(|(i, _)| i)
+	}
+
+	/// The status of the checksum, which is only known if the word count is
+	/// valid and all words are known.
+	pub fn checksum_status(&self) -> ChecksumStatus {
+		self.checksum_status
+	}
+
+	/// Whether the input is a valid mnemonic.
+	pub fn is_valid(&self) -> bool {
+		self.checksum_status == ChecksumStatus::Valid
+	}
+
+	/// The mnemonic, if the input is valid.
+	pub fn to_mnemonic(&self) -> Option<Mnemonic> {
+		if !self.is_valid() {
+			return None;
+		}
+		let mut indices = [0u16; MAX_NB_WORDS];
+		for (idx, word) in indices.iter_mut().zip(self.words()) {
+			if let WordStatus::Known(i) = word {
+				*idx = i.to_u16();
+			}
+		}
+		Mnemonic::from_indices_in(self.language, indices.get(..self.word_count)?).ok()
+	}
+}
+
+impl fmt::Debug for ValidationReport {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("ValidationReport")
+			.field("language", &self.language)
+			.field("word_count", &self.word_count)
+			.field("unknown_words", &self.unknown_positions().count())
+			.field("checksum_status", &self.checksum_status)
+			.finish()
+	}
+}
+
+/// The state of a mnemonic being typed, for giving feedback on each key
+/// press.
+///
+/// Created with [Mnemonic::parse_partial_in]. The last word is being typed
+/// unless the input ends with whitespace, and then it only needs to be the
+/// start of a word. The [fmt::Debug] output doesn't contain the words.
+///
+/// Example:
+///
+/// ```
+/// use bip39::{Language, Mnemonic};
+///
+/// let partial = Mnemonic::parse_partial_in(Language::English, "letter advice ca");
+/// assert!(partial.is_typing());
+/// assert!(partial.can_be_completed());
+/// assert!(!partial.is_complete());
+///
+/// let partial = Mnemonic::parse_partial_in(Language::English, "letter advise cage");
+/// assert_eq!(partial.first_unknown_position(), Some(1));
+/// assert!(!partial.can_be_completed());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PartialParse {
+	language: Language,
+	/// The number of words in the input, the one being typed included.
+	word_count: usize,
+	/// Whether the last word is being typed.
+	typing: bool,
+	/// The position of the first word that is not in the word list, or not
+	/// the start of a word for the one being typed.
+	first_unknown: Option<usize>,
+	/// The indices of the first words.
+	indices: [u16; MAX_NB_WORDS],
+	checksum_status: ChecksumStatus,
+}
+
+impl PartialParse {
+	pub(crate) fn new(language: Language, s: &str) -> PartialParse {
+		let typing = s.chars().last().map_or(false, |c| !c.is_whitespace());
+		let word_count = s.split_whitespace().count();
+		let mut indices = [0u16; MAX_NB_WORDS];
+		let mut first_unknown = None;
+		let mut all_known = true;
+		for (i, word) in s.split_whitespace().enumerate() {
+			match language.find_word(word) {
+				Some(idx) => {
+					if let Some(slot) = indices.get_mut(i) {
+						*slot = idx;
+					}
+				}
+				None => {
+					all_known = false;
+					let is_start = typing
+						&& i + 1 == word_count
+						&& language.word_list().iter().any(|w| w.starts_with(word));
+					if !is_start && first_unknown.is_none() {
+						first_unknown = Some(i);
+					}
+				}
+			}
+		}
+
+		let checksum_status = checksum_status(&indices, word_count, all_known);
+
+		PartialParse {
+			language,
+			word_count,
+			typing,
+			first_unknown,
+			indices,
+			checksum_status,
+		}
+	}
+
+	/// The language the words were checked against.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// The number of words in the input, the one being typed included.
+	pub fn word_count(&self) -> usize {
+		self.word_count
+	}
+
+	/// Whether the last word is being typed, because the input doesn't end
+	/// with whitespace.
+	pub fn is_typing(&self) -> bool {
+		self.typing
+	}
+
+	/// The zero-based position of the first unknown word.
+	///
+	/// The word being typed is only unknown if no word starts with it.
+	pub fn first_unknown_position(&self) -> Option<usize> {
+		self.first_unknown
+	}
+
+	/// Whether the number of words can still reach 12, 15, 18, 21 or 24.
+	pub fn can_reach_valid_count(&self) -> bool {
+		self.word_count <= MAX_NB_WORDS
+	}
+
+	/// The status of the checksum of the words so far, which is only known
+	/// if their number is valid and they are all in the word list.
+	pub fn checksum_status(&self) -> ChecksumStatus {
+		self.checksum_status
+	}
+
+	/// Whether the words so far are a valid mnemonic.
+	///
+	/// The user may still want to type more words for a longer mnemonic.
+	pub fn is_complete(&self) -> bool {
+		self.checksum_status == ChecksumStatus::Valid
+	}
+
+	/// Whether the words so far can still be completed into a valid mnemonic,
+	/// by finishing the word being typed or adding words.
+	///
+	/// A valid checksum can always be reached with more words, unless there
+	/// are already 24 and the last one is finished. While it is being typed,
+	/// it may still become a longer word, like "act" becomes "action".
+	pub fn can_be_completed(&self) -> bool {
+		let final_checksum = self.word_count == MAX_NB_WORDS && !self.typing;
+		self.first_unknown.is_none()
+			&& self.can_reach_valid_count()
+			&& !(final_checksum && self.checksum_status == ChecksumStatus::Invalid)
+	}
+
+	/// The mnemonic, if the words so far are a valid mnemonic.
+	pub fn to_mnemonic(&self) -> Option<Mnemonic> {
+		if !self.is_complete() {
+			return None;
+		}
+		Mnemonic::from_indices_in(self.language, self.indices.get(..self.word_count)?).ok()
+	}
+}
+
+impl fmt::Debug for PartialParse {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("PartialParse")
+			.field("language", &self.language)
+			.field("word_count", &self.word_count)
+			.field("typing", &self.typing)
+			.field("first_unknown", &self.first_unknown)
+			.field("checksum_status", &self.checksum_status)
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validation_report() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		let s = m.to_string();
+		let report = Mnemonic::check_in(Language::English, &s);
+		assert!(report.is_valid());
+		assert!(report.is_valid_word_count());
+		assert_eq!(report.word_count(), 12);
+		assert_eq!(report.words().count(), 12);
+		assert_eq!(report.unknown_positions().count(), 0);
+		assert_eq!(report.to_mnemonic(), Some(m.clone()));
+		assert_eq!(report.word(12), None);
+		let first = m.word_indices().next().unwrap() as u16;
+		assert_eq!(report.word(0), Some(WordStatus::Known(WordIndex::new(first).unwrap())));
+		assert!(!format!("{:?}", report).contains(&format!("{}", first)));
+
+		// Swapping two words breaks the checksum.
+		let mut words = m.words().collect::<Vec<_>>();
+		words.swap(0, 1);
+		let report = Mnemonic::check_in(Language::English, &words.join(" "));
+		assert_eq!(report.checksum_status(), ChecksumStatus::Invalid);
+		assert_eq!(report.to_mnemonic(), None);
+
+		let report = Mnemonic::check_in(Language::English, &format!("{} zoo bitcoin", s));
+		assert!(!report.is_valid_word_count());
+		assert_eq!(report.unknown_positions().collect::<Vec<_>>(), vec![13]);
+		assert_eq!(
+			report.word(13),
+			Some(WordStatus::Unknown(Suggestions::new(Language::English, "bitcoin")))
+		);
+		assert_eq!(report.checksum_status(), ChecksumStatus::Unknown);
+
+		let long = "zoo ".repeat(30);
+		let report = Mnemonic::check_in(Language::English, &long);
+		assert_eq!(report.word_count(), 30);
+		assert_eq!(report.words().count(), MAX_NB_WORDS);
+		assert_eq!(report.checksum_status(), ChecksumStatus::Unknown);
+
+		let report = Mnemonic::check_in(Language::English, "");
+		assert_eq!(report.word_count(), 0);
+		assert_eq!(report.checksum_status(), ChecksumStatus::Unknown);
+	}
+
+	#[test]
+	fn test_partial_parse() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let s = m.to_string();
+
+		// Every prefix of the phrase can be completed.
+		for end in 0..=s.len() {
+			let partial = Mnemonic::parse_partial_in(Language::English, &s[..end]);
+			assert!(partial.can_be_completed(), "prefix {}", end);
+			assert_eq!(partial.first_unknown_position(), None);
+			assert_eq!(partial.is_typing(), end > 0 && &s[end - 1..end] != " ");
+		}
+		let partial = Mnemonic::parse_partial_in(Language::English, &s);
+		assert!(partial.is_complete());
+		assert_eq!(partial.to_mnemonic(), Some(m.clone()));
+
+		let partial = Mnemonic::parse_partial_in(Language::English, "");
+		assert_eq!(partial.word_count(), 0);
+		assert!(!partial.is_complete());
+
+		// An unknown word can't be completed once it is followed by a space.
+		let partial = Mnemonic::parse_partial_in(Language::English, "abandon zo");
+		assert!(partial.can_be_completed());
+		let partial = Mnemonic::parse_partial_in(Language::English, "abandon zo ");
+		assert_eq!(partial.first_unknown_position(), Some(1));
+		let partial = Mnemonic::parse_partial_in(Language::English, "abandon zx");
+		assert_eq!(partial.first_unknown_position(), Some(1));
+
+		// 24 words with an invalid checksum can't be fixed by adding words.
+		let mut words = m.words().collect::<Vec<_>>();
+		words.swap(0, 1);
+		let partial = Mnemonic::parse_partial_in(Language::English, &words.join(" "));
+		assert_eq!(partial.checksum_status(), ChecksumStatus::Invalid);
+		assert!(partial.is_typing());
+		assert!(partial.can_be_completed());
+		let partial = Mnemonic::parse_partial_in(Language::English, &(words.join(" ") + " "));
+		assert!(!partial.can_be_completed());
+
+		// The last of 24 words is a word, but may still become a longer one.
+		let mut words = words[..23].to_vec();
+		words.push("act");
+		let partial = Mnemonic::parse_partial_in(Language::English, &words.join(" "));
+		assert_eq!(partial.checksum_status(), ChecksumStatus::Invalid);
+		assert!(partial.can_be_completed());
+		let partial = Mnemonic::parse_partial_in(Language::English, &(words.join(" ") + " "));
+		assert!(!partial.can_be_completed());
+		let partial = Mnemonic::parse_partial_in(Language::English, &words[..12].join(" "));
+		assert!(partial.can_be_completed());
+
+		let long = format!("{} zoo", s);
+		let partial = Mnemonic::parse_partial_in(Language::English, &long);
+		assert!(!partial.can_reach_valid_count());
+		assert!(!partial.can_be_completed());
+		assert!(!format!("{:?}", partial).contains("zoo"));
+	}
+}
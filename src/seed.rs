@@ -0,0 +1,144 @@
+use core::{fmt, str};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{bytes_eq_ct, decode_hex, Error, Mnemonic};
+
+/// The length of a seed in bytes.
+const SEED_LEN: usize = 64;
+
+/// The 64-byte seed derived from a [Mnemonic] and a passphrase.
+///
+/// The [fmt::Display] output and the [str::FromStr] input are the seed in hex.
+/// The [fmt::Debug] output doesn't contain the seed, and the comparison takes
+/// a constant time. With the `zeroize` or `wipe` feature, the seed is erased
+/// from memory on drop.
+///
+/// Example:
+///
+/// ```
+/// use bip39::{Mnemonic, Seed};
+///
+/// let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+/// let seed = Seed::from_mnemonic_normalized(&m, "TREZOR");
+/// assert_eq!(seed.as_bytes(), &m.to_seed_normalized("TREZOR"));
+/// assert_eq!(seed.to_string().parse::<Seed>(), Ok(seed));
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct Seed([u8; SEED_LEN]);
+
+impl Seed {
+	/// Derive the seed of the mnemonic with a passphrase in normalized UTF8.
+	pub fn from_mnemonic_normalized(mnemonic: &Mnemonic, normalized_passphrase: &str) -> Seed {
+		// The seed is derived in place so that no copy of it is left behind.
+		let mut seed = Seed([0; SEED_LEN]);
+		mnemonic.to_seed_normalized_into(normalized_passphrase, &mut seed.0);
+		seed
+	}
+
+	/// Derive the seed of the mnemonic with a passphrase.
+	#[cfg(feature = "unicode-normalization")]
+	pub fn from_mnemonic<'a, P: Into<Cow<'a, str>>>(mnemonic: &Mnemonic, passphrase: P) -> Seed {
+		let mut cow = passphrase.into();
+		Mnemonic::normalize_utf8_cow(&mut cow);
+		Seed::from_mnemonic_normalized(mnemonic, cow.as_ref())
+	}
+
+	/// The seed bytes.
+	pub fn as_bytes(&self) -> &[u8; SEED_LEN] {
+		&self.0
+	}
+}
+
+impl From<[u8; SEED_LEN]> for Seed {
+	fn from(bytes: [u8; SEED_LEN]) -> Seed {
+		Seed(bytes)
+	}
+}
+
+impl AsRef<[u8]> for Seed {
+	fn as_ref(&self) -> &[u8] {
+		&self.0
+	}
+}
+
+impl PartialEq for Seed {
+	fn eq(&self, other: &Seed) -> bool {
+		bytes_eq_ct(&self.0, &other.0)
+	}
+}
+
+impl Eq for Seed {}
+
+#[cfg(all(feature = "wipe", not(feature = "zeroize")))]
+impl Drop for Seed {
+	fn drop(&mut self) {
+		crate::wipe::wipe(&mut self.0);
+	}
+}
+
+impl fmt::Debug for Seed {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("Seed(..)")
+	}
+}
+
+impl fmt::Display for Seed {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::LowerHex::fmt(self, f)
+	}
+}
+
+impl fmt::LowerHex for Seed {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for b in self.0.iter() {
+			write!(f, "{:02x}", b)?;
+		}
+		Ok(())
+	}
+}
+
+impl str::FromStr for Seed {
+	type Err = Error;
+
+	/// Parse a seed from 128 hex digits, in lower or upper case.
+	///
+	/// Returns [Error::InvalidEncoding] if the string is not a seed in hex.
+	fn from_str(s: &str) -> Result<Seed, Error> {
+		let mut seed = Seed([0; SEED_LEN]);
+		match decode_hex(s, &mut seed.0) {
+			Ok(SEED_LEN) => Ok(seed),
+			_ => Err(Error::InvalidEncoding),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_seed() {
+		let m = Mnemonic::from_entropy(&[0x42; 32]).unwrap();
+		let seed = Seed::from_mnemonic_normalized(&m, "");
+		assert_eq!(seed, Seed::from(m.to_seed_normalized("")));
+		assert_ne!(seed, Seed::from([0; 64]));
+		assert_eq!(seed.as_ref(), &m.to_seed_normalized("")[..]);
+		assert_eq!(format!("{:?}", seed), "Seed(..)");
+		#[cfg(feature = "unicode-normalization")]
+		assert_eq!(Seed::from_mnemonic(&m, "caf\u{e9}"), Seed::from(m.to_seed("caf\u{e9}")));
+
+		let hex = seed.to_string();
+		assert_eq!(hex.len(), 128);
+		assert_eq!(hex.parse::<Seed>(), Ok(seed.clone()));
+		assert_eq!(hex.to_uppercase().parse::<Seed>(), Ok(seed));
+		assert_eq!(hex[1..].parse::<Seed>(), Err(Error::InvalidEncoding));
+		assert_eq!(hex.replacen(&hex[..1], "g", 1).parse::<Seed>(), Err(Error::InvalidEncoding));
+		assert_eq!(format!("+{}", &hex[1..]).parse::<Seed>(), Err(Error::InvalidEncoding));
+	}
+}
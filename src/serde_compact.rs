@@ -0,0 +1,178 @@
+//! Compact serde representation of mnemonics, as the code of the language and
+//! the entropy, for binary formats.
+//!
+//! By default, a [Mnemonic] is serialized as its phrase. With this module, it
+//! is serialized as a tuple of the language code of [Language::to_u8] and the
+//! entropy bytes instead, which is smaller and doesn't store the words. The
+//! phrase is reconstructed when deserializing.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::Mnemonic;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Wallet {
+//!     #[serde(with = "bip39::serde_compact")]
+//!     mnemonic: Mnemonic,
+//! }
+//!
+//! let wallet = Wallet { mnemonic: Mnemonic::from_entropy(&[0; 16]).unwrap() };
+//! let json = serde_json::to_string(&wallet).unwrap();
+//! assert_eq!(json, r#"{"mnemonic":[0,[0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]]}"#);
+//! let decoded: Wallet = serde_json::from_str(&json).unwrap();
+//! assert_eq!(decoded.mnemonic, wallet.mnemonic);
+//! ```
+
+use core::fmt;
+
+use serde::de::{self, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserializer, Serializer};
+
+use crate::{Language, Mnemonic};
+
+/// Serialize the mnemonic as its language code and entropy.
+pub fn serialize<S: Serializer>(mnemonic: &Mnemonic, serializer: S) -> Result<S::Ok, S::Error> {
+	let (entropy, len) = mnemonic.to_entropy_array();
+	let mut tuple = serializer.serialize_tuple(2)?;
+	tuple.serialize_element(&mnemonic.language().to_u8())?;
+	tuple.serialize_element(&Entropy(entropy.get(..len).unwrap_or_default()))?;
+	tuple.end()
+}
+
+/// Deserialize a mnemonic from its language code and entropy.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Mnemonic, D::Error> {
+	struct CompactVisitor;
+
+	impl<'de> Visitor<'de> for CompactVisitor {
+		type Value = Mnemonic;
+
+		fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			f.write_str("a language code and entropy")
+		}
+
+		fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Mnemonic, A::Error> {
+			let language =
+				seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+			let entropy = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+			mnemonic(language, entropy)
+		}
+	}
+
+	deserializer.deserialize_tuple(2, CompactVisitor)
+}
+
+/// Create the mnemonic from its deserialized language code and entropy.
+pub(crate) fn mnemonic<E: de::Error>(language: u8, entropy: EntropyBuf) -> Result<Mnemonic, E> {
+	let language = Language::from_u8(language).ok_or_else(|| {
+		de::Error::invalid_value(de::Unexpected::Unsigned(language as u64), &"a language code")
+	})?;
+	let entropy = entropy.0.get(..entropy.1).unwrap_or_default();
+	Mnemonic::from_entropy_in(language, entropy).map_err(E::custom)
+}
+
+/// The entropy, serialized as bytes.
+pub(crate) struct Entropy<'a>(pub(crate) &'a [u8]);
+
+impl<'a> serde::Serialize for Entropy<'a> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_bytes(self.0)
+	}
+}
+
+/// The deserialized entropy, accepting bytes or a sequence of numbers.
+pub(crate) struct EntropyBuf([u8; 32], usize);
+
+impl<'de> serde::Deserialize<'de> for EntropyBuf {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<EntropyBuf, D::Error> {
+		struct EntropyVisitor;
+
+		impl<'de> Visitor<'de> for EntropyVisitor {
+			type Value = EntropyBuf;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("at most 32 bytes of entropy")
+			}
+
+			fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<EntropyBuf, E> {
+				let mut buf = [0; 32];
+				buf.get_mut(..v.len())
+					.ok_or_else(|| E::invalid_length(v.len(), &self))?
+					.copy_from_slice(v);
+				Ok(EntropyBuf(buf, v.len()))
+			}
+
+			fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<EntropyBuf, A::Error> {
+				let mut buf = [0; 32];
+				let mut len = 0;
+				while let Some(b) = seq.next_element()? {
+					*buf.get_mut(len).ok_or_else(|| de::Error::invalid_length(len, &self))? = b;
+					len += 1;
+				}
+				Ok(EntropyBuf(buf, len))
+			}
+		}
+
+		deserializer.deserialize_bytes(EntropyVisitor)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+	#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+	#[serde(transparent)]
+	struct Compact(#[serde(with = "super")] Mnemonic);
+
+	#[test]
+	fn test_tokens() {
+		let m = Mnemonic::from_entropy(&[0x42; 16]).unwrap();
+		assert_tokens(
+			&Compact(m),
+			&[
+				Token::Tuple {
+					len: 2,
+				},
+				Token::U8(0),
+				Token::Bytes(&[0x42; 16]),
+				Token::TupleEnd,
+			],
+		);
+
+		assert_de_tokens_error::<Compact>(
+			&[
+				Token::Tuple {
+					len: 2,
+				},
+				Token::U8(0xff),
+				Token::Bytes(&[0; 16]),
+				Token::TupleEnd,
+			],
+			"invalid value: integer `255`, expected a language code",
+		);
+		assert_de_tokens_error::<Compact>(
+			&[
+				Token::Tuple {
+					len: 2,
+				},
+				Token::U8(0),
+				Token::Bytes(&[0; 33]),
+			],
+			"invalid length 33, expected at most 32 bytes of entropy",
+		);
+	}
+
+	#[test]
+	fn test_json_round_trip() {
+		for entropy in &[&[0u8; 16][..], &[0xff; 20], &[0x42; 32]] {
+			let m = Compact(Mnemonic::from_entropy(entropy).unwrap());
+			let json = serde_json::to_string(&m).unwrap();
+			assert_eq!(serde_json::from_str::<Compact>(&json).unwrap(), m);
+		}
+		assert!(serde_json::from_str::<Compact>("[0,[0,0,0]]").is_err());
+	}
+}
@@ -0,0 +1,155 @@
+// The edit distance indexes its fixed-size rows with bounded positions.
+#![allow(clippy::indexing_slicing)]
+
+use core::fmt;
+
+use crate::Language;
+
+/// The maximum number of suggestions for an unknown word.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The maximum edit distance of a suggestion from the unknown word.
+const MAX_DISTANCE: usize = 2;
+
+/// The maximum number of characters of a word of a word list the edit distance
+/// is computed for. The longest words have 11 characters.
+const MAX_WORD_CHARS: usize = 16;
+
+/// The closest words of the word list to an unknown word, for "did you mean"
+/// messages.
+///
+/// These are the words at most 2 edits away from the unknown word, closest
+/// first, as returned by [Language::suggestions] and carried by
+/// [ValidationReport](crate::ValidationReport). They reveal the unknown word,
+/// which is likely a mistyped word of a secret mnemonic, so the [fmt::Debug]
+/// output only shows their number.
+///
+/// Example:
+///
+/// ```
+/// use bip39::{Error, Language, Mnemonic};
+///
+/// let s = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abuot";
+/// assert_eq!(Mnemonic::parse(s), Err(Error::UnknownWord(11)));
+/// let word = s.split_whitespace().nth(11).unwrap();
+/// let suggestions = Language::English.suggestions(word);
+/// assert_eq!(suggestions.iter().next(), Some("about"));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Suggestions {
+	words: [&'static str; MAX_SUGGESTIONS],
+	len: usize,
+}
+
+impl Suggestions {
+	/// Find the closest words to the unknown word in the word list of the
+	/// language.
+	pub(crate) fn new(language: Language, word: &str) -> Suggestions {
+		let mut best = [(usize::max_value(), ""); MAX_SUGGESTIONS];
+		for candidate in language.word_list().iter() {
+			let distance = match distance(word, candidate) {
+				Some(d) => d,
+				None => continue,
+			};
+			// Keep the best ones sorted by distance, the earlier words of the
+			// word list first in case of a tie.
+			if let Some(pos) = best.iter().position(|(d, _)| distance < *d) {
+				for i in (pos + 1..MAX_SUGGESTIONS).rev() {
+					best[i] = best[i - 1];
+				}
+				best[pos] = (distance, candidate);
+			}
+		}
+
+		let mut suggestions = Suggestions::default();
+		for (slot, (_, word)) in suggestions.words.iter_mut().zip(best.iter()) {
+			if word.is_empty() {
+				break;
+			}
+			*slot = word;
+			suggestions.len += 1;
+		}
+		suggestions
+	}
+
+	/// The suggested words, closest first.
+	pub fn iter(&self) -> impl Iterator<Item = &'static str> + Clone + '_ {
+		self.words.iter().take(self.len).cloned()
+	}
+
+	/// The number of suggested words.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Whether no word is close to the unknown word.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+}
+
+impl fmt::Debug for Suggestions {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Suggestions").field("len", &self.len).finish()
+	}
+}
+
+/// The Levenshtein distance between the word and a word of a word list, in
+/// characters, or `None` if it is more than [MAX_DISTANCE].
+fn distance(word: &str, candidate: &str) -> Option<usize> {
+	let nb_chars = candidate.chars().count();
+	if nb_chars >= MAX_WORD_CHARS {
+		return None;
+	}
+
+	// The distances between the prefix of the word read so far and all the
+	// prefixes of the candidate.
+	let mut row = [0usize; MAX_WORD_CHARS];
+	for (j, d) in row.iter_mut().enumerate() {
+		*d = j;
+	}
+	for (i, c) in word.chars().enumerate() {
+		let mut diagonal = row[0];
+		row[0] = i + 1;
+		let mut min = row[0];
+		for (j, cc) in candidate.chars().enumerate() {
+			let substitution = diagonal + (c != cc) as usize;
+			diagonal = row[j + 1];
+			row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+			min = min.min(row[j + 1]);
+		}
+		if min > MAX_DISTANCE {
+			return None;
+		}
+	}
+
+	Some(row[nb_chars]).filter(|d| *d <= MAX_DISTANCE)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_distance() {
+		assert_eq!(distance("about", "about"), Some(0));
+		assert_eq!(distance("abuot", "about"), Some(2));
+		assert_eq!(distance("abot", "about"), Some(1));
+		assert_eq!(distance("abouts", "about"), Some(1));
+		assert_eq!(distance("", "act"), None);
+		assert_eq!(distance("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzz", "zoo"), None);
+	}
+
+	#[test]
+	fn test_suggestions() {
+		let suggestions = Suggestions::new(Language::English, "acoustik");
+		assert_eq!(suggestions.iter().collect::<Vec<_>>(), vec!["acoustic"]);
+		assert_eq!(format!("{:?}", suggestions), "Suggestions { len: 1 }");
+
+		let suggestions = Suggestions::new(Language::English, "zo");
+		assert_eq!(suggestions.iter().collect::<Vec<_>>(), vec!["zoo", "box", "boy"]);
+		assert_eq!(suggestions.len(), MAX_SUGGESTIONS);
+
+		assert!(Suggestions::new(Language::English, "bitcoin").is_empty());
+	}
+}
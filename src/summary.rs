@@ -0,0 +1,92 @@
+use core::fmt;
+
+use bitcoin_hashes::{hmac, sha256, Hash, HashEngine};
+
+use crate::{Language, Mnemonic};
+
+/// The key used to compute the fingerprint of a mnemonic.
+const FINGERPRINT_KEY: &[u8] = b"BIP39/summary/fingerprint";
+
+/// A summary of a [Mnemonic] that doesn't contain any secrets, for use in logs.
+///
+/// Created with [Mnemonic::summary].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Summary {
+	/// The language of the mnemonic.
+	pub language: Language,
+	/// The number of words of the mnemonic.
+	pub word_count: usize,
+	/// The number of bits of entropy of the mnemonic.
+	pub entropy_bits: usize,
+	/// A fingerprint identifying the mnemonic.
+	///
+	/// This is a truncated keyed hash of the entropy, unrelated to the BIP32
+	/// fingerprint and independent of the passphrase. Being only 32 bits, it
+	/// doesn't weaken a mnemonic with at least 128 bits of entropy in practice.
+	pub fingerprint: u32,
+}
+
+impl Summary {
+	pub(crate) fn new(mnemonic: &Mnemonic) -> Summary {
+		let (entropy, len) = mnemonic.to_entropy_array();
+		let mut engine = hmac::HmacEngine::<sha256::Hash>::new(FINGERPRINT_KEY);
+		engine.input(entropy.get(..len).unwrap_or(&[]));
+		let hash = hmac::Hmac::from_engine(engine).to_byte_array();
+
+		Summary {
+			language: mnemonic.language(),
+			word_count: mnemonic.word_count(),
+			entropy_bits: len * 8,
+			fingerprint: u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]),
+		}
+	}
+}
+
+impl fmt::Display for Summary {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"{} mnemonic with {} words ({} bits), fingerprint {:08x}",
+			self.language, self.word_count, self.entropy_bits, self.fingerprint,
+		)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Summary {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		use serde::ser::SerializeStruct;
+
+		let mut s = serializer.serialize_struct("Summary", 4)?;
+		s.serialize_field("language", &format_args!("{}", self.language))?;
+		s.serialize_field("word_count", &self.word_count)?;
+		s.serialize_field("entropy_bits", &self.entropy_bits)?;
+		s.serialize_field("fingerprint", &format_args!("{:08x}", self.fingerprint))?;
+		s.end()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_summary() {
+		let m = Mnemonic::from_entropy(&[0x7f; 16]).unwrap();
+		let summary = m.summary();
+		assert_eq!(summary.language, Language::English);
+		assert_eq!(summary.word_count, 12);
+		assert_eq!(summary.entropy_bits, 128);
+		assert_eq!(summary, Mnemonic::from_entropy(&[0x7f; 16]).unwrap().summary());
+		assert_ne!(
+			summary.fingerprint,
+			Mnemonic::from_entropy(&[0x7e; 16]).unwrap().summary().fingerprint
+		);
+
+		let s = summary.to_string();
+		assert!(s.starts_with("English mnemonic with 12 words (128 bits), fingerprint "));
+		for word in m.words() {
+			assert!(!s.contains(word));
+		}
+	}
+}
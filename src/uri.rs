@@ -0,0 +1,228 @@
+//! `bip39:` URIs, to transfer mnemonics between airgapped tools by QR code or
+//! NFC.
+//!
+//! The format is:
+//!
+//! ```text
+//! bip39:<word>-<word>-...-<word>[?<key>=<value>[&<key>=<value>]...]
+//! ```
+//!
+//! - The scheme is case-insensitive.
+//! - The words are separated by `-` and percent-encoded as UTF-8: all bytes
+//!   except ASCII letters, digits, `.`, `_` and `~` are written as `%XX`.
+//!   Decoded words are normalized like [Mnemonic::parse].
+//! - The `lang` parameter is the language of the words, one of `en`, `zh-Hans`,
+//!   `zh-Hant`, `cs`, `fr`, `it`, `ja`, `ko`, `pt` and `es`. It is always
+//!   written, and detected from the words when it is missing.
+//! - Other parameters are metadata, like a label, kept in order. Keys and
+//!   values are percent-encoded like the words.
+//!
+//! Example:
+//!
+//! ```
+//! use bip39::{uri::Uri, Mnemonic};
+//!
+//! let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+//! let uri = Uri::new(m.clone()).with_metadata("label", "cold storage");
+//! let s = uri.to_string();
+//! assert_eq!(s, "bip39:abandon-abandon-abandon-abandon-abandon-abandon-abandon-abandon-\
+//!     abandon-abandon-abandon-about?lang=en&label=cold%20storage");
+//!
+//! let parsed = s.parse::<Uri>().unwrap();
+//! assert_eq!(parsed.mnemonic(), &m);
+//! assert_eq!(parsed.metadata().collect::<Vec<_>>(), vec![("label", "cold storage")]);
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::{fmt, str};
+
+use crate::language::LANGUAGE_TAGS;
+use crate::{Error, Language, Mnemonic};
+
+/// The scheme of the URIs.
+pub const SCHEME: &str = "bip39";
+
+/// The separator of the words.
+const WORD_SEPARATOR: char = '-';
+
+/// A `bip39:` URI holding a mnemonic and metadata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Uri {
+	mnemonic: Mnemonic,
+	/// The metadata keys and values, not percent-encoded.
+	metadata: Vec<(String, String)>,
+}
+
+impl Uri {
+	/// Create a URI for the mnemonic, without metadata.
+	pub fn new(mnemonic: Mnemonic) -> Uri {
+		Uri {
+			mnemonic,
+			metadata: Vec::new(),
+		}
+	}
+
+	/// Add a metadata parameter. The `lang` key is reserved and ignored.
+	pub fn with_metadata(mut self, key: &str, value: &str) -> Uri {
+		if key != "lang" {
+			self.metadata.push((key.into(), value.into()));
+		}
+		self
+	}
+
+	/// The mnemonic.
+	pub fn mnemonic(&self) -> &Mnemonic {
+		&self.mnemonic
+	}
+
+	/// Get the mnemonic, dropping the metadata.
+	pub fn into_mnemonic(self) -> Mnemonic {
+		self.mnemonic
+	}
+
+	/// The metadata keys and values, in order.
+	pub fn metadata(&self) -> impl Iterator<Item = (&str, &str)> + Clone + '_ {
+		self.metadata.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+	}
+}
+
+/// Percent-encode the string.
+fn encode(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+	for b in s.bytes() {
+		match b {
+			b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'.' | b'_' | b'~' => {
+				write!(f, "{}", b as char)?
+			}
+			_ => write!(f, "%{:02X}", b)?,
+		}
+	}
+	Ok(())
+}
+
+/// Decode a percent-encoded string.
+fn decode(s: &str) -> Result<String, Error> {
+	let mut bytes = Vec::with_capacity(s.len());
+	let mut iter = s.bytes();
+	while let Some(b) = iter.next() {
+		if b == b'%' {
+			let hex = [iter.next(), iter.next()];
+			let digits = match hex {
+				[Some(hi), Some(lo)] => [hi, lo],
+				_ => return Err(Error::InvalidEncoding),
+			};
+			let digits = str::from_utf8(&digits).map_err(|_| Error::InvalidEncoding)?;
+			bytes.push(u8::from_str_radix(digits, 16).map_err(|_| Error::InvalidEncoding)?);
+		} else {
+			bytes.push(b);
+		}
+	}
+	String::from_utf8(bytes).map_err(|_| Error::InvalidEncoding)
+}
+
+impl fmt::Display for Uri {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}:", SCHEME)?;
+		for (i, word) in self.mnemonic.words().enumerate() {
+			if i > 0 {
+				write!(f, "{}", WORD_SEPARATOR)?;
+			}
+			encode(f, word)?;
+		}
+		write!(f, "?lang={}", self.mnemonic.language().tag())?;
+		for (key, value) in &self.metadata {
+			f.write_str("&")?;
+			encode(f, key)?;
+			f.write_str("=")?;
+			encode(f, value)?;
+		}
+		Ok(())
+	}
+}
+
+impl str::FromStr for Uri {
+	type Err = Error;
+
+	/// Parse a URI, see the [module documentation](self) for the format.
+	///
+	/// Returns [Error::InvalidEncoding] if the URI is malformed and
+	/// [Error::UnknownLanguage] if the language is not enabled.
+	fn from_str(s: &str) -> Result<Uri, Error> {
+		let rest = match s.get(..SCHEME.len() + 1) {
+			Some(scheme) if scheme.eq_ignore_ascii_case("bip39:") => &s[SCHEME.len() + 1..],
+			_ => return Err(Error::InvalidEncoding),
+		};
+		let mut parts = rest.splitn(2, '?');
+		let payload = parts.next().unwrap_or("");
+
+		let mut language = None;
+		let mut metadata = Vec::new();
+		for param in parts.next().into_iter().flat_map(|q| q.split('&')) {
+			let mut kv = param.splitn(2, '=');
+			let key = decode(kv.next().unwrap_or(""))?;
+			let value = decode(kv.next().ok_or(Error::InvalidEncoding)?)?;
+			if key == "lang" {
+				let code = LANGUAGE_TAGS
+					.iter()
+					.position(|t| t.eq_ignore_ascii_case(&value))
+					.ok_or(Error::InvalidEncoding)? as u8;
+				language = Some(Language::from_u8(code).ok_or(Error::UnknownLanguage(code))?);
+			} else {
+				metadata.push((key, value));
+			}
+		}
+
+		let mut phrase = String::with_capacity(payload.len());
+		for (i, word) in payload.split(WORD_SEPARATOR).enumerate() {
+			if i > 0 {
+				phrase.push(' ');
+			}
+			phrase.push_str(&decode(word)?);
+		}
+		let mnemonic = match language {
+			Some(language) => Mnemonic::parse_in(language, phrase)?,
+			None => Mnemonic::parse(phrase)?,
+		};
+		Ok(Uri {
+			mnemonic,
+			metadata,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_roundtrip() {
+		for lang in Language::ALL {
+			let m = Mnemonic::from_entropy_in(*lang, &[0x42; 32]).unwrap();
+			let uri = Uri::new(m.clone()).with_metadata("a&b=c", "100%").with_metadata("lang", "x");
+			let s = uri.to_string();
+			assert!(s.is_ascii());
+			assert_eq!(s.parse::<Uri>(), Ok(uri.clone()));
+			assert_eq!(uri.metadata().collect::<Vec<_>>(), vec![("a&b=c", "100%")]);
+			assert_eq!(s.parse::<Uri>().unwrap().into_mnemonic(), m);
+		}
+	}
+
+	#[test]
+	fn test_parse() {
+		let m = Mnemonic::from_entropy(&[0; 16]).unwrap();
+		let words = m.words().collect::<Vec<_>>().join("-");
+
+		// The language is detected if missing and the scheme is case-insensitive.
+		let uri = format!("BIP39:{}", words).parse::<Uri>().unwrap();
+		assert_eq!(uri, Uri::new(m.clone()));
+		let uri = format!("bip39:{}?label=%E2%82%BF&lang=EN", words).parse::<Uri>().unwrap();
+		assert_eq!(uri.metadata().collect::<Vec<_>>(), vec![("label", "\u{20bf}")]);
+
+		assert_eq!(format!("bitcoin:{}", words).parse::<Uri>(), Err(Error::InvalidEncoding));
+		assert_eq!(format!("bip39:{}?lang=xx", words).parse::<Uri>(), Err(Error::InvalidEncoding));
+		assert_eq!(format!("bip39:{}?label", words).parse::<Uri>(), Err(Error::InvalidEncoding));
+		assert_eq!(format!("bip39:{}?a=%4", words).parse::<Uri>(), Err(Error::InvalidEncoding));
+		assert_eq!(format!("bip39:{}?a=%FF", words).parse::<Uri>(), Err(Error::InvalidEncoding));
+		assert_eq!("bip39:abandon-zoo".parse::<Uri>(), Err(Error::BadWordCount(2)));
+	}
+}
@@ -0,0 +1,33 @@
+//! Best-effort wiping of secrets from memory without the `zeroize`
+//! dependency, used for the intermediate buffers with both the `wipe` and the
+//! `zeroize` features.
+//!
+//! The values are overwritten with volatile writes followed by a compiler
+//! fence, so the compiler can't remove the writes as dead stores. Copies the
+//! compiler made earlier, like in registers or on the stack, are not wiped.
+
+use core::ptr;
+use core::sync::atomic::{self, Ordering};
+
+/// Overwrite the values with their default.
+pub(crate) fn wipe<T: Copy + Default>(values: &mut [T]) {
+	for value in values.iter_mut() {
+		// SAFETY: the pointer comes from a mutable reference, so it is valid
+		// and aligned, and nothing needs to be dropped for a Copy type.
+		unsafe { ptr::write_volatile(value, T::default()) };
+	}
+	atomic::compiler_fence(Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_wipe() {
+		let mut buf = [0x42u8; 64];
+		wipe(&mut buf[..32]);
+		assert_eq!(&buf[..32], &[0; 32][..]);
+		assert_eq!(&buf[32..], &[0x42; 32][..]);
+	}
+}
@@ -0,0 +1,149 @@
+use core::fmt;
+
+use crate::Language;
+
+/// The number of words in a word list.
+const NB_WORDS: u16 = 2048;
+
+/// The zero-based index of a word in a word list, from 0 to 2047.
+///
+/// Word lists printed on backup plates often number the words from 1, use
+/// [WordIndex::from_one_based] and [WordIndex::to_one_based] for those.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WordIndex(u16);
+
+impl WordIndex {
+	/// The largest word index.
+	pub const MAX: WordIndex = WordIndex(NB_WORDS - 1);
+
+	/// Create a word index from a zero-based index.
+	///
+	/// Returns `None` if the index is out of range.
+	pub fn new(index: u16) -> Option<WordIndex> {
+		if index < NB_WORDS {
+			Some(WordIndex(index))
+		} else {
+			None
+		}
+	}
+
+	/// Create a word index from a one-based number, from 1 to 2048.
+	///
+	/// Returns `None` if the number is out of range.
+	pub fn from_one_based(number: u16) -> Option<WordIndex> {
+		WordIndex::new(number.checked_sub(1)?)
+	}
+
+	/// The zero-based index.
+	pub fn to_u16(self) -> u16 {
+		self.0
+	}
+
+	/// The one-based number, from 1 to 2048.
+	pub fn to_one_based(self) -> u16 {
+		self.0 + 1
+	}
+}
+
+impl From<WordIndex> for u16 {
+	fn from(index: WordIndex) -> u16 {
+		index.0
+	}
+}
+
+impl From<WordIndex> for usize {
+	fn from(index: WordIndex) -> usize {
+		index.0 as usize
+	}
+}
+
+impl fmt::Display for WordIndex {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)
+	}
+}
+
+/// A word of the word list of a language.
+///
+/// Example:
+///
+/// ```
+/// use bip39::{Language, Word, WordIndex};
+///
+/// let word = Word::find_in(Language::English, "zoo").unwrap();
+/// assert_eq!(word.index(), WordIndex::MAX);
+/// assert_eq!(word.to_string(), "zoo");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Word {
+	language: Language,
+	index: WordIndex,
+}
+
+impl Word {
+	/// Get the word at the index in the word list of the language.
+	pub fn new(language: Language, index: WordIndex) -> Word {
+		Word {
+			language,
+			index,
+		}
+	}
+
+	/// Find a normalized word in the word list of the language.
+	pub fn find_in(language: Language, word: &str) -> Option<Word> {
+		let index = WordIndex::new(language.find_word(word)?)?;
+		Some(Word::new(language, index))
+	}
+
+	/// The language of the word list.
+	pub fn language(&self) -> Language {
+		self.language
+	}
+
+	/// The index of the word in the word list.
+	pub fn index(&self) -> WordIndex {
+		self.index
+	}
+
+	/// The word as a string.
+	pub fn as_str(&self) -> &'static str {
+		self.language.word_list().get(usize::from(self.index)).copied().unwrap_or("")
+	}
+}
+
+impl AsRef<str> for Word {
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl fmt::Display for Word {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_word_index() {
+		assert_eq!(WordIndex::new(2047), Some(WordIndex::MAX));
+		assert_eq!(WordIndex::new(2048), None);
+		assert_eq!(WordIndex::from_one_based(0), None);
+		assert_eq!(WordIndex::from_one_based(1).map(u16::from), Some(0));
+		assert_eq!(WordIndex::from_one_based(2048), Some(WordIndex::MAX));
+		assert_eq!(WordIndex::from_one_based(2049), None);
+		assert_eq!(WordIndex::MAX.to_one_based(), 2048);
+		assert_eq!(WordIndex::MAX.to_string(), "2047");
+	}
+
+	#[test]
+	fn test_word() {
+		let word = Word::new(Language::English, WordIndex::new(0).unwrap());
+		assert_eq!(word.as_str(), "abandon");
+		assert_eq!(Word::find_in(Language::English, "abandon"), Some(word));
+		assert_eq!(Word::find_in(Language::English, "abandons"), None);
+	}
+}
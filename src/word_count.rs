@@ -0,0 +1,99 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::Error;
+
+/// A number of words of a standard mnemonic.
+///
+/// Unlike a `usize`, it can't hold an unsupported word count, so
+/// [Mnemonic::generate_sized_in](crate::Mnemonic::generate_sized_in) can't fail.
+///
+/// Example:
+///
+/// ```
+/// use bip39::{Error, WordCount};
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(WordCount::try_from(18), Ok(WordCount::Words18));
+/// assert_eq!(WordCount::try_from(13), Err(Error::BadWordCount(13)));
+/// assert_eq!(WordCount::Words24.entropy_bytes(), 32);
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WordCount {
+	/// 12 words, 128 bits of entropy.
+	Words12,
+	/// 15 words, 160 bits of entropy.
+	Words15,
+	/// 18 words, 192 bits of entropy.
+	Words18,
+	/// 21 words, 224 bits of entropy.
+	Words21,
+	/// 24 words, 256 bits of entropy.
+	Words24,
+}
+
+impl WordCount {
+	/// The number of words.
+	pub fn word_count(self) -> usize {
+		match self {
+			WordCount::Words12 => 12,
+			WordCount::Words15 => 15,
+			WordCount::Words18 => 18,
+			WordCount::Words21 => 21,
+			WordCount::Words24 => 24,
+		}
+	}
+
+	/// The number of bytes of entropy.
+	pub fn entropy_bytes(self) -> usize {
+		self.word_count() / 3 * 4
+	}
+}
+
+impl TryFrom<usize> for WordCount {
+	type Error = Error;
+
+	/// Returns [Error::BadWordCount] if the word count is not supported.
+	fn try_from(word_count: usize) -> Result<WordCount, Error> {
+		match word_count {
+			12 => Ok(WordCount::Words12),
+			15 => Ok(WordCount::Words15),
+			18 => Ok(WordCount::Words18),
+			21 => Ok(WordCount::Words21),
+			24 => Ok(WordCount::Words24),
+			_ => Err(Error::BadWordCount(word_count)),
+		}
+	}
+}
+
+impl From<WordCount> for usize {
+	fn from(word_count: WordCount) -> usize {
+		word_count.word_count()
+	}
+}
+
+impl fmt::Display for WordCount {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(&self.word_count(), f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_word_count() {
+		for n in 0..30 {
+			match WordCount::try_from(n) {
+				Ok(count) => {
+					assert_eq!(usize::from(count), n);
+					assert_eq!(count.entropy_bytes() * 8, n / 3 * 32);
+					assert_eq!(count.to_string(), n.to_string());
+				}
+				Err(e) => assert_eq!(e, Error::BadWordCount(n)),
+			}
+		}
+		assert!(WordCount::Words12 < WordCount::Words24);
+	}
+}